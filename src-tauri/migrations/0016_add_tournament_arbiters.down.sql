@@ -0,0 +1,3 @@
+-- Remove arbiter information from tournament_settings table
+ALTER TABLE tournament_settings DROP COLUMN deputy_arbiters;
+ALTER TABLE tournament_settings DROP COLUMN chief_arbiter;
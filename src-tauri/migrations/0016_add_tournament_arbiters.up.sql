@@ -0,0 +1,3 @@
+-- Add arbiter information required by the FIDE tournament report (TRF-16 lines 102/112)
+ALTER TABLE tournament_settings ADD COLUMN chief_arbiter TEXT;
+ALTER TABLE tournament_settings ADD COLUMN deputy_arbiters TEXT; -- Comma separated list of deputy chief arbiters
@@ -0,0 +1,8 @@
+-- Explanation of each generated pairing, stored with the games of the round
+CREATE TABLE IF NOT EXISTS pairing_explanations (
+    game_id INTEGER PRIMARY KEY,
+    board_number INTEGER NOT NULL,
+    explanation TEXT NOT NULL, -- JSON encoded PairingExplanation
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+);
@@ -0,0 +1,10 @@
+-- Restore the original pairing methods, mapping the others to 'swiss'
+ALTER TABLE tournaments ADD COLUMN pairing_method_old TEXT DEFAULT 'manual' CHECK (pairing_method_old IN ('manual', 'swiss', 'round_robin'));
+
+UPDATE tournaments SET pairing_method_old = CASE
+    WHEN pairing_method IN ('manual', 'swiss', 'round_robin') THEN pairing_method
+    ELSE 'swiss'
+END;
+
+ALTER TABLE tournaments DROP COLUMN pairing_method;
+ALTER TABLE tournaments RENAME COLUMN pairing_method_old TO pairing_method;
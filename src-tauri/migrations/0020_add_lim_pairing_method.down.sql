@@ -0,0 +1,19 @@
+-- Remove the Lim System, mapping its tournaments to 'swiss'
+ALTER TABLE tournaments ADD COLUMN pairing_method_old TEXT DEFAULT 'manual' CHECK (pairing_method_old IN (
+    'manual',
+    'swiss',
+    'dutch_optimal',
+    'burstein',
+    'dubov',
+    'round_robin',
+    'knockout',
+    'scheveningen'
+));
+
+UPDATE tournaments SET pairing_method_old = CASE
+    WHEN pairing_method = 'lim' THEN 'swiss'
+    ELSE pairing_method
+END;
+
+ALTER TABLE tournaments DROP COLUMN pairing_method;
+ALTER TABLE tournaments RENAME COLUMN pairing_method_old TO pairing_method;
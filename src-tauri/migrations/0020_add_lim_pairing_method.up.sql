@@ -0,0 +1,19 @@
+-- Allow the Lim System in tournaments.pairing_method
+-- Note: SQLite can't alter a CHECK constraint, so the column is replaced
+
+ALTER TABLE tournaments ADD COLUMN pairing_method_new TEXT DEFAULT 'manual' CHECK (pairing_method_new IN (
+    'manual',
+    'swiss',
+    'dutch_optimal',
+    'burstein',
+    'dubov',
+    'lim',
+    'round_robin',
+    'knockout',
+    'scheveningen'
+));
+
+UPDATE tournaments SET pairing_method_new = COALESCE(pairing_method, 'manual');
+
+ALTER TABLE tournaments DROP COLUMN pairing_method;
+ALTER TABLE tournaments RENAME COLUMN pairing_method_new TO pairing_method;
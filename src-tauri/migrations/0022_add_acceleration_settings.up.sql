@@ -0,0 +1,12 @@
+-- Baku acceleration (FIDE C.04.5) configuration of Swiss tournaments
+CREATE TABLE IF NOT EXISTS tournament_acceleration_settings (
+    tournament_id INTEGER PRIMARY KEY,
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    accelerated_rounds INTEGER NOT NULL DEFAULT 2, -- Rounds paired with virtual points
+    group_boundary TEXT NOT NULL DEFAULT 'starting_rank' CHECK (group_boundary IN ('starting_rank', 'rating')),
+    group_a_min_rating INTEGER, -- Lowest rating in group A when the boundary is 'rating'
+    virtual_points_first_half REAL NOT NULL DEFAULT 1.0, -- Group A bonus in the first half of the accelerated rounds
+    virtual_points_second_half REAL NOT NULL DEFAULT 0.5, -- Group A bonus in the second half of the accelerated rounds
+    updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (tournament_id) REFERENCES tournaments(id) ON DELETE CASCADE
+);
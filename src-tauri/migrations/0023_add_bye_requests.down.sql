@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS tournament_bye_settings;
+DROP INDEX IF EXISTS idx_bye_requests_tournament_round;
+DROP TABLE IF EXISTS bye_requests;
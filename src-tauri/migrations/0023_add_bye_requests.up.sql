@@ -0,0 +1,22 @@
+-- Byes requested by players for future rounds
+CREATE TABLE IF NOT EXISTS bye_requests (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    tournament_id INTEGER NOT NULL,
+    player_id INTEGER NOT NULL,
+    round_number INTEGER NOT NULL,
+    bye_points REAL NOT NULL CHECK (bye_points IN (0, 0.5, 1)),
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (tournament_id) REFERENCES tournaments(id) ON DELETE CASCADE,
+    FOREIGN KEY (player_id) REFERENCES players(id) ON DELETE CASCADE,
+    UNIQUE(player_id, round_number)
+);
+
+CREATE INDEX IF NOT EXISTS idx_bye_requests_tournament_round ON bye_requests(tournament_id, round_number);
+
+-- Limit on the byes a player may request during a tournament
+CREATE TABLE IF NOT EXISTS tournament_bye_settings (
+    tournament_id INTEGER PRIMARY KEY,
+    max_requested_byes INTEGER NOT NULL DEFAULT 2,
+    updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (tournament_id) REFERENCES tournaments(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_forbidden_pairings_tournament;
+DROP TABLE IF EXISTS forbidden_pairings;
@@ -0,0 +1,13 @@
+-- Players (pairs or groups) who must not be paired against each other
+CREATE TABLE IF NOT EXISTS forbidden_pairings (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    tournament_id INTEGER NOT NULL,
+    player_ids TEXT NOT NULL, -- JSON array of player ids
+    reason TEXT NOT NULL DEFAULT '',
+    first_round INTEGER, -- NULL = from the first round
+    last_round INTEGER, -- NULL = until the last round
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (tournament_id) REFERENCES tournaments(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_forbidden_pairings_tournament ON forbidden_pairings(tournament_id);
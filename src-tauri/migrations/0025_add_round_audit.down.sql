@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_round_audit_round_id;
+DROP TABLE IF EXISTS round_audit;
@@ -0,0 +1,12 @@
+-- Changes made to a round after its pairings were published
+CREATE TABLE IF NOT EXISTS round_audit (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    round_id INTEGER NOT NULL,
+    action TEXT NOT NULL, -- e.g. 'partial_repairing'
+    details TEXT NOT NULL,
+    reason TEXT,
+    changed_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (round_id) REFERENCES rounds(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_round_audit_round_id ON round_audit(round_id);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_pairing_drafts_round_id;
+DROP TABLE IF EXISTS pairing_drafts;
@@ -0,0 +1,13 @@
+-- Candidate pairings generated for a round before one is published
+CREATE TABLE IF NOT EXISTS pairing_drafts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    round_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    pairing_method TEXT NOT NULL,
+    avoid_same_club BOOLEAN NOT NULL DEFAULT FALSE,
+    pairings TEXT NOT NULL, -- JSON encoded list of Pairing
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (round_id) REFERENCES rounds(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_pairing_drafts_round_id ON pairing_drafts(round_id);
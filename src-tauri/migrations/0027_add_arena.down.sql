@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_arena_games_tournament;
+DROP TABLE IF EXISTS arena_games;
+DROP TABLE IF EXISTS arena_waiting_players;
+DROP TABLE IF EXISTS arena_settings;
@@ -0,0 +1,36 @@
+-- Arena tournaments: players are paired again as soon as they finish, until a deadline
+CREATE TABLE IF NOT EXISTS arena_settings (
+    tournament_id INTEGER PRIMARY KEY,
+    ends_at TEXT NOT NULL, -- RFC 3339 deadline, nobody is paired after it
+    berserk_allowed BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (tournament_id) REFERENCES tournaments(id) ON DELETE CASCADE
+);
+
+-- Players waiting for their next arena game
+CREATE TABLE IF NOT EXISTS arena_waiting_players (
+    tournament_id INTEGER NOT NULL,
+    player_id INTEGER NOT NULL,
+    waiting_since TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (tournament_id, player_id),
+    FOREIGN KEY (tournament_id) REFERENCES tournaments(id) ON DELETE CASCADE,
+    FOREIGN KEY (player_id) REFERENCES players(id) ON DELETE CASCADE
+);
+
+-- Arena games are not grouped into rounds
+CREATE TABLE IF NOT EXISTS arena_games (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    tournament_id INTEGER NOT NULL,
+    white_player_id INTEGER NOT NULL,
+    black_player_id INTEGER NOT NULL,
+    result TEXT NOT NULL DEFAULT '*',
+    white_berserk BOOLEAN NOT NULL DEFAULT FALSE,
+    black_berserk BOOLEAN NOT NULL DEFAULT FALSE,
+    started_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    finished_at TEXT, -- NULL while the game is being played
+    FOREIGN KEY (tournament_id) REFERENCES tournaments(id) ON DELETE CASCADE,
+    FOREIGN KEY (white_player_id) REFERENCES players(id) ON DELETE CASCADE,
+    FOREIGN KEY (black_player_id) REFERENCES players(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_arena_games_tournament ON arena_games(tournament_id);
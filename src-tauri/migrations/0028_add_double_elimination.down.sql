@@ -0,0 +1,8 @@
+-- Remove double elimination, dropping the losers-bracket and grand-final positions
+DELETE FROM bracket_positions WHERE bracket_side != 'winners';
+
+DROP INDEX IF EXISTS idx_bracket_positions_unique;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_bracket_positions_unique ON bracket_positions(bracket_id, round_number, position_number);
+
+ALTER TABLE knockout_brackets DROP COLUMN bracket_reset;
+ALTER TABLE bracket_positions DROP COLUMN bracket_side;
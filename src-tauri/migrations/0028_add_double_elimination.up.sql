@@ -0,0 +1,12 @@
+-- Double elimination: positions belong to the winners bracket, the losers
+-- bracket or the grand final, and a bracket may replay its grand final
+ALTER TABLE bracket_positions ADD COLUMN bracket_side TEXT NOT NULL DEFAULT 'winners' CHECK (bracket_side IN (
+    'winners',
+    'losers',
+    'grand_final'
+));
+
+ALTER TABLE knockout_brackets ADD COLUMN bracket_reset BOOLEAN NOT NULL DEFAULT FALSE;
+
+DROP INDEX IF EXISTS idx_bracket_positions_unique;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_bracket_positions_unique ON bracket_positions(bracket_id, bracket_side, round_number, position_number);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_knockout_match_games_round;
+DROP TABLE IF EXISTS knockout_match_games;
+DROP TABLE IF EXISTS knockout_match_formats;
@@ -0,0 +1,43 @@
+-- Mini-match formats of knockout rounds: a number of games, tiebreak stages
+-- and finally an Armageddon game
+CREATE TABLE IF NOT EXISTS knockout_match_formats (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    bracket_id INTEGER NOT NULL,
+    bracket_side TEXT NOT NULL DEFAULT 'winners',
+    round_number INTEGER NOT NULL,
+    games INTEGER NOT NULL DEFAULT 2,
+    time_control_id INTEGER,
+    tiebreak_stages TEXT NOT NULL DEFAULT '[]', -- JSON array of {games, time_control_id}
+    armageddon_time_control_id INTEGER,
+    armageddon_color TEXT NOT NULL DEFAULT 'choice' CHECK (armageddon_color IN ('choice', 'bidding')),
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (bracket_id) REFERENCES knockout_brackets(id) ON DELETE CASCADE,
+    FOREIGN KEY (time_control_id) REFERENCES time_controls(id) ON DELETE SET NULL,
+    FOREIGN KEY (armageddon_time_control_id) REFERENCES time_controls(id) ON DELETE SET NULL,
+    UNIQUE (bracket_id, bracket_side, round_number)
+);
+
+-- Games of the mini-matches, by stage: 0 = main games, then the tiebreak
+-- stages, the Armageddon game last
+CREATE TABLE IF NOT EXISTS knockout_match_games (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    bracket_id INTEGER NOT NULL,
+    bracket_side TEXT NOT NULL DEFAULT 'winners',
+    round_number INTEGER NOT NULL,
+    match_number INTEGER NOT NULL,
+    stage INTEGER NOT NULL,
+    game_number INTEGER NOT NULL,
+    white_player_id INTEGER NOT NULL,
+    black_player_id INTEGER NOT NULL,
+    result TEXT NOT NULL DEFAULT '*',
+    time_control_id INTEGER,
+    black_time_minutes INTEGER, -- Winning Armageddon bid
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (bracket_id) REFERENCES knockout_brackets(id) ON DELETE CASCADE,
+    FOREIGN KEY (white_player_id) REFERENCES players(id) ON DELETE CASCADE,
+    FOREIGN KEY (black_player_id) REFERENCES players(id) ON DELETE CASCADE,
+    FOREIGN KEY (time_control_id) REFERENCES time_controls(id) ON DELETE SET NULL,
+    UNIQUE (bracket_id, bracket_side, round_number, match_number, stage, game_number)
+);
+
+CREATE INDEX IF NOT EXISTS idx_knockout_match_games_round ON knockout_match_games(bracket_id, bracket_side, round_number);
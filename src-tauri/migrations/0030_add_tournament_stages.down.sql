@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_tournament_stages_tournament;
+DROP TABLE IF EXISTS tournament_stage_groups;
+DROP TABLE IF EXISTS tournament_stages;
@@ -0,0 +1,29 @@
+-- Multi-stage events: ordered stages, each played as one or more tournaments
+CREATE TABLE IF NOT EXISTS tournament_stages (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    tournament_id INTEGER NOT NULL, -- The event the stages belong to
+    stage_number INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    tournament_type TEXT NOT NULL,
+    rounds INTEGER, -- NULL derives the rounds from the type and the qualifiers
+    qualification TEXT NOT NULL DEFAULT 'top_n' CHECK (qualification IN ('top_n', 'group_winners')),
+    qualifiers INTEGER NOT NULL DEFAULT 8, -- Total for top_n, per group for group_winners
+    seeding TEXT NOT NULL DEFAULT 'qualifier_rank' CHECK (seeding IN ('qualifier_rank', 'rating')),
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (tournament_id, stage_number),
+    FOREIGN KEY (tournament_id) REFERENCES tournaments(id) ON DELETE CASCADE
+);
+
+-- Tournaments played in a stage, one per group
+CREATE TABLE IF NOT EXISTS tournament_stage_groups (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    stage_id INTEGER NOT NULL,
+    group_number INTEGER NOT NULL,
+    group_tournament_id INTEGER NOT NULL UNIQUE,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (stage_id, group_number),
+    FOREIGN KEY (stage_id) REFERENCES tournament_stages(id) ON DELETE CASCADE,
+    FOREIGN KEY (group_tournament_id) REFERENCES tournaments(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_tournament_stages_tournament ON tournament_stages(tournament_id);
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_event_registrations_event;
+DROP INDEX IF EXISTS idx_event_sections_event;
+DROP TABLE IF EXISTS event_registrations;
+DROP TABLE IF EXISTS event_sections;
+DROP TABLE IF EXISTS events;
@@ -0,0 +1,39 @@
+-- Events group several section tournaments behind one registration desk
+CREATE TABLE IF NOT EXISTS events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    location TEXT NOT NULL,
+    date TEXT NOT NULL,
+    country_code TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Section tournaments of an event with their rating eligibility
+CREATE TABLE IF NOT EXISTS event_sections (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_id INTEGER NOT NULL,
+    tournament_id INTEGER NOT NULL UNIQUE,
+    name TEXT NOT NULL, -- e.g. "Open", "U2000"
+    min_rating INTEGER,
+    max_rating INTEGER, -- Inclusive, e.g. 1999 for an U2000 section
+    allow_unrated BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (event_id, name),
+    FOREIGN KEY (event_id) REFERENCES events(id) ON DELETE CASCADE,
+    FOREIGN KEY (tournament_id) REFERENCES tournaments(id) ON DELETE CASCADE
+);
+
+-- Entries taken at the event desk, each playing in one section
+CREATE TABLE IF NOT EXISTS event_registrations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_id INTEGER NOT NULL,
+    section_id INTEGER NOT NULL,
+    player_id INTEGER NOT NULL UNIQUE, -- Player of the section tournament
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (event_id) REFERENCES events(id) ON DELETE CASCADE,
+    FOREIGN KEY (section_id) REFERENCES event_sections(id) ON DELETE CASCADE,
+    FOREIGN KEY (player_id) REFERENCES players(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_event_sections_event ON event_sections(event_id);
+CREATE INDEX IF NOT EXISTS idx_event_registrations_event ON event_registrations(event_id);
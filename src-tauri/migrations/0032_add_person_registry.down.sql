@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_person_rating_history_person;
+DROP INDEX IF EXISTS idx_persons_name;
+DROP INDEX IF EXISTS idx_players_person;
+DROP TABLE IF EXISTS person_rating_history;
+ALTER TABLE players DROP COLUMN person_id;
+DROP TABLE IF EXISTS persons;
@@ -0,0 +1,38 @@
+-- Registry of people shared by all tournaments
+CREATE TABLE IF NOT EXISTS persons (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    fide_id TEXT UNIQUE,
+    national_id TEXT,
+    federation TEXT, -- Country code
+    birth_date TEXT,
+    gender TEXT,
+    title TEXT,
+    fide_rating INTEGER,
+    national_rating INTEGER,
+    rapid_rating INTEGER,
+    blitz_rating INTEGER,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TEXT
+);
+
+-- Tournament players link to the person they are; NULL for players entered by hand
+ALTER TABLE players ADD COLUMN person_id INTEGER;
+
+-- Ratings of a person over time, whichever tournament recorded them
+CREATE TABLE IF NOT EXISTS person_rating_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    person_id INTEGER NOT NULL,
+    rating_type TEXT NOT NULL CHECK (rating_type IN ('fide', 'national', 'club', 'rapid', 'blitz')),
+    rating INTEGER NOT NULL CHECK (rating >= 0 AND rating <= 4000),
+    is_provisional BOOLEAN NOT NULL DEFAULT FALSE,
+    effective_date TEXT NOT NULL,
+    tournament_id INTEGER, -- Tournament the rating was recorded in, if any
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (person_id) REFERENCES persons(id) ON DELETE CASCADE,
+    FOREIGN KEY (tournament_id) REFERENCES tournaments(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_players_person ON players(person_id);
+CREATE INDEX IF NOT EXISTS idx_persons_name ON persons(name);
+CREATE INDEX IF NOT EXISTS idx_person_rating_history_person ON person_rating_history(person_id, rating_type, effective_date);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_fide_ratings_name;
+DROP TABLE IF EXISTS fide_ratings;
+DROP TABLE IF EXISTS fide_rating_lists;
@@ -0,0 +1,27 @@
+-- Official FIDE rating lists loaded from the downloadable TXT/XML files
+CREATE TABLE IF NOT EXISTS fide_rating_lists (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    list_date TEXT NOT NULL UNIQUE, -- YYYY-MM-DD the list is valid from
+    source_file TEXT NOT NULL,
+    player_count INTEGER NOT NULL DEFAULT 0,
+    imported_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- One row per player and list; the standard, rapid and blitz files of a month share a list
+CREATE TABLE IF NOT EXISTS fide_ratings (
+    list_id INTEGER NOT NULL,
+    fide_id INTEGER NOT NULL,
+    name TEXT NOT NULL COLLATE NOCASE, -- "Surname, Firstname" as published by FIDE
+    federation TEXT,
+    sex TEXT,
+    title TEXT,
+    birth_year INTEGER,
+    standard_rating INTEGER,
+    rapid_rating INTEGER,
+    blitz_rating INTEGER,
+    flag TEXT, -- i for inactive, w for women, wi for both
+    PRIMARY KEY (list_id, fide_id),
+    FOREIGN KEY (list_id) REFERENCES fide_rating_lists(id) ON DELETE CASCADE
+) WITHOUT ROWID;
+
+CREATE INDEX IF NOT EXISTS idx_fide_ratings_name ON fide_ratings(list_id, name);
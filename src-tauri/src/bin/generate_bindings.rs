@@ -39,6 +39,7 @@ fn main() {
             tournament::get_tournament_settings,
             tournament::update_tournament_settings,
             tournament::update_tournament_status,
+            tournament::import_tournament_trf,
             // Round operations
             round::get_rounds_by_tournament,
             round::get_current_round,
@@ -55,6 +56,7 @@ fn main() {
             round::analyze_swiss_pairings,
             round::analyze_round_robin_pairings,
             round::validate_pairing_configuration,
+            round::check_tournament_pairings,
             round::benchmark_pairing_performance,
             // Game result operations
             game_result::update_game_result,
@@ -0,0 +1,217 @@
+use crate::pawn::{
+    common::{error::PawnError, types::CommandResult},
+    db::Db,
+    domain::{
+        dto::{ArenaResultOutcome, ArenaStanding, RecordArenaResult, UpdateArenaSettings},
+        model::{ArenaGame, ArenaSettings, ArenaWaitingPlayer},
+    },
+    service::arena::ArenaService,
+    state::PawnState,
+};
+use tauri::{State, command};
+
+#[command]
+#[specta::specta]
+pub async fn get_arena_settings(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+) -> CommandResult<Option<ArenaSettings>> {
+    let db = &*state.db;
+    let settings = db.get_arena_settings(tournament_id).await?;
+    Ok(settings)
+}
+
+#[command]
+#[specta::specta]
+pub async fn update_arena_settings(
+    state: State<'_, PawnState>,
+    data: UpdateArenaSettings,
+) -> CommandResult<ArenaSettings> {
+    let db = &*state.db;
+
+    let tournament = db.get_tournament(data.tournament_id).await?;
+    if !ArenaService::is_arena(tournament.tournament_type.as_deref()) {
+        return Err(PawnError::InvalidInput(format!(
+            "{} is not an arena tournament",
+            tournament.name
+        )));
+    }
+    ArenaService::validate_settings(&data)?;
+
+    let settings = db.upsert_arena_settings(&data).await?;
+    Ok(settings)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_arena_waiting_players(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+) -> CommandResult<Vec<ArenaWaitingPlayer>> {
+    let db = &*state.db;
+    let waiting = db.get_arena_waiting_players(tournament_id).await?;
+    Ok(waiting)
+}
+
+#[command]
+#[specta::specta]
+pub async fn join_arena(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+    player_id: i32,
+) -> CommandResult<Vec<ArenaGame>> {
+    let db = &*state.db;
+
+    let settings = running_arena(db, tournament_id).await?;
+    let player = db.get_player(player_id).await?;
+    if player.tournament_id != tournament_id {
+        return Err(PawnError::InvalidInput(format!(
+            "Player {player_id} is not registered in tournament {tournament_id}"
+        )));
+    }
+    let games = db.get_arena_games(tournament_id).await?;
+    if games.iter().any(|game| {
+        game.finished_at.is_none()
+            && (game.white_player_id == player_id || game.black_player_id == player_id)
+    }) {
+        return Err(PawnError::InvalidInput(format!(
+            "{} is still playing",
+            player.name
+        )));
+    }
+
+    db.add_arena_waiting_player(tournament_id, player_id)
+        .await?;
+    pair_waiting_players(db, &settings).await
+}
+
+#[command]
+#[specta::specta]
+pub async fn leave_arena(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+    player_id: i32,
+) -> CommandResult<()> {
+    let db = &*state.db;
+    db.remove_arena_waiting_player(tournament_id, player_id)
+        .await?;
+    Ok(())
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_arena_games(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+) -> CommandResult<Vec<ArenaGame>> {
+    let db = &*state.db;
+    let games = db.get_arena_games(tournament_id).await?;
+    Ok(games)
+}
+
+#[command]
+#[specta::specta]
+pub async fn record_arena_result(
+    state: State<'_, PawnState>,
+    data: RecordArenaResult,
+) -> CommandResult<ArenaResultOutcome> {
+    let db = &*state.db;
+
+    let game = db.get_arena_game(data.game_id).await?;
+    if game.finished_at.is_some() {
+        return Err(PawnError::InvalidInput(format!(
+            "Arena game {} already has a result",
+            game.id
+        )));
+    }
+    ArenaService::parse_result(&data.result)?;
+
+    let settings = db
+        .get_arena_settings(game.tournament_id)
+        .await?
+        .ok_or_else(|| {
+            PawnError::NotFound(format!(
+                "Arena settings of tournament {}",
+                game.tournament_id
+            ))
+        })?;
+    if (data.white_berserk || data.black_berserk) && !settings.berserk_allowed {
+        return Err(PawnError::InvalidInput(
+            "Berserk is not allowed in this arena".to_string(),
+        ));
+    }
+
+    let game = db.finish_arena_game(&data).await?;
+
+    // Both players are paired again straight away until the deadline
+    let new_games = if ArenaService::has_ended(&settings, chrono::Utc::now())? {
+        Vec::new()
+    } else {
+        for player_id in [game.white_player_id, game.black_player_id] {
+            db.add_arena_waiting_player(game.tournament_id, player_id)
+                .await?;
+        }
+        pair_waiting_players(db, &settings).await?
+    };
+
+    Ok(ArenaResultOutcome { game, new_games })
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_arena_standings(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+) -> CommandResult<Vec<ArenaStanding>> {
+    let db = &*state.db;
+    let players = db.get_players_by_tournament(tournament_id).await?;
+    let games = db.get_arena_games(tournament_id).await?;
+    Ok(ArenaService::calculate_standings(&players, &games))
+}
+
+/// Settings of an arena that has not reached its deadline yet
+async fn running_arena<D: Db>(db: &D, tournament_id: i32) -> CommandResult<ArenaSettings> {
+    let settings = db.get_arena_settings(tournament_id).await?.ok_or_else(|| {
+        PawnError::NotFound(format!("Arena settings of tournament {tournament_id}"))
+    })?;
+    if ArenaService::has_ended(&settings, chrono::Utc::now())? {
+        return Err(PawnError::InvalidInput(format!(
+            "The arena ended at {}",
+            settings.ends_at
+        )));
+    }
+    Ok(settings)
+}
+
+/// Start games for the players waiting in the pool
+async fn pair_waiting_players<D: Db>(
+    db: &D,
+    settings: &ArenaSettings,
+) -> CommandResult<Vec<ArenaGame>> {
+    let tournament_id = settings.tournament_id;
+    let players = db.get_players_by_tournament(tournament_id).await?;
+    let games = db.get_arena_games(tournament_id).await?;
+    let waiting: Vec<_> = db
+        .get_arena_waiting_players(tournament_id)
+        .await?
+        .into_iter()
+        .filter_map(|waiting| {
+            players
+                .iter()
+                .find(|player| player.id == waiting.player_id)
+                .cloned()
+        })
+        .collect();
+
+    let standings = ArenaService::calculate_standings(&players, &games);
+    let mut new_games = Vec::new();
+    for (white_player_id, black_player_id) in
+        ArenaService::pair_waiting_players(&waiting, &standings, &games)
+    {
+        new_games.push(
+            db.start_arena_game(tournament_id, white_player_id, black_player_id)
+                .await?,
+        );
+    }
+    Ok(new_games)
+}
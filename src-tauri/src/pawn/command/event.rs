@@ -0,0 +1,204 @@
+use crate::pawn::{
+    common::{error::PawnError, types::CommandResult},
+    db::Db,
+    domain::{
+        dto::{
+            CreateEvent, CreateEventSection, CreatePlayer, EventEntry, MoveEventPlayer,
+            RegisterEventPlayer,
+        },
+        model::{Event, EventRegistration, EventSection},
+    },
+    service::event::EventService,
+    state::PawnState,
+};
+use tauri::{State, command};
+
+#[command]
+#[specta::specta]
+pub async fn get_events(state: State<'_, PawnState>) -> CommandResult<Vec<Event>> {
+    let db = &*state.db;
+    let events = db.get_events().await?;
+    Ok(events)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_event(state: State<'_, PawnState>, event_id: i32) -> CommandResult<Event> {
+    let db = &*state.db;
+    let event = db.get_event(event_id).await?;
+    Ok(event)
+}
+
+#[command]
+#[specta::specta]
+pub async fn create_event(state: State<'_, PawnState>, data: CreateEvent) -> CommandResult<Event> {
+    let db = &*state.db;
+    EventService::validate_event(&data)?;
+    let event = db.create_event(&data).await?;
+    Ok(event)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_event_sections(
+    state: State<'_, PawnState>,
+    event_id: i32,
+) -> CommandResult<Vec<EventSection>> {
+    let db = &*state.db;
+    let sections = db.get_event_sections(event_id).await?;
+    Ok(sections)
+}
+
+#[command]
+#[specta::specta]
+pub async fn add_event_section(
+    state: State<'_, PawnState>,
+    data: CreateEventSection,
+) -> CommandResult<EventSection> {
+    let db = &*state.db;
+
+    db.get_event(data.event_id).await?;
+    db.get_tournament(data.tournament_id).await?;
+    let sections = db.get_event_sections(data.event_id).await?;
+    EventService::validate_section(&data, &sections)?;
+
+    let section = db.create_event_section(&data).await?;
+    Ok(section)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_eligible_event_sections(
+    state: State<'_, PawnState>,
+    event_id: i32,
+    rating: Option<i32>,
+) -> CommandResult<Vec<EventSection>> {
+    let db = &*state.db;
+    let sections = db.get_event_sections(event_id).await?;
+    Ok(EventService::eligible_sections(&sections, rating))
+}
+
+#[command]
+#[specta::specta]
+pub async fn register_event_player(
+    state: State<'_, PawnState>,
+    data: RegisterEventPlayer,
+) -> CommandResult<EventEntry> {
+    let db = &*state.db;
+
+    let section = event_section(db, data.event_id, data.section_id).await?;
+    if data.name.trim().is_empty() {
+        return Err(PawnError::InvalidInput("A player needs a name".to_string()));
+    }
+    EventService::check_eligibility(&section, data.rating)?;
+
+    let player = CreatePlayer {
+        tournament_id: section.tournament_id,
+        name: data.name,
+        rating: data.rating,
+        country_code: data.country_code,
+        title: data.title,
+        birth_date: data.birth_date,
+        gender: data.gender,
+        email: data.email,
+        phone: data.phone,
+        club: data.club,
+    };
+    let registration = db.create_event_registration(&section, player).await?;
+    event_entry(db, registration, &section).await
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_event_entries(
+    state: State<'_, PawnState>,
+    event_id: i32,
+) -> CommandResult<Vec<EventEntry>> {
+    let db = &*state.db;
+
+    let sections = db.get_event_sections(event_id).await?;
+    let mut players = Vec::new();
+    for section in &sections {
+        players.extend(db.get_players_by_tournament(section.tournament_id).await?);
+    }
+
+    let entries = db
+        .get_event_registrations(event_id)
+        .await?
+        .into_iter()
+        .filter_map(|registration| {
+            let section = sections.iter().find(|s| s.id == registration.section_id)?;
+            let player = players.iter().find(|p| p.id == registration.player_id)?;
+            Some(EventEntry {
+                section_name: section.name.clone(),
+                player: player.clone(),
+                registration,
+            })
+        })
+        .collect();
+    Ok(entries)
+}
+
+#[command]
+#[specta::specta]
+pub async fn move_event_player(
+    state: State<'_, PawnState>,
+    data: MoveEventPlayer,
+) -> CommandResult<EventEntry> {
+    let db = &*state.db;
+
+    let registration = db.get_event_registration(data.registration_id).await?;
+    let from = event_section(db, registration.event_id, registration.section_id).await?;
+    let to = event_section(db, registration.event_id, data.section_id).await?;
+    if from.id == to.id {
+        return event_entry(db, registration, &to).await;
+    }
+
+    // Players only change sections before either section has paired a round
+    for section in [&from, &to] {
+        if !db
+            .get_rounds_by_tournament(section.tournament_id)
+            .await?
+            .is_empty()
+        {
+            return Err(PawnError::BusinessLogic(format!(
+                "{} has already started",
+                section.name
+            )));
+        }
+    }
+    let player = db.get_player(registration.player_id).await?;
+    EventService::check_eligibility(&to, player.rating)?;
+
+    let registration = db.move_event_registration(registration.id, &to).await?;
+    event_entry(db, registration, &to).await
+}
+
+/// Section of the event, rejecting sections of other events
+async fn event_section<D: Db>(
+    db: &D,
+    event_id: i32,
+    section_id: i32,
+) -> CommandResult<EventSection> {
+    let section = db.get_event_section(section_id).await?;
+    if section.event_id != event_id {
+        return Err(PawnError::InvalidInput(format!(
+            "{} is not a section of event {event_id}",
+            section.name
+        )));
+    }
+    Ok(section)
+}
+
+async fn event_entry<D: Db>(
+    db: &D,
+    registration: EventRegistration,
+    section: &EventSection,
+) -> CommandResult<EventEntry> {
+    let player = db.get_player(registration.player_id).await?;
+    Ok(EventEntry {
+        registration,
+        section_name: section.name.clone(),
+        player,
+    })
+}
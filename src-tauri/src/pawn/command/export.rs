@@ -4,7 +4,7 @@ use tracing::{info, instrument};
 use crate::pawn::{
     common::error::PawnError,
     db::Db,
-    domain::tiebreak::{ExportRequest, ExportResult},
+    domain::tiebreak::{EventExportRequest, ExportRequest, ExportResult},
     state::PawnState,
 };
 
@@ -20,6 +20,18 @@ pub async fn export_tournament_data(
     state.export_service.export_tournament_data(request).await
 }
 
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn export_event_data(
+    state: State<'_, PawnState>,
+    request: EventExportRequest,
+) -> Result<ExportResult, PawnError> {
+    info!("Exporting event data: {:?}", request);
+
+    state.export_service.export_event_data(request).await
+}
+
 #[instrument(ret, skip(state))]
 #[tauri::command]
 #[specta::specta]
@@ -45,6 +57,7 @@ pub async fn get_available_export_formats() -> Result<Vec<String>, PawnError> {
         "txt".to_string(),
         "pdf".to_string(),
         "xlsx".to_string(),
+        "trf".to_string(),
     ];
 
     Ok(formats)
@@ -113,6 +126,36 @@ pub async fn validate_export_request(
                 ));
             }
         }
+        crate::pawn::domain::tiebreak::ExportType::Pairings => {
+            let games = state
+                .db
+                .get_games_by_tournament(request.tournament_id)
+                .await?;
+            if games.is_empty() {
+                return Err(PawnError::ValidationError(
+                    "No pairings found for pairings export".to_string(),
+                ));
+            }
+        }
+        crate::pawn::domain::tiebreak::ExportType::Arena => {
+            let games = state.db.get_arena_games(request.tournament_id).await?;
+            if games.is_empty() {
+                return Err(PawnError::ValidationError(
+                    "No arena games found for arena export".to_string(),
+                ));
+            }
+        }
+        crate::pawn::domain::tiebreak::ExportType::RatingReport => {
+            let games = state
+                .db
+                .get_games_by_tournament(request.tournament_id)
+                .await?;
+            if games.is_empty() {
+                return Err(PawnError::ValidationError(
+                    "No games found for rating report export".to_string(),
+                ));
+            }
+        }
         _ => {} // Other types are always valid if tournament exists
     }
 
@@ -278,13 +321,14 @@ mod tests {
         let result = get_available_export_formats().await;
         assert!(result.is_ok());
         let formats = result.unwrap();
-        assert_eq!(formats.len(), 6);
+        assert_eq!(formats.len(), 7);
         assert!(formats.contains(&"csv".to_string()));
         assert!(formats.contains(&"json".to_string()));
         assert!(formats.contains(&"html".to_string()));
         assert!(formats.contains(&"txt".to_string()));
         assert!(formats.contains(&"pdf".to_string()));
         assert!(formats.contains(&"xlsx".to_string()));
+        assert!(formats.contains(&"trf".to_string()));
     }
 
     #[tokio::test]
@@ -429,6 +473,7 @@ mod tests {
             ExportFormat::Txt,
             ExportFormat::Pdf,
             ExportFormat::Xlsx,
+            ExportFormat::Trf,
         ];
 
         for format in formats {
@@ -458,6 +503,9 @@ mod tests {
             ExportType::GameResults,
             ExportType::PlayerList,
             ExportType::TournamentSummary,
+            ExportType::Pairings,
+            ExportType::Arena,
+            ExportType::RatingReport,
             ExportType::Complete,
         ];
 
@@ -521,13 +569,14 @@ mod tests {
         let result = get_available_export_formats().await;
         assert!(result.is_ok());
         let formats = result.unwrap();
-        assert_eq!(formats.len(), 6);
+        assert_eq!(formats.len(), 7);
         assert!(formats.contains(&"csv".to_string()));
         assert!(formats.contains(&"json".to_string()));
         assert!(formats.contains(&"html".to_string()));
         assert!(formats.contains(&"txt".to_string()));
         assert!(formats.contains(&"pdf".to_string()));
         assert!(formats.contains(&"xlsx".to_string()));
+        assert!(formats.contains(&"trf".to_string()));
     }
 
     #[tokio::test]
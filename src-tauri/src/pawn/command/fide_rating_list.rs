@@ -0,0 +1,201 @@
+use crate::pawn::{
+    command::registry::check_not_registered,
+    common::{error::PawnError, types::CommandResult},
+    db::Db,
+    domain::{
+        dto::{
+            FideRatingListImport, FideRatingRefresh, ImportFideRatingList,
+            ImportFideRatingListRequest, PlayerRatingUpdate, RegisterFidePlayer,
+        },
+        model::{FideRating, FideRatingList, Player},
+    },
+    service::fide_rating_list::{
+        file_rating_type, list_rating, match_by_name, name_variants, parse_fide_rating_list,
+        person_data, person_update, tournament_rating_type,
+    },
+    state::PawnState,
+};
+use std::{fs::File, io::BufReader, path::PathBuf};
+use tauri::{State, command};
+
+#[command]
+#[specta::specta]
+pub async fn import_fide_rating_list(
+    state: State<'_, PawnState>,
+    data: ImportFideRatingListRequest,
+) -> CommandResult<FideRatingListImport> {
+    let db = &*state.db;
+
+    chrono::NaiveDate::parse_from_str(&data.list_date, "%Y-%m-%d").map_err(|_| {
+        PawnError::InvalidInput(format!("{} is not a YYYY-MM-DD date", data.list_date))
+    })?;
+    let path = PathBuf::from(&data.file_path);
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let rating_type = file_rating_type(&file_name);
+    // The full list runs to hundreds of thousands of lines, so keep it off the async runtime
+    let parsed = tokio::task::spawn_blocking(move || {
+        let file = File::open(&path).map_err(PawnError::Io)?;
+        parse_fide_rating_list(BufReader::new(file), rating_type)
+    })
+    .await
+    .map_err(|e| PawnError::Io(e.into()))??;
+
+    let mut import = db
+        .import_fide_rating_list(ImportFideRatingList {
+            list_date: data.list_date,
+            source_file: file_name,
+            ratings: parsed.ratings,
+        })
+        .await?;
+    import.players_skipped = parsed.skipped;
+    import.warnings = parsed.warnings;
+    Ok(import)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_fide_rating_lists(
+    state: State<'_, PawnState>,
+) -> CommandResult<Vec<FideRatingList>> {
+    let db = &*state.db;
+    let lists = db.get_fide_rating_lists().await?;
+    Ok(lists)
+}
+
+#[command]
+#[specta::specta]
+pub async fn search_fide_ratings(
+    state: State<'_, PawnState>,
+    query: String,
+    list_date: Option<String>,
+) -> CommandResult<Vec<FideRating>> {
+    let db = &*state.db;
+    let list = rating_list(db, list_date.as_deref()).await?;
+    let ratings = db.search_fide_ratings(list.id, query.trim()).await?;
+    Ok(ratings)
+}
+
+#[command]
+#[specta::specta]
+pub async fn register_fide_player(
+    state: State<'_, PawnState>,
+    data: RegisterFidePlayer,
+) -> CommandResult<Player> {
+    let db = &*state.db;
+
+    let tournament = db.get_tournament(data.tournament_id).await?;
+    let list = rating_list(db, data.list_date.as_deref()).await?;
+    let rating = db
+        .get_fide_rating(list.id, data.fide_id)
+        .await?
+        .ok_or_else(|| {
+            PawnError::NotFound(format!(
+                "FIDE ID {} in the {} list",
+                data.fide_id, list.list_date
+            ))
+        })?;
+
+    // Players entered from the list join the registry, which later lists are matched through
+    let person = match db
+        .get_person_by_fide_id(&rating.fide_id.to_string())
+        .await?
+    {
+        Some(person) => db.update_person(&person_update(person.id, &rating)).await?,
+        None => db.create_person(&person_data(&rating)).await?,
+    };
+    check_not_registered(db, &person, tournament.id).await?;
+
+    let rating = list_rating(&rating, tournament_rating_type(&tournament.time_type));
+    let player = db
+        .create_person_player(&person, tournament.id, rating)
+        .await?;
+    Ok(player)
+}
+
+#[command]
+#[specta::specta]
+pub async fn refresh_tournament_ratings(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+    list_date: Option<String>,
+) -> CommandResult<FideRatingRefresh> {
+    let db = &*state.db;
+
+    let tournament = db.get_tournament(tournament_id).await?;
+    if !db.get_rounds_by_tournament(tournament_id).await?.is_empty() {
+        return Err(PawnError::BusinessLogic(format!(
+            "{} has already started; ratings are refreshed before seeding",
+            tournament.name
+        )));
+    }
+    let list = rating_list(db, list_date.as_deref()).await?;
+    let rating_type = tournament_rating_type(&tournament.time_type);
+
+    let mut updated = Vec::new();
+    let mut unchanged = 0;
+    let mut unmatched = Vec::new();
+    for player in db.get_players_by_tournament(tournament_id).await? {
+        let Some(rating) = listed_player(db, list.id, &player).await? else {
+            unmatched.push(player);
+            continue;
+        };
+        match list_rating(&rating, rating_type) {
+            Some(new_rating) if player.rating != Some(new_rating) => {
+                updated.push(PlayerRatingUpdate {
+                    player_id: player.id,
+                    name: player.name,
+                    fide_id: rating.fide_id,
+                    old_rating: player.rating,
+                    new_rating,
+                });
+            }
+            _ => unchanged += 1,
+        }
+    }
+
+    db.update_player_ratings(&updated, rating_type, &list.list_date)
+        .await?;
+    Ok(FideRatingRefresh {
+        list,
+        updated,
+        unchanged,
+        unmatched,
+    })
+}
+
+/// List in force on the date, or the latest one
+async fn rating_list<D: Db>(db: &D, list_date: Option<&str>) -> CommandResult<FideRatingList> {
+    db.get_fide_rating_list(list_date)
+        .await?
+        .ok_or_else(|| match list_date {
+            Some(list_date) => PawnError::NotFound(format!("FIDE rating list for {list_date}")),
+            None => PawnError::NotFound("FIDE rating list".to_string()),
+        })
+}
+
+/// Listed entry of a tournament player, through the FIDE ID of their registry person or by name
+async fn listed_player<D: Db>(
+    db: &D,
+    list_id: i32,
+    player: &Player,
+) -> CommandResult<Option<FideRating>> {
+    let fide_id = db
+        .get_player_person(player.id)
+        .await?
+        .and_then(|person| person.fide_id)
+        .and_then(|fide_id| fide_id.parse::<i32>().ok());
+    if let Some(fide_id) = fide_id {
+        return Ok(db.get_fide_rating(list_id, fide_id).await?);
+    }
+
+    for name in name_variants(&player.name) {
+        let candidates = db.find_fide_ratings_by_name(list_id, &name).await?;
+        if let Some(rating) = match_by_name(player, candidates) {
+            return Ok(Some(rating));
+        }
+    }
+    Ok(None)
+}
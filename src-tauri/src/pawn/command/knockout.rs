@@ -1,11 +1,17 @@
 use crate::pawn::{
-    common::types::CommandResult,
+    common::{error::PawnError, types::CommandResult},
     db::Db,
     domain::{
-        dto::CreateKnockoutBracket,
-        model::{BracketPosition, KnockoutBracket, Pairing},
+        dto::{
+            CreateKnockoutBracket, KnockoutMatchStatus, KnockoutPlacing, SetKnockoutMatchFormat,
+            StartArmageddon,
+        },
+        model::{
+            BracketPosition, BracketPositionStatus, BracketSide, BracketType, KnockoutBracket,
+            KnockoutMatchFormat, KnockoutMatchGame, Pairing,
+        },
     },
-    service::knockout::KnockoutService,
+    service::{knockout::KnockoutService, knockout_match::KnockoutMatchService},
     state::PawnState,
 };
 use tauri::{State, command};
@@ -24,6 +30,7 @@ pub async fn create_knockout_bracket(
         tournament_id: data.tournament_id,
         bracket_type: data.bracket_type,
         total_rounds: 0, // Will be calculated based on player count
+        bracket_reset: false,
         created_at: chrono::Utc::now().to_rfc3339(),
     };
 
@@ -48,13 +55,14 @@ pub async fn initialize_knockout_tournament(
     state: State<'_, PawnState>,
     tournament_id: i32,
     bracket_type: String,
+    bracket_reset: Option<bool>,
 ) -> CommandResult<KnockoutBracket> {
     let db = &*state.db;
 
     // Get tournament players
     let players = db.get_players_by_tournament(tournament_id).await?;
     if players.len() < 2 {
-        return Err(crate::pawn::common::error::PawnError::InvalidInput(
+        return Err(PawnError::InvalidInput(
             "At least 2 players required for knockout tournament".to_string(),
         ));
     }
@@ -68,6 +76,9 @@ pub async fn initialize_knockout_tournament(
         tournament_id,
         bracket_type: bracket_type.clone(),
         total_rounds,
+        // Only a double-elimination grand final can be reset
+        bracket_reset: bracket_type == BracketType::DoubleElimination.to_str()
+            && bracket_reset.unwrap_or(false),
         created_at: chrono::Utc::now().to_rfc3339(),
     };
 
@@ -78,9 +89,7 @@ pub async fn initialize_knockout_tournament(
         KnockoutService::generate_first_round_positions(created_bracket.id, players);
 
     // Save positions to database
-    for position in first_round_positions {
-        db.create_bracket_position(position).await?;
-    }
+    save_positions(db, &created_bracket, first_round_positions).await?;
 
     Ok(created_bracket)
 }
@@ -116,6 +125,7 @@ pub async fn generate_knockout_pairings(
     state: State<'_, PawnState>,
     bracket_id: i32,
     round_number: i32,
+    bracket_side: Option<String>,
 ) -> CommandResult<Vec<Pairing>> {
     let db = &*state.db;
 
@@ -125,7 +135,12 @@ pub async fn generate_knockout_pairings(
         .await?;
 
     // Generate pairings using KnockoutService
-    let pairings = KnockoutService::generate_round_pairings(bracket_id, round_number, &positions);
+    let pairings = match parse_bracket_side(bracket_side)? {
+        BracketSide::Winners => {
+            KnockoutService::generate_round_pairings(bracket_id, round_number, &positions)
+        }
+        side => KnockoutService::generate_side_pairings(&side, round_number, &positions),
+    };
 
     Ok(pairings)
 }
@@ -137,19 +152,61 @@ pub async fn advance_knockout_winners(
     bracket_id: i32,
     round_number: i32,
     winner_results: Vec<(i32, i32)>, // (winner_player_id, loser_player_id)
+    bracket_side: Option<String>,
 ) -> CommandResult<Vec<BracketPosition>> {
     let db = &*state.db;
+    let side = parse_bracket_side(bracket_side)?;
+
+    // Mini-match winners only advance once their match is decided
+    if let Some(format) = db
+        .get_knockout_match_format(bracket_id, side.to_str(), round_number)
+        .await?
+    {
+        let games = db
+            .get_knockout_match_games(bracket_id, side.to_str(), round_number)
+            .await?;
+        KnockoutMatchService::check_decided(&format, &games, &winner_results)?;
+    }
 
     // Generate next round positions
-    let next_round_positions =
-        KnockoutService::advance_winners(bracket_id, round_number, &winner_results);
+    let bracket = db.get_knockout_bracket_by_id(bracket_id).await?;
+    let next_round_positions = match &bracket {
+        Some(bracket) if bracket.bracket_type == BracketType::DoubleElimination.to_str() => {
+            let positions = db.get_bracket_positions(bracket_id).await?;
+            KnockoutService::advance_double_elimination(
+                bracket,
+                &side,
+                round_number,
+                &positions,
+                &winner_results,
+            )?
+        }
+        _ => {
+            let mut positions =
+                KnockoutService::advance_winners(bracket_id, round_number, &winner_results);
+            // Semi-final losers move on to the third-place match
+            if let Some(bracket) = &bracket {
+                positions.extend(KnockoutService::third_place_positions(
+                    bracket,
+                    round_number,
+                    &winner_results,
+                ));
+            }
+            positions
+        }
+    };
 
     // Save new positions to database
-    let mut created_positions = Vec::new();
-    for position in next_round_positions {
-        let created = db.create_bracket_position(position).await?;
-        created_positions.push(created);
-    }
+    let created_positions = match &bracket {
+        Some(bracket) => save_positions(db, bracket, next_round_positions).await?,
+        None => {
+            let mut created_positions = Vec::new();
+            for position in next_round_positions {
+                created_positions.push(db.create_bracket_position(position).await?);
+            }
+            created_positions
+        }
+    };
 
     Ok(created_positions)
 }
@@ -175,6 +232,23 @@ pub async fn get_knockout_tournament_winner(
     Ok(winner_id)
 }
 
+#[command]
+#[specta::specta]
+pub async fn get_knockout_placings(
+    state: State<'_, PawnState>,
+    bracket_id: i32,
+) -> CommandResult<Vec<KnockoutPlacing>> {
+    let db = &*state.db;
+
+    let Some(bracket) = db.get_knockout_bracket_by_id(bracket_id).await? else {
+        return Ok(Vec::new());
+    };
+    let positions = db.get_bracket_positions(bracket_id).await?;
+
+    let placings = KnockoutService::get_placings(&positions, bracket.total_rounds);
+    Ok(placings)
+}
+
 #[command]
 #[specta::specta]
 pub async fn is_knockout_tournament_complete(
@@ -212,6 +286,268 @@ pub async fn validate_knockout_bracket(
     }
 }
 
+#[command]
+#[specta::specta]
+pub async fn set_knockout_match_format(
+    state: State<'_, PawnState>,
+    data: SetKnockoutMatchFormat,
+) -> CommandResult<KnockoutMatchFormat> {
+    let db = &*state.db;
+
+    db.get_knockout_bracket_by_id(data.bracket_id)
+        .await?
+        .ok_or_else(|| PawnError::NotFound(format!("Knockout bracket {}", data.bracket_id)))?;
+    let side = parse_bracket_side(data.bracket_side.clone())?;
+    KnockoutMatchService::validate_format(&data)?;
+    let time_control_ids = [data.time_control_id, data.armageddon_time_control_id]
+        .into_iter()
+        .chain(
+            data.tiebreak_stages
+                .iter()
+                .map(|stage| stage.time_control_id),
+        )
+        .flatten();
+    for time_control_id in time_control_ids {
+        db.get_time_control(time_control_id).await?;
+    }
+
+    let format = db
+        .upsert_knockout_match_format(&data, side.to_str())
+        .await?;
+    Ok(format)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_knockout_match_formats(
+    state: State<'_, PawnState>,
+    bracket_id: i32,
+) -> CommandResult<Vec<KnockoutMatchFormat>> {
+    let db = &*state.db;
+    let formats = db.get_knockout_match_formats(bracket_id).await?;
+    Ok(formats)
+}
+
+/// Schedule the main games of every mini-match of a round whose two
+/// players are known
+#[command]
+#[specta::specta]
+pub async fn start_knockout_matches(
+    state: State<'_, PawnState>,
+    bracket_id: i32,
+    round_number: i32,
+    bracket_side: Option<String>,
+) -> CommandResult<Vec<KnockoutMatchGame>> {
+    let db = &*state.db;
+    let side = parse_bracket_side(bracket_side)?;
+    let format = match_format(db, bracket_id, &side, round_number).await?;
+
+    let positions = db
+        .get_bracket_positions_by_round(bracket_id, round_number)
+        .await?;
+    let games = db
+        .get_knockout_match_games(bracket_id, side.to_str(), round_number)
+        .await?;
+    let ready_player = |position_number: i32| {
+        positions
+            .iter()
+            .find(|p| {
+                p.bracket_side == side.to_str()
+                    && p.position_number == position_number
+                    && p.status == BracketPositionStatus::Ready.to_str()
+            })
+            .and_then(|p| p.player_id)
+    };
+    let last_position = positions
+        .iter()
+        .filter(|p| p.bracket_side == side.to_str())
+        .map(|p| p.position_number)
+        .max()
+        .unwrap_or(0);
+
+    let mut created_games = Vec::new();
+    for match_number in 1..=(last_position + 1) / 2 {
+        let (Some(player1_id), Some(player2_id)) = (
+            ready_player(2 * match_number - 1),
+            ready_player(2 * match_number),
+        ) else {
+            continue;
+        };
+        let status = KnockoutMatchService::match_status(
+            &format,
+            match_number,
+            (player1_id, player2_id),
+            &games,
+        )?;
+        for game in KnockoutMatchService::next_stage_games(&format, &status, &games) {
+            created_games.push(db.create_knockout_match_game(game).await?);
+        }
+    }
+
+    Ok(created_games)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_knockout_match_games(
+    state: State<'_, PawnState>,
+    bracket_id: i32,
+    round_number: i32,
+    bracket_side: Option<String>,
+) -> CommandResult<Vec<KnockoutMatchGame>> {
+    let db = &*state.db;
+    let side = parse_bracket_side(bracket_side)?;
+    let games = db
+        .get_knockout_match_games(bracket_id, side.to_str(), round_number)
+        .await?;
+    Ok(games)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_knockout_match_statuses(
+    state: State<'_, PawnState>,
+    bracket_id: i32,
+    round_number: i32,
+    bracket_side: Option<String>,
+) -> CommandResult<Vec<KnockoutMatchStatus>> {
+    let db = &*state.db;
+    let side = parse_bracket_side(bracket_side)?;
+    let format = match_format(db, bracket_id, &side, round_number).await?;
+    let games = db
+        .get_knockout_match_games(bracket_id, side.to_str(), round_number)
+        .await?;
+
+    let mut match_numbers: Vec<i32> = games.iter().map(|game| game.match_number).collect();
+    match_numbers.dedup();
+    match_numbers
+        .into_iter()
+        .map(|match_number| match_status(&format, match_number, &games))
+        .collect()
+}
+
+/// Record the result of a mini-match game. A stage that ends tied is
+/// followed straight away by the games of the next tiebreak stage.
+#[command]
+#[specta::specta]
+pub async fn record_knockout_match_game(
+    state: State<'_, PawnState>,
+    game_id: i32,
+    result: String,
+) -> CommandResult<KnockoutMatchStatus> {
+    let db = &*state.db;
+
+    let game = db.get_knockout_match_game(game_id).await?;
+    if game.result != "*" {
+        return Err(PawnError::InvalidInput(format!(
+            "Game {game_id} already has a result"
+        )));
+    }
+    if KnockoutMatchService::game_points(&result)?.is_none() {
+        return Err(PawnError::InvalidInput(format!(
+            "{result} is not a valid mini-match result"
+        )));
+    }
+    let side = parse_bracket_side(Some(game.bracket_side.clone()))?;
+    let format = match_format(db, game.bracket_id, &side, game.round_number).await?;
+
+    db.update_knockout_match_game_result(game_id, &result)
+        .await?;
+    let games = db
+        .get_knockout_match_games(game.bracket_id, side.to_str(), game.round_number)
+        .await?;
+    let status = match_status(&format, game.match_number, &games)?;
+    for next_game in KnockoutMatchService::next_stage_games(&format, &status, &games) {
+        db.create_knockout_match_game(next_game).await?;
+    }
+
+    Ok(status)
+}
+
+#[command]
+#[specta::specta]
+pub async fn start_knockout_armageddon(
+    state: State<'_, PawnState>,
+    data: StartArmageddon,
+) -> CommandResult<KnockoutMatchGame> {
+    let db = &*state.db;
+    let side = parse_bracket_side(data.bracket_side.clone())?;
+    let format = match_format(db, data.bracket_id, &side, data.round_number).await?;
+    let games = db
+        .get_knockout_match_games(data.bracket_id, side.to_str(), data.round_number)
+        .await?;
+
+    let status = match_status(&format, data.match_number, &games)?;
+    let game = KnockoutMatchService::armageddon_game(&format, &status, &data)?;
+    let game = db.create_knockout_match_game(game).await?;
+    Ok(game)
+}
+
+/// Mini-match format of a round, which must have been set
+async fn match_format<D: Db>(
+    db: &D,
+    bracket_id: i32,
+    side: &BracketSide,
+    round_number: i32,
+) -> CommandResult<KnockoutMatchFormat> {
+    db.get_knockout_match_format(bracket_id, side.to_str(), round_number)
+        .await?
+        .ok_or_else(|| {
+            PawnError::NotFound(format!(
+                "Match format of round {round_number} of the {} bracket",
+                side.to_str()
+            ))
+        })
+}
+
+/// Status of a mini-match that has started
+fn match_status(
+    format: &KnockoutMatchFormat,
+    match_number: i32,
+    games: &[KnockoutMatchGame],
+) -> CommandResult<KnockoutMatchStatus> {
+    let match_games: Vec<KnockoutMatchGame> = games
+        .iter()
+        .filter(|game| game.match_number == match_number)
+        .cloned()
+        .collect();
+    let players = KnockoutMatchService::match_players(&match_games)
+        .ok_or_else(|| PawnError::NotFound(format!("Match {match_number} has not started")))?;
+    let status = KnockoutMatchService::match_status(format, match_number, players, &match_games)?;
+    Ok(status)
+}
+
+/// Store new bracket positions. Players of a double-elimination bracket whose
+/// opponent slot stays empty for good move on at once, and the positions they
+/// walk over to are returned along with the stored ones.
+async fn save_positions<D: Db>(
+    db: &D,
+    bracket: &KnockoutBracket,
+    positions: Vec<BracketPosition>,
+) -> CommandResult<Vec<BracketPosition>> {
+    let mut created_positions = Vec::new();
+    let mut pending = positions;
+    while !pending.is_empty() {
+        for position in pending {
+            created_positions.push(db.create_bracket_position(position).await?);
+        }
+        if bracket.bracket_type != BracketType::DoubleElimination.to_str() {
+            break;
+        }
+        let positions = db.get_bracket_positions(bracket.id).await?;
+        pending = KnockoutService::double_elimination_byes(bracket, &positions);
+    }
+    Ok(created_positions)
+}
+
+/// Side of a double-elimination bracket, the winners bracket by default
+fn parse_bracket_side(bracket_side: Option<String>) -> CommandResult<BracketSide> {
+    bracket_side
+        .as_deref()
+        .map_or(Ok(BracketSide::Winners), str::parse)
+        .map_err(PawnError::InvalidInput)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -299,6 +635,7 @@ mod tests {
             tournament_id,
             bracket_type: "double_elimination".to_string(),
             total_rounds: 4,
+            bracket_reset: false,
             created_at: "2024-01-01T00:00:00Z".to_string(),
         };
         assert_eq!(bracket.id, 1);
@@ -310,6 +647,7 @@ mod tests {
         let bracket_position = BracketPosition {
             id: 1,
             bracket_id: 1,
+            bracket_side: "winners".to_string(),
             round_number: 1,
             position_number: 1,
             player_id: Some(1),
@@ -684,6 +1022,7 @@ mod tests {
             let bracket_position = BracketPosition {
                 id: 1,
                 bracket_id: 1,
+                bracket_side: "winners".to_string(),
                 round_number: 1,
                 position_number: 1,
                 player_id: Some(1),
@@ -699,6 +1038,7 @@ mod tests {
         let boundary_position = BracketPosition {
             id: i32::MAX,
             bracket_id: i32::MAX,
+            bracket_side: "winners".to_string(),
             round_number: i32::MAX,
             position_number: i32::MAX,
             player_id: Some(i32::MAX),
@@ -718,6 +1058,7 @@ mod tests {
         let none_position = BracketPosition {
             id: 1,
             bracket_id: 1,
+            bracket_side: "winners".to_string(),
             round_number: 1,
             position_number: 1,
             player_id: None,
@@ -740,6 +1081,7 @@ mod tests {
             tournament_id: 1,
             bracket_type: "single_elimination".to_string(),
             total_rounds: 0,
+            bracket_reset: false,
             created_at: chrono::Utc::now().to_rfc3339(),
         };
         assert_eq!(bracket.tournament_id, 1);
@@ -941,6 +1283,7 @@ mod tests {
             tournament_id: create_data.tournament_id,
             bracket_type: create_data.bracket_type.clone(),
             total_rounds: 0, // Will be calculated based on player count
+            bracket_reset: false,
             created_at: chrono::Utc::now().to_rfc3339(),
         };
 
@@ -976,6 +1319,7 @@ mod tests {
                 tournament_id: tournament.id,
                 bracket_type: "single_elimination".to_string(),
                 total_rounds,
+                bracket_reset: false,
                 created_at: chrono::Utc::now().to_rfc3339(),
             };
 
@@ -1034,6 +1378,7 @@ mod tests {
             created_positions.push(BracketPosition {
                 id: 1,
                 bracket_id: 1,
+                bracket_side: "winners".to_string(),
                 round_number: 2,
                 position_number: 1,
                 player_id: Some(1),
@@ -1135,6 +1480,7 @@ mod tests {
                 tournament_id: 1,
                 bracket_type: bracket_type.to_string(),
                 total_rounds: 3,
+                bracket_reset: false,
                 created_at: chrono::Utc::now().to_rfc3339(),
             };
             assert_eq!(bracket.bracket_type, bracket_type);
@@ -1257,6 +1603,7 @@ mod tests {
             tournament_id: create_data.tournament_id,
             bracket_type: create_data.bracket_type,
             total_rounds: 3, // Simulated calculation
+            bracket_reset: false,
             created_at: chrono::Utc::now().to_rfc3339(),
         };
 
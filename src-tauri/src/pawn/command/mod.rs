@@ -1,11 +1,17 @@
+pub mod arena;
+pub mod event;
 pub mod export;
+pub mod fide_rating_list;
 pub mod game_result;
 pub mod knockout;
 pub mod norm_calculation;
 pub mod player;
+pub mod rating;
+pub mod registry;
 pub mod round;
 pub mod seeding;
 pub mod settings;
+pub mod stage;
 pub mod team;
 pub mod time_control;
 pub mod tournament;
@@ -2,10 +2,13 @@ use crate::pawn::common::error::PawnError;
 #[cfg(test)]
 use crate::pawn::domain::dto::BulkImportPlayer;
 use crate::pawn::domain::dto::{
-    AssignPlayerToCategory, BulkImportRequest, BulkImportResult, CreatePlayer,
-    CreatePlayerCategory, CreateRatingHistory, PlayerSearchFilters, UpdatePlayer,
+    AssignPlayerToCategory, BulkImportRequest, BulkImportResult, CreateByeRequest, CreatePlayer,
+    CreatePlayerCategory, CreateRatingHistory, PlayerSearchFilters, UpdateByeSettings,
+    UpdatePlayer,
+};
+use crate::pawn::domain::model::{
+    ByeRequest, ByeSettings, Player, PlayerCategory, PlayerCategoryAssignment, RatingHistory,
 };
-use crate::pawn::domain::model::{Player, PlayerCategory, PlayerCategoryAssignment, RatingHistory};
 type TxError = PawnError;
 use crate::pawn::state::PawnState;
 use tauri::State;
@@ -203,6 +206,51 @@ pub async fn request_player_bye(
     state.player_service.request_player_bye(player_id).await
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn request_byes(
+    data: CreateByeRequest,
+    state: State<'_, PawnState>,
+) -> Result<Vec<ByeRequest>, TxError> {
+    state.player_service.request_byes(data).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn cancel_bye_request(
+    request_id: i32,
+    state: State<'_, PawnState>,
+) -> Result<(), TxError> {
+    state.player_service.cancel_bye_request(request_id).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_bye_requests(
+    tournament_id: i32,
+    state: State<'_, PawnState>,
+) -> Result<Vec<ByeRequest>, TxError> {
+    state.player_service.get_bye_requests(tournament_id).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_bye_settings(
+    tournament_id: i32,
+    state: State<'_, PawnState>,
+) -> Result<ByeSettings, TxError> {
+    state.player_service.get_bye_settings(tournament_id).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn update_bye_settings(
+    data: UpdateByeSettings,
+    state: State<'_, PawnState>,
+) -> Result<ByeSettings, TxError> {
+    state.player_service.update_bye_settings(data).await
+}
+
 // Utility Commands
 
 #[tauri::command]
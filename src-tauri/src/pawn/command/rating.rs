@@ -0,0 +1,18 @@
+use crate::pawn::{
+    common::types::CommandResult, domain::dto::RatingReport,
+    service::rating_report::RatingReportService, state::PawnState,
+};
+use std::sync::Arc;
+use tauri::{State, command};
+
+#[command]
+#[specta::specta]
+pub async fn get_rating_report(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+) -> CommandResult<RatingReport> {
+    let report = RatingReportService::new(Arc::clone(&state.db))
+        .calculate_rating_report(tournament_id)
+        .await?;
+    Ok(report)
+}
@@ -0,0 +1,225 @@
+use crate::pawn::{
+    common::{error::PawnError, types::CommandResult},
+    db::Db,
+    domain::{
+        dto::{
+            CreatePerson, CreatePersonRating, PersonTournament, RegisterPersonPlayer, UpdatePerson,
+        },
+        model::{Person, PersonRating, Player},
+        tiebreak::TournamentTiebreakConfig,
+    },
+    service::registry::RegistryService,
+    state::PawnState,
+};
+use tauri::{State, command};
+
+#[command]
+#[specta::specta]
+pub async fn get_persons(state: State<'_, PawnState>) -> CommandResult<Vec<Person>> {
+    let db = &*state.db;
+    let persons = db.get_persons().await?;
+    Ok(persons)
+}
+
+#[command]
+#[specta::specta]
+pub async fn search_persons(
+    state: State<'_, PawnState>,
+    query: String,
+) -> CommandResult<Vec<Person>> {
+    let db = &*state.db;
+    let persons = db.search_persons(query.trim()).await?;
+    Ok(persons)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_person(state: State<'_, PawnState>, person_id: i32) -> CommandResult<Person> {
+    let db = &*state.db;
+    let person = db.get_person(person_id).await?;
+    Ok(person)
+}
+
+#[command]
+#[specta::specta]
+pub async fn create_person(
+    state: State<'_, PawnState>,
+    data: CreatePerson,
+) -> CommandResult<Person> {
+    let db = &*state.db;
+
+    RegistryService::validate_person(&data)?;
+    if let Some(fide_id) = &data.fide_id
+        && let Some(person) = db
+            .search_persons(fide_id)
+            .await?
+            .into_iter()
+            .find(|p| p.fide_id.as_ref() == Some(fide_id))
+    {
+        return Err(PawnError::InvalidInput(format!(
+            "FIDE ID {fide_id} is already registered to {}",
+            person.name
+        )));
+    }
+
+    let person = db.create_person(&data).await?;
+    Ok(person)
+}
+
+#[command]
+#[specta::specta]
+pub async fn update_person(
+    state: State<'_, PawnState>,
+    data: UpdatePerson,
+) -> CommandResult<Person> {
+    let db = &*state.db;
+    if data
+        .name
+        .as_ref()
+        .is_some_and(|name| name.trim().is_empty())
+    {
+        return Err(PawnError::ValidationError(
+            "Person name cannot be empty".to_string(),
+        ));
+    }
+    let person = db.update_person(&data).await?;
+    Ok(person)
+}
+
+#[command]
+#[specta::specta]
+pub async fn register_person_player(
+    state: State<'_, PawnState>,
+    data: RegisterPersonPlayer,
+) -> CommandResult<Player> {
+    let db = &*state.db;
+
+    db.get_tournament(data.tournament_id).await?;
+    let person = db.get_person(data.person_id).await?;
+    check_not_registered(db, &person, data.tournament_id).await?;
+    let rating = RegistryService::registration_rating(&person, data.rating_type.as_deref())?;
+
+    let player = db
+        .create_person_player(&person, data.tournament_id, rating)
+        .await?;
+    Ok(player)
+}
+
+#[command]
+#[specta::specta]
+pub async fn link_player_to_person(
+    state: State<'_, PawnState>,
+    player_id: i32,
+    person_id: Option<i32>,
+) -> CommandResult<Player> {
+    let db = &*state.db;
+
+    let player = db.get_player(player_id).await?;
+    if let Some(person_id) = person_id {
+        let person = db.get_person(person_id).await?;
+        check_not_registered(db, &person, player.tournament_id).await?;
+    }
+
+    let player = db.link_player_to_person(player_id, person_id).await?;
+    Ok(player)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_player_person(
+    state: State<'_, PawnState>,
+    player_id: i32,
+) -> CommandResult<Option<Person>> {
+    let db = &*state.db;
+    let person = db.get_player_person(player_id).await?;
+    Ok(person)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_person_history(
+    state: State<'_, PawnState>,
+    person_id: i32,
+) -> CommandResult<Vec<PersonTournament>> {
+    let db = &*state.db;
+
+    db.get_person(person_id).await?;
+    let mut history = Vec::new();
+    for player in db.get_person_players(person_id).await? {
+        let tournament_id = player.tournament_id;
+        let tournament = db.get_tournament(tournament_id).await?;
+        let config = match db.get_tournament_settings(tournament_id).await? {
+            Some(config) => config,
+            None => TournamentTiebreakConfig {
+                tournament_id,
+                ..Default::default()
+            },
+        };
+        let standings = state
+            .tiebreak_calculator
+            .calculate_standings(tournament_id, &config)
+            .await?;
+        let standing = standings
+            .standings
+            .iter()
+            .find(|standing| standing.player.id == player.id);
+
+        history.push(PersonTournament {
+            tournament,
+            rank: standing.map(|standing| standing.rank),
+            points: standing.map_or(0.0, |standing| standing.points),
+            games_played: standing.map_or(0, |standing| standing.games_played),
+            player,
+        });
+    }
+    Ok(history)
+}
+
+#[command]
+#[specta::specta]
+pub async fn add_person_rating(
+    state: State<'_, PawnState>,
+    data: CreatePersonRating,
+) -> CommandResult<PersonRating> {
+    let db = &*state.db;
+
+    RegistryService::validate_rating(&data.rating_type, data.rating)?;
+    db.get_person(data.person_id).await?;
+    if let Some(tournament_id) = data.tournament_id {
+        db.get_tournament(tournament_id).await?;
+    }
+
+    let rating = db.add_person_rating(&data).await?;
+    Ok(rating)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_person_rating_history(
+    state: State<'_, PawnState>,
+    person_id: i32,
+) -> CommandResult<Vec<PersonRating>> {
+    let db = &*state.db;
+    let history = db.get_person_rating_history(person_id).await?;
+    Ok(history)
+}
+
+/// Reject a second player for the same person in one tournament
+pub(crate) async fn check_not_registered<D: Db>(
+    db: &D,
+    person: &Person,
+    tournament_id: i32,
+) -> CommandResult<()> {
+    if db
+        .get_person_players(person.id)
+        .await?
+        .iter()
+        .any(|player| player.tournament_id == tournament_id)
+    {
+        return Err(PawnError::InvalidInput(format!(
+            "{} is already registered in this tournament",
+            person.name
+        )));
+    }
+    Ok(())
+}
@@ -5,11 +5,15 @@ use crate::pawn::{
     common::types::CommandResult,
     domain::{
         dto::{
-            CreateRound, EnhancedPairingRequest, EnhancedPairingResult, GeneratePairingsRequest,
-            PairingValidationResults, RoundRobinAnalysis, RoundRobinOptions, SwissPairingAnalysis,
-            SwissPairingOptions, UpdateRoundStatus, UpdateTournamentPairingMethod,
+            CheckPairingsRequest, CreateForbiddenPairing, CreatePairingDraft, CreateRound,
+            EnhancedPairingRequest, EnhancedPairingResult, GeneratePairingsRequest,
+            PairingCheckReport, PairingDraftComparison, PairingDraftSummary,
+            PairingPerformanceMetrics, PairingValidationResults, RepairRoundRequest,
+            RepairRoundResult, RoundRobinAnalysis, RoundRobinOptions, SwissPairingAnalysis,
+            SwissPairingOptions, UpdateAccelerationSettings, UpdateRoundStatus,
+            UpdateTournamentPairingMethod,
         },
-        model::{GameResult, Pairing, Round, RoundDetails},
+        model::{AccelerationSettings, ForbiddenPairing, GameResult, Pairing, Round, RoundDetails},
     },
     state::PawnState,
 };
@@ -107,18 +111,17 @@ pub async fn create_next_round(
     state.round_service.create_next_round(tournament_id).await
 }
 
-#[instrument(ret, skip(_state))]
+#[instrument(ret, skip(state))]
 #[tauri::command]
 #[specta::specta]
 pub async fn update_tournament_pairing_method(
-    _state: State<'_, PawnState>,
-    _data: UpdateTournamentPairingMethod,
+    state: State<'_, PawnState>,
+    data: UpdateTournamentPairingMethod,
 ) -> CommandResult<()> {
-    // Update the tournament's pairing method
-    // This would need to be implemented in the tournament service
-    // For now, we'll just return Ok(())
-    // TODO: Implement tournament pairing method update
-    Ok(())
+    state
+        .tournament_service
+        .update_tournament_pairing_method(data)
+        .await
 }
 
 // Enhanced Pairing System Commands
@@ -134,14 +137,18 @@ pub async fn generate_enhanced_pairings(
     // This would integrate with the SwissPairingEngine, RoundRobinEngine,
     // ManualPairingController, and PairingOptimizer
 
-    // For now, fall back to basic pairing generation
+    // For now, fall back to basic pairing generation. The pairing method
+    // selects the engine ("swiss" or "dutch_optimal" for the Dutch System).
+    let algorithm_used = request.pairing_method.clone();
     let basic_request = GeneratePairingsRequest {
         tournament_id: request.tournament_id,
         round_number: request.round_number,
         pairing_method: request.pairing_method,
     };
 
+    let started = std::time::Instant::now();
     let pairings = state.round_service.generate_pairings(basic_request).await?;
+    let pairing_generation_ms = started.elapsed().as_millis();
 
     // Create a basic enhanced result
     let validation_results = PairingValidationResults {
@@ -151,10 +158,24 @@ pub async fn generate_enhanced_pairings(
         suggestions: vec![],
     };
 
+    let performance_metrics = PairingPerformanceMetrics {
+        total_duration_ms: started.elapsed().as_millis(),
+        pairing_generation_ms,
+        validation_duration_ms: 0,
+        players_processed: pairings
+            .iter()
+            .map(|p| 1 + usize::from(p.black_player.is_some()))
+            .sum(),
+        pairings_generated: pairings.len(),
+        cache_hits: 0,
+        cache_misses: 0,
+        algorithm_used,
+    };
+
     Ok(EnhancedPairingResult {
         pairings,
         validation_results,
-        performance_metrics: None,
+        performance_metrics: Some(performance_metrics),
         warnings: vec![],
     })
 }
@@ -211,6 +232,136 @@ pub async fn validate_pairing_configuration(
     })
 }
 
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn check_tournament_pairings(
+    state: State<'_, PawnState>,
+    data: CheckPairingsRequest,
+) -> CommandResult<PairingCheckReport> {
+    state.round_service.check_pairings(data).await
+}
+
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn repair_round_pairings(
+    state: State<'_, PawnState>,
+    data: RepairRoundRequest,
+) -> CommandResult<RepairRoundResult> {
+    state.round_service.repair_round(data).await
+}
+
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn create_pairing_draft(
+    state: State<'_, PawnState>,
+    data: CreatePairingDraft,
+) -> CommandResult<PairingDraftSummary> {
+    state.round_service.create_pairing_draft(data).await
+}
+
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn get_pairing_drafts(
+    state: State<'_, PawnState>,
+    round_id: i32,
+) -> CommandResult<Vec<PairingDraftSummary>> {
+    state.round_service.get_pairing_drafts(round_id).await
+}
+
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn compare_pairing_drafts(
+    state: State<'_, PawnState>,
+    first_draft_id: i32,
+    second_draft_id: i32,
+) -> CommandResult<PairingDraftComparison> {
+    state
+        .round_service
+        .compare_pairing_drafts(first_draft_id, second_draft_id)
+        .await
+}
+
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn publish_pairing_draft(
+    state: State<'_, PawnState>,
+    draft_id: i32,
+) -> CommandResult<Vec<GameResult>> {
+    state.round_service.publish_pairing_draft(draft_id).await
+}
+
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_pairing_draft(state: State<'_, PawnState>, draft_id: i32) -> CommandResult<()> {
+    state.round_service.delete_pairing_draft(draft_id).await
+}
+
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn get_acceleration_settings(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+) -> CommandResult<AccelerationSettings> {
+    state
+        .round_service
+        .get_acceleration_settings(tournament_id)
+        .await
+}
+
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn update_acceleration_settings(
+    state: State<'_, PawnState>,
+    data: UpdateAccelerationSettings,
+) -> CommandResult<AccelerationSettings> {
+    state.round_service.update_acceleration_settings(data).await
+}
+
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn get_forbidden_pairings(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+) -> CommandResult<Vec<ForbiddenPairing>> {
+    state
+        .round_service
+        .get_forbidden_pairings(tournament_id)
+        .await
+}
+
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn create_forbidden_pairing(
+    state: State<'_, PawnState>,
+    data: CreateForbiddenPairing,
+) -> CommandResult<ForbiddenPairing> {
+    state.round_service.create_forbidden_pairing(data).await
+}
+
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_forbidden_pairing(
+    state: State<'_, PawnState>,
+    pairing_id: i32,
+) -> CommandResult<()> {
+    state
+        .round_service
+        .delete_forbidden_pairing(pairing_id)
+        .await
+}
+
 #[instrument(ret, skip(state))]
 #[tauri::command]
 #[specta::specta]
@@ -233,7 +384,10 @@ mod tests {
     use crate::pawn::{
         db::{Db, sqlite::SqliteDb},
         domain::{
-            dto::{CreateTournament, PairingSuggestionDto, PairingWarningDto},
+            dto::{
+                CreateByeRequest, CreateGame, CreatePlayer, CreateTournament, PairingSuggestionDto,
+                PairingWarningDto,
+            },
             model::Tournament,
         },
         state::State,
@@ -392,6 +546,102 @@ mod tests {
         assert!(result.is_ok() || result.is_err()); // Either outcome is valid for contract testing
     }
 
+    #[tokio::test]
+    async fn command_requested_bye_keeps_opponent_history() {
+        let state = setup_test_state().await;
+        let tournament = create_test_tournament(&state).await;
+
+        let mut players = Vec::new();
+        for (name, rating) in [
+            ("A", 2500),
+            ("B", 2200),
+            ("C", 2300),
+            ("D", 2400),
+            ("E", 2100),
+            ("F", 2000),
+        ] {
+            let player = state
+                .db
+                .create_player(CreatePlayer {
+                    tournament_id: tournament.id,
+                    name: name.to_string(),
+                    rating: Some(rating),
+                    country_code: None,
+                    title: None,
+                    birth_date: None,
+                    gender: None,
+                    email: None,
+                    phone: None,
+                    club: None,
+                })
+                .await
+                .unwrap();
+            players.push(player.id);
+        }
+        let [a, b, c, d, e, f] = players[..] else {
+            unreachable!()
+        };
+
+        // All draws; B had black in both rounds, once against A
+        let played = [
+            (1, a, b),
+            (1, c, d),
+            (1, e, f),
+            (2, c, b),
+            (2, d, e),
+            (2, f, a),
+        ];
+        for (round_number, white, black) in played {
+            state
+                .db
+                .create_game(CreateGame {
+                    tournament_id: tournament.id,
+                    round_number,
+                    white_player_id: white,
+                    black_player_id: black,
+                    result: "1/2-1/2".to_string(),
+                })
+                .await
+                .unwrap();
+        }
+        state
+            .db
+            .create_bye_requests(&CreateByeRequest {
+                tournament_id: tournament.id,
+                player_id: a,
+                round_numbers: vec![3],
+                bye_points: 0.5,
+            })
+            .await
+            .unwrap();
+
+        let pairings = state
+            .round_service
+            .generate_pairings(GeneratePairingsRequest {
+                tournament_id: tournament.id,
+                round_number: 3,
+                pairing_method: "swiss".to_string(),
+            })
+            .await
+            .unwrap();
+        let games: Vec<(i32, i32)> = pairings
+            .iter()
+            .filter_map(|pairing| {
+                let black = pairing.black_player.as_ref()?;
+                Some((pairing.white_player.id, black.id))
+            })
+            .collect();
+
+        // The game against A still gives B two blacks in a row, so B gets white
+        // against the higher rated D
+        assert!(games.contains(&(b, d)));
+        assert!(games.iter().all(|&(white, black)| {
+            !played
+                .iter()
+                .any(|&(_, x, y)| (x, y) == (white, black) || (y, x) == (white, black))
+        }));
+    }
+
     #[tokio::test]
     async fn command_create_pairings_as_games_contract() {
         let state = setup_test_state().await;
@@ -425,10 +675,26 @@ mod tests {
 
     #[tokio::test]
     async fn command_update_tournament_pairing_method_contract() {
-        // Test that the function signature is correct
-        // This function always returns Ok(()) for now as a placeholder
-        let expected_result: Result<(), crate::pawn::common::error::PawnError> = Ok(());
-        assert!(expected_result.is_ok());
+        let state = setup_test_state().await;
+        let tournament = create_test_tournament(&state).await;
+
+        let result = state
+            .tournament_service
+            .update_tournament_pairing_method(UpdateTournamentPairingMethod {
+                tournament_id: tournament.id,
+                pairing_method: "burstein".to_string(),
+            })
+            .await;
+        assert!(result.is_ok());
+
+        let result = state
+            .tournament_service
+            .update_tournament_pairing_method(UpdateTournamentPairingMethod {
+                tournament_id: tournament.id,
+                pairing_method: "lottery".to_string(),
+            })
+            .await;
+        assert!(result.is_err());
     }
 
     #[tokio::test]
@@ -694,12 +960,11 @@ mod tests {
 
         // Test placeholder command logic (lines 154-158, 203, 208-211, 224, 227)
 
-        // update_tournament_pairing_method placeholder logic
+        // update_tournament_pairing_method logic
         let _update_data = UpdateTournamentPairingMethod {
             tournament_id: tournament.id,
             pairing_method: "swiss".to_string(),
         };
-        // Placeholder returns Ok(())
 
         // validate_pairing_configuration placeholder logic
         let _pairings: Vec<Pairing> = vec![];
@@ -772,14 +1037,16 @@ mod tests {
         // Test placeholder function execution to cover those lines
 
         // Cover update_tournament_pairing_method function lines (113, 121)
-        let _update_data = UpdateTournamentPairingMethod {
+        let update_data = UpdateTournamentPairingMethod {
             tournament_id: tournament.id,
             pairing_method: "swiss".to_string(),
         };
 
-        // The function always returns Ok(()) - test this logic directly
-        let placeholder_result: Result<(), crate::pawn::common::error::PawnError> = Ok(());
-        assert!(placeholder_result.is_ok());
+        let result = state
+            .tournament_service
+            .update_tournament_pairing_method(update_data)
+            .await;
+        assert!(result.is_ok());
     }
 
     #[tokio::test]
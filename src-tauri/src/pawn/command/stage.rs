@@ -0,0 +1,173 @@
+use crate::pawn::{
+    common::{error::PawnError, types::CommandResult},
+    db::Db,
+    domain::{
+        dto::{AdvanceTournamentStage, CreateTournament, CreateTournamentStage, StageQualifier},
+        model::{TournamentStage, TournamentStageGroup},
+        tiebreak::TournamentTiebreakConfig,
+    },
+    service::stage::StageService,
+    state::PawnState,
+};
+use tauri::{State, command};
+
+#[command]
+#[specta::specta]
+pub async fn get_tournament_stages(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+) -> CommandResult<Vec<TournamentStage>> {
+    let db = &*state.db;
+    let stages = db.get_tournament_stages(tournament_id).await?;
+    Ok(stages)
+}
+
+#[command]
+#[specta::specta]
+pub async fn get_tournament_stage_groups(
+    state: State<'_, PawnState>,
+    stage_id: i32,
+) -> CommandResult<Vec<TournamentStageGroup>> {
+    let db = &*state.db;
+    let groups = db.get_tournament_stage_groups(stage_id).await?;
+    Ok(groups)
+}
+
+#[command]
+#[specta::specta]
+pub async fn create_tournament_stage(
+    state: State<'_, PawnState>,
+    data: CreateTournamentStage,
+) -> CommandResult<TournamentStage> {
+    let db = &*state.db;
+
+    db.get_tournament(data.tournament_id).await?;
+    let stage_number = db.get_tournament_stages(data.tournament_id).await?.len() as i32 + 1;
+    StageService::validate_stage(&data, stage_number)?;
+    for &group_tournament_id in &data.group_tournament_ids {
+        db.get_tournament(group_tournament_id).await?;
+    }
+
+    let stage = db.create_tournament_stage(&data, stage_number).await?;
+    Ok(stage)
+}
+
+#[command]
+#[specta::specta]
+pub async fn preview_stage_qualifiers(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+    stage_number: i32,
+) -> CommandResult<Vec<StageQualifier>> {
+    let stages = state.db.get_tournament_stages(tournament_id).await?;
+    let stage = find_stage(&stages, stage_number)?;
+
+    let qualifiers = stage_qualifiers(&state, stage).await?;
+    match stages.iter().find(|s| s.stage_number == stage_number + 1) {
+        Some(next_stage) => StageService::seed_qualifiers(next_stage, qualifiers),
+        None => Ok(qualifiers),
+    }
+}
+
+#[command]
+#[specta::specta]
+pub async fn advance_tournament_stage(
+    state: State<'_, PawnState>,
+    tournament_id: i32,
+    stage_number: i32,
+) -> CommandResult<AdvanceTournamentStage> {
+    let db = &*state.db;
+
+    let event = db.get_tournament(tournament_id).await?;
+    let stages = db.get_tournament_stages(tournament_id).await?;
+    let stage = find_stage(&stages, stage_number)?;
+    let next_stage = stages
+        .iter()
+        .find(|s| s.stage_number == stage_number + 1)
+        .ok_or_else(|| {
+            PawnError::InvalidInput(format!("{} has no stage after {}", event.name, stage.name))
+        })?;
+    if !db
+        .get_tournament_stage_groups(next_stage.id)
+        .await?
+        .is_empty()
+    {
+        return Err(PawnError::InvalidInput(format!(
+            "{} has already been created",
+            next_stage.name
+        )));
+    }
+
+    // Qualifiers only move on once every group is over
+    for group in db.get_tournament_stage_groups(stage.id).await? {
+        let group_tournament = db.get_tournament(group.group_tournament_id).await?;
+        let games = db
+            .get_games_by_tournament(group.group_tournament_id)
+            .await?;
+        StageService::check_group_complete(&group_tournament, &games)?;
+    }
+
+    let qualifiers = stage_qualifiers(&state, stage).await?;
+    let qualifiers = StageService::seed_qualifiers(next_stage, qualifiers)?;
+    let player_count = qualifiers.len() as i32;
+    let data = CreateTournament {
+        name: format!("{} - {}", event.name, next_stage.name),
+        location: event.location.clone(),
+        date: event.date.clone(),
+        time_type: event.time_type.clone(),
+        tournament_type: Some(next_stage.tournament_type.clone()),
+        player_count,
+        rounds_played: 0,
+        total_rounds: StageService::stage_rounds(next_stage, player_count)?,
+        country_code: event.country_code.clone(),
+    };
+
+    let tournament = db
+        .create_stage_tournament(next_stage.id, data, &qualifiers)
+        .await?;
+    Ok(AdvanceTournamentStage {
+        stage: next_stage.clone(),
+        tournament,
+        qualifiers,
+    })
+}
+
+fn find_stage(stages: &[TournamentStage], stage_number: i32) -> CommandResult<&TournamentStage> {
+    stages
+        .iter()
+        .find(|stage| stage.stage_number == stage_number)
+        .ok_or_else(|| PawnError::NotFound(format!("Stage {stage_number}")))
+}
+
+/// Qualifiers of a stage from the standings of each of its groups
+async fn stage_qualifiers(
+    state: &PawnState,
+    stage: &TournamentStage,
+) -> CommandResult<Vec<StageQualifier>> {
+    let groups = state.db.get_tournament_stage_groups(stage.id).await?;
+    if groups.is_empty() {
+        return Err(PawnError::InvalidInput(format!(
+            "{} has not been created yet",
+            stage.name
+        )));
+    }
+
+    let mut group_standings = Vec::with_capacity(groups.len());
+    for group in groups {
+        let tournament_id = group.group_tournament_id;
+        let config = match state.db.get_tournament_settings(tournament_id).await? {
+            Some(config) => config,
+            None => TournamentTiebreakConfig {
+                tournament_id,
+                ..Default::default()
+            },
+        };
+        let standings = state
+            .tiebreak_calculator
+            .calculate_standings(tournament_id, &config)
+            .await?;
+        group_standings.push((group.group_number, standings.standings));
+    }
+
+    StageService::select_qualifiers(stage, &group_standings)
+}
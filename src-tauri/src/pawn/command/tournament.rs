@@ -6,8 +6,8 @@ use crate::pawn::{
     db::Db,
     domain::{
         dto::{
-            CreateGame, CreatePlayer, CreateTournament, UpdateTournamentSettings,
-            UpdateTournamentStatus,
+            CreateGame, CreatePlayer, CreateTournament, ImportTrfRequest, TrfImportResult,
+            UpdateTournamentSettings, UpdateTournamentStatus,
         },
         model::{Game, GameResult, Player, PlayerResult, Tournament, TournamentDetails},
         tiebreak::{
@@ -72,6 +72,16 @@ pub async fn update_tournament_status(
         .await
 }
 
+#[instrument(ret, skip(state))]
+#[tauri::command]
+#[specta::specta]
+pub async fn import_tournament_trf(
+    state: State<'_, PawnState>,
+    data: ImportTrfRequest,
+) -> CommandResult<TrfImportResult> {
+    state.tournament_service.import_trf(data).await
+}
+
 // Player operations
 #[instrument(ret, skip(state))]
 #[tauri::command]
@@ -578,6 +588,99 @@ mod tests {
         assert!(result.is_ok() || result.is_err()); // Either outcome is valid for contract testing
     }
 
+    #[tokio::test]
+    async fn command_import_tournament_trf_contract() {
+        let state = setup_test_state().await;
+
+        let player_line = |rank: i32, name: &str, points: f32, rounds: &[(i32, char, char)]| {
+            let mut line = format!(
+                "001 {rank:>4}      {name:<33} 2400 UKR {:>11} {:<10} {points:>4.1} {rank:>4}",
+                "", ""
+            );
+            for (opponent, color, result) in rounds {
+                line.push_str(&format!("  {opponent:>4} {color} {result}"));
+            }
+            line
+        };
+        let content = [
+            "012 Imported Open".to_string(),
+            "XXR 3".to_string(),
+            player_line(1, "Player One", 1.5, &[(2, 'w', '1'), (3, 'b', '=')]),
+            player_line(2, "Player Two", 1.0, &[(1, 'b', '0'), (0, '-', 'U')]),
+            player_line(3, "Player Three", 1.5, &[(0, '-', 'U'), (1, 'w', '=')]),
+        ]
+        .join("\n");
+
+        // Validation alone writes nothing
+        let result = state
+            .tournament_service
+            .import_trf(ImportTrfRequest {
+                content: content.clone(),
+                validate_only: true,
+            })
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert!(result.tournament.is_none());
+        assert_eq!(result.games_imported, 4);
+        assert!(
+            state
+                .tournament_service
+                .get_tournaments()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let result = state
+            .tournament_service
+            .import_trf(ImportTrfRequest {
+                content,
+                validate_only: false,
+            })
+            .await
+            .unwrap();
+        let tournament = result.tournament.unwrap();
+        assert_eq!(tournament.name, "Imported Open");
+        assert_eq!(tournament.rounds_played, 2);
+        assert_eq!(tournament.total_rounds, 3);
+
+        let players = state
+            .tournament_service
+            .get_players_by_tournament(tournament.id)
+            .await
+            .unwrap();
+        assert_eq!(players.len(), 3);
+        let games = state
+            .tournament_service
+            .get_games_by_tournament(tournament.id)
+            .await
+            .unwrap();
+        assert_eq!(games.len(), 4);
+        assert!(
+            games
+                .iter()
+                .any(|game| game.black_player_id == -tournament.id && game.result == "1-0")
+        );
+
+        // An inconsistent file is rejected with line numbers
+        let result = state
+            .tournament_service
+            .import_trf(ImportTrfRequest {
+                content: player_line(1, "Lonely", 1.0, &[(2, 'w', '1')]),
+                validate_only: false,
+            })
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|issue| issue.line_number == Some(1))
+        );
+    }
+
     #[tokio::test]
     async fn command_get_players_by_tournament_contract() {
         let state = setup_test_state().await;
@@ -784,6 +887,8 @@ mod tests {
             organizer_name: Some("Test Organizer".to_string()),
             organizer_email: Some("organizer@test.com".to_string()),
             prize_structure: Some("Winner takes all".to_string()),
+            chief_arbiter: None,
+            deputy_arbiters: None,
         };
 
         let result = state.db.upsert_tournament_settings(&settings).await;
@@ -867,6 +972,8 @@ mod tests {
             organizer_name: Some("FIDE".to_string()),
             organizer_email: Some("info@fide.com".to_string()),
             prize_structure: Some("$50,000 first place".to_string()),
+            chief_arbiter: None,
+            deputy_arbiters: None,
         };
         assert_eq!(update_settings.tournament_id, tournament_id);
         assert_eq!(update_settings.tiebreak_order.len(), 3);
@@ -1017,6 +1124,8 @@ mod tests {
             organizer_name: Some("International Chess Federation".to_string()),
             organizer_email: Some("admin@chess.org".to_string()),
             prize_structure: Some("1st: $10000, 2nd: $5000, 3rd: $2500".to_string()),
+            chief_arbiter: Some("IA Jane Doe".to_string()),
+            deputy_arbiters: Some("FA John Roe, NA Max Mustermann".to_string()),
         };
 
         assert_eq!(settings.tournament_id, 1);
@@ -1027,6 +1136,7 @@ mod tests {
         assert!(settings.mobile_phone_policy.is_some());
         assert!(settings.late_entry_allowed.is_some());
         assert!(settings.prize_structure.is_some());
+        assert!(settings.chief_arbiter.is_some());
     }
 
     #[tokio::test]
@@ -1222,6 +1332,8 @@ mod tests {
             organizer_name: Some("Test Organizer".to_string()),
             organizer_email: Some("test@test.com".to_string()),
             prize_structure: Some("Test prizes".to_string()),
+            chief_arbiter: None,
+            deputy_arbiters: None,
         };
         let _result = state.db.upsert_tournament_settings(&update_settings).await;
     }
@@ -1503,6 +1615,8 @@ mod tests {
             organizer_name: Some("Test Organizer".to_string()),
             organizer_email: Some("organizer@test.com".to_string()),
             prize_structure: Some("Winner takes all".to_string()),
+            chief_arbiter: None,
+            deputy_arbiters: None,
         };
 
         // Test database upsert call
@@ -1,13 +1,22 @@
 use super::domain::{
     dto::{
-        ApproveGameResult, AssignPlayerToCategory, CreateGame, CreatePlayer, CreatePlayerCategory,
-        CreateRound, CreateTournament, UpdateGameResult, UpdatePlayer, UpdateTimeControl,
+        ApplyRoundRepair, ApproveGameResult, AssignPlayerToCategory, CreateByeRequest, CreateEvent,
+        CreateEventSection, CreateForbiddenPairing, CreateGame, CreatePairingDraft, CreatePerson,
+        CreatePersonRating, CreatePlayer, CreatePlayerCategory, CreateRatingHistory, CreateRound,
+        CreateTournament, CreateTournamentStage, FideRatingChange, FideRatingListImport,
+        ImportFideRatingList, ImportTournament, PlayerRatingUpdate, RecordArenaResult,
+        SetKnockoutMatchFormat, StageQualifier, UpdateAccelerationSettings, UpdateArenaSettings,
+        UpdateByeSettings, UpdateGameResult, UpdatePerson, UpdatePlayer, UpdateTimeControl,
         UpdateTournamentSettings,
     },
     model::{
-        BracketPosition, EnhancedGameResult, Game, GameResult, GameResultAudit, KnockoutBracket,
-        Player, PlayerCategory, PlayerCategoryAssignment, PlayerResult, Round, TimeControl,
-        Tournament, TournamentDetails,
+        AccelerationSettings, ArenaGame, ArenaSettings, ArenaWaitingPlayer,
+        BoardPairingExplanation, BracketPosition, ByeRequest, ByeSettings, EnhancedGameResult,
+        Event, EventRegistration, EventSection, FideRating, FideRatingList, ForbiddenPairing, Game,
+        GameResult, GameResultAudit, KnockoutBracket, KnockoutMatchFormat, KnockoutMatchGame,
+        Pairing, PairingDraft, PairingExplanation, Person, PersonRating, Player, PlayerCategory,
+        PlayerCategoryAssignment, PlayerResult, RatingHistory, Round, RoundAudit, TimeControl,
+        Tournament, TournamentDetails, TournamentStage, TournamentStageGroup,
     },
     tiebreak::TournamentTiebreakConfig,
 };
@@ -40,6 +49,19 @@ pub trait Db: Send + Sync {
         tournament_id: i32,
         status: &str,
     ) -> impl std::future::Future<Output = Result<Tournament, sqlx::Error>> + Send;
+    fn update_tournament_pairing_method(
+        &self,
+        tournament_id: i32,
+        pairing_method: &str,
+    ) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
+    fn get_tournament_pairing_method(
+        &self,
+        tournament_id: i32,
+    ) -> impl std::future::Future<Output = Result<Option<String>, sqlx::Error>> + Send;
+    fn import_tournament(
+        &self,
+        data: ImportTournament,
+    ) -> impl std::future::Future<Output = Result<Tournament, sqlx::Error>> + Send;
 
     // Player operations
     fn get_players_by_tournament(
@@ -76,6 +98,10 @@ pub trait Db: Send + Sync {
         &self,
         data: CreateGame,
     ) -> impl std::future::Future<Output = Result<Game, sqlx::Error>> + Send;
+    fn delete_game(
+        &self,
+        game_id: i32,
+    ) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
     fn update_game_result(
         &self,
         data: UpdateGameResult,
@@ -126,6 +152,14 @@ pub trait Db: Send + Sync {
         &self,
         settings: &UpdateTournamentSettings,
     ) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
+    fn get_acceleration_settings(
+        &self,
+        tournament_id: i32,
+    ) -> impl std::future::Future<Output = Result<Option<AccelerationSettings>, sqlx::Error>> + Send;
+    fn upsert_acceleration_settings(
+        &self,
+        settings: &UpdateAccelerationSettings,
+    ) -> impl std::future::Future<Output = Result<AccelerationSettings, sqlx::Error>> + Send;
 
     // Round operations
     fn get_rounds_by_tournament(
@@ -154,6 +188,94 @@ pub trait Db: Send + Sync {
         tournament_id: i32,
         round_number: i32,
     ) -> impl std::future::Future<Output = Result<Vec<GameResult>, sqlx::Error>> + Send;
+    fn create_round_audit(
+        &self,
+        round_id: i32,
+        action: &str,
+        details: &str,
+        reason: Option<&str>,
+    ) -> impl std::future::Future<Output = Result<RoundAudit, sqlx::Error>> + Send;
+    fn apply_round_repair(
+        &self,
+        data: ApplyRoundRepair,
+    ) -> impl std::future::Future<Output = Result<RoundAudit, sqlx::Error>> + Send;
+    fn get_round_audit_trail(
+        &self,
+        round_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<RoundAudit>, sqlx::Error>> + Send;
+    fn create_pairing_draft(
+        &self,
+        data: &CreatePairingDraft,
+        name: &str,
+        pairings: &[Pairing],
+    ) -> impl std::future::Future<Output = Result<PairingDraft, sqlx::Error>> + Send;
+    fn get_pairing_draft(
+        &self,
+        draft_id: i32,
+    ) -> impl std::future::Future<Output = Result<PairingDraft, sqlx::Error>> + Send;
+    fn get_pairing_drafts(
+        &self,
+        round_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<PairingDraft>, sqlx::Error>> + Send;
+    fn delete_pairing_draft(
+        &self,
+        draft_id: i32,
+    ) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
+    fn delete_round_pairing_drafts(
+        &self,
+        round_id: i32,
+    ) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
+    fn save_pairing_explanation(
+        &self,
+        game_id: i32,
+        board_number: i32,
+        explanation: &PairingExplanation,
+    ) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
+    fn get_pairing_explanations(
+        &self,
+        tournament_id: i32,
+        round_number: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<BoardPairingExplanation>, sqlx::Error>> + Send;
+
+    // Bye request operations
+    fn create_bye_requests(
+        &self,
+        data: &CreateByeRequest,
+    ) -> impl std::future::Future<Output = Result<Vec<ByeRequest>, sqlx::Error>> + Send;
+    fn get_bye_request(
+        &self,
+        request_id: i32,
+    ) -> impl std::future::Future<Output = Result<ByeRequest, sqlx::Error>> + Send;
+    fn delete_bye_request(
+        &self,
+        request_id: i32,
+    ) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
+    fn get_bye_requests(
+        &self,
+        tournament_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<ByeRequest>, sqlx::Error>> + Send;
+    fn get_bye_settings(
+        &self,
+        tournament_id: i32,
+    ) -> impl std::future::Future<Output = Result<Option<ByeSettings>, sqlx::Error>> + Send;
+    fn upsert_bye_settings(
+        &self,
+        settings: &UpdateByeSettings,
+    ) -> impl std::future::Future<Output = Result<ByeSettings, sqlx::Error>> + Send;
+
+    // Forbidden pairing operations
+    fn get_forbidden_pairings(
+        &self,
+        tournament_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<ForbiddenPairing>, sqlx::Error>> + Send;
+    fn create_forbidden_pairing(
+        &self,
+        data: &CreateForbiddenPairing,
+    ) -> impl std::future::Future<Output = Result<ForbiddenPairing, sqlx::Error>> + Send;
+    fn delete_forbidden_pairing(
+        &self,
+        pairing_id: i32,
+    ) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
 
     // Player category operations
     fn get_tournament_categories(
@@ -210,6 +332,252 @@ pub trait Db: Send + Sync {
         player_id: Option<i32>,
         status: String,
     ) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
+    fn get_knockout_match_formats(
+        &self,
+        bracket_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<KnockoutMatchFormat>, sqlx::Error>> + Send;
+    fn get_knockout_match_format(
+        &self,
+        bracket_id: i32,
+        bracket_side: &str,
+        round_number: i32,
+    ) -> impl std::future::Future<Output = Result<Option<KnockoutMatchFormat>, sqlx::Error>> + Send;
+    fn upsert_knockout_match_format(
+        &self,
+        data: &SetKnockoutMatchFormat,
+        bracket_side: &str,
+    ) -> impl std::future::Future<Output = Result<KnockoutMatchFormat, sqlx::Error>> + Send;
+    fn create_knockout_match_game(
+        &self,
+        game: KnockoutMatchGame,
+    ) -> impl std::future::Future<Output = Result<KnockoutMatchGame, sqlx::Error>> + Send;
+    fn get_knockout_match_game(
+        &self,
+        game_id: i32,
+    ) -> impl std::future::Future<Output = Result<KnockoutMatchGame, sqlx::Error>> + Send;
+    fn get_knockout_match_games(
+        &self,
+        bracket_id: i32,
+        bracket_side: &str,
+        round_number: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<KnockoutMatchGame>, sqlx::Error>> + Send;
+    fn update_knockout_match_game_result(
+        &self,
+        game_id: i32,
+        result: &str,
+    ) -> impl std::future::Future<Output = Result<KnockoutMatchGame, sqlx::Error>> + Send;
+
+    // Arena tournament operations
+    fn get_arena_settings(
+        &self,
+        tournament_id: i32,
+    ) -> impl std::future::Future<Output = Result<Option<ArenaSettings>, sqlx::Error>> + Send;
+    fn upsert_arena_settings(
+        &self,
+        settings: &UpdateArenaSettings,
+    ) -> impl std::future::Future<Output = Result<ArenaSettings, sqlx::Error>> + Send;
+    fn get_arena_waiting_players(
+        &self,
+        tournament_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<ArenaWaitingPlayer>, sqlx::Error>> + Send;
+    fn add_arena_waiting_player(
+        &self,
+        tournament_id: i32,
+        player_id: i32,
+    ) -> impl std::future::Future<Output = Result<ArenaWaitingPlayer, sqlx::Error>> + Send;
+    fn remove_arena_waiting_player(
+        &self,
+        tournament_id: i32,
+        player_id: i32,
+    ) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
+    fn start_arena_game(
+        &self,
+        tournament_id: i32,
+        white_player_id: i32,
+        black_player_id: i32,
+    ) -> impl std::future::Future<Output = Result<ArenaGame, sqlx::Error>> + Send;
+    fn get_arena_game(
+        &self,
+        game_id: i32,
+    ) -> impl std::future::Future<Output = Result<ArenaGame, sqlx::Error>> + Send;
+    fn get_arena_games(
+        &self,
+        tournament_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<ArenaGame>, sqlx::Error>> + Send;
+    fn finish_arena_game(
+        &self,
+        data: &RecordArenaResult,
+    ) -> impl std::future::Future<Output = Result<ArenaGame, sqlx::Error>> + Send;
+
+    // Multi-stage event operations
+    fn get_tournament_stages(
+        &self,
+        tournament_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<TournamentStage>, sqlx::Error>> + Send;
+    fn create_tournament_stage(
+        &self,
+        data: &CreateTournamentStage,
+        stage_number: i32,
+    ) -> impl std::future::Future<Output = Result<TournamentStage, sqlx::Error>> + Send;
+    fn get_tournament_stage_groups(
+        &self,
+        stage_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<TournamentStageGroup>, sqlx::Error>> + Send;
+    fn create_stage_tournament(
+        &self,
+        stage_id: i32,
+        data: CreateTournament,
+        qualifiers: &[StageQualifier],
+    ) -> impl std::future::Future<Output = Result<Tournament, sqlx::Error>> + Send;
+
+    // Event operations
+    fn get_events(
+        &self,
+    ) -> impl std::future::Future<Output = Result<Vec<Event>, sqlx::Error>> + Send;
+    fn get_event(
+        &self,
+        event_id: i32,
+    ) -> impl std::future::Future<Output = Result<Event, sqlx::Error>> + Send;
+    fn create_event(
+        &self,
+        data: &CreateEvent,
+    ) -> impl std::future::Future<Output = Result<Event, sqlx::Error>> + Send;
+    fn get_event_sections(
+        &self,
+        event_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<EventSection>, sqlx::Error>> + Send;
+    fn get_event_section(
+        &self,
+        section_id: i32,
+    ) -> impl std::future::Future<Output = Result<EventSection, sqlx::Error>> + Send;
+    fn create_event_section(
+        &self,
+        data: &CreateEventSection,
+    ) -> impl std::future::Future<Output = Result<EventSection, sqlx::Error>> + Send;
+    fn get_event_registrations(
+        &self,
+        event_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<EventRegistration>, sqlx::Error>> + Send;
+    fn get_event_registration(
+        &self,
+        registration_id: i32,
+    ) -> impl std::future::Future<Output = Result<EventRegistration, sqlx::Error>> + Send;
+    fn create_event_registration(
+        &self,
+        section: &EventSection,
+        player: CreatePlayer,
+    ) -> impl std::future::Future<Output = Result<EventRegistration, sqlx::Error>> + Send;
+    fn move_event_registration(
+        &self,
+        registration_id: i32,
+        section: &EventSection,
+    ) -> impl std::future::Future<Output = Result<EventRegistration, sqlx::Error>> + Send;
+
+    // Player registry operations
+    fn get_persons(
+        &self,
+    ) -> impl std::future::Future<Output = Result<Vec<Person>, sqlx::Error>> + Send;
+    fn search_persons(
+        &self,
+        query: &str,
+    ) -> impl std::future::Future<Output = Result<Vec<Person>, sqlx::Error>> + Send;
+    fn get_person(
+        &self,
+        person_id: i32,
+    ) -> impl std::future::Future<Output = Result<Person, sqlx::Error>> + Send;
+    fn create_person(
+        &self,
+        data: &CreatePerson,
+    ) -> impl std::future::Future<Output = Result<Person, sqlx::Error>> + Send;
+    fn update_person(
+        &self,
+        data: &UpdatePerson,
+    ) -> impl std::future::Future<Output = Result<Person, sqlx::Error>> + Send;
+    fn create_person_player(
+        &self,
+        person: &Person,
+        tournament_id: i32,
+        rating: Option<i32>,
+    ) -> impl std::future::Future<Output = Result<Player, sqlx::Error>> + Send;
+    fn link_player_to_person(
+        &self,
+        player_id: i32,
+        person_id: Option<i32>,
+    ) -> impl std::future::Future<Output = Result<Player, sqlx::Error>> + Send;
+    fn get_person_players(
+        &self,
+        person_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<Player>, sqlx::Error>> + Send;
+    fn get_person_tournaments(
+        &self,
+        person_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<Tournament>, sqlx::Error>> + Send;
+    fn get_person_tournament_players(
+        &self,
+        person_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<Player>, sqlx::Error>> + Send;
+    fn get_person_tournament_games(
+        &self,
+        person_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<Game>, sqlx::Error>> + Send;
+    fn get_player_person(
+        &self,
+        player_id: i32,
+    ) -> impl std::future::Future<Output = Result<Option<Person>, sqlx::Error>> + Send;
+    fn add_person_rating(
+        &self,
+        data: &CreatePersonRating,
+    ) -> impl std::future::Future<Output = Result<PersonRating, sqlx::Error>> + Send;
+    fn get_person_rating_history(
+        &self,
+        person_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<PersonRating>, sqlx::Error>> + Send;
+    fn add_rating_history(
+        &self,
+        data: &CreateRatingHistory,
+    ) -> impl std::future::Future<Output = Result<RatingHistory, sqlx::Error>> + Send;
+    fn get_player_rating_history(
+        &self,
+        player_id: i32,
+    ) -> impl std::future::Future<Output = Result<Vec<RatingHistory>, sqlx::Error>> + Send;
+    fn get_person_by_fide_id(
+        &self,
+        fide_id: &str,
+    ) -> impl std::future::Future<Output = Result<Option<Person>, sqlx::Error>> + Send;
+
+    // FIDE rating list operations
+    fn import_fide_rating_list(
+        &self,
+        data: ImportFideRatingList,
+    ) -> impl std::future::Future<Output = Result<FideRatingListImport, sqlx::Error>> + Send;
+    fn get_fide_rating_lists(
+        &self,
+    ) -> impl std::future::Future<Output = Result<Vec<FideRatingList>, sqlx::Error>> + Send;
+    fn get_fide_rating_list(
+        &self,
+        list_date: Option<&str>,
+    ) -> impl std::future::Future<Output = Result<Option<FideRatingList>, sqlx::Error>> + Send;
+    fn search_fide_ratings(
+        &self,
+        list_id: i32,
+        query: &str,
+    ) -> impl std::future::Future<Output = Result<Vec<FideRating>, sqlx::Error>> + Send;
+    fn get_fide_rating(
+        &self,
+        list_id: i32,
+        fide_id: i32,
+    ) -> impl std::future::Future<Output = Result<Option<FideRating>, sqlx::Error>> + Send;
+    fn find_fide_ratings_by_name(
+        &self,
+        list_id: i32,
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Vec<FideRating>, sqlx::Error>> + Send;
+    fn update_player_ratings(
+        &self,
+        updates: &[PlayerRatingUpdate],
+        rating_type: &str,
+        effective_date: &str,
+    ) -> impl std::future::Future<Output = Result<(), sqlx::Error>> + Send;
 
     // Time control operations
     fn get_time_controls(
@@ -93,6 +93,63 @@ impl Db for SqliteDb {
             .execute(&mut *tx)
             .await?;
 
+        sqlx::query("DELETE FROM tournament_acceleration_settings WHERE tournament_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM tournament_bye_settings WHERE tournament_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM bye_requests WHERE tournament_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM forbidden_pairings WHERE tournament_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM arena_settings WHERE tournament_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM arena_waiting_players WHERE tournament_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM arena_games WHERE tournament_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(
+            "DELETE FROM round_audit WHERE round_id IN (SELECT id FROM rounds WHERE tournament_id = ?)",
+        )
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "DELETE FROM pairing_drafts WHERE round_id IN (SELECT id FROM rounds WHERE tournament_id = ?)",
+        )
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
+        // Delete pairing explanations of the tournament's games
+        sqlx::query(
+            "DELETE FROM pairing_explanations WHERE game_id IN (SELECT id FROM games WHERE tournament_id = ?)",
+        )
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+
         // Delete all games for this tournament
         sqlx::query("DELETE FROM games WHERE tournament_id = ?")
             .bind(id)
@@ -134,6 +191,146 @@ impl Db for SqliteDb {
         self.get_tournament(tournament_id).await
     }
 
+    #[instrument(ret, skip(self))]
+    async fn update_tournament_pairing_method(
+        &self,
+        tournament_id: i32,
+        pairing_method: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE tournaments SET pairing_method = ? WHERE id = ?")
+            .bind(pairing_method)
+            .bind(tournament_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_tournament_pairing_method(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Option<String>, sqlx::Error> {
+        let pairing_method =
+            sqlx::query_scalar("SELECT pairing_method FROM tournaments WHERE id = ?")
+                .bind(tournament_id)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(pairing_method)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn import_tournament(&self, data: ImportTournament) -> Result<Tournament, sqlx::Error> {
+        // Everything is written in one transaction so a failure leaves no partial import
+        let mut tx = self.pool.begin().await?;
+
+        let status = if data.tournament.rounds_played >= data.tournament.total_rounds
+            && data.rounds.iter().all(|round| round.status == "completed")
+        {
+            "completed"
+        } else {
+            "ongoing"
+        };
+        let tournament: Tournament = sqlx::query_as(
+            "INSERT INTO tournaments (name, location, date, time_type, tournament_type, player_count, rounds_played, total_rounds, country_code, status, end_time)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             RETURNING *"
+        )
+        .bind(&data.tournament.name)
+        .bind(&data.tournament.location)
+        .bind(&data.tournament.date)
+        .bind(&data.tournament.time_type)
+        .bind(&data.tournament.tournament_type)
+        .bind(data.tournament.player_count)
+        .bind(data.tournament.rounds_played)
+        .bind(data.tournament.total_rounds)
+        .bind(&data.tournament.country_code)
+        .bind(status)
+        .bind(&data.end_date)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if data.chief_arbiter.is_some() || data.deputy_arbiters.is_some() {
+            sqlx::query(
+                "INSERT INTO tournament_settings (tournament_id, chief_arbiter, deputy_arbiters) VALUES (?, ?, ?)",
+            )
+            .bind(tournament.id)
+            .bind(&data.chief_arbiter)
+            .bind(&data.deputy_arbiters)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let mut player_ids = std::collections::HashMap::new();
+        for import in &data.players {
+            let player = &import.player;
+            let player_id: i32 = sqlx::query_scalar(
+                "INSERT INTO players (tournament_id, name, rating, country_code, title, birth_date, gender, email, phone, club, status, pairing_number, initial_rating)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'active', ?, ?)
+                 RETURNING id"
+            )
+            .bind(tournament.id)
+            .bind(&player.name)
+            .bind(player.rating)
+            .bind(&player.country_code)
+            .bind(&player.title)
+            .bind(&player.birth_date)
+            .bind(&player.gender)
+            .bind(&player.email)
+            .bind(&player.phone)
+            .bind(&player.club)
+            .bind(import.pairing_number)
+            .bind(player.rating)
+            .fetch_one(&mut *tx)
+            .await?;
+            player_ids.insert(import.pairing_number, player_id);
+        }
+
+        for round in &data.rounds {
+            sqlx::query(
+                "INSERT INTO rounds (tournament_id, round_number, status, completed_at)
+                 VALUES (?, ?, ?, CASE WHEN ? = 'completed' THEN CURRENT_TIMESTAMP ELSE NULL END)",
+            )
+            .bind(tournament.id)
+            .bind(round.round_number)
+            .bind(&round.status)
+            .bind(&round.status)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let player_id = |pairing_number: i32| {
+            player_ids.get(&pairing_number).copied().ok_or_else(|| {
+                sqlx::Error::Protocol(format!("Unknown pairing number {pairing_number}"))
+            })
+        };
+        for game in &data.games {
+            // Byes are played against the tournament's virtual BYE player
+            let black_player_id = match game.black_pairing_number {
+                Some(pairing_number) => player_id(pairing_number)?,
+                None => -tournament.id,
+            };
+            sqlx::query(
+                "INSERT INTO games (tournament_id, round_number, white_player_id, black_player_id, result, result_type, approved_by)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(tournament.id)
+            .bind(game.round_number)
+            .bind(player_id(game.white_pairing_number)?)
+            .bind(black_player_id)
+            .bind(&game.result)
+            .bind(&game.result_type)
+            .bind(game.result_type.as_ref().map(|_| "TRF import"))
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(tournament)
+    }
+
     #[instrument(ret, skip(self))]
     async fn get_players_by_tournament(
         &self,
@@ -281,6 +478,29 @@ impl Db for SqliteDb {
         Ok(player)
     }
 
+    #[instrument(ret, skip(self))]
+    async fn delete_game(&self, game_id: i32) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM pairing_explanations WHERE game_id = ?")
+            .bind(game_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let result = sqlx::query("DELETE FROM games WHERE id = ?")
+            .bind(game_id)
+            .execute(&mut *tx)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
     #[instrument(ret, skip(self))]
     async fn update_game_result(&self, data: UpdateGameResult) -> Result<Game, sqlx::Error> {
         let game: Game = sqlx::query_as(
@@ -507,6 +727,8 @@ impl Db for SqliteDb {
             organizer_name: Option<String>,
             organizer_email: Option<String>,
             prize_structure: Option<String>,
+            chief_arbiter: Option<String>,
+            deputy_arbiters: Option<String>,
         }
 
         let result: Option<TournamentSettingsRow> = sqlx::query_as(
@@ -514,7 +736,8 @@ impl Db for SqliteDb {
             SELECT tiebreak_order, use_fide_defaults, forfeit_time_minutes, 
                    draw_offers_allowed, mobile_phone_policy, default_color_allocation,
                    late_entry_allowed, bye_assignment_rule, arbiter_notes,
-                   tournament_category, organizer_name, organizer_email, prize_structure
+                   tournament_category, organizer_name, organizer_email, prize_structure,
+                   chief_arbiter, deputy_arbiters
             FROM tournament_settings
             WHERE tournament_id = ?
             "#,
@@ -546,6 +769,8 @@ impl Db for SqliteDb {
                     organizer_name: row.organizer_name,
                     organizer_email: row.organizer_email,
                     prize_structure: row.prize_structure,
+                    chief_arbiter: row.chief_arbiter,
+                    deputy_arbiters: row.deputy_arbiters,
                 }))
             }
             None => {
@@ -575,9 +800,9 @@ impl Db for SqliteDb {
                 forfeit_time_minutes, draw_offers_allowed, mobile_phone_policy,
                 default_color_allocation, late_entry_allowed, bye_assignment_rule,
                 arbiter_notes, tournament_category, organizer_name,
-                organizer_email, prize_structure
+                organizer_email, prize_structure, chief_arbiter, deputy_arbiters
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT(tournament_id) DO UPDATE SET
                 tiebreak_order = excluded.tiebreak_order,
                 use_fide_defaults = excluded.use_fide_defaults,
@@ -592,6 +817,8 @@ impl Db for SqliteDb {
                 organizer_name = excluded.organizer_name,
                 organizer_email = excluded.organizer_email,
                 prize_structure = excluded.prize_structure,
+                chief_arbiter = excluded.chief_arbiter,
+                deputy_arbiters = excluded.deputy_arbiters,
                 updated_at = CURRENT_TIMESTAMP
             "#,
         )
@@ -609,12 +836,65 @@ impl Db for SqliteDb {
         .bind(settings.organizer_name.as_deref())
         .bind(settings.organizer_email.as_deref())
         .bind(settings.prize_structure.as_deref())
+        .bind(settings.chief_arbiter.as_deref())
+        .bind(settings.deputy_arbiters.as_deref())
         .execute(&self.pool)
         .await?;
 
         Ok(())
     }
 
+    #[instrument(ret, skip(self))]
+    async fn get_acceleration_settings(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Option<AccelerationSettings>, sqlx::Error> {
+        let settings = sqlx::query_as(
+            "SELECT * FROM tournament_acceleration_settings WHERE tournament_id = ?",
+        )
+        .bind(tournament_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(settings)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn upsert_acceleration_settings(
+        &self,
+        settings: &UpdateAccelerationSettings,
+    ) -> Result<AccelerationSettings, sqlx::Error> {
+        let settings = sqlx::query_as(
+            r#"
+            INSERT INTO tournament_acceleration_settings (
+                tournament_id, enabled, accelerated_rounds, group_boundary,
+                group_a_min_rating, virtual_points_first_half, virtual_points_second_half
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(tournament_id) DO UPDATE SET
+                enabled = excluded.enabled,
+                accelerated_rounds = excluded.accelerated_rounds,
+                group_boundary = excluded.group_boundary,
+                group_a_min_rating = excluded.group_a_min_rating,
+                virtual_points_first_half = excluded.virtual_points_first_half,
+                virtual_points_second_half = excluded.virtual_points_second_half,
+                updated_at = CURRENT_TIMESTAMP
+            RETURNING *
+            "#,
+        )
+        .bind(settings.tournament_id)
+        .bind(settings.enabled)
+        .bind(settings.accelerated_rounds)
+        .bind(&settings.group_boundary)
+        .bind(settings.group_a_min_rating)
+        .bind(settings.virtual_points_first_half)
+        .bind(settings.virtual_points_second_half)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(settings)
+    }
+
     // Round operations
     #[instrument(ret, skip(self))]
     async fn get_rounds_by_tournament(
@@ -730,242 +1010,1831 @@ impl Db for SqliteDb {
         Ok(game_results)
     }
 
-    // Player category operations
-
     #[instrument(ret, skip(self))]
-    async fn get_tournament_categories(
+    async fn create_round_audit(
         &self,
-        tournament_id: i32,
-    ) -> Result<Vec<PlayerCategory>, sqlx::Error> {
-        let categories = sqlx::query_as::<_, PlayerCategory>(
-            "SELECT * FROM player_categories WHERE tournament_id = ? ORDER BY created_at",
+        round_id: i32,
+        action: &str,
+        details: &str,
+        reason: Option<&str>,
+    ) -> Result<RoundAudit, sqlx::Error> {
+        let audit = sqlx::query_as(
+            "INSERT INTO round_audit (round_id, action, details, reason)
+             VALUES (?, ?, ?, ?)
+             RETURNING *",
         )
-        .bind(tournament_id)
-        .fetch_all(&self.pool)
+        .bind(round_id)
+        .bind(action)
+        .bind(details)
+        .bind(reason)
+        .fetch_one(&self.pool)
         .await?;
 
-        Ok(categories)
+        Ok(audit)
     }
 
     #[instrument(ret, skip(self))]
-    async fn create_player_category(
-        &self,
-        data: CreatePlayerCategory,
-    ) -> Result<PlayerCategory, sqlx::Error> {
-        let result = sqlx::query(
-            r#"
-            INSERT INTO player_categories (
-                tournament_id, name, description, min_rating, max_rating, 
-                min_age, max_age, gender_restriction
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
-            "#,
+    async fn apply_round_repair(&self, data: ApplyRoundRepair) -> Result<RoundAudit, sqlx::Error> {
+        // The replaced games, the new ones and the audit entry land together or not at all
+        let mut tx = self.pool.begin().await?;
+
+        for game_id in &data.replaced_game_ids {
+            sqlx::query("DELETE FROM pairing_explanations WHERE game_id = ?")
+                .bind(game_id)
+                .execute(&mut *tx)
+                .await?;
+            let result = sqlx::query("DELETE FROM games WHERE id = ?")
+                .bind(game_id)
+                .execute(&mut *tx)
+                .await?;
+            if result.rows_affected() == 0 {
+                return Err(sqlx::Error::RowNotFound);
+            }
+        }
+
+        for repaired in &data.games {
+            let game = &repaired.game;
+            let game_id: i32 = sqlx::query_scalar(
+                "INSERT INTO games (id, tournament_id, round_number, white_player_id, black_player_id, result)
+                 VALUES (?, ?, ?, ?, ?, ?)
+                 RETURNING id",
+            )
+            .bind(repaired.game_id)
+            .bind(game.tournament_id)
+            .bind(game.round_number)
+            .bind(game.white_player_id)
+            .bind(game.black_player_id)
+            .bind(&game.result)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            if let Some(explanation) = &repaired.explanation {
+                let explanation_json = serde_json::to_string(explanation).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Failed to serialize pairing explanation: {e}"))
+                })?;
+                sqlx::query(
+                    "INSERT INTO pairing_explanations (game_id, board_number, explanation) VALUES (?, ?, ?)",
+                )
+                .bind(game_id)
+                .bind(repaired.board_number)
+                .bind(explanation_json)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+
+        let audit = sqlx::query_as(
+            "INSERT INTO round_audit (round_id, action, details, reason)
+             VALUES (?, 'partial_repairing', ?, ?)
+             RETURNING *",
         )
-        .bind(data.tournament_id)
-        .bind(&data.name)
-        .bind(data.description.as_ref())
-        .bind(data.min_rating)
-        .bind(data.max_rating)
-        .bind(data.min_age)
-        .bind(data.max_age)
-        .bind(data.gender_restriction.as_ref())
-        .execute(&self.pool)
+        .bind(data.round_id)
+        .bind(&data.audit_details)
+        .bind(&data.reason)
+        .fetch_one(&mut *tx)
         .await?;
 
-        // Fetch the created category
-        let category =
-            sqlx::query_as::<_, PlayerCategory>("SELECT * FROM player_categories WHERE id = ?")
-                .bind(result.last_insert_rowid())
-                .fetch_one(&self.pool)
-                .await?;
+        tx.commit().await?;
 
-        Ok(category)
+        Ok(audit)
     }
 
     #[instrument(ret, skip(self))]
-    async fn delete_player_category(&self, category_id: i32) -> Result<(), sqlx::Error> {
-        // First delete all assignments for this category
-        sqlx::query("DELETE FROM player_category_assignments WHERE category_id = ?")
-            .bind(category_id)
-            .execute(&self.pool)
-            .await?;
-
-        // Then delete the category itself
-        sqlx::query("DELETE FROM player_categories WHERE id = ?")
-            .bind(category_id)
-            .execute(&self.pool)
-            .await?;
+    async fn get_round_audit_trail(&self, round_id: i32) -> Result<Vec<RoundAudit>, sqlx::Error> {
+        let audit_trail =
+            sqlx::query_as("SELECT * FROM round_audit WHERE round_id = ? ORDER BY changed_at, id")
+                .bind(round_id)
+                .fetch_all(&self.pool)
+                .await?;
 
-        Ok(())
+        Ok(audit_trail)
     }
 
     #[instrument(ret, skip(self))]
-    async fn assign_player_to_category(
+    async fn create_pairing_draft(
         &self,
-        data: AssignPlayerToCategory,
-    ) -> Result<PlayerCategoryAssignment, sqlx::Error> {
-        let _result = sqlx::query(
-            "INSERT INTO player_category_assignments (player_id, category_id) VALUES (?, ?) ON CONFLICT(player_id, category_id) DO NOTHING"
-        )
-        .bind(data.player_id)
-        .bind(data.category_id)
-        .execute(&self.pool)
-        .await?;
-
-        // Fetch the assignment
-        let assignment = sqlx::query_as::<_, PlayerCategoryAssignment>(
-            "SELECT * FROM player_category_assignments WHERE player_id = ? AND category_id = ?",
+        data: &CreatePairingDraft,
+        name: &str,
+        pairings: &[Pairing],
+    ) -> Result<PairingDraft, sqlx::Error> {
+        let pairings_json = serde_json::to_string(pairings)
+            .map_err(|e| sqlx::Error::Protocol(format!("Failed to serialize pairings: {e}")))?;
+
+        let row: PairingDraftRow = sqlx::query_as(
+            "INSERT INTO pairing_drafts (round_id, name, pairing_method, avoid_same_club, pairings)
+             VALUES (?, ?, ?, ?, ?)
+             RETURNING id, round_id, name, pairing_method, avoid_same_club, pairings, created_at",
         )
-        .bind(data.player_id)
-        .bind(data.category_id)
+        .bind(data.round_id)
+        .bind(name)
+        .bind(&data.pairing_method)
+        .bind(data.avoid_same_club)
+        .bind(pairings_json)
         .fetch_one(&self.pool)
         .await?;
 
-        Ok(assignment)
+        pairing_draft_from_row(row)
     }
 
     #[instrument(ret, skip(self))]
-    async fn get_player_category_assignments(
-        &self,
-        tournament_id: i32,
-    ) -> Result<Vec<PlayerCategoryAssignment>, sqlx::Error> {
-        let assignments = sqlx::query_as::<_, PlayerCategoryAssignment>(
-            r#"
-            SELECT pca.* 
-            FROM player_category_assignments pca
-            JOIN player_categories pc ON pca.category_id = pc.id
-            WHERE pc.tournament_id = ?
-            ORDER BY pca.assigned_at
-            "#,
+    async fn get_pairing_draft(&self, draft_id: i32) -> Result<PairingDraft, sqlx::Error> {
+        let row: PairingDraftRow = sqlx::query_as(
+            "SELECT id, round_id, name, pairing_method, avoid_same_club, pairings, created_at
+             FROM pairing_drafts WHERE id = ?",
         )
-        .bind(tournament_id)
-        .fetch_all(&self.pool)
+        .bind(draft_id)
+        .fetch_one(&self.pool)
         .await?;
 
-        Ok(assignments)
+        pairing_draft_from_row(row)
     }
 
-    // Knockout tournament operations
     #[instrument(ret, skip(self))]
-    async fn create_knockout_bracket(
-        &self,
-        bracket: KnockoutBracket,
-    ) -> Result<KnockoutBracket, sqlx::Error> {
-        let result = sqlx::query_as(
-            "INSERT INTO knockout_brackets (tournament_id, bracket_type, total_rounds)
-             VALUES (?, ?, ?)
-             RETURNING *",
+    async fn get_pairing_drafts(&self, round_id: i32) -> Result<Vec<PairingDraft>, sqlx::Error> {
+        let rows: Vec<PairingDraftRow> = sqlx::query_as(
+            "SELECT id, round_id, name, pairing_method, avoid_same_club, pairings, created_at
+             FROM pairing_drafts WHERE round_id = ? ORDER BY id",
         )
-        .bind(bracket.tournament_id)
-        .bind(&bracket.bracket_type)
-        .bind(bracket.total_rounds)
-        .fetch_one(&self.pool)
+        .bind(round_id)
+        .fetch_all(&self.pool)
         .await?;
 
-        Ok(result)
+        rows.into_iter().map(pairing_draft_from_row).collect()
     }
 
     #[instrument(ret, skip(self))]
-    async fn get_knockout_bracket(
+    async fn delete_pairing_draft(&self, draft_id: i32) -> Result<(), sqlx::Error> {
+        let result = sqlx::query("DELETE FROM pairing_drafts WHERE id = ?")
+            .bind(draft_id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn delete_round_pairing_drafts(&self, round_id: i32) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM pairing_drafts WHERE round_id = ?")
+            .bind(round_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // Player category operations
+
+    #[instrument(ret, skip(self))]
+    async fn save_pairing_explanation(
+        &self,
+        game_id: i32,
+        board_number: i32,
+        explanation: &PairingExplanation,
+    ) -> Result<(), sqlx::Error> {
+        let explanation_json = serde_json::to_string(explanation).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize pairing explanation: {e}"))
+        })?;
+
+        sqlx::query(
+            "INSERT INTO pairing_explanations (game_id, board_number, explanation) VALUES (?, ?, ?)
+             ON CONFLICT(game_id) DO UPDATE SET
+                board_number = excluded.board_number,
+                explanation = excluded.explanation",
+        )
+        .bind(game_id)
+        .bind(board_number)
+        .bind(explanation_json)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_pairing_explanations(
+        &self,
+        tournament_id: i32,
+        round_number: i32,
+    ) -> Result<Vec<BoardPairingExplanation>, sqlx::Error> {
+        let rows: Vec<(i32, i32, String)> = sqlx::query_as(
+            "SELECT pe.game_id, pe.board_number, pe.explanation
+             FROM pairing_explanations pe
+             JOIN games g ON g.id = pe.game_id
+             WHERE g.tournament_id = ? AND g.round_number = ?
+             ORDER BY pe.board_number",
+        )
+        .bind(tournament_id)
+        .bind(round_number)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|(game_id, board_number, explanation)| {
+                let explanation = serde_json::from_str(&explanation).map_err(|e| {
+                    sqlx::Error::Protocol(format!("Failed to parse pairing explanation: {e}"))
+                })?;
+                Ok(BoardPairingExplanation {
+                    game_id,
+                    board_number,
+                    explanation,
+                })
+            })
+            .collect()
+    }
+
+    // Bye request operations
+
+    #[instrument(ret, skip(self))]
+    async fn create_bye_requests(
+        &self,
+        data: &CreateByeRequest,
+    ) -> Result<Vec<ByeRequest>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let mut requests = Vec::with_capacity(data.round_numbers.len());
+        for &round_number in &data.round_numbers {
+            let request = sqlx::query_as(
+                "INSERT INTO bye_requests (tournament_id, player_id, round_number, bye_points)
+                 VALUES (?, ?, ?, ?)
+                 RETURNING *",
+            )
+            .bind(data.tournament_id)
+            .bind(data.player_id)
+            .bind(round_number)
+            .bind(data.bye_points)
+            .fetch_one(&mut *tx)
+            .await?;
+            requests.push(request);
+        }
+
+        tx.commit().await?;
+
+        Ok(requests)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_bye_request(&self, request_id: i32) -> Result<ByeRequest, sqlx::Error> {
+        let request = sqlx::query_as("SELECT * FROM bye_requests WHERE id = ?")
+            .bind(request_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(request)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn delete_bye_request(&self, request_id: i32) -> Result<(), sqlx::Error> {
+        let result = sqlx::query("DELETE FROM bye_requests WHERE id = ?")
+            .bind(request_id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_bye_requests(&self, tournament_id: i32) -> Result<Vec<ByeRequest>, sqlx::Error> {
+        let requests = sqlx::query_as(
+            "SELECT * FROM bye_requests WHERE tournament_id = ? ORDER BY round_number, player_id",
+        )
+        .bind(tournament_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(requests)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_bye_settings(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Option<ByeSettings>, sqlx::Error> {
+        let settings =
+            sqlx::query_as("SELECT * FROM tournament_bye_settings WHERE tournament_id = ?")
+                .bind(tournament_id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(settings)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn upsert_bye_settings(
+        &self,
+        settings: &UpdateByeSettings,
+    ) -> Result<ByeSettings, sqlx::Error> {
+        let settings = sqlx::query_as(
+            "INSERT INTO tournament_bye_settings (tournament_id, max_requested_byes)
+             VALUES (?, ?)
+             ON CONFLICT(tournament_id) DO UPDATE SET
+                max_requested_byes = excluded.max_requested_byes,
+                updated_at = CURRENT_TIMESTAMP
+             RETURNING *",
+        )
+        .bind(settings.tournament_id)
+        .bind(settings.max_requested_byes)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(settings)
+    }
+
+    // Forbidden pairing operations
+
+    #[instrument(ret, skip(self))]
+    async fn get_forbidden_pairings(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Vec<ForbiddenPairing>, sqlx::Error> {
+        let rows: Vec<ForbiddenPairingRow> = sqlx::query_as(
+            "SELECT id, tournament_id, player_ids, reason, first_round, last_round, created_at
+             FROM forbidden_pairings WHERE tournament_id = ? ORDER BY id",
+        )
+        .bind(tournament_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(forbidden_pairing_from_row).collect()
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn create_forbidden_pairing(
+        &self,
+        data: &CreateForbiddenPairing,
+    ) -> Result<ForbiddenPairing, sqlx::Error> {
+        let player_ids = serde_json::to_string(&data.player_ids)
+            .map_err(|e| sqlx::Error::Protocol(format!("Failed to serialize player_ids: {e}")))?;
+
+        let row: ForbiddenPairingRow = sqlx::query_as(
+            "INSERT INTO forbidden_pairings (tournament_id, player_ids, reason, first_round, last_round)
+             VALUES (?, ?, ?, ?, ?)
+             RETURNING id, tournament_id, player_ids, reason, first_round, last_round, created_at",
+        )
+        .bind(data.tournament_id)
+        .bind(player_ids)
+        .bind(&data.reason)
+        .bind(data.first_round)
+        .bind(data.last_round)
+        .fetch_one(&self.pool)
+        .await?;
+
+        forbidden_pairing_from_row(row)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn delete_forbidden_pairing(&self, pairing_id: i32) -> Result<(), sqlx::Error> {
+        let result = sqlx::query("DELETE FROM forbidden_pairings WHERE id = ?")
+            .bind(pairing_id)
+            .execute(&self.pool)
+            .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        Ok(())
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_tournament_categories(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Vec<PlayerCategory>, sqlx::Error> {
+        let categories = sqlx::query_as::<_, PlayerCategory>(
+            "SELECT * FROM player_categories WHERE tournament_id = ? ORDER BY created_at",
+        )
+        .bind(tournament_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(categories)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn create_player_category(
+        &self,
+        data: CreatePlayerCategory,
+    ) -> Result<PlayerCategory, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO player_categories (
+                tournament_id, name, description, min_rating, max_rating, 
+                min_age, max_age, gender_restriction
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(data.tournament_id)
+        .bind(&data.name)
+        .bind(data.description.as_ref())
+        .bind(data.min_rating)
+        .bind(data.max_rating)
+        .bind(data.min_age)
+        .bind(data.max_age)
+        .bind(data.gender_restriction.as_ref())
+        .execute(&self.pool)
+        .await?;
+
+        // Fetch the created category
+        let category =
+            sqlx::query_as::<_, PlayerCategory>("SELECT * FROM player_categories WHERE id = ?")
+                .bind(result.last_insert_rowid())
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(category)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn delete_player_category(&self, category_id: i32) -> Result<(), sqlx::Error> {
+        // First delete all assignments for this category
+        sqlx::query("DELETE FROM player_category_assignments WHERE category_id = ?")
+            .bind(category_id)
+            .execute(&self.pool)
+            .await?;
+
+        // Then delete the category itself
+        sqlx::query("DELETE FROM player_categories WHERE id = ?")
+            .bind(category_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn assign_player_to_category(
+        &self,
+        data: AssignPlayerToCategory,
+    ) -> Result<PlayerCategoryAssignment, sqlx::Error> {
+        let _result = sqlx::query(
+            "INSERT INTO player_category_assignments (player_id, category_id) VALUES (?, ?) ON CONFLICT(player_id, category_id) DO NOTHING"
+        )
+        .bind(data.player_id)
+        .bind(data.category_id)
+        .execute(&self.pool)
+        .await?;
+
+        // Fetch the assignment
+        let assignment = sqlx::query_as::<_, PlayerCategoryAssignment>(
+            "SELECT * FROM player_category_assignments WHERE player_id = ? AND category_id = ?",
+        )
+        .bind(data.player_id)
+        .bind(data.category_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(assignment)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_player_category_assignments(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Vec<PlayerCategoryAssignment>, sqlx::Error> {
+        let assignments = sqlx::query_as::<_, PlayerCategoryAssignment>(
+            r#"
+            SELECT pca.* 
+            FROM player_category_assignments pca
+            JOIN player_categories pc ON pca.category_id = pc.id
+            WHERE pc.tournament_id = ?
+            ORDER BY pca.assigned_at
+            "#,
+        )
+        .bind(tournament_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(assignments)
+    }
+
+    // Knockout tournament operations
+    #[instrument(ret, skip(self))]
+    async fn create_knockout_bracket(
+        &self,
+        bracket: KnockoutBracket,
+    ) -> Result<KnockoutBracket, sqlx::Error> {
+        let result = sqlx::query_as(
+            "INSERT INTO knockout_brackets (tournament_id, bracket_type, total_rounds, bracket_reset)
+             VALUES (?, ?, ?, ?)
+             RETURNING *",
+        )
+        .bind(bracket.tournament_id)
+        .bind(&bracket.bracket_type)
+        .bind(bracket.total_rounds)
+        .bind(bracket.bracket_reset)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_knockout_bracket(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Option<KnockoutBracket>, sqlx::Error> {
+        let result = sqlx::query_as("SELECT * FROM knockout_brackets WHERE tournament_id = ?")
+            .bind(tournament_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_knockout_bracket_by_id(
+        &self,
+        bracket_id: i32,
+    ) -> Result<Option<KnockoutBracket>, sqlx::Error> {
+        let result = sqlx::query_as("SELECT * FROM knockout_brackets WHERE id = ?")
+            .bind(bracket_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn create_bracket_position(
+        &self,
+        position: BracketPosition,
+    ) -> Result<BracketPosition, sqlx::Error> {
+        let result = sqlx::query_as(
+            "INSERT INTO bracket_positions (bracket_id, bracket_side, round_number, position_number, player_id, advanced_from_position, status)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             RETURNING *"
+        )
+        .bind(position.bracket_id)
+        .bind(&position.bracket_side)
+        .bind(position.round_number)
+        .bind(position.position_number)
+        .bind(position.player_id)
+        .bind(position.advanced_from_position)
+        .bind(&position.status)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_bracket_positions(
+        &self,
+        bracket_id: i32,
+    ) -> Result<Vec<BracketPosition>, sqlx::Error> {
+        let positions = sqlx::query_as(
+            "SELECT * FROM bracket_positions 
+             WHERE bracket_id = ? 
+             ORDER BY bracket_side DESC, round_number, position_number",
+        )
+        .bind(bracket_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(positions)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_bracket_positions_by_round(
+        &self,
+        bracket_id: i32,
+        round_number: i32,
+    ) -> Result<Vec<BracketPosition>, sqlx::Error> {
+        let positions = sqlx::query_as(
+            "SELECT * FROM bracket_positions 
+             WHERE bracket_id = ? AND round_number = ?
+             ORDER BY bracket_side DESC, position_number",
+        )
+        .bind(bracket_id)
+        .bind(round_number)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(positions)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn update_bracket_position(
+        &self,
+        position_id: i32,
+        player_id: Option<i32>,
+        status: String,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE bracket_positions 
+             SET player_id = ?, status = ?
+             WHERE id = ?",
+        )
+        .bind(player_id)
+        .bind(&status)
+        .bind(position_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_knockout_match_formats(
+        &self,
+        bracket_id: i32,
+    ) -> Result<Vec<KnockoutMatchFormat>, sqlx::Error> {
+        let rows: Vec<KnockoutMatchFormatRow> = sqlx::query_as(
+            "SELECT id, bracket_id, bracket_side, round_number, games, time_control_id, tiebreak_stages,
+                    armageddon_time_control_id, armageddon_color, created_at
+             FROM knockout_match_formats WHERE bracket_id = ?
+             ORDER BY bracket_side DESC, round_number",
+        )
+        .bind(bracket_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(knockout_match_format_from_row)
+            .collect()
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_knockout_match_format(
+        &self,
+        bracket_id: i32,
+        bracket_side: &str,
+        round_number: i32,
+    ) -> Result<Option<KnockoutMatchFormat>, sqlx::Error> {
+        let row: Option<KnockoutMatchFormatRow> = sqlx::query_as(
+            "SELECT id, bracket_id, bracket_side, round_number, games, time_control_id, tiebreak_stages,
+                    armageddon_time_control_id, armageddon_color, created_at
+             FROM knockout_match_formats
+             WHERE bracket_id = ? AND bracket_side = ? AND round_number = ?",
+        )
+        .bind(bracket_id)
+        .bind(bracket_side)
+        .bind(round_number)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(knockout_match_format_from_row).transpose()
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn upsert_knockout_match_format(
+        &self,
+        data: &SetKnockoutMatchFormat,
+        bracket_side: &str,
+    ) -> Result<KnockoutMatchFormat, sqlx::Error> {
+        let tiebreak_stages = serde_json::to_string(&data.tiebreak_stages).map_err(|e| {
+            sqlx::Error::Protocol(format!("Failed to serialize tiebreak_stages: {e}"))
+        })?;
+
+        let row: KnockoutMatchFormatRow = sqlx::query_as(
+            r#"
+            INSERT INTO knockout_match_formats (bracket_id, bracket_side, round_number, games,
+                time_control_id, tiebreak_stages, armageddon_time_control_id, armageddon_color)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(bracket_id, bracket_side, round_number) DO UPDATE SET
+                games = excluded.games,
+                time_control_id = excluded.time_control_id,
+                tiebreak_stages = excluded.tiebreak_stages,
+                armageddon_time_control_id = excluded.armageddon_time_control_id,
+                armageddon_color = excluded.armageddon_color
+            RETURNING id, bracket_id, bracket_side, round_number, games, time_control_id,
+                tiebreak_stages, armageddon_time_control_id, armageddon_color, created_at
+            "#,
+        )
+        .bind(data.bracket_id)
+        .bind(bracket_side)
+        .bind(data.round_number)
+        .bind(data.games)
+        .bind(data.time_control_id)
+        .bind(tiebreak_stages)
+        .bind(data.armageddon_time_control_id)
+        .bind(&data.armageddon_color)
+        .fetch_one(&self.pool)
+        .await?;
+
+        knockout_match_format_from_row(row)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn create_knockout_match_game(
+        &self,
+        game: KnockoutMatchGame,
+    ) -> Result<KnockoutMatchGame, sqlx::Error> {
+        let game = sqlx::query_as(
+            "INSERT INTO knockout_match_games (bracket_id, bracket_side, round_number, match_number, stage,
+                game_number, white_player_id, black_player_id, time_control_id, black_time_minutes)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             RETURNING *",
+        )
+        .bind(game.bracket_id)
+        .bind(&game.bracket_side)
+        .bind(game.round_number)
+        .bind(game.match_number)
+        .bind(game.stage)
+        .bind(game.game_number)
+        .bind(game.white_player_id)
+        .bind(game.black_player_id)
+        .bind(game.time_control_id)
+        .bind(game.black_time_minutes)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(game)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_knockout_match_game(
+        &self,
+        game_id: i32,
+    ) -> Result<KnockoutMatchGame, sqlx::Error> {
+        let game = sqlx::query_as("SELECT * FROM knockout_match_games WHERE id = ?")
+            .bind(game_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(game)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_knockout_match_games(
+        &self,
+        bracket_id: i32,
+        bracket_side: &str,
+        round_number: i32,
+    ) -> Result<Vec<KnockoutMatchGame>, sqlx::Error> {
+        let games = sqlx::query_as(
+            "SELECT * FROM knockout_match_games
+             WHERE bracket_id = ? AND bracket_side = ? AND round_number = ?
+             ORDER BY match_number, stage, game_number",
+        )
+        .bind(bracket_id)
+        .bind(bracket_side)
+        .bind(round_number)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(games)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn update_knockout_match_game_result(
+        &self,
+        game_id: i32,
+        result: &str,
+    ) -> Result<KnockoutMatchGame, sqlx::Error> {
+        let game =
+            sqlx::query_as("UPDATE knockout_match_games SET result = ? WHERE id = ? RETURNING *")
+                .bind(result)
+                .bind(game_id)
+                .fetch_one(&self.pool)
+                .await?;
+
+        Ok(game)
+    }
+
+    // Arena tournament operations
+    #[instrument(ret, skip(self))]
+    async fn get_arena_settings(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Option<ArenaSettings>, sqlx::Error> {
+        let settings = sqlx::query_as("SELECT * FROM arena_settings WHERE tournament_id = ?")
+            .bind(tournament_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(settings)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn upsert_arena_settings(
+        &self,
+        settings: &UpdateArenaSettings,
+    ) -> Result<ArenaSettings, sqlx::Error> {
+        let settings = sqlx::query_as(
+            r#"
+            INSERT INTO arena_settings (tournament_id, ends_at, berserk_allowed)
+            VALUES (?, ?, ?)
+            ON CONFLICT(tournament_id) DO UPDATE SET
+                ends_at = excluded.ends_at,
+                berserk_allowed = excluded.berserk_allowed,
+                updated_at = CURRENT_TIMESTAMP
+            RETURNING *
+            "#,
+        )
+        .bind(settings.tournament_id)
+        .bind(&settings.ends_at)
+        .bind(settings.berserk_allowed)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(settings)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_arena_waiting_players(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Vec<ArenaWaitingPlayer>, sqlx::Error> {
+        let waiting = sqlx::query_as(
+            "SELECT * FROM arena_waiting_players WHERE tournament_id = ? ORDER BY waiting_since, player_id",
+        )
+        .bind(tournament_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(waiting)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn add_arena_waiting_player(
         &self,
         tournament_id: i32,
-    ) -> Result<Option<KnockoutBracket>, sqlx::Error> {
-        let result = sqlx::query_as("SELECT * FROM knockout_brackets WHERE tournament_id = ?")
+        player_id: i32,
+    ) -> Result<ArenaWaitingPlayer, sqlx::Error> {
+        // A player already waiting keeps their place in the queue
+        let waiting = sqlx::query_as(
+            r#"
+            INSERT INTO arena_waiting_players (tournament_id, player_id)
+            VALUES (?, ?)
+            ON CONFLICT(tournament_id, player_id) DO UPDATE SET
+                waiting_since = arena_waiting_players.waiting_since
+            RETURNING *
+            "#,
+        )
+        .bind(tournament_id)
+        .bind(player_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(waiting)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn remove_arena_waiting_player(
+        &self,
+        tournament_id: i32,
+        player_id: i32,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM arena_waiting_players WHERE tournament_id = ? AND player_id = ?")
             .bind(tournament_id)
-            .fetch_optional(&self.pool)
+            .bind(player_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn start_arena_game(
+        &self,
+        tournament_id: i32,
+        white_player_id: i32,
+        black_player_id: i32,
+    ) -> Result<ArenaGame, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        // Both players leave the waiting pool as the game starts
+        sqlx::query(
+            "DELETE FROM arena_waiting_players WHERE tournament_id = ? AND player_id IN (?, ?)",
+        )
+        .bind(tournament_id)
+        .bind(white_player_id)
+        .bind(black_player_id)
+        .execute(&mut *tx)
+        .await?;
+
+        let game = sqlx::query_as(
+            r#"
+            INSERT INTO arena_games (tournament_id, white_player_id, black_player_id)
+            VALUES (?, ?, ?)
+            RETURNING *
+            "#,
+        )
+        .bind(tournament_id)
+        .bind(white_player_id)
+        .bind(black_player_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(game)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_arena_game(&self, game_id: i32) -> Result<ArenaGame, sqlx::Error> {
+        let game = sqlx::query_as("SELECT * FROM arena_games WHERE id = ?")
+            .bind(game_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(game)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_arena_games(&self, tournament_id: i32) -> Result<Vec<ArenaGame>, sqlx::Error> {
+        let games = sqlx::query_as("SELECT * FROM arena_games WHERE tournament_id = ? ORDER BY id")
+            .bind(tournament_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(games)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn finish_arena_game(&self, data: &RecordArenaResult) -> Result<ArenaGame, sqlx::Error> {
+        let game = sqlx::query_as(
+            r#"
+            UPDATE arena_games
+            SET result = ?, white_berserk = ?, black_berserk = ?, finished_at = CURRENT_TIMESTAMP
+            WHERE id = ?
+            RETURNING *
+            "#,
+        )
+        .bind(&data.result)
+        .bind(data.white_berserk)
+        .bind(data.black_berserk)
+        .bind(data.game_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(game)
+    }
+
+    // Multi-stage event operations
+    #[instrument(ret, skip(self))]
+    async fn get_tournament_stages(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Vec<TournamentStage>, sqlx::Error> {
+        let stages = sqlx::query_as(
+            "SELECT * FROM tournament_stages WHERE tournament_id = ? ORDER BY stage_number",
+        )
+        .bind(tournament_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(stages)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn create_tournament_stage(
+        &self,
+        data: &CreateTournamentStage,
+        stage_number: i32,
+    ) -> Result<TournamentStage, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let stage: TournamentStage = sqlx::query_as(
+            r#"
+            INSERT INTO tournament_stages (tournament_id, stage_number, name, tournament_type, rounds, qualification, qualifiers, seeding)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING *
+            "#,
+        )
+        .bind(data.tournament_id)
+        .bind(stage_number)
+        .bind(&data.name)
+        .bind(&data.tournament_type)
+        .bind(data.rounds)
+        .bind(&data.qualification)
+        .bind(data.qualifiers)
+        .bind(&data.seeding)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for (index, group_tournament_id) in data.group_tournament_ids.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO tournament_stage_groups (stage_id, group_number, group_tournament_id) VALUES (?, ?, ?)",
+            )
+            .bind(stage.id)
+            .bind(index as i32 + 1)
+            .bind(group_tournament_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(stage)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_tournament_stage_groups(
+        &self,
+        stage_id: i32,
+    ) -> Result<Vec<TournamentStageGroup>, sqlx::Error> {
+        let groups = sqlx::query_as(
+            "SELECT * FROM tournament_stage_groups WHERE stage_id = ? ORDER BY group_number",
+        )
+        .bind(stage_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(groups)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn create_stage_tournament(
+        &self,
+        stage_id: i32,
+        data: CreateTournament,
+        qualifiers: &[StageQualifier],
+    ) -> Result<Tournament, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let tournament: Tournament = sqlx::query_as(
+            "INSERT INTO tournaments (name, location, date, time_type, tournament_type, player_count, rounds_played, total_rounds, country_code)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+             RETURNING *"
+        )
+        .bind(&data.name)
+        .bind(&data.location)
+        .bind(&data.date)
+        .bind(&data.time_type)
+        .bind(&data.tournament_type)
+        .bind(data.player_count)
+        .bind(data.rounds_played)
+        .bind(data.total_rounds)
+        .bind(&data.country_code)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        // Qualifiers enter as new players, numbered by their seed
+        for qualifier in qualifiers {
+            let player = &qualifier.player;
+            sqlx::query(
+                "INSERT INTO players (tournament_id, name, rating, country_code, title, birth_date, gender, email, phone, club, status, seed_number, pairing_number, initial_rating)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'active', ?, ?, ?)"
+            )
+            .bind(tournament.id)
+            .bind(&player.name)
+            .bind(player.rating)
+            .bind(&player.country_code)
+            .bind(&player.title)
+            .bind(&player.birth_date)
+            .bind(&player.gender)
+            .bind(&player.email)
+            .bind(&player.phone)
+            .bind(&player.club)
+            .bind(qualifier.seed)
+            .bind(qualifier.seed)
+            .bind(player.rating)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        sqlx::query(
+            "INSERT INTO tournament_stage_groups (stage_id, group_number, group_tournament_id) VALUES (?, 1, ?)",
+        )
+        .bind(stage_id)
+        .bind(tournament.id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(tournament)
+    }
+
+    // Event operations
+    #[instrument(ret, skip(self))]
+    async fn get_events(&self) -> Result<Vec<Event>, sqlx::Error> {
+        let events = sqlx::query_as("SELECT * FROM events ORDER BY date DESC, name")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(events)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_event(&self, event_id: i32) -> Result<Event, sqlx::Error> {
+        let event = sqlx::query_as("SELECT * FROM events WHERE id = ?")
+            .bind(event_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(event)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn create_event(&self, data: &CreateEvent) -> Result<Event, sqlx::Error> {
+        let event = sqlx::query_as(
+            "INSERT INTO events (name, location, date, country_code) VALUES (?, ?, ?, ?) RETURNING *",
+        )
+        .bind(&data.name)
+        .bind(&data.location)
+        .bind(&data.date)
+        .bind(&data.country_code)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(event)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_event_sections(&self, event_id: i32) -> Result<Vec<EventSection>, sqlx::Error> {
+        let sections =
+            sqlx::query_as("SELECT * FROM event_sections WHERE event_id = ? ORDER BY id")
+                .bind(event_id)
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(sections)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_event_section(&self, section_id: i32) -> Result<EventSection, sqlx::Error> {
+        let section = sqlx::query_as("SELECT * FROM event_sections WHERE id = ?")
+            .bind(section_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(section)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn create_event_section(
+        &self,
+        data: &CreateEventSection,
+    ) -> Result<EventSection, sqlx::Error> {
+        let section = sqlx::query_as(
+            r#"
+            INSERT INTO event_sections (event_id, tournament_id, name, min_rating, max_rating, allow_unrated)
+            VALUES (?, ?, ?, ?, ?, ?)
+            RETURNING *
+            "#,
+        )
+        .bind(data.event_id)
+        .bind(data.tournament_id)
+        .bind(&data.name)
+        .bind(data.min_rating)
+        .bind(data.max_rating)
+        .bind(data.allow_unrated)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(section)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_event_registrations(
+        &self,
+        event_id: i32,
+    ) -> Result<Vec<EventRegistration>, sqlx::Error> {
+        let registrations =
+            sqlx::query_as("SELECT * FROM event_registrations WHERE event_id = ? ORDER BY id")
+                .bind(event_id)
+                .fetch_all(&self.pool)
+                .await?;
+
+        Ok(registrations)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_event_registration(
+        &self,
+        registration_id: i32,
+    ) -> Result<EventRegistration, sqlx::Error> {
+        let registration = sqlx::query_as("SELECT * FROM event_registrations WHERE id = ?")
+            .bind(registration_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(registration)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn create_event_registration(
+        &self,
+        section: &EventSection,
+        player: CreatePlayer,
+    ) -> Result<EventRegistration, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let player_id: i32 = sqlx::query_scalar(
+            "INSERT INTO players (tournament_id, name, rating, country_code, title, birth_date, gender, email, phone, club, status)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'active')
+             RETURNING id"
+        )
+        .bind(section.tournament_id)
+        .bind(&player.name)
+        .bind(player.rating)
+        .bind(&player.country_code)
+        .bind(&player.title)
+        .bind(&player.birth_date)
+        .bind(&player.gender)
+        .bind(&player.email)
+        .bind(&player.phone)
+        .bind(&player.club)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let registration = sqlx::query_as(
+            "INSERT INTO event_registrations (event_id, section_id, player_id) VALUES (?, ?, ?) RETURNING *",
+        )
+        .bind(section.event_id)
+        .bind(section.id)
+        .bind(player_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(registration)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn move_event_registration(
+        &self,
+        registration_id: i32,
+        section: &EventSection,
+    ) -> Result<EventRegistration, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let registration: EventRegistration = sqlx::query_as(
+            "UPDATE event_registrations SET section_id = ? WHERE id = ? RETURNING *",
+        )
+        .bind(section.id)
+        .bind(registration_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        // Seeds and pairing numbers belong to the section left behind
+        sqlx::query(
+            r#"
+            UPDATE players SET
+                tournament_id = ?,
+                seed_number = NULL,
+                pairing_number = NULL,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = ?
+            "#,
+        )
+        .bind(section.tournament_id)
+        .bind(registration.player_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(registration)
+    }
+
+    // Player registry operations
+    #[instrument(ret, skip(self))]
+    async fn get_persons(&self) -> Result<Vec<Person>, sqlx::Error> {
+        let persons = sqlx::query_as("SELECT * FROM persons ORDER BY name")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(persons)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn search_persons(&self, query: &str) -> Result<Vec<Person>, sqlx::Error> {
+        let pattern = format!("%{query}%");
+        let persons = sqlx::query_as(
+            "SELECT * FROM persons
+             WHERE name LIKE ? OR fide_id = ? OR national_id = ?
+             ORDER BY name
+             LIMIT 50",
+        )
+        .bind(&pattern)
+        .bind(query)
+        .bind(query)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(persons)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_person(&self, person_id: i32) -> Result<Person, sqlx::Error> {
+        let person = sqlx::query_as("SELECT * FROM persons WHERE id = ?")
+            .bind(person_id)
+            .fetch_one(&self.pool)
             .await?;
 
-        Ok(result)
+        Ok(person)
     }
 
     #[instrument(ret, skip(self))]
-    async fn get_knockout_bracket_by_id(
+    async fn create_person(&self, data: &CreatePerson) -> Result<Person, sqlx::Error> {
+        let person = sqlx::query_as(
+            "INSERT INTO persons (name, fide_id, national_id, federation, birth_date, gender, title, fide_rating, national_rating, rapid_rating, blitz_rating)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             RETURNING *",
+        )
+        .bind(&data.name)
+        .bind(&data.fide_id)
+        .bind(&data.national_id)
+        .bind(&data.federation)
+        .bind(&data.birth_date)
+        .bind(&data.gender)
+        .bind(&data.title)
+        .bind(data.fide_rating)
+        .bind(data.national_rating)
+        .bind(data.rapid_rating)
+        .bind(data.blitz_rating)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(person)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn update_person(&self, data: &UpdatePerson) -> Result<Person, sqlx::Error> {
+        // Ratings only change through the rating history
+        let person = sqlx::query_as(
+            "UPDATE persons SET
+                name = COALESCE(?, name),
+                fide_id = COALESCE(?, fide_id),
+                national_id = COALESCE(?, national_id),
+                federation = COALESCE(?, federation),
+                birth_date = COALESCE(?, birth_date),
+                gender = COALESCE(?, gender),
+                title = COALESCE(?, title),
+                updated_at = CURRENT_TIMESTAMP
+             WHERE id = ?
+             RETURNING *",
+        )
+        .bind(&data.name)
+        .bind(&data.fide_id)
+        .bind(&data.national_id)
+        .bind(&data.federation)
+        .bind(&data.birth_date)
+        .bind(&data.gender)
+        .bind(&data.title)
+        .bind(data.person_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(person)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn create_person_player(
         &self,
-        bracket_id: i32,
-    ) -> Result<Option<KnockoutBracket>, sqlx::Error> {
-        let result = sqlx::query_as("SELECT * FROM knockout_brackets WHERE id = ?")
-            .bind(bracket_id)
-            .fetch_optional(&self.pool)
-            .await?;
+        person: &Person,
+        tournament_id: i32,
+        rating: Option<i32>,
+    ) -> Result<Player, sqlx::Error> {
+        let player = sqlx::query_as(
+            "INSERT INTO players (tournament_id, name, rating, country_code, title, birth_date, gender, status, initial_rating, person_id)
+             VALUES (?, ?, ?, ?, ?, ?, ?, 'active', ?, ?)
+             RETURNING *",
+        )
+        .bind(tournament_id)
+        .bind(&person.name)
+        .bind(rating)
+        .bind(&person.federation)
+        .bind(&person.title)
+        .bind(&person.birth_date)
+        .bind(&person.gender)
+        .bind(rating)
+        .bind(person.id)
+        .fetch_one(&self.pool)
+        .await?;
 
-        Ok(result)
+        Ok(player)
     }
 
     #[instrument(ret, skip(self))]
-    async fn create_bracket_position(
+    async fn link_player_to_person(
         &self,
-        position: BracketPosition,
-    ) -> Result<BracketPosition, sqlx::Error> {
-        let result = sqlx::query_as(
-            "INSERT INTO bracket_positions (bracket_id, round_number, position_number, player_id, advanced_from_position, status)
-             VALUES (?, ?, ?, ?, ?, ?)
-             RETURNING *"
+        player_id: i32,
+        person_id: Option<i32>,
+    ) -> Result<Player, sqlx::Error> {
+        let player = sqlx::query_as(
+            "UPDATE players SET person_id = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ? RETURNING *",
         )
-        .bind(position.bracket_id)
-        .bind(position.round_number)
-        .bind(position.position_number)
-        .bind(position.player_id)
-        .bind(position.advanced_from_position)
-        .bind(&position.status)
+        .bind(person_id)
+        .bind(player_id)
         .fetch_one(&self.pool)
         .await?;
 
-        Ok(result)
+        Ok(player)
     }
 
     #[instrument(ret, skip(self))]
-    async fn get_bracket_positions(
+    async fn get_person_players(&self, person_id: i32) -> Result<Vec<Player>, sqlx::Error> {
+        let players = sqlx::query_as(
+            "SELECT players.* FROM players
+             JOIN tournaments ON tournaments.id = players.tournament_id
+             WHERE players.person_id = ?
+             ORDER BY tournaments.date DESC, players.id DESC",
+        )
+        .bind(person_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(players)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_person_tournaments(&self, person_id: i32) -> Result<Vec<Tournament>, sqlx::Error> {
+        let tournaments = sqlx::query_as(
+            "SELECT * FROM tournaments
+             WHERE id IN (SELECT tournament_id FROM players WHERE person_id = ?)
+             ORDER BY date DESC",
+        )
+        .bind(person_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tournaments)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_person_tournament_players(
         &self,
-        bracket_id: i32,
-    ) -> Result<Vec<BracketPosition>, sqlx::Error> {
-        let positions = sqlx::query_as(
-            "SELECT * FROM bracket_positions 
-             WHERE bracket_id = ? 
-             ORDER BY round_number, position_number",
+        person_id: i32,
+    ) -> Result<Vec<Player>, sqlx::Error> {
+        let players = sqlx::query_as(
+            "SELECT * FROM players
+             WHERE tournament_id IN (SELECT tournament_id FROM players WHERE person_id = ?)
+               AND name != 'BYE'",
         )
-        .bind(bracket_id)
+        .bind(person_id)
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(positions)
+        Ok(players)
     }
 
     #[instrument(ret, skip(self))]
-    async fn get_bracket_positions_by_round(
+    async fn get_person_tournament_games(&self, person_id: i32) -> Result<Vec<Game>, sqlx::Error> {
+        let games = sqlx::query_as(
+            "SELECT * FROM games
+             WHERE tournament_id IN (SELECT tournament_id FROM players WHERE person_id = ?)
+             ORDER BY tournament_id, round_number, id",
+        )
+        .bind(person_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(games)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_player_person(&self, player_id: i32) -> Result<Option<Person>, sqlx::Error> {
+        let person = sqlx::query_as(
+            "SELECT persons.* FROM persons
+             JOIN players ON players.person_id = persons.id
+             WHERE players.id = ?",
+        )
+        .bind(player_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(person)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn add_person_rating(
         &self,
-        bracket_id: i32,
-        round_number: i32,
-    ) -> Result<Vec<BracketPosition>, sqlx::Error> {
-        let positions = sqlx::query_as(
-            "SELECT * FROM bracket_positions 
-             WHERE bracket_id = ? AND round_number = ?
-             ORDER BY position_number",
+        data: &CreatePersonRating,
+    ) -> Result<PersonRating, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        let rating = record_person_rating(&mut tx, data).await?;
+        tx.commit().await?;
+
+        Ok(rating)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_person_rating_history(
+        &self,
+        person_id: i32,
+    ) -> Result<Vec<PersonRating>, sqlx::Error> {
+        let history = sqlx::query_as(
+            "SELECT * FROM person_rating_history
+             WHERE person_id = ?
+             ORDER BY effective_date DESC, id DESC",
         )
-        .bind(bracket_id)
-        .bind(round_number)
+        .bind(person_id)
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(positions)
+        Ok(history)
     }
 
     #[instrument(ret, skip(self))]
-    async fn update_bracket_position(
+    async fn add_rating_history(
         &self,
-        position_id: i32,
-        player_id: Option<i32>,
-        status: String,
-    ) -> Result<(), sqlx::Error> {
-        sqlx::query(
-            "UPDATE bracket_positions 
-             SET player_id = ?, status = ?
-             WHERE id = ?",
+        data: &CreateRatingHistory,
+    ) -> Result<RatingHistory, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        let rating = record_rating_history(&mut tx, data).await?;
+        tx.commit().await?;
+
+        Ok(rating)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_player_rating_history(
+        &self,
+        player_id: i32,
+    ) -> Result<Vec<RatingHistory>, sqlx::Error> {
+        let history = sqlx::query_as(
+            "SELECT * FROM rating_history
+             WHERE player_id = ?
+             ORDER BY effective_date DESC, id DESC",
         )
         .bind(player_id)
-        .bind(&status)
-        .bind(position_id)
-        .execute(&self.pool)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(history)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_person_by_fide_id(&self, fide_id: &str) -> Result<Option<Person>, sqlx::Error> {
+        let person = sqlx::query_as("SELECT * FROM persons WHERE fide_id = ?")
+            .bind(fide_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(person)
+    }
+
+    // FIDE rating list operations
+    #[instrument(ret, skip(self, data))]
+    async fn import_fide_rating_list(
+        &self,
+        data: ImportFideRatingList,
+    ) -> Result<FideRatingListImport, sqlx::Error> {
+        // One transaction and multi-row inserts keep a full list of a million players fast
+        let mut tx = self.pool.begin().await?;
+
+        let list: FideRatingList = sqlx::query_as(
+            "INSERT INTO fide_rating_lists (list_date, source_file) VALUES (?, ?)
+             ON CONFLICT(list_date) DO UPDATE SET
+                source_file = excluded.source_file,
+                imported_at = CURRENT_TIMESTAMP
+             RETURNING *",
+        )
+        .bind(&data.list_date)
+        .bind(&data.source_file)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for batch in data.ratings.chunks(FIDE_RATING_BATCH_SIZE) {
+            let mut query = sqlx::QueryBuilder::new(
+                "INSERT INTO fide_ratings (list_id, fide_id, name, federation, sex, title, birth_year, standard_rating, rapid_rating, blitz_rating, flag) ",
+            );
+            query.push_values(batch, |mut row, rating| {
+                row.push_bind(list.id)
+                    .push_bind(rating.fide_id)
+                    .push_bind(rating.name.as_str())
+                    .push_bind(rating.federation.as_deref())
+                    .push_bind(rating.sex.as_deref())
+                    .push_bind(rating.title.as_deref())
+                    .push_bind(rating.birth_year)
+                    .push_bind(rating.standard_rating)
+                    .push_bind(rating.rapid_rating)
+                    .push_bind(rating.blitz_rating)
+                    .push_bind(rating.flag.as_deref());
+            });
+            // The standard, rapid and blitz files of a month fill in each other's ratings
+            query.push(
+                " ON CONFLICT(list_id, fide_id) DO UPDATE SET
+                    name = excluded.name,
+                    federation = excluded.federation,
+                    sex = excluded.sex,
+                    title = excluded.title,
+                    birth_year = COALESCE(excluded.birth_year, birth_year),
+                    standard_rating = COALESCE(excluded.standard_rating, standard_rating),
+                    rapid_rating = COALESCE(excluded.rapid_rating, rapid_rating),
+                    blitz_rating = COALESCE(excluded.blitz_rating, blitz_rating),
+                    flag = excluded.flag",
+            );
+            query.build().execute(&mut *tx).await?;
+        }
+
+        let list: FideRatingList = sqlx::query_as(
+            "UPDATE fide_rating_lists
+             SET player_count = (SELECT COUNT(*) FROM fide_ratings WHERE list_id = ?)
+             WHERE id = ?
+             RETURNING *",
+        )
+        .bind(list.id)
+        .bind(list.id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let previous_list_id: Option<i32> = sqlx::query_scalar(
+            "SELECT id FROM fide_rating_lists WHERE list_date < ? ORDER BY list_date DESC LIMIT 1",
+        )
+        .bind(&list.list_date)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let (players_added, players_changed, changes) = match previous_list_id {
+            Some(previous_list_id) => {
+                let players_added: i64 = sqlx::query_scalar(
+                    "SELECT COUNT(*) FROM fide_ratings AS current
+                     WHERE current.list_id = ? AND NOT EXISTS (
+                         SELECT 1 FROM fide_ratings AS previous
+                         WHERE previous.list_id = ? AND previous.fide_id = current.fide_id
+                     )",
+                )
+                .bind(list.id)
+                .bind(previous_list_id)
+                .fetch_one(&mut *tx)
+                .await?;
+
+                let players_changed: i64 = sqlx::query_scalar(&format!(
+                    "SELECT COUNT(*) FROM fide_ratings AS current
+                     JOIN fide_ratings AS previous
+                        ON previous.list_id = ? AND previous.fide_id = current.fide_id
+                     WHERE current.list_id = ? AND ({FIDE_RATING_CHANGED})"
+                ))
+                .bind(previous_list_id)
+                .bind(list.id)
+                .fetch_one(&mut *tx)
+                .await?;
+
+                // Changes are listed for the registry; the rest of the list is only counted.
+                // CROSS JOIN makes SQLite look the few registry players up rather than scan the list
+                let registry_changes = |side: &str| {
+                    format!(
+                        "SELECT {side}.* FROM persons
+                         CROSS JOIN fide_ratings AS current
+                            ON current.list_id = ? AND current.fide_id = CAST(persons.fide_id AS INTEGER)
+                         JOIN fide_ratings AS previous
+                            ON previous.list_id = ? AND previous.fide_id = current.fide_id
+                         WHERE {FIDE_RATING_CHANGED}
+                         ORDER BY current.fide_id"
+                    )
+                };
+                let current: Vec<FideRating> = sqlx::query_as(&registry_changes("current"))
+                    .bind(list.id)
+                    .bind(previous_list_id)
+                    .fetch_all(&mut *tx)
+                    .await?;
+                let previous: Vec<FideRating> = sqlx::query_as(&registry_changes("previous"))
+                    .bind(list.id)
+                    .bind(previous_list_id)
+                    .fetch_all(&mut *tx)
+                    .await?;
+                let changes = previous
+                    .into_iter()
+                    .zip(current)
+                    .map(|(previous, current)| FideRatingChange { previous, current })
+                    .collect();
+
+                (players_added as i32, players_changed as i32, changes)
+            }
+            None => (list.player_count, 0, Vec::new()),
+        };
+
+        tx.commit().await?;
+
+        Ok(FideRatingListImport {
+            list,
+            players_imported: data.ratings.len() as i32,
+            players_added,
+            players_changed,
+            players_skipped: 0,
+            changes,
+            warnings: Vec::new(),
+        })
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_fide_rating_lists(&self) -> Result<Vec<FideRatingList>, sqlx::Error> {
+        let lists = sqlx::query_as("SELECT * FROM fide_rating_lists ORDER BY list_date DESC")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(lists)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_fide_rating_list(
+        &self,
+        list_date: Option<&str>,
+    ) -> Result<Option<FideRatingList>, sqlx::Error> {
+        // The list in force on the date: the latest one published on or before it
+        let list = sqlx::query_as(
+            "SELECT * FROM fide_rating_lists
+             WHERE ? IS NULL OR list_date <= ?
+             ORDER BY list_date DESC
+             LIMIT 1",
+        )
+        .bind(list_date)
+        .bind(list_date)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(list)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn search_fide_ratings(
+        &self,
+        list_id: i32,
+        query: &str,
+    ) -> Result<Vec<FideRating>, sqlx::Error> {
+        if let Ok(fide_id) = query.parse::<i32>() {
+            let ratings =
+                sqlx::query_as("SELECT * FROM fide_ratings WHERE list_id = ? AND fide_id = ?")
+                    .bind(list_id)
+                    .bind(fide_id)
+                    .fetch_all(&self.pool)
+                    .await?;
+            return Ok(ratings);
+        }
+
+        let ratings = sqlx::query_as(
+            "SELECT * FROM fide_ratings
+             WHERE list_id = ? AND name LIKE ? ESCAPE '\\'
+             ORDER BY name
+             LIMIT 50",
+        )
+        .bind(list_id)
+        .bind(format!("{}%", like_escape(query)))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ratings)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn get_fide_rating(
+        &self,
+        list_id: i32,
+        fide_id: i32,
+    ) -> Result<Option<FideRating>, sqlx::Error> {
+        let rating = sqlx::query_as("SELECT * FROM fide_ratings WHERE list_id = ? AND fide_id = ?")
+            .bind(list_id)
+            .bind(fide_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(rating)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn find_fide_ratings_by_name(
+        &self,
+        list_id: i32,
+        name: &str,
+    ) -> Result<Vec<FideRating>, sqlx::Error> {
+        // LIKE without wildcards compares case-insensitively through the name index
+        let ratings = sqlx::query_as(
+            "SELECT * FROM fide_ratings WHERE list_id = ? AND name LIKE ? ESCAPE '\\'",
+        )
+        .bind(list_id)
+        .bind(like_escape(name))
+        .fetch_all(&self.pool)
         .await?;
 
+        Ok(ratings)
+    }
+
+    #[instrument(ret, skip(self))]
+    async fn update_player_ratings(
+        &self,
+        updates: &[PlayerRatingUpdate],
+        rating_type: &str,
+        effective_date: &str,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        for update in updates {
+            sqlx::query(
+                "UPDATE players SET rating = ?, initial_rating = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+            )
+            .bind(update.new_rating)
+            .bind(update.new_rating)
+            .bind(update.player_id)
+            .execute(&mut *tx)
+            .await?;
+
+            let history = CreateRatingHistory {
+                player_id: update.player_id,
+                rating_type: rating_type.to_string(),
+                rating: update.new_rating,
+                is_provisional: false,
+                effective_date: effective_date.to_string(),
+            };
+            record_rating_history(&mut tx, &history).await?;
+        }
+
+        tx.commit().await?;
+
         Ok(())
     }
 
@@ -1526,3 +3395,191 @@ impl Db for SqliteDb {
         Ok(result)
     }
 }
+
+/// `forbidden_pairings` row with the player ids still JSON encoded
+type ForbiddenPairingRow = (i32, i32, String, String, Option<i32>, Option<i32>, String);
+
+fn forbidden_pairing_from_row(
+    (id, tournament_id, player_ids, reason, first_round, last_round, created_at): ForbiddenPairingRow,
+) -> Result<ForbiddenPairing, sqlx::Error> {
+    let player_ids = serde_json::from_str(&player_ids)
+        .map_err(|e| sqlx::Error::Protocol(format!("Failed to parse player_ids: {e}")))?;
+
+    Ok(ForbiddenPairing {
+        id,
+        tournament_id,
+        player_ids,
+        reason,
+        first_round,
+        last_round,
+        created_at,
+    })
+}
+
+/// `knockout_match_formats` row with the tiebreak stages still JSON encoded
+type KnockoutMatchFormatRow = (
+    i32,
+    i32,
+    String,
+    i32,
+    i32,
+    Option<i32>,
+    String,
+    Option<i32>,
+    String,
+    String,
+);
+
+fn knockout_match_format_from_row(
+    (
+        id,
+        bracket_id,
+        bracket_side,
+        round_number,
+        games,
+        time_control_id,
+        tiebreak_stages,
+        armageddon_time_control_id,
+        armageddon_color,
+        created_at,
+    ): KnockoutMatchFormatRow,
+) -> Result<KnockoutMatchFormat, sqlx::Error> {
+    let tiebreak_stages = serde_json::from_str(&tiebreak_stages)
+        .map_err(|e| sqlx::Error::Protocol(format!("Failed to parse tiebreak_stages: {e}")))?;
+
+    Ok(KnockoutMatchFormat {
+        id,
+        bracket_id,
+        bracket_side,
+        round_number,
+        games,
+        time_control_id,
+        tiebreak_stages,
+        armageddon_time_control_id,
+        armageddon_color,
+        created_at,
+    })
+}
+
+/// `pairing_drafts` row with the pairings still JSON encoded
+type PairingDraftRow = (i32, i32, String, String, bool, String, String);
+
+fn pairing_draft_from_row(
+    (id, round_id, name, pairing_method, avoid_same_club, pairings, created_at): PairingDraftRow,
+) -> Result<PairingDraft, sqlx::Error> {
+    let pairings = serde_json::from_str(&pairings)
+        .map_err(|e| sqlx::Error::Protocol(format!("Failed to parse pairings: {e}")))?;
+
+    Ok(PairingDraft {
+        id,
+        round_id,
+        name,
+        pairing_method,
+        avoid_same_club,
+        pairings,
+        created_at,
+    })
+}
+
+/// Record a tournament player's rating and, for players from the registry, their person's
+async fn record_rating_history(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    data: &CreateRatingHistory,
+) -> Result<RatingHistory, sqlx::Error> {
+    let rating: RatingHistory = sqlx::query_as(
+        "INSERT INTO rating_history (player_id, rating_type, rating, is_provisional, effective_date)
+         VALUES (?, ?, ?, ?, ?)
+         RETURNING *",
+    )
+    .bind(data.player_id)
+    .bind(&data.rating_type)
+    .bind(data.rating)
+    .bind(data.is_provisional)
+    .bind(&data.effective_date)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    let (person_id, tournament_id): (Option<i32>, i32) =
+        sqlx::query_as("SELECT person_id, tournament_id FROM players WHERE id = ?")
+            .bind(data.player_id)
+            .fetch_one(&mut **tx)
+            .await?;
+    if let Some(person_id) = person_id {
+        let person_rating = CreatePersonRating {
+            person_id,
+            rating_type: data.rating_type.clone(),
+            rating: data.rating,
+            is_provisional: data.is_provisional,
+            effective_date: data.effective_date.clone(),
+            tournament_id: Some(tournament_id),
+        };
+        record_person_rating(tx, &person_rating).await?;
+    }
+
+    Ok(rating)
+}
+
+/// Record a person's rating, making it their current rating unless a later one is on file
+async fn record_person_rating(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    data: &CreatePersonRating,
+) -> Result<PersonRating, sqlx::Error> {
+    let rating: PersonRating = sqlx::query_as(
+        "INSERT INTO person_rating_history (person_id, rating_type, rating, is_provisional, effective_date, tournament_id)
+         VALUES (?, ?, ?, ?, ?, ?)
+         RETURNING *",
+    )
+    .bind(data.person_id)
+    .bind(&data.rating_type)
+    .bind(data.rating)
+    .bind(data.is_provisional)
+    .bind(&data.effective_date)
+    .bind(data.tournament_id)
+    .fetch_one(&mut **tx)
+    .await?;
+
+    // Club ratings are kept in the history only
+    let column = match data.rating_type.as_str() {
+        "fide" => "fide_rating",
+        "national" => "national_rating",
+        "rapid" => "rapid_rating",
+        "blitz" => "blitz_rating",
+        _ => return Ok(rating),
+    };
+    sqlx::query(&format!(
+        "UPDATE persons SET {column} = ?, updated_at = CURRENT_TIMESTAMP
+         WHERE id = ? AND NOT EXISTS (
+             SELECT 1 FROM person_rating_history
+             WHERE person_id = ? AND rating_type = ? AND effective_date > ?
+         )"
+    ))
+    .bind(data.rating)
+    .bind(data.person_id)
+    .bind(data.person_id)
+    .bind(&data.rating_type)
+    .bind(&data.effective_date)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(rating)
+}
+
+/// Rows per insert statement, within SQLite's limit of 32766 bound values
+const FIDE_RATING_BATCH_SIZE: usize = 2_000;
+
+/// Rows of two FIDE lists that differ; ratings missing from the newer list are not changes
+const FIDE_RATING_CHANGED: &str = "current.name IS NOT previous.name
+    OR current.federation IS NOT previous.federation
+    OR current.title IS NOT previous.title
+    OR current.flag IS NOT previous.flag
+    OR (current.standard_rating IS NOT NULL AND current.standard_rating IS NOT previous.standard_rating)
+    OR (current.rapid_rating IS NOT NULL AND current.rapid_rating IS NOT previous.rapid_rating)
+    OR (current.blitz_rating IS NOT NULL AND current.blitz_rating IS NOT previous.blitz_rating)";
+
+/// Value for a LIKE pattern with `\` as escape character
+fn like_escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
@@ -59,6 +59,8 @@ pub struct UpdateTournamentSettings {
     pub organizer_name: Option<String>,
     pub organizer_email: Option<String>,
     pub prize_structure: Option<String>,
+    pub chief_arbiter: Option<String>,
+    pub deputy_arbiters: Option<String>, // Comma separated
 }
 
 #[allow(dead_code)]
@@ -294,6 +296,32 @@ pub struct AssignPlayerToCategory {
     pub category_id: i32,
 }
 
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreateByeRequest {
+    pub tournament_id: i32,
+    pub player_id: i32,
+    pub round_numbers: Vec<i32>,
+    pub bye_points: f64, // 0, 0.5 or 1
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateByeSettings {
+    pub tournament_id: i32,
+    pub max_requested_byes: i32,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreateForbiddenPairing {
+    pub tournament_id: i32,
+    pub player_ids: Vec<i32>,
+    pub reason: String,
+    pub first_round: Option<i32>,
+    pub last_round: Option<i32>,
+}
+
 // Knockout Tournament DTOs
 
 #[allow(dead_code)]
@@ -329,6 +357,369 @@ pub struct KnockoutRoundResult {
     pub winner_advances: Vec<(i32, i32)>, // (position_id, player_id)
 }
 
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct KnockoutPlacing {
+    pub place: i32, // Semi-final losers share 3 without a third-place match
+    pub player_id: i32,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct SetKnockoutMatchFormat {
+    pub bracket_id: i32,
+    pub bracket_side: Option<String>, // None = winners bracket
+    pub round_number: i32,
+    pub games: i32,
+    pub time_control_id: Option<i32>,
+    pub tiebreak_stages: Vec<crate::pawn::domain::model::TiebreakStage>,
+    pub armageddon_time_control_id: Option<i32>,
+    pub armageddon_color: String, // "choice", "bidding"
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct StartArmageddon {
+    pub bracket_id: i32,
+    pub bracket_side: Option<String>,
+    pub round_number: i32,
+    pub match_number: i32,
+    pub white_player_id: Option<i32>, // Colour choice, or the lot after equal bids
+    pub bids: Vec<ArmageddonBid>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ArmageddonBid {
+    pub player_id: i32,
+    pub minutes: i32, // Time the player accepts to play Black with
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct KnockoutMatchStatus {
+    pub match_number: i32,
+    pub player1_id: i32, // White in the first game
+    pub player2_id: i32,
+    pub player1_score: f32,
+    pub player2_score: f32,
+    pub stage: i32, // Stage being played or decided
+    pub awaiting_armageddon: bool,
+    pub winner_id: Option<i32>,
+}
+
+// Arena Tournament DTOs
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateArenaSettings {
+    pub tournament_id: i32,
+    pub ends_at: String, // RFC 3339
+    pub berserk_allowed: bool,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct RecordArenaResult {
+    pub game_id: i32,
+    pub result: String,
+    pub white_berserk: bool,
+    pub black_berserk: bool,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ArenaResultOutcome {
+    pub game: crate::pawn::domain::model::ArenaGame,
+    pub new_games: Vec<crate::pawn::domain::model::ArenaGame>, // Paired right after the result
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ArenaStanding {
+    pub rank: i32,
+    pub player: crate::pawn::domain::model::Player,
+    pub score: i32,
+    pub games_played: i32,
+    pub wins: i32,
+    pub draws: i32,
+    pub losses: i32,
+    pub berserks: i32,
+    pub on_fire: bool, // Two wins in a row, the next win or draw scores double
+    pub performance_rating: Option<i32>,
+    pub sheet: Vec<i32>, // Points of every game in the order played
+}
+
+// Multi-Stage Event DTOs
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreateTournamentStage {
+    pub tournament_id: i32,
+    pub name: String,
+    pub tournament_type: String,
+    pub rounds: Option<i32>,
+    pub qualification: String, // "top_n", "group_winners"
+    pub qualifiers: i32,
+    pub seeding: String,                // "qualifier_rank", "rating"
+    pub group_tournament_ids: Vec<i32>, // Required for the first stage only
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct StageQualifier {
+    pub seed: i32,
+    pub player: crate::pawn::domain::model::Player, // Player of the group tournament
+    pub group_number: i32,
+    pub group_rank: i32,
+    pub points: f64,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct AdvanceTournamentStage {
+    pub stage: crate::pawn::domain::model::TournamentStage,
+    pub tournament: crate::pawn::domain::model::Tournament,
+    pub qualifiers: Vec<StageQualifier>,
+}
+
+// Event DTOs
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreateEvent {
+    pub name: String,
+    pub location: String,
+    pub date: String,
+    pub country_code: String,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreateEventSection {
+    pub event_id: i32,
+    pub tournament_id: i32,
+    pub name: String,
+    pub min_rating: Option<i32>,
+    pub max_rating: Option<i32>,
+    pub allow_unrated: bool,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct RegisterEventPlayer {
+    pub event_id: i32,
+    pub section_id: i32,
+    pub name: String,
+    pub rating: Option<i32>,
+    pub country_code: Option<String>,
+    pub title: Option<String>,
+    pub birth_date: Option<String>,
+    pub gender: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub club: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct MoveEventPlayer {
+    pub registration_id: i32,
+    pub section_id: i32,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct EventEntry {
+    pub registration: crate::pawn::domain::model::EventRegistration,
+    pub section_name: String,
+    pub player: crate::pawn::domain::model::Player,
+}
+
+// Player Registry DTOs
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreatePerson {
+    pub name: String,
+    pub fide_id: Option<String>,
+    pub national_id: Option<String>,
+    pub federation: Option<String>,
+    pub birth_date: Option<String>,
+    pub gender: Option<String>,
+    pub title: Option<String>,
+    pub fide_rating: Option<i32>,
+    pub national_rating: Option<i32>,
+    pub rapid_rating: Option<i32>,
+    pub blitz_rating: Option<i32>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdatePerson {
+    pub person_id: i32,
+    pub name: Option<String>,
+    pub fide_id: Option<String>,
+    pub national_id: Option<String>,
+    pub federation: Option<String>,
+    pub birth_date: Option<String>,
+    pub gender: Option<String>,
+    pub title: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct RegisterPersonPlayer {
+    pub person_id: i32,
+    pub tournament_id: i32,
+    pub rating_type: Option<String>, // Registry rating to enter with, FIDE by default; national only when asked for
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreatePersonRating {
+    pub person_id: i32,
+    pub rating_type: String, // fide, national, club, rapid, blitz
+    pub rating: i32,
+    pub is_provisional: bool,
+    pub effective_date: String,
+    pub tournament_id: Option<i32>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct PersonTournament {
+    pub tournament: crate::pawn::domain::model::Tournament,
+    pub player: crate::pawn::domain::model::Player,
+    pub rank: Option<i32>, // None when the player is missing from the standings
+    pub points: f64,
+    pub games_played: i32,
+}
+
+// FIDE Rating List DTOs
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ImportFideRatingListRequest {
+    pub file_path: String, // TXT or XML file downloaded from ratings.fide.com
+    pub list_date: String,
+}
+
+/// Rating list read from a FIDE file, written in a single transaction
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ImportFideRatingList {
+    pub list_date: String,
+    pub source_file: String,
+    pub ratings: Vec<ImportFideRating>,
+}
+
+/// Player of a FIDE file; ratings missing from a file keep their value in the list
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportFideRating {
+    pub fide_id: i32,
+    pub name: String,
+    pub federation: Option<String>,
+    pub sex: Option<String>,
+    pub title: Option<String>,
+    pub birth_year: Option<i32>,
+    pub standard_rating: Option<i32>,
+    pub rapid_rating: Option<i32>,
+    pub blitz_rating: Option<i32>,
+    pub flag: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct FideRatingChange {
+    pub previous: crate::pawn::domain::model::FideRating,
+    pub current: crate::pawn::domain::model::FideRating,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct FideRatingListImport {
+    pub list: crate::pawn::domain::model::FideRatingList,
+    pub players_imported: i32,
+    pub players_added: i32,             // Not in the previous list
+    pub players_changed: i32,           // Different from the previous list
+    pub players_skipped: i32,           // Unreadable entries of the file
+    pub changes: Vec<FideRatingChange>, // Only players of the registry
+    pub warnings: Vec<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct RegisterFidePlayer {
+    pub tournament_id: i32,
+    pub fide_id: i32,
+    pub list_date: Option<String>, // Latest list when not given
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PlayerRatingUpdate {
+    pub player_id: i32,
+    pub name: String,
+    pub fide_id: i32,
+    pub old_rating: Option<i32>,
+    pub new_rating: i32,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct FideRatingRefresh {
+    pub list: crate::pawn::domain::model::FideRatingList,
+    pub updated: Vec<PlayerRatingUpdate>,
+    pub unchanged: i32,
+    pub unmatched: Vec<crate::pawn::domain::model::Player>, // No FIDE ID and no unique name match
+}
+
+// Rating Report DTOs
+
+/// Game of a player as seen by the FIDE rating calculation
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct RatedGame {
+    pub round_number: i32,
+    pub opponent_id: i32,
+    pub opponent_name: String,
+    pub opponent_rating: Option<i32>,
+    pub color: String, // "white" or "black"
+    pub result: String,
+    pub score: Option<f64>,             // Only for games played over the board
+    pub rating_difference: Option<i32>, // After the 400-point rule
+    pub expected_score: Option<f64>,
+    pub rating_change: Option<f64>,
+    pub counted: bool,
+    pub note: Option<String>, // Why the game does not count
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct PlayerRatingReport {
+    pub player: crate::pawn::domain::model::Player,
+    pub k_factor: Option<i32>, // Unrated players get a first rating instead
+    pub k_factor_reason: Option<String>,
+    pub games: Vec<RatedGame>,
+    pub games_counted: i32,
+    pub score: f64,
+    pub expected_score: Option<f64>,
+    pub rating_change: Option<f64>,
+    pub new_rating: Option<i32>,
+    pub initial_rating: bool, // new_rating is a first rating
+    pub note: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct RatingReport {
+    pub tournament_id: i32,
+    pub rating_type: String, // fide, rapid or blitz
+    pub players: Vec<PlayerRatingReport>,
+}
+
 // Time Control DTOs
 
 #[allow(dead_code)]
@@ -477,6 +868,136 @@ pub struct PairingSuggestionDto {
     pub alternative_pairing: Option<crate::pawn::domain::model::Pairing>,
 }
 
+// Pairing checker DTOs
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CheckPairingsRequest {
+    pub tournament_id: Option<i32>,
+    pub trf_content: Option<String>, // Checks an imported TRF file instead of a stored tournament
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PairingDifferenceDto {
+    pub board_number: i32,
+    pub stored_pairing: String,
+    pub engine_pairing: String,
+    pub criterion: String, // C.04.3 rule explaining the difference, e.g. "C.04.3 C1"
+    pub explanation: String,
+    pub affected_players: Vec<i32>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct RoundPairingCheck {
+    pub round_number: i32,
+    pub matches_engine: bool,
+    pub stored_pairings: Vec<crate::pawn::domain::model::Pairing>,
+    pub engine_pairings: Vec<crate::pawn::domain::model::Pairing>,
+    pub differences: Vec<PairingDifferenceDto>,
+    pub validation: PairingValidationResults,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PairingCheckReport {
+    pub tournament_name: String,
+    pub rounds_checked: i32,
+    pub rounds_matching: i32,
+    pub rounds: Vec<RoundPairingCheck>,
+}
+
+// Partial re-pairing DTOs
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct RepairRoundRequest {
+    pub round_id: i32,
+    pub pairing_method: String,
+    pub locked_boards: Vec<i32>, // Boards kept exactly as published
+    pub removed_player_ids: Vec<i32>,
+    pub added_player_ids: Vec<i32>,
+    pub reason: Option<String>,
+    pub apply: bool, // false only previews the new pairings
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct BoardChangeDto {
+    pub board_number: i32,
+    pub before: Option<String>, // None for a board that did not exist
+    pub after: Option<String>,  // None for a board that no longer exists
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct RepairRoundResult {
+    pub before: Vec<crate::pawn::domain::model::Pairing>,
+    pub after: Vec<crate::pawn::domain::model::Pairing>,
+    pub changed_boards: Vec<BoardChangeDto>,
+    pub audit: Option<crate::pawn::domain::model::RoundAudit>, // Set once applied
+}
+
+/// Re-paired boards of a round, written with their audit entry in a single transaction
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ApplyRoundRepair {
+    pub round_id: i32,
+    pub replaced_game_ids: Vec<i32>,
+    pub games: Vec<RepairedGame>,
+    pub audit_details: String,
+    pub reason: Option<String>,
+}
+
+/// Game of a re-paired board, stored under the id of the game it replaces so
+/// the board keeps its place in the round
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct RepairedGame {
+    pub game_id: Option<i32>, // None for a board added after the last one
+    pub board_number: i32,
+    pub game: CreateGame,
+    pub explanation: Option<crate::pawn::domain::model::PairingExplanation>,
+}
+
+// Pairing draft DTOs
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct CreatePairingDraft {
+    pub round_id: i32,
+    pub name: Option<String>, // Defaults to the pairing method
+    pub pairing_method: String,
+    pub avoid_same_club: bool,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PairingDraftMetrics {
+    pub color_violations: i32, // Boards giving a player a third colour in a row
+    pub average_rating_difference: f64,
+    pub max_rating_difference: i32,
+    pub floats: i32,
+    pub rematches: i32,
+    pub byes: i32,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PairingDraftSummary {
+    pub draft: crate::pawn::domain::model::PairingDraft,
+    pub metrics: PairingDraftMetrics,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PairingDraftComparison {
+    pub first: PairingDraftSummary,
+    pub second: PairingDraftSummary,
+    pub changed_boards: Vec<BoardChangeDto>,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct PairingPerformanceMetrics {
@@ -504,6 +1025,18 @@ pub struct SwissPairingOptions {
     pub rating_difference_penalty: f64,
 }
 
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct UpdateAccelerationSettings {
+    pub tournament_id: i32,
+    pub enabled: bool,
+    pub accelerated_rounds: i32,
+    pub group_boundary: String, // starting_rank, rating
+    pub group_a_min_rating: Option<i32>,
+    pub virtual_points_first_half: f64,
+    pub virtual_points_second_half: f64,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct SwissPairingAnalysis {
@@ -511,6 +1044,9 @@ pub struct SwissPairingAnalysis {
     pub float_statistics: FloatStatisticsDto,
     pub color_balance_analysis: ColorBalanceAnalysisDto,
     pub rating_distribution: RatingDistributionDto,
+    pub player_float_records: Vec<PlayerFloatRecordDto>,
+    pub aro_progression: Vec<PlayerAroProgressionDto>,
+    pub acceleration: Vec<AcceleratedRoundDto>,
 }
 
 #[allow(dead_code)]
@@ -532,6 +1068,46 @@ pub struct FloatStatisticsDto {
     pub float_percentage: f64,
 }
 
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PlayerFloatRecordDto {
+    pub player_id: i32,
+    pub player_name: String,
+    pub upfloat_rounds: Vec<i32>,
+    pub downfloat_rounds: Vec<i32>,
+    pub bye_rounds: Vec<i32>,
+    pub unplayed_rounds: Vec<i32>,
+    pub can_receive_bye: bool,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct PlayerAroProgressionDto {
+    pub player_id: i32,
+    pub player_name: String,
+    pub aro_by_round: Vec<f64>,
+}
+
+/// Baku acceleration groups and virtual points used to pair one round
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AcceleratedRoundDto {
+    pub round_number: i32,
+    pub group_a_virtual_points: f64,
+    pub players: Vec<AcceleratedPlayerDto>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct AcceleratedPlayerDto {
+    pub player_id: i32,
+    pub player_name: String,
+    pub group: String,       // "A" or "B"
+    pub score: f64,          // Points scored before the round
+    pub virtual_points: f64, // Added for pairing only
+    pub pairing_score: f64,  // score + virtual_points
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize, Type)]
 pub struct ColorBalanceAnalysisDto {
@@ -796,6 +1372,71 @@ pub struct TournamentTemplateFilter {
     pub created_by: Option<String>,
 }
 
+// TRF Import DTOs
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+pub struct ImportTrfRequest {
+    pub content: String,
+    pub validate_only: bool,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct TrfImportIssue {
+    pub line_number: Option<usize>, // 1-based line in the TRF file
+    pub message: String,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct TrfImportResult {
+    pub success: bool,
+    pub tournament: Option<crate::pawn::domain::model::Tournament>,
+    pub players_imported: i32,
+    pub rounds_imported: i32,
+    pub games_imported: i32,
+    pub errors: Vec<TrfImportIssue>,
+    pub warnings: Vec<String>,
+}
+
+/// Complete tournament read from a TRF file, written in a single transaction
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ImportTournament {
+    pub tournament: CreateTournament,
+    pub end_date: Option<String>,
+    pub chief_arbiter: Option<String>,
+    pub deputy_arbiters: Option<String>,
+    pub players: Vec<ImportPlayer>,
+    pub rounds: Vec<ImportRound>,
+    pub games: Vec<ImportGame>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ImportPlayer {
+    pub player: CreatePlayer,
+    pub pairing_number: i32,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ImportRound {
+    pub round_number: i32,
+    pub status: String,
+}
+
+/// Game between imported players, referenced by pairing number; no black player means a bye
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ImportGame {
+    pub round_number: i32,
+    pub white_pairing_number: i32,
+    pub black_pairing_number: Option<i32>,
+    pub result: String,
+    pub result_type: Option<String>,
+}
+
 // Seeding and Ranking DTOs
 
 #[allow(dead_code)]
@@ -113,6 +113,33 @@ pub struct RoundDetails {
     pub round: Round,
     pub games: Vec<GameResult>,
     pub status: RoundStatus,
+    pub pairing_explanations: Vec<BoardPairingExplanation>,
+    pub audit_trail: Vec<RoundAudit>,
+}
+
+/// Candidate pairings of a round that is being paired
+#[allow(dead_code)]
+#[derive(Debug, Serialize, serde::Deserialize, SpectaType, Clone)]
+pub struct PairingDraft {
+    pub id: i32,
+    pub round_id: i32,
+    pub name: String,
+    pub pairing_method: String,
+    pub avoid_same_club: bool,
+    pub pairings: Vec<Pairing>,
+    pub created_at: String,
+}
+
+/// Change made to a round after its pairings were published
+#[allow(dead_code)]
+#[derive(Debug, Serialize, serde::Deserialize, FromRow, SpectaType, Clone)]
+pub struct RoundAudit {
+    pub id: i32,
+    pub round_id: i32,
+    pub action: String, // e.g. "partial_repairing"
+    pub details: String,
+    pub reason: Option<String>,
+    pub changed_at: String,
 }
 
 #[allow(dead_code)]
@@ -121,6 +148,76 @@ pub struct Pairing {
     pub white_player: Player,
     pub black_player: Option<Player>, // None for bye
     pub board_number: i32,
+    #[serde(default)]
+    pub explanation: Option<PairingExplanation>, // Set by the Swiss engines
+}
+
+/// Why a board was paired as it was, for answering players' questions
+#[allow(dead_code)]
+#[derive(Debug, Serialize, serde::Deserialize, SpectaType, Clone, PartialEq)]
+pub struct PairingExplanation {
+    pub score_group: f32, // Score of the bracket the pairing was made in
+    pub floater_id: Option<i32>,
+    pub float_direction: Option<String>, // "down" or "up"
+    pub color_rule: String,              // C.04.3 E rule that allocated the colours
+    pub relaxed_criteria: Vec<String>,   // C.04.3 quality criteria not met on this board
+    pub summary: String,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Serialize, SpectaType, Clone)]
+pub struct BoardPairingExplanation {
+    pub game_id: i32,
+    pub board_number: i32,
+    pub explanation: PairingExplanation,
+}
+
+/// Baku acceleration (FIDE C.04.5) configuration of a Swiss tournament
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone, PartialEq)]
+pub struct AccelerationSettings {
+    pub tournament_id: i32,
+    pub enabled: bool,
+    pub accelerated_rounds: i32,
+    pub group_boundary: String,          // starting_rank, rating
+    pub group_a_min_rating: Option<i32>, // Only used with the rating boundary
+    pub virtual_points_first_half: f64,
+    pub virtual_points_second_half: f64,
+    pub updated_at: Option<String>,
+}
+
+/// Arena configuration of a tournament
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone, PartialEq)]
+pub struct ArenaSettings {
+    pub tournament_id: i32,
+    pub ends_at: String, // RFC 3339
+    pub berserk_allowed: bool,
+    pub updated_at: Option<String>,
+}
+
+/// Player waiting in the arena pool for the next game
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone)]
+pub struct ArenaWaitingPlayer {
+    pub tournament_id: i32,
+    pub player_id: i32,
+    pub waiting_since: String,
+}
+
+/// Game of an arena tournament, paired from the waiting pool
+#[allow(dead_code)]
+#[derive(Debug, Serialize, serde::Deserialize, FromRow, SpectaType, Clone)]
+pub struct ArenaGame {
+    pub id: i32,
+    pub tournament_id: i32,
+    pub white_player_id: i32,
+    pub black_player_id: i32,
+    pub result: String,
+    pub white_berserk: bool, // Played with half the clock for a bonus point
+    pub black_berserk: bool,
+    pub started_at: String,
+    pub finished_at: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -158,6 +255,11 @@ pub enum TournamentType {
 pub enum PairingMethod {
     Manual,
     Swiss,
+    DutchOptimal,
+    Burstein,
+    Dubov,
+    Lim,
+    Monrad,
     RoundRobin,
     Knockout,
     Scheveningen,
@@ -268,6 +370,19 @@ impl GameResultType {
         !matches!(self, GameResultType::Ongoing | GameResultType::Adjourned)
     }
 
+    /// Whether the game was scored without being played over the board
+    pub fn is_unplayed(&self) -> bool {
+        matches!(
+            self,
+            GameResultType::WhiteForfeit
+                | GameResultType::BlackForfeit
+                | GameResultType::WhiteDefault
+                | GameResultType::BlackDefault
+                | GameResultType::DoubleForfeit
+                | GameResultType::Cancelled
+        )
+    }
+
     pub fn requires_arbiter_approval(&self) -> bool {
         matches!(
             self,
@@ -417,6 +532,11 @@ impl std::str::FromStr for PairingMethod {
         Ok(match s {
             "manual" => PairingMethod::Manual,
             "swiss" => PairingMethod::Swiss,
+            "dutch_optimal" => PairingMethod::DutchOptimal,
+            "burstein" => PairingMethod::Burstein,
+            "dubov" => PairingMethod::Dubov,
+            "lim" => PairingMethod::Lim,
+            "monrad" => PairingMethod::Monrad,
             "round_robin" => PairingMethod::RoundRobin,
             "knockout" => PairingMethod::Knockout,
             "scheveningen" => PairingMethod::Scheveningen,
@@ -431,11 +551,37 @@ impl PairingMethod {
         match self {
             PairingMethod::Manual => "manual",
             PairingMethod::Swiss => "swiss",
+            PairingMethod::DutchOptimal => "dutch_optimal",
+            PairingMethod::Burstein => "burstein",
+            PairingMethod::Dubov => "dubov",
+            PairingMethod::Lim => "lim",
+            PairingMethod::Monrad => "monrad",
             PairingMethod::RoundRobin => "round_robin",
             PairingMethod::Knockout => "knockout",
             PairingMethod::Scheveningen => "scheveningen",
         }
     }
+
+    /// Swiss-system methods pair by score and need the game history
+    pub fn is_swiss_system(&self) -> bool {
+        matches!(
+            self,
+            PairingMethod::Swiss
+                | PairingMethod::DutchOptimal
+                | PairingMethod::Burstein
+                | PairingMethod::Dubov
+                | PairingMethod::Lim
+                | PairingMethod::Monrad
+        )
+    }
+
+    /// Round-robin methods follow a fixed schedule of opponents
+    pub fn is_round_robin(&self) -> bool {
+        matches!(
+            self,
+            PairingMethod::RoundRobin | PairingMethod::Scheveningen
+        )
+    }
 }
 
 // Enhanced Player Management Models
@@ -476,6 +622,41 @@ pub struct PlayerCategoryAssignment {
     pub assigned_at: String,
 }
 
+/// Bye a player requested for a future round, scored against the BYE player
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone, PartialEq)]
+pub struct ByeRequest {
+    pub id: i32,
+    pub tournament_id: i32,
+    pub player_id: i32,
+    pub round_number: i32,
+    pub bye_points: f64, // 0, 0.5 or 1
+    pub created_at: String,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone, PartialEq)]
+pub struct ByeSettings {
+    pub tournament_id: i32,
+    pub max_requested_byes: i32,
+    pub updated_at: Option<String>,
+}
+
+/// Players of a tournament who must not be paired against each other, optionally
+/// only within a range of rounds. The Swiss engines pair around them; round-robin,
+/// Scheveningen and manual pairings that break one are rejected.
+#[allow(dead_code)]
+#[derive(Debug, Serialize, SpectaType, Clone, PartialEq)]
+pub struct ForbiddenPairing {
+    pub id: i32,
+    pub tournament_id: i32,
+    pub player_ids: Vec<i32>, // Two players or a whole group
+    pub reason: String,
+    pub first_round: Option<i32>, // None = from the first round
+    pub last_round: Option<i32>,  // None = until the last round
+    pub created_at: String,
+}
+
 #[allow(dead_code)]
 #[derive(Serialize, Debug, Type, SpectaType, Clone, PartialEq)]
 pub enum PlayerStatus {
@@ -768,8 +949,9 @@ impl TimeControlType {
 pub struct KnockoutBracket {
     pub id: i32,
     pub tournament_id: i32,
-    pub bracket_type: String, // "main", "consolation", "third_place"
+    pub bracket_type: String, // "single_elimination", "double_elimination", "third_place_playoff"
     pub total_rounds: i32,
+    pub bracket_reset: bool, // Replay the grand final if the losers-bracket champion wins it
     pub created_at: String,
 }
 
@@ -778,6 +960,7 @@ pub struct KnockoutBracket {
 pub struct BracketPosition {
     pub id: i32,
     pub bracket_id: i32,
+    pub bracket_side: String, // "winners", "losers", "grand_final"
     pub round_number: i32,
     pub position_number: i32,
     pub player_id: Option<i32>,
@@ -794,6 +977,14 @@ pub enum BracketType {
     ThirdPlacePlayoff,
 }
 
+#[allow(dead_code)]
+#[derive(Serialize, Debug, Type, SpectaType, Clone, PartialEq)]
+pub enum BracketSide {
+    Winners,
+    Losers,
+    GrandFinal,
+}
+
 #[allow(dead_code)]
 #[derive(Serialize, Debug, Type, SpectaType, Clone, PartialEq)]
 pub enum BracketPositionStatus {
@@ -804,6 +995,260 @@ pub enum BracketPositionStatus {
     Advanced,   // Player advanced to next round
 }
 
+/// Mini-match format of a knockout round: the main games, then tiebreak
+/// stages while the match is tied, and finally an Armageddon game
+#[allow(dead_code)]
+#[derive(Debug, Serialize, SpectaType, Clone)]
+pub struct KnockoutMatchFormat {
+    pub id: i32,
+    pub bracket_id: i32,
+    pub bracket_side: String,
+    pub round_number: i32,
+    pub games: i32,
+    pub time_control_id: Option<i32>,
+    pub tiebreak_stages: Vec<TiebreakStage>, // e.g. rapid, then blitz
+    pub armageddon_time_control_id: Option<i32>,
+    pub armageddon_color: String, // "choice", "bidding"
+    pub created_at: String,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Serialize, Deserialize, SpectaType, Clone, PartialEq)]
+pub struct TiebreakStage {
+    pub games: i32,
+    pub time_control_id: Option<i32>,
+}
+
+/// Game of a knockout mini-match
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone)]
+pub struct KnockoutMatchGame {
+    pub id: i32,
+    pub bracket_id: i32,
+    pub bracket_side: String,
+    pub round_number: i32,
+    pub match_number: i32, // Positions 2n-1 and 2n of the round
+    pub stage: i32,        // 0 = main games, then the tiebreak stages, Armageddon last
+    pub game_number: i32,
+    pub white_player_id: i32,
+    pub black_player_id: i32,
+    pub result: String,
+    pub time_control_id: Option<i32>,
+    pub black_time_minutes: Option<i32>, // Winning Armageddon bid
+    pub created_at: String,
+}
+
+#[allow(dead_code)]
+#[derive(Serialize, Debug, Type, SpectaType, Clone, PartialEq)]
+pub enum ArmageddonColor {
+    Choice,  // A player, e.g. drawn by lot, chooses the colour
+    Bidding, // The lowest time bid plays Black with draw odds
+}
+
+impl std::str::FromStr for ArmageddonColor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "choice" => Ok(ArmageddonColor::Choice),
+            "bidding" => Ok(ArmageddonColor::Bidding),
+            _ => Err(format!("Unknown Armageddon colour assignment: {s}")),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ArmageddonColor {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            ArmageddonColor::Choice => "choice",
+            ArmageddonColor::Bidding => "bidding",
+        }
+    }
+}
+
+/// Stage of a multi-stage event, e.g. a Swiss qualifier followed by a knockout.
+/// The qualification picks who leaves the stage, the seeding orders who enters it.
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone)]
+pub struct TournamentStage {
+    pub id: i32,
+    pub tournament_id: i32,
+    pub stage_number: i32,
+    pub name: String,
+    pub tournament_type: String,
+    pub rounds: Option<i32>, // Derived from the type and qualifiers when unset
+    pub qualification: String, // "top_n", "group_winners"
+    pub qualifiers: i32,     // Total for top_n, per group for group_winners
+    pub seeding: String,     // "qualifier_rank", "rating"
+    pub created_at: String,
+}
+
+/// Tournament played as one group of a stage
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone)]
+pub struct TournamentStageGroup {
+    pub id: i32,
+    pub stage_id: i32,
+    pub group_number: i32,
+    pub group_tournament_id: i32,
+    pub created_at: String,
+}
+
+#[allow(dead_code)]
+#[derive(Serialize, Debug, Type, SpectaType, Clone, PartialEq)]
+pub enum StageQualification {
+    TopN,         // The best players of the whole stage
+    GroupWinners, // The best players of every group
+}
+
+impl std::str::FromStr for StageQualification {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "top_n" => Ok(StageQualification::TopN),
+            "group_winners" => Ok(StageQualification::GroupWinners),
+            _ => Err(format!("Unknown stage qualification: {s}")),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl StageQualification {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            StageQualification::TopN => "top_n",
+            StageQualification::GroupWinners => "group_winners",
+        }
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Serialize, Debug, Type, SpectaType, Clone, PartialEq)]
+pub enum StageSeeding {
+    QualifierRank, // Seeded in the order they qualified
+    Rating,
+}
+
+impl std::str::FromStr for StageSeeding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "qualifier_rank" => Ok(StageSeeding::QualifierRank),
+            "rating" => Ok(StageSeeding::Rating),
+            _ => Err(format!("Unknown stage seeding: {s}")),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl StageSeeding {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            StageSeeding::QualifierRank => "qualifier_rank",
+            StageSeeding::Rating => "rating",
+        }
+    }
+}
+
+/// Event grouping several section tournaments with one registration desk
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone)]
+pub struct Event {
+    pub id: i32,
+    pub name: String,
+    pub location: String,
+    pub date: String,
+    pub country_code: String,
+    pub created_at: String,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone)]
+pub struct EventSection {
+    pub id: i32,
+    pub event_id: i32,
+    pub tournament_id: i32,
+    pub name: String, // e.g. "Open", "U2000"
+    pub min_rating: Option<i32>,
+    pub max_rating: Option<i32>, // Inclusive, e.g. 1999 for an U2000 section
+    pub allow_unrated: bool,
+    pub created_at: String,
+}
+
+/// Entry of a player registered at the event desk
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone)]
+pub struct EventRegistration {
+    pub id: i32,
+    pub event_id: i32,
+    pub section_id: i32,
+    pub player_id: i32, // Player of the section tournament
+    pub created_at: String,
+}
+
+/// Person in the player registry, linked to the players they have been in each tournament
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone)]
+pub struct Person {
+    pub id: i32,
+    pub name: String,
+    pub fide_id: Option<String>,
+    pub national_id: Option<String>,
+    pub federation: Option<String>,
+    pub birth_date: Option<String>,
+    pub gender: Option<String>,
+    pub title: Option<String>,
+    pub fide_rating: Option<i32>,
+    pub national_rating: Option<i32>,
+    pub rapid_rating: Option<i32>,
+    pub blitz_rating: Option<i32>,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone)]
+pub struct PersonRating {
+    pub id: i32,
+    pub person_id: i32,
+    pub rating_type: String, // fide, national, club, rapid, blitz
+    pub rating: i32,
+    pub is_provisional: bool,
+    pub effective_date: String,
+    pub tournament_id: Option<i32>, // Tournament the rating was recorded in
+    pub created_at: String,
+}
+
+/// FIDE rating list loaded for a list date
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone)]
+pub struct FideRatingList {
+    pub id: i32,
+    pub list_date: String,
+    pub source_file: String,
+    pub player_count: i32,
+    pub imported_at: String,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Serialize, FromRow, SpectaType, Clone)]
+pub struct FideRating {
+    pub list_id: i32,
+    pub fide_id: i32,
+    pub name: String, // "Surname, Firstname"
+    pub federation: Option<String>,
+    pub sex: Option<String>,
+    pub title: Option<String>,
+    pub birth_year: Option<i32>,
+    pub standard_rating: Option<i32>,
+    pub rapid_rating: Option<i32>,
+    pub blitz_rating: Option<i32>,
+    pub flag: Option<String>, // i for inactive, w for women
+}
+
 impl std::str::FromStr for BracketType {
     type Err = String;
 
@@ -828,6 +1273,30 @@ impl BracketType {
     }
 }
 
+impl std::str::FromStr for BracketSide {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "winners" => Ok(BracketSide::Winners),
+            "losers" => Ok(BracketSide::Losers),
+            "grand_final" => Ok(BracketSide::GrandFinal),
+            _ => Err(format!("Unknown bracket side: {s}")),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl BracketSide {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            BracketSide::Winners => "winners",
+            BracketSide::Losers => "losers",
+            BracketSide::GrandFinal => "grand_final",
+        }
+    }
+}
+
 impl std::str::FromStr for BracketPositionStatus {
     type Err = String;
 
@@ -24,6 +24,7 @@ pub enum TiebreakType {
     // Performance based
     AverageRatingOfOpponents,
     TournamentPerformanceRating,
+    PerfectTournamentPerformance,
 
     // Game based
     NumberOfWins,
@@ -54,6 +55,7 @@ impl TiebreakType {
             TiebreakType::DirectEncounter => "Direct Encounter",
             TiebreakType::AverageRatingOfOpponents => "Average Rating of Opponents (ARO)",
             TiebreakType::TournamentPerformanceRating => "Tournament Performance Rating (TPR)",
+            TiebreakType::PerfectTournamentPerformance => "Perfect Tournament Performance (PTP)",
             TiebreakType::NumberOfWins => "Number of Wins",
             TiebreakType::NumberOfGamesWithBlack => "Games with Black",
             TiebreakType::NumberOfWinsWithBlack => "Wins with Black",
@@ -78,6 +80,7 @@ impl TiebreakType {
             TiebreakType::DirectEncounter => "DE",
             TiebreakType::AverageRatingOfOpponents => "ARO",
             TiebreakType::TournamentPerformanceRating => "TPR",
+            TiebreakType::PerfectTournamentPerformance => "PTP",
             TiebreakType::NumberOfWins => "Wins",
             TiebreakType::NumberOfGamesWithBlack => "Black",
             TiebreakType::NumberOfWinsWithBlack => "W-Black",
@@ -148,6 +151,8 @@ pub struct TournamentTiebreakConfig {
     pub organizer_name: Option<String>,
     pub organizer_email: Option<String>,
     pub prize_structure: Option<String>,
+    pub chief_arbiter: Option<String>,
+    pub deputy_arbiters: Option<String>, // Comma separated
 }
 
 impl Default for TournamentTiebreakConfig {
@@ -172,6 +177,8 @@ impl Default for TournamentTiebreakConfig {
             organizer_name: None,
             organizer_email: None,
             prize_structure: None,
+            chief_arbiter: None,
+            deputy_arbiters: None,
         }
     }
 }
@@ -283,6 +290,15 @@ pub struct ExportRequest {
     pub template_options: Option<ExportTemplateOptions>,
 }
 
+/// Standings of every section of an event in one file
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize, SpectaType)]
+pub struct EventExportRequest {
+    pub event_id: i32,
+    pub format: ExportFormat, // CSV, JSON, HTML or TXT
+    pub custom_filename: Option<String>,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize, SpectaType)]
 pub enum ExportType {
@@ -291,7 +307,10 @@ pub enum ExportType {
     GameResults,
     PlayerList,
     TournamentSummary,
-    Complete, // All data
+    Pairings,     // Pairings of every round with their explanations
+    Arena,        // Arena standings with score sheets and the games played
+    RatingReport, // FIDE rating change of every player, game by game
+    Complete,     // All data
 }
 
 #[allow(dead_code)]
@@ -303,6 +322,7 @@ pub enum ExportFormat {
     Json,
     Xlsx,
     Txt,
+    Trf, // FIDE Tournament Report File (TRF-16)
 }
 
 #[allow(dead_code)]
@@ -430,6 +450,20 @@ impl NormType {
         }
     }
 
+    /// Lowest opponent rating counted for the norm (FIDE B.01); FM and CM titles have none
+    pub fn opponent_rating_floor(&self) -> Option<i32> {
+        match self {
+            NormType::Grandmaster => Some(2200),
+            NormType::InternationalMaster => Some(2050),
+            NormType::WomanGrandmaster => Some(2000),
+            NormType::WomanInternationalMaster => Some(1850),
+            NormType::FideMaster
+            | NormType::CandidateMaster
+            | NormType::WomanFideMaster
+            | NormType::WomanCandidateMaster => None,
+        }
+    }
+
     pub fn minimum_games(&self) -> i32 {
         match self {
             NormType::Grandmaster | NormType::InternationalMaster => 9,
@@ -42,6 +42,7 @@ pub fn init_plugin<R: Runtime>() -> TauriPlugin<R> {
             command::tournament::get_tournament_settings,
             command::tournament::update_tournament_settings,
             command::tournament::update_tournament_status,
+            command::tournament::import_tournament_trf,
             command::round::get_rounds_by_tournament,
             command::round::get_current_round,
             command::round::create_round,
@@ -57,6 +58,18 @@ pub fn init_plugin<R: Runtime>() -> TauriPlugin<R> {
             command::round::analyze_swiss_pairings,
             command::round::analyze_round_robin_pairings,
             command::round::validate_pairing_configuration,
+            command::round::check_tournament_pairings,
+            command::round::repair_round_pairings,
+            command::round::create_pairing_draft,
+            command::round::get_pairing_drafts,
+            command::round::compare_pairing_drafts,
+            command::round::publish_pairing_draft,
+            command::round::delete_pairing_draft,
+            command::round::get_acceleration_settings,
+            command::round::update_acceleration_settings,
+            command::round::get_forbidden_pairings,
+            command::round::create_forbidden_pairing,
+            command::round::delete_forbidden_pairing,
             command::round::benchmark_pairing_performance,
             command::game_result::update_game_result,
             command::game_result::validate_game_result,
@@ -86,6 +99,11 @@ pub fn init_plugin<R: Runtime>() -> TauriPlugin<R> {
             command::player::update_player_status,
             command::player::withdraw_player,
             command::player::request_player_bye,
+            command::player::request_byes,
+            command::player::cancel_bye_request,
+            command::player::get_bye_requests,
+            command::player::get_bye_settings,
+            command::player::update_bye_settings,
             command::player::get_player_statistics,
             // Knockout Tournament Commands
             command::knockout::create_knockout_bracket,
@@ -96,8 +114,61 @@ pub fn init_plugin<R: Runtime>() -> TauriPlugin<R> {
             command::knockout::generate_knockout_pairings,
             command::knockout::advance_knockout_winners,
             command::knockout::get_knockout_tournament_winner,
+            command::knockout::get_knockout_placings,
             command::knockout::is_knockout_tournament_complete,
             command::knockout::validate_knockout_bracket,
+            command::knockout::set_knockout_match_format,
+            command::knockout::get_knockout_match_formats,
+            command::knockout::start_knockout_matches,
+            command::knockout::get_knockout_match_games,
+            command::knockout::get_knockout_match_statuses,
+            command::knockout::record_knockout_match_game,
+            command::knockout::start_knockout_armageddon,
+            // Arena Tournament Commands
+            command::arena::get_arena_settings,
+            command::arena::update_arena_settings,
+            command::arena::get_arena_waiting_players,
+            command::arena::join_arena,
+            command::arena::leave_arena,
+            command::arena::get_arena_games,
+            command::arena::record_arena_result,
+            command::arena::get_arena_standings,
+            // Multi-Stage Event Commands
+            command::stage::get_tournament_stages,
+            command::stage::get_tournament_stage_groups,
+            command::stage::create_tournament_stage,
+            command::stage::preview_stage_qualifiers,
+            command::stage::advance_tournament_stage,
+            // Event Commands
+            command::event::get_events,
+            command::event::get_event,
+            command::event::create_event,
+            command::event::get_event_sections,
+            command::event::add_event_section,
+            command::event::get_eligible_event_sections,
+            command::event::register_event_player,
+            command::event::get_event_entries,
+            command::event::move_event_player,
+            // Player Registry Commands
+            command::registry::get_persons,
+            command::registry::search_persons,
+            command::registry::get_person,
+            command::registry::create_person,
+            command::registry::update_person,
+            command::registry::register_person_player,
+            command::registry::link_player_to_person,
+            command::registry::get_player_person,
+            command::registry::get_person_history,
+            command::registry::add_person_rating,
+            command::registry::get_person_rating_history,
+            // FIDE Rating List Commands
+            command::fide_rating_list::import_fide_rating_list,
+            command::fide_rating_list::get_fide_rating_lists,
+            command::fide_rating_list::search_fide_ratings,
+            command::fide_rating_list::register_fide_player,
+            command::fide_rating_list::refresh_tournament_ratings,
+            // Rating Report Commands
+            command::rating::get_rating_report,
             // Time Control Commands
             command::time_control::create_time_control,
             command::time_control::get_time_control,
@@ -117,6 +188,7 @@ pub fn init_plugin<R: Runtime>() -> TauriPlugin<R> {
             command::seeding::analyze_tournament_seeding,
             // Export Commands
             command::export::export_tournament_data,
+            command::export::export_event_data,
             command::export::get_export_directory,
             command::export::get_available_export_formats,
             command::export::get_export_templates,
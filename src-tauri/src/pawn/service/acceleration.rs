@@ -0,0 +1,319 @@
+use crate::pawn::{
+    common::error::PawnError,
+    domain::{
+        dto::{SwissPairingOptions, UpdateAccelerationSettings},
+        model::{AccelerationSettings, Player},
+    },
+    service::swiss_pairing::{SwissPlayer, starting_rank_order},
+};
+use std::collections::{HashMap, HashSet};
+
+/// How the players of group A are chosen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GroupBoundary {
+    /// The first 2 * ceil(N / 4) players of the starting rank
+    StartingRank,
+    /// Every player rated at least this rating
+    Rating(i32),
+}
+
+/// Baku acceleration (FIDE C.04.5).
+///
+/// Group A players get virtual points added to their pairing score in the
+/// accelerated rounds: the first-half value in the first half of those
+/// rounds (rounded up) and the second-half value in the rest. Group B gets
+/// none. Virtual points only affect pairing and never reach the standings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BakuAcceleration {
+    pub accelerated_rounds: i32,
+    pub boundary: GroupBoundary,
+    pub virtual_points_first_half: f64,
+    pub virtual_points_second_half: f64,
+}
+
+impl BakuAcceleration {
+    /// Acceleration configured for a tournament, `None` when it is disabled
+    pub fn from_settings(settings: &AccelerationSettings) -> Result<Option<Self>, PawnError> {
+        if !settings.enabled {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            accelerated_rounds: settings.accelerated_rounds,
+            boundary: parse_group_boundary(&settings.group_boundary, settings.group_a_min_rating)?,
+            virtual_points_first_half: settings.virtual_points_first_half,
+            virtual_points_second_half: settings.virtual_points_second_half,
+        }))
+    }
+
+    /// Acceleration requested through the Swiss pairing options, with the
+    /// groups split by starting rank
+    pub fn from_options(options: &SwissPairingOptions) -> Option<Self> {
+        options.use_accelerated_pairings.then(|| Self {
+            accelerated_rounds: options.accelerated_rounds,
+            boundary: GroupBoundary::StartingRank,
+            virtual_points_first_half: options.virtual_points_round1,
+            virtual_points_second_half: options.virtual_points_round2,
+        })
+    }
+
+    /// Virtual points given to group A players in a round
+    pub fn virtual_points(&self, round_number: i32) -> f64 {
+        if round_number < 1 || round_number > self.accelerated_rounds {
+            0.0
+        } else if round_number <= (self.accelerated_rounds + 1) / 2 {
+            self.virtual_points_first_half
+        } else {
+            self.virtual_points_second_half
+        }
+    }
+
+    /// Ids of the group A players
+    pub fn group_a(&self, players: &[Player]) -> HashSet<i32> {
+        match self.boundary {
+            GroupBoundary::StartingRank => {
+                let mut ranked: Vec<&Player> = players.iter().collect();
+                ranked.sort_by(|a, b| starting_rank_order(a, b));
+                let size = (players.len().div_ceil(4) * 2).min(players.len());
+                ranked.into_iter().take(size).map(|p| p.id).collect()
+            }
+            GroupBoundary::Rating(min_rating) => players
+                .iter()
+                .filter(|p| p.rating.unwrap_or(0) >= min_rating)
+                .map(|p| p.id)
+                .collect(),
+        }
+    }
+
+    /// Add the virtual points of the round to the pairing scores of group A,
+    /// returning the virtual points each player received
+    pub fn apply(&self, players: &mut [SwissPlayer], round_number: i32) -> HashMap<i32, f64> {
+        let virtual_points = self.virtual_points(round_number);
+        if virtual_points == 0.0 {
+            return HashMap::new();
+        }
+
+        let field: Vec<Player> = players.iter().map(|p| p.player.clone()).collect();
+        let group_a = self.group_a(&field);
+        let mut granted = HashMap::new();
+        for player in players
+            .iter_mut()
+            .filter(|p| group_a.contains(&p.player.id))
+        {
+            player.points += virtual_points;
+            granted.insert(player.player.id, virtual_points);
+        }
+
+        tracing::info!(
+            "Baku acceleration for round {}: {} group A players received {} virtual points",
+            round_number,
+            granted.len(),
+            virtual_points
+        );
+
+        granted
+    }
+}
+
+/// Check acceleration settings before they are stored
+pub fn validate_acceleration_settings(
+    settings: &UpdateAccelerationSettings,
+) -> Result<(), PawnError> {
+    if settings.accelerated_rounds < 1 {
+        return Err(PawnError::InvalidInput(
+            "At least one round must be accelerated".into(),
+        ));
+    }
+
+    for points in [
+        settings.virtual_points_first_half,
+        settings.virtual_points_second_half,
+    ] {
+        if !points.is_finite() || points < 0.0 {
+            return Err(PawnError::InvalidInput(format!(
+                "Invalid number of virtual points: {points}"
+            )));
+        }
+    }
+
+    parse_group_boundary(&settings.group_boundary, settings.group_a_min_rating)?;
+
+    Ok(())
+}
+
+fn parse_group_boundary(
+    boundary: &str,
+    min_rating: Option<i32>,
+) -> Result<GroupBoundary, PawnError> {
+    match boundary {
+        "starting_rank" => Ok(GroupBoundary::StartingRank),
+        "rating" => min_rating.map(GroupBoundary::Rating).ok_or_else(|| {
+            PawnError::InvalidInput("A rating group boundary needs a minimum rating".into())
+        }),
+        other => Err(PawnError::InvalidInput(format!(
+            "Unknown acceleration group boundary: {other}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::service::swiss_pairing::SwissPairingEngine;
+
+    fn create_test_player(id: i32, name: &str, rating: Option<i32>) -> Player {
+        Player {
+            id,
+            tournament_id: 1,
+            name: name.to_string(),
+            rating,
+            country_code: None,
+            title: None,
+            birth_date: None,
+            gender: None,
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: None,
+            initial_rating: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    fn create_field(count: i32) -> Vec<Player> {
+        (1..=count)
+            .map(|id| create_test_player(id, &format!("Player {id}"), Some(2500 - id * 50)))
+            .collect()
+    }
+
+    fn baku(accelerated_rounds: i32, boundary: GroupBoundary) -> BakuAcceleration {
+        BakuAcceleration {
+            accelerated_rounds,
+            boundary,
+            virtual_points_first_half: 1.0,
+            virtual_points_second_half: 0.5,
+        }
+    }
+
+    fn settings(
+        group_boundary: &str,
+        group_a_min_rating: Option<i32>,
+    ) -> UpdateAccelerationSettings {
+        UpdateAccelerationSettings {
+            tournament_id: 1,
+            enabled: true,
+            accelerated_rounds: 2,
+            group_boundary: group_boundary.to_string(),
+            group_a_min_rating,
+            virtual_points_first_half: 1.0,
+            virtual_points_second_half: 0.5,
+        }
+    }
+
+    #[test]
+    fn test_virtual_points_by_round() {
+        let acceleration = baku(4, GroupBoundary::StartingRank);
+        let points: Vec<f64> = (0..=5).map(|r| acceleration.virtual_points(r)).collect();
+        assert_eq!(points, vec![0.0, 1.0, 1.0, 0.5, 0.5, 0.0]);
+
+        // An odd number of accelerated rounds rounds the first half up
+        let acceleration = baku(3, GroupBoundary::StartingRank);
+        let points: Vec<f64> = (1..=4).map(|r| acceleration.virtual_points(r)).collect();
+        assert_eq!(points, vec![1.0, 1.0, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn test_group_a_by_starting_rank() {
+        let acceleration = baku(2, GroupBoundary::StartingRank);
+
+        // 2 * ceil(10 / 4) = 6 players
+        let group_a = acceleration.group_a(&create_field(10));
+        assert_eq!(group_a, (1..=6).collect());
+
+        // Pairing numbers take precedence over ratings
+        let mut players = create_field(4);
+        for (player, number) in players.iter_mut().zip([4, 3, 2, 1]) {
+            player.pairing_number = Some(number);
+        }
+        assert_eq!(acceleration.group_a(&players), HashSet::from([3, 4]));
+    }
+
+    #[test]
+    fn test_group_a_by_rating() {
+        let acceleration = baku(2, GroupBoundary::Rating(2300));
+        let mut players = create_field(6);
+        players.push(create_test_player(7, "Unrated", None));
+
+        // Ratings are 2450, 2400, ..., 2200
+        assert_eq!(acceleration.group_a(&players), HashSet::from([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_apply_only_changes_group_a() {
+        let acceleration = baku(2, GroupBoundary::StartingRank);
+        let mut swiss_players = SwissPairingEngine::new()
+            .build_swiss_players(create_field(8), vec![], vec![])
+            .unwrap();
+
+        let granted = acceleration.apply(&mut swiss_players, 2);
+        assert_eq!(granted.len(), 4);
+        for player in &swiss_players {
+            let expected = if player.player.id <= 4 { 0.5 } else { 0.0 };
+            assert_eq!(player.points, expected);
+        }
+
+        // Nothing is added after the accelerated rounds
+        assert!(acceleration.apply(&mut swiss_players, 3).is_empty());
+    }
+
+    #[test]
+    fn test_from_settings_and_options() {
+        let mut stored = AccelerationSettings {
+            tournament_id: 1,
+            enabled: false,
+            accelerated_rounds: 4,
+            group_boundary: "rating".to_string(),
+            group_a_min_rating: Some(2000),
+            virtual_points_first_half: 1.0,
+            virtual_points_second_half: 0.5,
+            updated_at: None,
+        };
+        assert_eq!(BakuAcceleration::from_settings(&stored).unwrap(), None);
+
+        stored.enabled = true;
+        let acceleration = BakuAcceleration::from_settings(&stored).unwrap().unwrap();
+        assert_eq!(acceleration.boundary, GroupBoundary::Rating(2000));
+        assert_eq!(acceleration.accelerated_rounds, 4);
+
+        let options = SwissPairingOptions {
+            use_accelerated_pairings: true,
+            accelerated_rounds: 2,
+            virtual_points_round1: 1.0,
+            virtual_points_round2: 0.5,
+            avoid_same_team: false,
+            color_preference_weight: 1.0,
+            rating_difference_penalty: 1.0,
+        };
+        let acceleration = BakuAcceleration::from_options(&options).unwrap();
+        assert_eq!(acceleration, baku(2, GroupBoundary::StartingRank));
+    }
+
+    #[test]
+    fn test_validate_acceleration_settings() {
+        assert!(validate_acceleration_settings(&settings("starting_rank", None)).is_ok());
+        assert!(validate_acceleration_settings(&settings("rating", Some(2000))).is_ok());
+        assert!(validate_acceleration_settings(&settings("rating", None)).is_err());
+        assert!(validate_acceleration_settings(&settings("score", None)).is_err());
+
+        let mut invalid = settings("starting_rank", None);
+        invalid.accelerated_rounds = 0;
+        assert!(validate_acceleration_settings(&invalid).is_err());
+
+        let mut invalid = settings("starting_rank", None);
+        invalid.virtual_points_second_half = -0.5;
+        assert!(validate_acceleration_settings(&invalid).is_err());
+    }
+}
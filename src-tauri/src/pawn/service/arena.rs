@@ -0,0 +1,375 @@
+use crate::pawn::common::error::PawnError;
+use crate::pawn::domain::{
+    dto::{ArenaStanding, UpdateArenaSettings},
+    model::{ArenaGame, ArenaSettings, GameResultType, Player, TournamentType},
+};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// Points for a win; a draw scores half
+const WIN_POINTS: i32 = 2;
+/// Extra point for winning a berserk game
+const BERSERK_BONUS: i32 = 1;
+/// Wins in a row after which wins and draws score double
+const STREAK_LENGTH: i32 = 2;
+
+#[allow(dead_code)]
+pub struct ArenaService;
+
+#[allow(dead_code)]
+impl ArenaService {
+    /// Whether a tournament is played as an arena
+    pub fn is_arena(tournament_type: Option<&str>) -> bool {
+        tournament_type.is_some_and(|tournament_type| {
+            tournament_type.to_lowercase().parse::<TournamentType>() == Ok(TournamentType::Arena)
+        })
+    }
+
+    /// Check new arena settings
+    pub fn validate_settings(data: &UpdateArenaSettings) -> Result<(), PawnError> {
+        Self::parse_deadline(&data.ends_at).map(|_| ())
+    }
+
+    /// Whether the deadline has passed, after which nobody is paired
+    pub fn has_ended(settings: &ArenaSettings, now: DateTime<Utc>) -> Result<bool, PawnError> {
+        Ok(now >= Self::parse_deadline(&settings.ends_at)?)
+    }
+
+    /// Results an arena game can end with
+    pub fn parse_result(result: &str) -> Result<GameResultType, PawnError> {
+        let result_type = result.parse().unwrap_or(GameResultType::Ongoing);
+        match result_type {
+            GameResultType::WhiteWins
+            | GameResultType::BlackWins
+            | GameResultType::Draw
+            | GameResultType::WhiteForfeit
+            | GameResultType::BlackForfeit
+            | GameResultType::DoubleForfeit => Ok(result_type),
+            _ => Err(PawnError::InvalidInput(format!(
+                "{result} is not a valid arena result"
+            ))),
+        }
+    }
+
+    /// Standings of an arena. Finished games are scored in the order they
+    /// ended: a win scores 2 and a draw 1, both doubled while the player is on
+    /// fire after two wins in a row, and a berserk win played over the board
+    /// earns one more point.
+    pub fn calculate_standings(players: &[Player], games: &[ArenaGame]) -> Vec<ArenaStanding> {
+        let ratings: HashMap<i32, Option<i32>> = players
+            .iter()
+            .map(|player| (player.id, player.rating))
+            .collect();
+        let mut standings: HashMap<i32, (ArenaStanding, i32, Vec<i32>)> = players
+            .iter()
+            .map(|player| {
+                let standing = ArenaStanding {
+                    rank: 0,
+                    player: player.clone(),
+                    score: 0,
+                    games_played: 0,
+                    wins: 0,
+                    draws: 0,
+                    losses: 0,
+                    berserks: 0,
+                    on_fire: false,
+                    performance_rating: None,
+                    sheet: Vec::new(),
+                };
+                // Current win streak and the performance of every rated game
+                (player.id, (standing, 0, Vec::new()))
+            })
+            .collect();
+
+        let mut finished: Vec<&ArenaGame> = games
+            .iter()
+            .filter(|game| game.finished_at.is_some())
+            .collect();
+        finished.sort_by(|a, b| (&a.finished_at, a.id).cmp(&(&b.finished_at, b.id)));
+
+        for game in finished {
+            let Ok(result) = Self::parse_result(&game.result) else {
+                continue;
+            };
+            let (white_points, black_points) = result.get_points();
+            let sides = [
+                (
+                    game.white_player_id,
+                    game.black_player_id,
+                    white_points,
+                    game.white_berserk,
+                ),
+                (
+                    game.black_player_id,
+                    game.white_player_id,
+                    black_points,
+                    game.black_berserk,
+                ),
+            ];
+
+            for (player_id, opponent_id, points, berserk) in sides {
+                let Some((standing, streak, performances)) = standings.get_mut(&player_id) else {
+                    continue;
+                };
+
+                let mut game_points = (points * WIN_POINTS as f32) as i32;
+                if *streak >= STREAK_LENGTH {
+                    game_points *= 2;
+                }
+                if berserk {
+                    standing.berserks += 1;
+                    if points == 1.0 && !result.is_unplayed() {
+                        game_points += BERSERK_BONUS;
+                    }
+                }
+
+                standing.score += game_points;
+                standing.games_played += 1;
+                standing.sheet.push(game_points);
+                if points == 1.0 {
+                    standing.wins += 1;
+                    *streak += 1;
+                } else {
+                    if points == 0.5 {
+                        standing.draws += 1;
+                    } else {
+                        standing.losses += 1;
+                    }
+                    *streak = 0;
+                }
+
+                if !result.is_unplayed()
+                    && let Some(Some(rating)) = ratings.get(&opponent_id)
+                {
+                    performances.push(rating + (1000.0 * (points - 0.5)) as i32);
+                }
+            }
+        }
+
+        let mut standings: Vec<ArenaStanding> = standings
+            .into_values()
+            .map(|(mut standing, streak, performances)| {
+                standing.on_fire = streak >= STREAK_LENGTH;
+                if !performances.is_empty() {
+                    standing.performance_rating =
+                        Some(performances.iter().sum::<i32>() / performances.len() as i32);
+                }
+                standing
+            })
+            .collect();
+        standings.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then(b.performance_rating.cmp(&a.performance_rating))
+                .then(a.player.name.cmp(&b.player.name))
+        });
+        for (rank, standing) in (1..).zip(standings.iter_mut()) {
+            standing.rank = rank;
+        }
+
+        standings
+    }
+
+    /// Pair the waiting players from the top of the standings down. Nobody
+    /// meets their previous opponent again straight away, so a player without
+    /// another partner keeps waiting; the player with more Whites gets Black.
+    /// Returns (white, black) player ids.
+    pub fn pair_waiting_players(
+        waiting: &[Player],
+        standings: &[ArenaStanding],
+        games: &[ArenaGame],
+    ) -> Vec<(i32, i32)> {
+        let scores: HashMap<i32, i32> = standings
+            .iter()
+            .map(|standing| (standing.player.id, standing.score))
+            .collect();
+        let mut last_opponents = HashMap::new();
+        let mut color_balance: HashMap<i32, i32> = HashMap::new();
+        for game in games {
+            last_opponents.insert(game.white_player_id, game.black_player_id);
+            last_opponents.insert(game.black_player_id, game.white_player_id);
+            *color_balance.entry(game.white_player_id).or_default() += 1;
+            *color_balance.entry(game.black_player_id).or_default() -= 1;
+        }
+        let just_met = |a: i32, b: i32| {
+            last_opponents.get(&a) == Some(&b) || last_opponents.get(&b) == Some(&a)
+        };
+        let balance = |id: i32| color_balance.get(&id).copied().unwrap_or(0);
+
+        let mut pool: Vec<&Player> = waiting.iter().collect();
+        pool.sort_by(|a, b| {
+            let score = |player: &Player| scores.get(&player.id).copied().unwrap_or(0);
+            score(b).cmp(&score(a)).then(b.rating.cmp(&a.rating))
+        });
+
+        let mut pairs = Vec::new();
+        while !pool.is_empty() {
+            let player = pool.remove(0);
+            let Some(index) = pool
+                .iter()
+                .position(|opponent| !just_met(player.id, opponent.id))
+            else {
+                continue;
+            };
+            let opponent = pool.remove(index);
+
+            if balance(opponent.id) < balance(player.id) {
+                pairs.push((opponent.id, player.id));
+            } else {
+                pairs.push((player.id, opponent.id));
+            }
+        }
+
+        pairs
+    }
+
+    fn parse_deadline(ends_at: &str) -> Result<DateTime<Utc>, PawnError> {
+        DateTime::parse_from_rfc3339(ends_at)
+            .map(|deadline| deadline.with_timezone(&Utc))
+            .map_err(|_| {
+                PawnError::InvalidInput(format!("{ends_at} is not an RFC 3339 date and time"))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_player(id: i32, rating: Option<i32>) -> Player {
+        Player {
+            id,
+            tournament_id: 1,
+            name: format!("Player {id}"),
+            rating,
+            country_code: None,
+            title: None,
+            birth_date: None,
+            gender: None,
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: None,
+            initial_rating: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    fn create_game(id: i32, white: i32, black: i32, result: &str) -> ArenaGame {
+        ArenaGame {
+            id,
+            tournament_id: 1,
+            white_player_id: white,
+            black_player_id: black,
+            result: result.to_string(),
+            white_berserk: false,
+            black_berserk: false,
+            started_at: "2024-01-01 18:00:00".to_string(),
+            finished_at: (result != "*").then(|| format!("2024-01-01 18:{id:02}:00")),
+        }
+    }
+
+    fn settings(ends_at: &str) -> ArenaSettings {
+        ArenaSettings {
+            tournament_id: 1,
+            ends_at: ends_at.to_string(),
+            berserk_allowed: true,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_is_arena() {
+        assert!(ArenaService::is_arena(Some("arena")));
+        assert!(ArenaService::is_arena(Some("Arena")));
+        assert!(!ArenaService::is_arena(Some("swiss")));
+        assert!(!ArenaService::is_arena(None));
+    }
+
+    #[test]
+    fn test_deadline() {
+        let now = DateTime::parse_from_rfc3339("2024-01-01T19:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert!(!ArenaService::has_ended(&settings("2024-01-01T20:00:00+01:00"), now).unwrap());
+        assert!(ArenaService::has_ended(&settings("2024-01-01T18:59:00Z"), now).unwrap());
+        assert!(ArenaService::has_ended(&settings("tonight"), now).is_err());
+    }
+
+    #[test]
+    fn test_parse_result() {
+        assert!(ArenaService::parse_result("1-0").is_ok());
+        assert!(ArenaService::parse_result("1/2-1/2").is_ok());
+        assert!(ArenaService::parse_result("*").is_err());
+        assert!(ArenaService::parse_result("ADJ").is_err());
+    }
+
+    #[test]
+    fn test_streak_and_berserk_scoring() {
+        let players = vec![
+            create_test_player(1, Some(2000)),
+            create_test_player(2, Some(1800)),
+            create_test_player(3, Some(1600)),
+        ];
+        let mut berserk_win = create_game(4, 1, 3, "1-0");
+        berserk_win.white_berserk = true;
+        let games = vec![
+            create_game(1, 1, 2, "1-0"),
+            create_game(2, 3, 1, "0-1"),
+            create_game(3, 2, 1, "1/2-1/2"),
+            berserk_win,
+            create_game(5, 2, 3, "*"),
+        ];
+
+        let standings = ArenaService::calculate_standings(&players, &games);
+        let leader = &standings[0];
+
+        // 2 + 2, a draw on fire scores 2, the streak ends, then 2 + 1 berserk
+        assert_eq!(leader.player.id, 1);
+        assert_eq!(leader.sheet, vec![2, 2, 2, 3]);
+        assert_eq!(leader.score, 9);
+        assert_eq!(leader.berserks, 1);
+        assert!(!leader.on_fire);
+        assert_eq!(standings[1].player.id, 2);
+        assert_eq!(standings[1].score, 1);
+        assert_eq!(standings[2].games_played, 2);
+    }
+
+    #[test]
+    fn test_pairing_avoids_immediate_rematch() {
+        let players: Vec<Player> = (1..=4)
+            .map(|id| create_test_player(id, Some(2000 - id * 100)))
+            .collect();
+        let games = vec![create_game(1, 1, 2, "1-0"), create_game(2, 3, 4, "1-0")];
+        let standings = ArenaService::calculate_standings(&players, &games);
+
+        let pairs = ArenaService::pair_waiting_players(&players, &standings, &games);
+
+        // Winners meet winners; with equal colours the higher-placed player gets White
+        assert_eq!(pairs, vec![(1, 3), (2, 4)]);
+
+        // Two players who just met keep waiting
+        let pairs = ArenaService::pair_waiting_players(&players[..2], &standings, &games);
+        assert!(pairs.is_empty());
+    }
+
+    #[test]
+    fn test_pairing_balances_colours() {
+        let players: Vec<Player> = (1..=3).map(|id| create_test_player(id, None)).collect();
+        let games = vec![
+            create_game(1, 1, 2, "1-0"),
+            create_game(2, 1, 3, "1-0"),
+            create_game(3, 2, 3, "1-0"),
+        ];
+        let standings = ArenaService::calculate_standings(&players, &games);
+
+        // Player 1 had White twice, player 2 once of two games
+        let pairs = ArenaService::pair_waiting_players(&players[..2], &standings, &games);
+
+        assert_eq!(pairs, vec![(2, 1)]);
+    }
+}
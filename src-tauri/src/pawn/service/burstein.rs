@@ -0,0 +1,481 @@
+#![allow(dead_code)]
+
+use crate::pawn::{
+    common::error::PawnError,
+    domain::{
+        model::{GameResult, Pairing, Player, PlayerResult},
+        tiebreak::{StandingsCalculationResult, TiebreakType},
+    },
+    service::{
+        dutch_optimal::{half_points, higher_ranked_color},
+        swiss_pairing::{Color, ColorPreference, PairingResult, SwissPairingEngine, SwissPlayer},
+    },
+};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// Tiebreaks forming the Burstein index, most significant first
+pub const BURSTEIN_INDEX_TIEBREAKS: [TiebreakType; 3] = [
+    TiebreakType::SonnebornBerger,
+    TiebreakType::BuchholzFull,
+    TiebreakType::BuchholzMedian,
+];
+
+/// Search steps allowed per attempt before a bracket gives up on a pairing size
+const SEARCH_LIMIT: usize = 20_000;
+
+/// Index values of every player by player id, in `BURSTEIN_INDEX_TIEBREAKS` order
+pub type BursteinIndex = HashMap<i32, Vec<f64>>;
+
+/// Number of seeding rounds: half the rounds, rounded down, at most four
+pub fn seeding_rounds(total_rounds: i32) -> i32 {
+    (total_rounds / 2).clamp(0, 4)
+}
+
+/// Burstein index of every player from standings calculated with `BURSTEIN_INDEX_TIEBREAKS`
+pub fn index_from_standings(standings: &StandingsCalculationResult) -> BursteinIndex {
+    standings
+        .standings
+        .iter()
+        .map(|standing| {
+            let values = standing
+                .tiebreak_scores
+                .iter()
+                .map(|score| score.value)
+                .collect();
+            (standing.player.id, values)
+        })
+        .collect()
+}
+
+/// FIDE Burstein System (C.04.4.2).
+///
+/// During the seeding rounds score groups are ordered by rating and the top
+/// half meets the bottom half. Afterwards players are ordered by their index
+/// (Sonneborn-Berger, Buchholz, median Buchholz) and each score group is
+/// folded: the first player meets the last, the second the one before last.
+pub struct BursteinEngine {
+    swiss_engine: SwissPairingEngine,
+}
+
+/// Pairs and downfloaters of one bracket, as positions in the ranked field
+#[derive(Debug, Default)]
+struct BracketPairing {
+    pairs: Vec<(usize, usize)>,
+    floaters: Vec<usize>,
+}
+
+impl Default for BursteinEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BursteinEngine {
+    pub fn new() -> Self {
+        Self {
+            swiss_engine: SwissPairingEngine::new(),
+        }
+    }
+
+    /// Engine that keeps the given player pairs apart
+    pub fn with_forbidden_pairs(mut self, forbidden_pairs: HashSet<(i32, i32)>) -> Self {
+        self.swiss_engine = self.swiss_engine.with_forbidden_pairs(forbidden_pairs);
+        self
+    }
+
+    /// Generate Burstein System pairings; without an index the round is paired
+    /// as a seeding round
+    pub fn generate_pairings(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+        index: Option<&BursteinIndex>,
+    ) -> Result<PairingResult, PawnError> {
+        tracing::info!(
+            "Starting Burstein System pairing for {} players, round {} ({})",
+            players.len(),
+            round_number,
+            if index.is_some() {
+                "index round"
+            } else {
+                "seeding round"
+            }
+        );
+
+        let mut ranked =
+            self.swiss_engine
+                .build_swiss_players(players, player_results, game_history)?;
+        self.rank_players(&mut ranked, index);
+
+        let mut validation_errors = Vec::new();
+        let mut byes = Vec::new();
+
+        // The bye goes to the lowest ranked player who has not had one yet
+        if ranked.len() % 2 == 1 {
+            let position = ranked
+                .iter()
+                .rposition(|player| player.is_bye_eligible)
+                .unwrap_or_else(|| {
+                    let last = ranked.len() - 1;
+                    validation_errors.push(format!(
+                        "{} receives a second pairing-allocated bye",
+                        ranked[last].player.name
+                    ));
+                    last
+                });
+            byes.push(ranked.remove(position));
+        }
+
+        // Score groups are consecutive in the ranked field
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for (position, player) in ranked.iter().enumerate() {
+            match groups.last_mut() {
+                Some(group)
+                    if half_points(ranked[group[0]].points) == half_points(player.points) =>
+                {
+                    group.push(position)
+                }
+                _ => groups.push(vec![position]),
+            }
+        }
+
+        let fold = index.is_some();
+        let mut pairs = Vec::new();
+        let mut floaters: Vec<usize> = Vec::new();
+        for group in groups {
+            // Downfloaters from the bracket above are ranked above this group
+            let bracket: Vec<usize> = floaters.drain(..).chain(group).collect();
+            let bracket_pairing = self.pair_bracket(&ranked, &bracket, fold);
+            pairs.extend(bracket_pairing.pairs);
+            floaters = bracket_pairing.floaters;
+        }
+
+        // Players left over in the last bracket are paired relaxing the absolute criteria
+        for leftover in floaters.chunks(2) {
+            if let [higher, lower] = *leftover {
+                validation_errors.push(format!(
+                    "Absolute criteria relaxed to pair {} vs {}",
+                    ranked[higher].player.name, ranked[lower].player.name
+                ));
+                pairs.push((higher, lower));
+            }
+        }
+
+        // Boards are ordered by the higher score, then the lower score, then ranking
+        pairs.sort_by(|&(a_high, a_low), &(b_high, b_low)| {
+            ranked[b_high]
+                .points
+                .partial_cmp(&ranked[a_high].points)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| {
+                    ranked[b_low]
+                        .points
+                        .partial_cmp(&ranked[a_low].points)
+                        .unwrap_or(Ordering::Equal)
+                })
+                .then_with(|| a_high.cmp(&b_high))
+        });
+
+        let mut float_count = 0;
+        let pairings: Vec<Pairing> = pairs
+            .iter()
+            .enumerate()
+            .map(|(position, &(higher, lower))| {
+                if half_points(ranked[higher].points) != half_points(ranked[lower].points) {
+                    float_count += 1;
+                }
+                let board_number = position as i32 + 1;
+                let (white, black) =
+                    match higher_ranked_color(&ranked[higher], &ranked[lower], board_number) {
+                        Color::White => (&ranked[higher], &ranked[lower]),
+                        Color::Black => (&ranked[lower], &ranked[higher]),
+                    };
+                Pairing {
+                    white_player: white.player.clone(),
+                    black_player: Some(black.player.clone()),
+                    board_number,
+                    explanation: None,
+                }
+            })
+            .collect();
+
+        tracing::info!(
+            "Burstein System produced {} pairings, {} floats, {} byes",
+            pairings.len(),
+            float_count,
+            byes.len()
+        );
+
+        Ok(PairingResult {
+            pairings,
+            byes,
+            float_count,
+            validation_errors,
+        })
+    }
+
+    /// Order players by score, then by index (or rating in seeding rounds)
+    fn rank_players(&self, players: &mut [SwissPlayer], index: Option<&BursteinIndex>) {
+        let index_of = |player: &SwissPlayer| {
+            index
+                .and_then(|index| index.get(&player.player.id))
+                .cloned()
+                .unwrap_or_default()
+        };
+
+        players.sort_by(|a, b| {
+            b.points
+                .partial_cmp(&a.points)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| {
+                    let (a_index, b_index) = (index_of(a), index_of(b));
+                    b_index.partial_cmp(&a_index).unwrap_or(Ordering::Equal)
+                })
+                .then_with(|| b.rating.cmp(&a.rating))
+                .then_with(|| a.player.id.cmp(&b.player.id))
+        });
+    }
+
+    /// Pair as many players of the bracket as possible, leaving the lowest
+    /// ranked ones to float down
+    fn pair_bracket(
+        &self,
+        ranked: &[SwissPlayer],
+        bracket: &[usize],
+        fold: bool,
+    ) -> BracketPairing {
+        for pair_count in (0..=bracket.len() / 2).rev() {
+            let mut result = BracketPairing::default();
+            let mut budget = SEARCH_LIMIT;
+            let floats = bracket.len() - 2 * pair_count;
+            if self.search(ranked, bracket, floats, fold, &mut result, &mut budget) {
+                return result;
+            }
+        }
+
+        BracketPairing {
+            pairs: vec![],
+            floaters: bracket.to_vec(),
+        }
+    }
+
+    /// Backtracking search pairing the highest remaining player first
+    fn search(
+        &self,
+        ranked: &[SwissPlayer],
+        remaining: &[usize],
+        floats: usize,
+        fold: bool,
+        result: &mut BracketPairing,
+        budget: &mut usize,
+    ) -> bool {
+        let Some((&first, rest)) = remaining.split_first() else {
+            return true;
+        };
+        if *budget == 0 {
+            return false;
+        }
+        *budget -= 1;
+
+        let pairs_left = (remaining.len() - floats) / 2;
+        for candidate in candidate_order(rest.len(), pairs_left, floats, fold) {
+            let opponent = rest[candidate];
+            if !self.compatible(&ranked[first], &ranked[opponent]) {
+                continue;
+            }
+            let others: Vec<usize> = rest
+                .iter()
+                .copied()
+                .filter(|&position| position != opponent)
+                .collect();
+            result.pairs.push((first, opponent));
+            if self.search(ranked, &others, floats, fold, result, budget) {
+                return true;
+            }
+            result.pairs.pop();
+        }
+
+        if floats > 0 {
+            result.floaters.push(first);
+            if self.search(ranked, rest, floats - 1, fold, result, budget) {
+                return true;
+            }
+            result.floaters.pop();
+        }
+
+        false
+    }
+
+    /// C.1 (no rematches) and C.3 (no conflicting absolute colour preferences)
+    fn compatible(&self, a: &SwissPlayer, b: &SwissPlayer) -> bool {
+        if a.opponents.contains(&b.player.id) || b.opponents.contains(&a.player.id) {
+            return false;
+        }
+
+        !matches!(
+            (a.color_preference, b.color_preference),
+            (ColorPreference::Absolute(ca), ColorPreference::Absolute(cb)) if ca == cb
+        )
+    }
+}
+
+/// Order in which the remaining players of a bracket are tried as opponents
+/// of its highest player. Folding starts from the bottom of the bracket,
+/// above the players expected to float; seeding rounds start from the top of
+/// the lower half.
+fn candidate_order(rest_len: usize, pairs_left: usize, floats: usize, fold: bool) -> Vec<usize> {
+    if pairs_left == 0 || rest_len == 0 {
+        return vec![];
+    }
+
+    let natural = if fold {
+        rest_len.saturating_sub(floats + 1)
+    } else {
+        (pairs_left - 1).min(rest_len - 1)
+    };
+    if fold {
+        (0..=natural).rev().chain(natural + 1..rest_len).collect()
+    } else {
+        (natural..rest_len).chain((0..natural).rev()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::service::pairing_test_support::*;
+
+    #[test]
+    fn test_seeding_rounds() {
+        assert_eq!(seeding_rounds(1), 0);
+        assert_eq!(seeding_rounds(5), 2);
+        assert_eq!(seeding_rounds(9), 4);
+        assert_eq!(seeding_rounds(13), 4);
+    }
+
+    #[test]
+    fn test_empty_field() {
+        let engine = BursteinEngine::new();
+        let result = engine
+            .generate_pairings(vec![], vec![], vec![], 1, None)
+            .unwrap();
+
+        assert!(result.pairings.is_empty());
+        assert!(result.byes.is_empty());
+    }
+
+    #[test]
+    fn test_seeding_round_top_half_meets_bottom_half() {
+        let engine = BursteinEngine::new();
+        let players = create_field(8);
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.0))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 1, None)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 5), (2, 6), (3, 7), (4, 8)]);
+        assert_eq!(result.float_count, 0);
+        assert!(result.validation_errors.is_empty());
+    }
+
+    #[test]
+    fn test_index_round_folds_score_group_by_index() {
+        let engine = BursteinEngine::new();
+        let players = create_field(4);
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 1.0))
+            .collect();
+        // The index reverses the rating order: player 4 ranks first
+        let index: BursteinIndex = [
+            (1, vec![1.0, 2.0, 1.0]),
+            (2, vec![2.0, 2.0, 1.0]),
+            (3, vec![3.0, 2.0, 1.0]),
+            (4, vec![4.0, 2.0, 1.0]),
+        ]
+        .into_iter()
+        .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 4, Some(&index))
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 4), (2, 3)]);
+    }
+
+    #[test]
+    fn test_odd_score_group_floats_lowest_player_down() {
+        let engine = BursteinEngine::new();
+        let players = create_field(4);
+        let results = vec![
+            create_test_result(players[0].clone(), 1.0),
+            create_test_result(players[1].clone(), 1.0),
+            create_test_result(players[2].clone(), 1.0),
+            create_test_result(players[3].clone(), 0.0),
+        ];
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 2, Some(&BursteinIndex::new()))
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 2), (3, 4)]);
+        assert_eq!(result.float_count, 1);
+    }
+
+    #[test]
+    fn test_avoids_rematch_within_score_group() {
+        let engine = BursteinEngine::new();
+        let players = create_field(4);
+        let history = vec![
+            create_game_result(1, &players[0], &players[3], 1, "1/2-1/2"),
+            create_game_result(2, &players[1], &players[2], 1, "1/2-1/2"),
+        ];
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.5))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, history, 2, Some(&BursteinIndex::new()))
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 3), (2, 4)]);
+        assert!(result.validation_errors.is_empty());
+    }
+
+    #[test]
+    fn test_previous_bye_receiver_is_skipped() {
+        let engine = BursteinEngine::new();
+        let players = create_field(3);
+        let bye = create_test_player(-1, "BYE", None);
+        let history = vec![
+            create_game_result(1, &players[0], &players[1], 1, "1-0"),
+            create_game_result(2, &players[2], &bye, 1, "1-0"),
+        ];
+        let results = vec![
+            create_test_result(players[0].clone(), 1.0),
+            create_test_result(players[1].clone(), 0.0),
+            create_test_result(players[2].clone(), 0.0),
+        ];
+
+        // Player 3 is ranked last but already had the bye
+        let result = engine
+            .generate_pairings(players, results, history, 2, None)
+            .unwrap();
+
+        assert_eq!(result.byes.len(), 1);
+        assert_eq!(result.byes[0].player.id, 2);
+        assert_eq!(paired_ids(&result.pairings[0]), (1, 3));
+    }
+}
@@ -0,0 +1,602 @@
+#![allow(dead_code)]
+
+use crate::pawn::{
+    common::error::PawnError,
+    domain::{
+        model::{GameResult, Pairing, Player, PlayerResult},
+        tiebreak::StandingsCalculationResult,
+    },
+    service::{
+        dutch_optimal::{half_points, higher_ranked_color, preference_strength, preferred_color},
+        swiss_pairing::{Color, ColorPreference, PairingResult, SwissPairingEngine, SwissPlayer},
+    },
+};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// Search steps allowed per attempt before a bracket gives up on a pairing size
+const SEARCH_LIMIT: usize = 20_000;
+
+/// Average rating of opponents of every player from standings calculated with
+/// `TiebreakType::AverageRatingOfOpponents` as their first tiebreak
+pub fn aro_from_standings(standings: &StandingsCalculationResult) -> HashMap<i32, f64> {
+    standings
+        .standings
+        .iter()
+        .map(|standing| {
+            let aro = standing
+                .tiebreak_scores
+                .first()
+                .map(|score| score.value)
+                .unwrap_or(0.0);
+            (standing.player.id, aro)
+        })
+        .collect()
+}
+
+/// FIDE Dubov System (C.04.4.1).
+///
+/// Every score group is split into the players due white and the players due
+/// black. White seekers are ordered by ascending ARO, black seekers by
+/// descending rating, and the n-th white seeker meets the n-th black seeker so
+/// the players with the weakest opposition so far meet the strongest
+/// opponents. Odd score groups are completed with an upfloater from the group
+/// below.
+pub struct DubovEngine {
+    swiss_engine: SwissPairingEngine,
+}
+
+/// Pairs (white, black) and players left unpaired in one bracket, as positions
+/// in the ranked field
+#[derive(Debug, Default)]
+struct BracketPairing {
+    pairs: Vec<(usize, usize)>,
+    unpaired: Vec<usize>,
+}
+
+/// A player of the ranked field together with the ARO used for the split
+struct DubovPlayer {
+    swiss: SwissPlayer,
+    aro: f64,
+}
+
+impl Default for DubovEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DubovEngine {
+    pub fn new() -> Self {
+        Self {
+            swiss_engine: SwissPairingEngine::new(),
+        }
+    }
+
+    /// Engine that keeps the given player pairs apart
+    pub fn with_forbidden_pairs(mut self, forbidden_pairs: HashSet<(i32, i32)>) -> Self {
+        self.swiss_engine = self.swiss_engine.with_forbidden_pairs(forbidden_pairs);
+        self
+    }
+
+    /// Generate Dubov System pairings; `aro` holds the average rating of
+    /// opponents of every player by player id (missing players count as 0)
+    pub fn generate_pairings(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+        aro: &HashMap<i32, f64>,
+    ) -> Result<PairingResult, PawnError> {
+        tracing::info!(
+            "Starting Dubov System pairing for {} players, round {}",
+            players.len(),
+            round_number
+        );
+
+        let swiss_players =
+            self.swiss_engine
+                .build_swiss_players(players, player_results, game_history)?;
+        let mut ranked: Vec<DubovPlayer> = swiss_players
+            .into_iter()
+            .map(|swiss| DubovPlayer {
+                aro: aro.get(&swiss.player.id).copied().unwrap_or(0.0),
+                swiss,
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.swiss
+                .points
+                .partial_cmp(&a.swiss.points)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| b.swiss.rating.cmp(&a.swiss.rating))
+                .then_with(|| a.swiss.player.id.cmp(&b.swiss.player.id))
+        });
+
+        let mut validation_errors = Vec::new();
+        let mut byes = Vec::new();
+
+        // The bye goes to the lowest rated player of the lowest score group who
+        // has not had one yet
+        if ranked.len() % 2 == 1 {
+            let position = ranked
+                .iter()
+                .rposition(|player| player.swiss.is_bye_eligible)
+                .unwrap_or_else(|| {
+                    let last = ranked.len() - 1;
+                    validation_errors.push(format!(
+                        "{} receives a second pairing-allocated bye",
+                        ranked[last].swiss.player.name
+                    ));
+                    last
+                });
+            byes.push(ranked.remove(position).swiss);
+        }
+
+        // Score groups are consecutive in the ranked field
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for (position, player) in ranked.iter().enumerate() {
+            match groups.last_mut() {
+                Some(group)
+                    if half_points(ranked[group[0]].swiss.points)
+                        == half_points(player.swiss.points) =>
+                {
+                    group.push(position)
+                }
+                _ => groups.push(vec![position]),
+            }
+        }
+
+        let mut pairs = Vec::new();
+        let mut carried: Vec<usize> = Vec::new();
+        for group_index in 0..groups.len() {
+            let bracket: Vec<usize> = carried
+                .drain(..)
+                .chain(groups[group_index].iter().copied())
+                .collect();
+
+            // An odd bracket is completed with the highest rated player of the
+            // next group who lets the whole bracket be paired
+            let mut bracket_pairing = None;
+            if bracket.len() % 2 == 1 && group_index + 1 < groups.len() {
+                let mut candidates = groups[group_index + 1].clone();
+                candidates.sort_by(|&a, &b| {
+                    ranked[b]
+                        .swiss
+                        .rating
+                        .cmp(&ranked[a].swiss.rating)
+                        .then_with(|| {
+                            ranked[a]
+                                .aro
+                                .partial_cmp(&ranked[b].aro)
+                                .unwrap_or(Ordering::Equal)
+                        })
+                });
+                for upfloater in candidates {
+                    let mut extended = bracket.clone();
+                    extended.push(upfloater);
+                    let attempt = self.pair_bracket(&ranked, &extended);
+                    if attempt.unpaired.is_empty() {
+                        groups[group_index + 1].retain(|&position| position != upfloater);
+                        bracket_pairing = Some(attempt);
+                        break;
+                    }
+                }
+            }
+
+            let bracket_pairing =
+                bracket_pairing.unwrap_or_else(|| self.pair_bracket(&ranked, &bracket));
+            pairs.extend(bracket_pairing.pairs);
+            carried = bracket_pairing.unpaired;
+        }
+
+        // Players left over in the last bracket are paired relaxing the absolute criteria
+        for leftover in carried.chunks(2) {
+            if let [first, second] = *leftover {
+                validation_errors.push(format!(
+                    "Absolute criteria relaxed to pair {} vs {}",
+                    ranked[first].swiss.player.name, ranked[second].swiss.player.name
+                ));
+                pairs.push((first, second));
+            }
+        }
+
+        // Boards are ordered by the higher score, then the lower score, then rating
+        let board_key = |&(white, black): &(usize, usize)| {
+            let (a, b) = (&ranked[white].swiss, &ranked[black].swiss);
+            (
+                half_points(a.points.max(b.points)),
+                half_points(a.points.min(b.points)),
+                a.rating.max(b.rating),
+            )
+        };
+        pairs.sort_by_key(|pair| std::cmp::Reverse(board_key(pair)));
+
+        let mut float_count = 0;
+        let pairings: Vec<Pairing> = pairs
+            .iter()
+            .enumerate()
+            .map(|(position, &(white, black))| {
+                if half_points(ranked[white].swiss.points)
+                    != half_points(ranked[black].swiss.points)
+                {
+                    float_count += 1;
+                }
+                let board_number = position as i32 + 1;
+                let (white, black) = (&ranked[white].swiss, &ranked[black].swiss);
+
+                // Without colour preferences colours alternate as in the Dutch System
+                let (white, black) = match (white.color_preference, black.color_preference) {
+                    (ColorPreference::None, ColorPreference::None) => {
+                        match higher_ranked_color(white, black, board_number) {
+                            Color::White => (white, black),
+                            Color::Black => (black, white),
+                        }
+                    }
+                    _ => (white, black),
+                };
+                Pairing {
+                    white_player: white.player.clone(),
+                    black_player: Some(black.player.clone()),
+                    board_number,
+                    explanation: None,
+                }
+            })
+            .collect();
+
+        tracing::info!(
+            "Dubov System produced {} pairings, {} floats, {} byes",
+            pairings.len(),
+            float_count,
+            byes.len()
+        );
+
+        Ok(PairingResult {
+            pairings,
+            byes,
+            float_count,
+            validation_errors,
+        })
+    }
+
+    /// Split the bracket into white and black seekers and pair them, leaving
+    /// as few players unpaired as possible
+    fn pair_bracket(&self, ranked: &[DubovPlayer], bracket: &[usize]) -> BracketPairing {
+        let (mut white, mut black) = self.split_by_colour(ranked, bracket);
+
+        // White seekers by ascending ARO, black seekers by descending rating
+        white.sort_by(|&a, &b| {
+            ranked[a]
+                .aro
+                .partial_cmp(&ranked[b].aro)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| ranked[b].swiss.rating.cmp(&ranked[a].swiss.rating))
+                .then_with(|| a.cmp(&b))
+        });
+        black.sort_by(|&a, &b| {
+            ranked[b]
+                .swiss
+                .rating
+                .cmp(&ranked[a].swiss.rating)
+                .then_with(|| {
+                    ranked[a]
+                        .aro
+                        .partial_cmp(&ranked[b].aro)
+                        .unwrap_or(Ordering::Equal)
+                })
+                .then_with(|| a.cmp(&b))
+        });
+
+        let pair_target = white.len().min(black.len());
+        for pair_count in (0..=pair_target).rev() {
+            let mut search = SubgroupSearch {
+                ranked,
+                white: &white,
+                black: &black,
+                used: vec![false; black.len()],
+                result: BracketPairing::default(),
+                budget: SEARCH_LIMIT,
+            };
+            if search.search(0, white.len() - pair_count) {
+                let mut result = search.result;
+                result.unpaired.extend(
+                    black
+                        .iter()
+                        .zip(&search.used)
+                        .filter(|(_, used)| !**used)
+                        .map(|(&position, _)| position),
+                );
+                result.unpaired.sort_unstable();
+                return result;
+            }
+        }
+
+        let mut unpaired = bracket.to_vec();
+        unpaired.sort_unstable();
+        BracketPairing {
+            pairs: vec![],
+            unpaired,
+        }
+    }
+
+    /// Split a bracket into equally sized groups of white and black seekers.
+    ///
+    /// Players without a preference fill the smaller group first; players then
+    /// move from the larger group starting with the weakest preference and the
+    /// lowest rating. Absolute preferences are never moved.
+    fn split_by_colour(
+        &self,
+        ranked: &[DubovPlayer],
+        bracket: &[usize],
+    ) -> (Vec<usize>, Vec<usize>) {
+        let mut white = Vec::new();
+        let mut black = Vec::new();
+        let mut neutral = Vec::new();
+        for &position in bracket {
+            match preferred_color(ranked[position].swiss.color_preference) {
+                Some(Color::White) => white.push(position),
+                Some(Color::Black) => black.push(position),
+                None => neutral.push(position),
+            }
+        }
+
+        // The higher rated neutral players complete the white seekers
+        neutral.sort_by(|&a, &b| ranked[b].swiss.rating.cmp(&ranked[a].swiss.rating));
+        let to_white = (bracket.len().div_ceil(2))
+            .saturating_sub(white.len())
+            .min(neutral.len());
+        black.extend(neutral.split_off(to_white));
+        white.extend(neutral);
+
+        let movable = |position: usize| {
+            !matches!(
+                ranked[position].swiss.color_preference,
+                ColorPreference::Absolute(_)
+            )
+        };
+        let move_order = |a: &usize, b: &usize| {
+            preference_strength(ranked[*a].swiss.color_preference)
+                .cmp(&preference_strength(ranked[*b].swiss.color_preference))
+                .then_with(|| ranked[*a].swiss.rating.cmp(&ranked[*b].swiss.rating))
+        };
+        while white.len() > black.len() + 1 {
+            let Some(position) = white
+                .iter()
+                .copied()
+                .filter(|&p| movable(p))
+                .min_by(move_order)
+            else {
+                break;
+            };
+            white.retain(|&p| p != position);
+            black.push(position);
+        }
+        while black.len() > white.len() + 1 {
+            let Some(position) = black
+                .iter()
+                .copied()
+                .filter(|&p| movable(p))
+                .min_by(move_order)
+            else {
+                break;
+            };
+            black.retain(|&p| p != position);
+            white.push(position);
+        }
+
+        (white, black)
+    }
+}
+
+/// State of the search pairing the white seekers of a bracket with its black seekers
+struct SubgroupSearch<'a> {
+    ranked: &'a [DubovPlayer],
+    white: &'a [usize],
+    black: &'a [usize],
+    used: Vec<bool>,
+    result: BracketPairing,
+    budget: usize,
+}
+
+impl SubgroupSearch<'_> {
+    /// Backtracking over the white seekers in order; each one meets a black
+    /// seeker in transposition order or, while skips remain, stays unpaired
+    fn search(&mut self, next: usize, skips: usize) -> bool {
+        let Some(&white_seeker) = self.white.get(next) else {
+            return true;
+        };
+        if self.budget == 0 {
+            return false;
+        }
+        self.budget -= 1;
+
+        for candidate in transposition_order(self.ranked, self.black, &self.used) {
+            let black_seeker = self.black[candidate];
+            if !compatible(
+                &self.ranked[white_seeker].swiss,
+                &self.ranked[black_seeker].swiss,
+            ) {
+                continue;
+            }
+            self.used[candidate] = true;
+            self.result.pairs.push((white_seeker, black_seeker));
+            if self.search(next + 1, skips) {
+                return true;
+            }
+            self.result.pairs.pop();
+            self.used[candidate] = false;
+        }
+
+        if skips > 0 {
+            self.result.unpaired.push(white_seeker);
+            if self.search(next + 1, skips - 1) {
+                return true;
+            }
+            self.result.unpaired.pop();
+        }
+
+        false
+    }
+}
+
+/// C.1 (no rematches) and the absolute colour preferences of the white seeker
+/// (who gets white) and the black seeker (who gets black)
+fn compatible(white: &SwissPlayer, black: &SwissPlayer) -> bool {
+    if white.opponents.contains(&black.player.id) || black.opponents.contains(&white.player.id) {
+        return false;
+    }
+
+    !matches!(
+        white.color_preference,
+        ColorPreference::Absolute(Color::Black)
+    ) && !matches!(
+        black.color_preference,
+        ColorPreference::Absolute(Color::White)
+    )
+}
+
+/// Dubov transposition order: the unused black seekers, starting with the
+/// natural opponent (the first unused one) and continuing with the ones closest
+/// to its rating, so a transposition changes the strength of the opposition as
+/// little as possible
+fn transposition_order(ranked: &[DubovPlayer], black: &[usize], used: &[bool]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..black.len()).filter(|&index| !used[index]).collect();
+    let Some(&natural) = order.first() else {
+        return order;
+    };
+    let natural_rating = ranked[black[natural]].swiss.rating;
+    order.sort_by_key(|&index| {
+        (
+            (ranked[black[index]].swiss.rating - natural_rating).abs(),
+            index,
+        )
+    });
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::service::pairing_test_support::*;
+
+    #[test]
+    fn test_empty_field() {
+        let engine = DubovEngine::new();
+        let result = engine
+            .generate_pairings(vec![], vec![], vec![], 1, &HashMap::new())
+            .unwrap();
+
+        assert!(result.pairings.is_empty());
+        assert!(result.byes.is_empty());
+    }
+
+    #[test]
+    fn test_first_round_top_half_meets_bottom_half() {
+        let engine = DubovEngine::new();
+        let players = create_field(8);
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.0))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 1, &HashMap::new())
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 5), (2, 6), (3, 7), (4, 8)]);
+        assert_eq!(result.float_count, 0);
+
+        // Colours alternate by board in the first round
+        assert_eq!(result.pairings[0].white_player.id, 1);
+        assert_eq!(result.pairings[1].white_player.id, 6);
+    }
+
+    #[test]
+    fn test_lowest_aro_meets_highest_rated_black_seeker() {
+        let engine = DubovEngine::new();
+        let players = create_field(4);
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 1.0))
+            .collect();
+        // Player 2 faced weaker opposition so far and meets the strongest black seeker
+        let aro: HashMap<i32, f64> = [(1, 2000.0), (2, 1500.0), (3, 1800.0), (4, 1800.0)]
+            .into_iter()
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 2, &aro)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 4), (2, 3)]);
+    }
+
+    #[test]
+    fn test_odd_score_group_takes_highest_rated_upfloater() {
+        let engine = DubovEngine::new();
+        let players = create_field(6);
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), if p.id <= 3 { 1.0 } else { 0.0 }))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 2, &HashMap::new())
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 3), (2, 4), (5, 6)]);
+        assert_eq!(result.float_count, 1);
+        assert!(result.validation_errors.is_empty());
+    }
+
+    #[test]
+    fn test_transposition_avoids_rematch() {
+        let engine = DubovEngine::new();
+        let players = create_field(4);
+        let history = vec![
+            create_game_result(1, &players[0], &players[2], 1, "1/2-1/2"),
+            create_game_result(2, &players[1], &players[3], 1, "1/2-1/2"),
+        ];
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.5))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, history, 2, &HashMap::new())
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 4), (2, 3)]);
+        assert!(result.validation_errors.is_empty());
+    }
+
+    #[test]
+    fn test_previous_bye_receiver_is_skipped() {
+        let engine = DubovEngine::new();
+        let players = create_field(3);
+        let bye = create_test_player(-1, "BYE", None);
+        let history = vec![
+            create_game_result(1, &players[0], &players[1], 1, "1-0"),
+            create_game_result(2, &players[2], &bye, 1, "1-0"),
+        ];
+        let results = vec![
+            create_test_result(players[0].clone(), 1.0),
+            create_test_result(players[1].clone(), 0.0),
+            create_test_result(players[2].clone(), 0.0),
+        ];
+
+        // Player 3 is ranked last but already had the bye
+        let result = engine
+            .generate_pairings(players, results, history, 2, &HashMap::new())
+            .unwrap();
+
+        assert_eq!(result.byes.len(), 1);
+        assert_eq!(result.byes[0].player.id, 2);
+        assert_eq!(paired_ids(&result.pairings[0]), (1, 3));
+    }
+}
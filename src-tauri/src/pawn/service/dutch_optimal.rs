@@ -0,0 +1,694 @@
+#![allow(dead_code)]
+
+use crate::pawn::{
+    common::error::PawnError,
+    domain::model::{GameResult, Pairing, Player, PlayerResult},
+    service::{
+        swiss_pairing::{
+            Color, ColorPreference, FloatDirection, PairingResult, SwissPairingEngine, SwissPlayer,
+        },
+        weighted_matching::{WeightedEdge, max_weight_matching},
+    },
+};
+use std::collections::{BTreeMap, HashSet};
+
+/// Bits available for every quality criterion on a single edge
+const CRITERION_BITS: u32 = 12;
+
+/// Largest penalty a single edge can carry for one quality criterion
+const CRITERION_CAP: i128 = (1 << CRITERION_BITS) - 1;
+
+/// Total bits the encoded edge weights may use (duals need the remaining headroom)
+const MAX_WEIGHT_BITS: u32 = 120;
+
+/// FIDE Dutch System (C.04.3) solved as a maximum weighted matching.
+///
+/// Instead of pairing bracket by bracket, every admissible pair in the field
+/// becomes an edge whose weight encodes the pairing criteria in lexicographic
+/// order. The heaviest matching is therefore the pairing that best satisfies
+/// the criteria across the whole field at once.
+pub struct DutchOptimalEngine {
+    swiss_engine: SwissPairingEngine,
+}
+
+/// Ranking information of a player in the current round
+#[derive(Debug, Clone)]
+struct RankedPlayer {
+    swiss: SwissPlayer,
+    rank: usize,
+    score_group: usize,
+    group_position: usize,
+    group_size: usize,
+    had_bye: bool,
+}
+
+/// Edge weight layout, from most to least significant criterion
+#[derive(Debug, Clone, Copy)]
+struct WeightLayout {
+    count_bits: u32,
+    criterion_bits: u32,
+}
+
+impl WeightLayout {
+    fn new(max_pairs: usize) -> Self {
+        let count_bits = usize::BITS - max_pairs.leading_zeros() + 1;
+        Self {
+            count_bits,
+            criterion_bits: count_bits + CRITERION_BITS,
+        }
+    }
+
+    fn total_bits(&self) -> u32 {
+        2 * self.count_bits + 4 * self.criterion_bits
+    }
+
+    /// Combine the criteria of one edge into a single weight
+    fn encode(&self, absolute_ok: bool, penalties: EdgePenalties) -> i128 {
+        let quality = [
+            penalties.score_difference,
+            penalties.colour,
+            penalties.floats,
+            penalties.ranking,
+        ];
+
+        // Every edge counts towards the number of pairs (C.5), then towards
+        // the number of pairs respecting the absolute criteria (C.1 - C.3)
+        let mut weight: i128 = 1;
+        weight = (weight << self.count_bits) | i128::from(absolute_ok);
+        for penalty in quality {
+            weight = (weight << self.criterion_bits) | (CRITERION_CAP - penalty.min(CRITERION_CAP));
+        }
+        weight
+    }
+}
+
+/// Quality criteria penalties of a single edge (lower is better)
+#[derive(Debug, Clone, Copy)]
+struct EdgePenalties {
+    score_difference: i128,
+    colour: i128,
+    floats: i128,
+    ranking: i128,
+}
+
+impl Default for DutchOptimalEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DutchOptimalEngine {
+    pub fn new() -> Self {
+        Self {
+            swiss_engine: SwissPairingEngine::new(),
+        }
+    }
+
+    /// Engine that keeps the given player pairs apart
+    pub fn with_forbidden_pairs(mut self, forbidden_pairs: HashSet<(i32, i32)>) -> Self {
+        self.swiss_engine = self.swiss_engine.with_forbidden_pairs(forbidden_pairs);
+        self
+    }
+
+    /// Generate Dutch System pairings for the whole field using a maximum weighted matching
+    pub fn generate_pairings(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+    ) -> Result<PairingResult, PawnError> {
+        tracing::info!(
+            "Starting optimal Dutch System pairing for {} players, round {}",
+            players.len(),
+            round_number
+        );
+
+        if players.is_empty() {
+            return Ok(PairingResult {
+                pairings: vec![],
+                byes: vec![],
+                float_count: 0,
+                validation_errors: vec![],
+            });
+        }
+
+        let swiss_players =
+            self.swiss_engine
+                .build_swiss_players(players, player_results, game_history)?;
+        let ranked = self.rank_players(swiss_players);
+
+        let needs_bye = ranked.len() % 2 == 1;
+        let vertex_count = ranked.len() + usize::from(needs_bye);
+        let bye_vertex = ranked.len();
+
+        let layout = WeightLayout::new(vertex_count / 2);
+        if layout.total_bits() > MAX_WEIGHT_BITS {
+            return Err(PawnError::InvalidInput(format!(
+                "Too many players ({}) for optimal Dutch pairing",
+                ranked.len()
+            )));
+        }
+
+        let mut edges = Vec::new();
+        for i in 0..ranked.len() {
+            for j in (i + 1)..ranked.len() {
+                let absolute_ok = self.satisfies_absolute_criteria(&ranked[i], &ranked[j]);
+                let penalties = self.edge_penalties(&ranked[i], &ranked[j]);
+                edges.push(WeightedEdge::new(
+                    i,
+                    j,
+                    layout.encode(absolute_ok, penalties),
+                ));
+            }
+
+            if needs_bye {
+                let penalties = self.bye_penalties(&ranked[i], ranked.len());
+                edges.push(WeightedEdge::new(
+                    i,
+                    bye_vertex,
+                    layout.encode(!ranked[i].had_bye, penalties),
+                ));
+            }
+        }
+
+        tracing::debug!(
+            "Solving maximum weighted matching with {} vertices and {} edges",
+            vertex_count,
+            edges.len()
+        );
+
+        let mates = max_weight_matching(vertex_count, &edges, true);
+
+        let mut validation_errors = Vec::new();
+        let mut pairs = Vec::new();
+        let mut byes = Vec::new();
+
+        for (i, mate) in mates.iter().enumerate().take(ranked.len()) {
+            match mate {
+                Some(j) if *j == bye_vertex => {
+                    if ranked[i].had_bye {
+                        validation_errors.push(format!(
+                            "{} receives a second pairing-allocated bye",
+                            ranked[i].swiss.player.name
+                        ));
+                    }
+                    byes.push(ranked[i].swiss.clone());
+                }
+                Some(j) if *j > i => {
+                    if !self.satisfies_absolute_criteria(&ranked[i], &ranked[*j]) {
+                        validation_errors.push(format!(
+                            "Absolute criteria relaxed to pair {} vs {}",
+                            ranked[i].swiss.player.name, ranked[*j].swiss.player.name
+                        ));
+                    }
+                    pairs.push((i, *j));
+                }
+                Some(_) => {}
+                None => {
+                    validation_errors.push(format!(
+                        "{} could not be paired",
+                        ranked[i].swiss.player.name
+                    ));
+                    byes.push(ranked[i].swiss.clone());
+                }
+            }
+        }
+
+        // Boards are ordered by the higher score, then the lower score, then ranking
+        pairs.sort_by(|a, b| {
+            let key = |&(i, j): &(usize, usize)| {
+                let high = ranked[i].swiss.points.max(ranked[j].swiss.points);
+                let low = ranked[i].swiss.points.min(ranked[j].swiss.points);
+                (high, low, std::cmp::Reverse(i.min(j)))
+            };
+            key(b)
+                .partial_cmp(&key(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut float_count = 0;
+        let pairings: Vec<Pairing> = pairs
+            .iter()
+            .enumerate()
+            .map(|(index, &(i, j))| {
+                if ranked[i].score_group != ranked[j].score_group {
+                    float_count += 1;
+                }
+                let board_number = index as i32 + 1;
+                let (white, black) = self.allocate_colors(&ranked[i], &ranked[j], board_number);
+                Pairing {
+                    white_player: white.swiss.player.clone(),
+                    black_player: Some(black.swiss.player.clone()),
+                    board_number,
+                    explanation: None,
+                }
+            })
+            .collect();
+
+        tracing::info!(
+            "Optimal Dutch System produced {} pairings, {} floats, {} byes",
+            pairings.len(),
+            float_count,
+            byes.len()
+        );
+
+        Ok(PairingResult {
+            pairings,
+            byes,
+            float_count,
+            validation_errors,
+        })
+    }
+
+    /// Order players by score and rating and attach their score group position
+    fn rank_players(&self, mut swiss_players: Vec<SwissPlayer>) -> Vec<RankedPlayer> {
+        swiss_players.sort_by(|a, b| {
+            b.points
+                .partial_cmp(&a.points)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| b.rating.cmp(&a.rating))
+                .then_with(|| a.player.id.cmp(&b.player.id))
+        });
+
+        // Score groups keyed by half points, highest first
+        let mut group_sizes: BTreeMap<std::cmp::Reverse<i64>, usize> = BTreeMap::new();
+        for player in &swiss_players {
+            *group_sizes
+                .entry(std::cmp::Reverse(half_points(player.points)))
+                .or_default() += 1;
+        }
+        let group_index: BTreeMap<i64, (usize, usize)> = group_sizes
+            .iter()
+            .enumerate()
+            .map(|(index, (score, size))| (score.0, (index, *size)))
+            .collect();
+
+        let mut positions: BTreeMap<i64, usize> = BTreeMap::new();
+        swiss_players
+            .into_iter()
+            .enumerate()
+            .map(|(rank, swiss)| {
+                let score = half_points(swiss.points);
+                let (score_group, group_size) = group_index[&score];
+                let position = positions.entry(score).or_default();
+                let group_position = *position;
+                *position += 1;
+                let had_bye = !swiss.is_bye_eligible;
+
+                RankedPlayer {
+                    swiss,
+                    rank,
+                    score_group,
+                    group_position,
+                    group_size,
+                    had_bye,
+                }
+            })
+            .collect()
+    }
+
+    /// C.1 (no rematches) and C.3 (no conflicting absolute colour preferences)
+    fn satisfies_absolute_criteria(&self, a: &RankedPlayer, b: &RankedPlayer) -> bool {
+        if a.swiss.opponents.contains(&b.swiss.player.id)
+            || b.swiss.opponents.contains(&a.swiss.player.id)
+        {
+            return false;
+        }
+
+        !matches!(
+            (a.swiss.color_preference, b.swiss.color_preference),
+            (ColorPreference::Absolute(ca), ColorPreference::Absolute(cb)) if ca == cb
+        )
+    }
+
+    /// Penalties for the quality criteria of pairing two players
+    fn edge_penalties(&self, a: &RankedPlayer, b: &RankedPlayer) -> EdgePenalties {
+        let (higher, lower) = if a.rank < b.rank { (a, b) } else { (b, a) };
+
+        // C.6/C.7: minimise downfloaters and their score differences
+        let difference = half_points(higher.swiss.points) - half_points(lower.swiss.points);
+        let score_difference = i128::from(difference * difference);
+
+        // C.10 - C.13: colour preferences
+        let colour = self
+            .colour_violation(&higher.swiss, &lower.swiss)
+            .min(self.colour_violation(&lower.swiss, &higher.swiss));
+
+        // C.14 - C.17: repeated floats
+        let floats = if difference > 0 {
+            float_repeat_penalty(&higher.swiss, FloatDirection::Down)
+                + float_repeat_penalty(&lower.swiss, FloatDirection::Up)
+        } else {
+            0
+        };
+
+        // Within a bracket, S1 should meet S2 in the same position; a downfloater
+        // should meet the highest ranked player of the lower bracket
+        let ranking = if higher.score_group == lower.score_group {
+            let offset = (lower.group_position - higher.group_position) as i128;
+            (offset - (higher.group_size / 2) as i128).abs()
+        } else {
+            (higher.group_size - 1 - higher.group_position + lower.group_position) as i128
+        };
+
+        EdgePenalties {
+            score_difference,
+            colour,
+            floats,
+            ranking,
+        }
+    }
+
+    /// Penalties for giving the pairing-allocated bye to a player
+    fn bye_penalties(&self, player: &RankedPlayer, field_size: usize) -> EdgePenalties {
+        // The bye goes to the lowest ranked player of the lowest score group
+        let score = half_points(player.swiss.points) + 1;
+        EdgePenalties {
+            score_difference: i128::from(score * score),
+            colour: 0,
+            floats: float_repeat_penalty(&player.swiss, FloatDirection::Down),
+            ranking: (field_size - 1 - player.rank) as i128,
+        }
+    }
+
+    /// Colour preference violations when `white` gets white and `black` gets black
+    fn colour_violation(&self, white: &SwissPlayer, black: &SwissPlayer) -> i128 {
+        preference_penalty(white.color_preference, Color::White)
+            + preference_penalty(black.color_preference, Color::Black)
+    }
+
+    /// Allocate colours following the Dutch System colour allocation rules
+    fn allocate_colors<'a>(
+        &self,
+        a: &'a RankedPlayer,
+        b: &'a RankedPlayer,
+        board_number: i32,
+    ) -> (&'a RankedPlayer, &'a RankedPlayer) {
+        let (higher, lower) = if a.rank < b.rank { (a, b) } else { (b, a) };
+
+        match higher_ranked_color(&higher.swiss, &lower.swiss, board_number) {
+            Color::White => (higher, lower),
+            Color::Black => (lower, higher),
+        }
+    }
+}
+
+/// Colour of the higher ranked player under the Dutch System colour allocation rules
+pub(crate) fn higher_ranked_color(
+    higher: &SwissPlayer,
+    lower: &SwissPlayer,
+    board_number: i32,
+) -> Color {
+    let higher_pref = preferred_color(higher.color_preference);
+    let lower_pref = preferred_color(lower.color_preference);
+
+    match (higher_pref, lower_pref) {
+        // Grant both colour preferences
+        (Some(hc), Some(lc)) if hc != lc => hc,
+        (Some(hc), None) => hc,
+        (None, Some(lc)) => opposite(lc),
+        // Grant the stronger preference
+        (Some(hc), Some(_))
+            if preference_strength(higher.color_preference)
+                != preference_strength(lower.color_preference) =>
+        {
+            if preference_strength(higher.color_preference)
+                > preference_strength(lower.color_preference)
+            {
+                hc
+            } else {
+                opposite(hc)
+            }
+        }
+        // Alternate to the most recent round in which the colours differed,
+        // otherwise grant the preference of the higher ranked player
+        (Some(hc), Some(_)) => last_differing_color(higher, lower)
+            .map(opposite)
+            .unwrap_or(hc),
+        // No preferences: alternate by board, starting with white
+        (None, None) => {
+            last_differing_color(higher, lower)
+                .map(opposite)
+                .unwrap_or(if board_number % 2 == 1 {
+                    Color::White
+                } else {
+                    Color::Black
+                })
+        }
+    }
+}
+
+/// Score expressed in half points so it can be compared exactly
+pub(crate) fn half_points(points: f64) -> i64 {
+    (points * 2.0).round() as i64
+}
+
+fn opposite(color: Color) -> Color {
+    match color {
+        Color::White => Color::Black,
+        Color::Black => Color::White,
+    }
+}
+
+pub(crate) fn preferred_color(preference: ColorPreference) -> Option<Color> {
+    match preference {
+        ColorPreference::Absolute(color)
+        | ColorPreference::Strong(color)
+        | ColorPreference::Mild(color) => Some(color),
+        ColorPreference::None => None,
+    }
+}
+
+pub(crate) fn preference_strength(preference: ColorPreference) -> u8 {
+    match preference {
+        ColorPreference::Absolute(_) => 3,
+        ColorPreference::Strong(_) => 2,
+        ColorPreference::Mild(_) => 1,
+        ColorPreference::None => 0,
+    }
+}
+
+/// Penalty for giving `color` to a player with the given preference
+fn preference_penalty(preference: ColorPreference, color: Color) -> i128 {
+    match preference {
+        ColorPreference::Absolute(wanted) if wanted != color => 16,
+        ColorPreference::Strong(wanted) if wanted != color => 3,
+        ColorPreference::Mild(wanted) if wanted != color => 2,
+        _ => 0,
+    }
+}
+
+/// Penalty for floating a player in the same direction as in the previous rounds
+fn float_repeat_penalty(player: &SwissPlayer, direction: FloatDirection) -> i128 {
+    let mut recent = player.float_history.iter().rev();
+    let last_round = recent
+        .next()
+        .is_some_and(|record| record.direction == Some(direction));
+    let two_rounds_ago = recent
+        .next()
+        .is_some_and(|record| record.direction == Some(direction));
+
+    i128::from(last_round) * 4 + i128::from(two_rounds_ago)
+}
+
+/// Colour the higher ranked player had in the most recent round where the two
+/// players had different colours
+fn last_differing_color(higher: &SwissPlayer, lower: &SwissPlayer) -> Option<Color> {
+    higher
+        .color_history
+        .iter()
+        .rev()
+        .zip(lower.color_history.iter().rev())
+        .find(|(h, l)| h != l)
+        .map(|(h, _)| *h)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::service::pairing_test_support::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_empty_field() {
+        let engine = DutchOptimalEngine::new();
+        let result = engine.generate_pairings(vec![], vec![], vec![], 1).unwrap();
+
+        assert!(result.pairings.is_empty());
+        assert!(result.byes.is_empty());
+    }
+
+    #[test]
+    fn test_first_round_top_half_meets_bottom_half() {
+        let engine = DutchOptimalEngine::new();
+        let players: Vec<Player> = (1..=8)
+            .map(|id| create_test_player(id, &format!("Player {id}"), Some(2500 - id * 50)))
+            .collect();
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.0))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 1)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 5), (2, 6), (3, 7), (4, 8)]);
+        assert_eq!(result.float_count, 0);
+        assert!(result.validation_errors.is_empty());
+
+        // Colours alternate by board in the first round
+        assert_eq!(result.pairings[0].white_player.id, 1);
+        assert_eq!(result.pairings[1].white_player.id, 6);
+    }
+
+    #[test]
+    fn test_bye_goes_to_lowest_ranked_player() {
+        let engine = DutchOptimalEngine::new();
+        let players: Vec<Player> = (1..=5)
+            .map(|id| create_test_player(id, &format!("Player {id}"), Some(2000 - id * 10)))
+            .collect();
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.0))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 1)
+            .unwrap();
+
+        assert_eq!(result.pairings.len(), 2);
+        assert_eq!(result.byes.len(), 1);
+        assert_eq!(result.byes[0].player.id, 5);
+    }
+
+    #[test]
+    fn test_previous_bye_receiver_is_skipped() {
+        let engine = DutchOptimalEngine::new();
+        let players: Vec<Player> = (1..=3)
+            .map(|id| create_test_player(id, &format!("Player {id}"), Some(2000 - id * 10)))
+            .collect();
+        let bye = create_test_player(-1, "BYE", None);
+        let history = vec![
+            create_game_result(1, &players[0], &players[1], 1, "1-0"),
+            create_game_result(2, &players[2], &bye, 1, "1-0"),
+        ];
+        let results = vec![
+            create_test_result(players[0].clone(), 1.0),
+            create_test_result(players[1].clone(), 0.0),
+            create_test_result(players[2].clone(), 1.0),
+        ];
+
+        let result = engine
+            .generate_pairings(players, results, history, 2)
+            .unwrap();
+
+        assert_eq!(result.byes.len(), 1);
+        assert_eq!(result.byes[0].player.id, 2);
+        assert_eq!(paired_ids(&result.pairings[0]), (1, 3));
+    }
+
+    #[test]
+    fn test_avoids_rematches_across_score_groups() {
+        let engine = DutchOptimalEngine::new();
+        let players: Vec<Player> = (1..=4)
+            .map(|id| create_test_player(id, &format!("Player {id}"), Some(2000 - id * 10)))
+            .collect();
+        let history = vec![
+            create_game_result(1, &players[0], &players[2], 1, "1-0"),
+            create_game_result(2, &players[3], &players[1], 1, "0-1"),
+            create_game_result(3, &players[1], &players[0], 2, "1/2-1/2"),
+            create_game_result(4, &players[2], &players[3], 2, "1/2-1/2"),
+        ];
+        let results = vec![
+            create_test_result(players[0].clone(), 1.5),
+            create_test_result(players[1].clone(), 1.5),
+            create_test_result(players[2].clone(), 0.5),
+            create_test_result(players[3].clone(), 0.5),
+        ];
+
+        let result = engine
+            .generate_pairings(players, results, history, 3)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 4), (2, 3)]);
+        assert_eq!(result.float_count, 2);
+        assert!(result.validation_errors.is_empty());
+    }
+
+    #[test]
+    fn test_colour_preferences_are_granted() {
+        let engine = DutchOptimalEngine::new();
+        let players: Vec<Player> = (1..=4)
+            .map(|id| create_test_player(id, &format!("Player {id}"), Some(2000 - id * 10)))
+            .collect();
+        let history = vec![
+            create_game_result(1, &players[0], &players[1], 1, "1/2-1/2"),
+            create_game_result(2, &players[2], &players[3], 1, "1/2-1/2"),
+            create_game_result(3, &players[0], &players[2], 2, "1/2-1/2"),
+            create_game_result(4, &players[3], &players[1], 2, "1/2-1/2"),
+        ];
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 1.0))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, history, 3)
+            .unwrap();
+
+        // Player 1 had white twice and player 2 black twice
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 4), (2, 3)]);
+        assert_eq!(result.pairings[0].white_player.id, 4);
+        assert_eq!(result.pairings[1].white_player.id, 2);
+    }
+
+    #[test]
+    fn test_relaxes_absolute_criteria_when_unavoidable() {
+        let engine = DutchOptimalEngine::new();
+        let players: Vec<Player> = (1..=2)
+            .map(|id| create_test_player(id, &format!("Player {id}"), Some(2000 - id * 10)))
+            .collect();
+        let history = vec![create_game_result(1, &players[0], &players[1], 1, "1-0")];
+        let results = vec![
+            create_test_result(players[0].clone(), 1.0),
+            create_test_result(players[1].clone(), 0.0),
+        ];
+
+        let result = engine
+            .generate_pairings(players, results, history, 2)
+            .unwrap();
+
+        assert_eq!(result.pairings.len(), 1);
+        assert_eq!(result.validation_errors.len(), 1);
+    }
+
+    #[test]
+    fn test_large_field_is_fully_paired() {
+        let engine = DutchOptimalEngine::new();
+        let players: Vec<Player> = (1..=101)
+            .map(|id| create_test_player(id, &format!("Player {id}"), Some(1500 + id * 7 % 800)))
+            .collect();
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), (p.id % 3) as f64 * 0.5))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 2)
+            .unwrap();
+
+        assert_eq!(result.pairings.len(), 50);
+        assert_eq!(result.byes.len(), 1);
+
+        let mut seen = HashSet::new();
+        for pairing in &result.pairings {
+            assert!(seen.insert(pairing.white_player.id));
+            assert!(seen.insert(pairing.black_player.as_ref().unwrap().id));
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::pawn::{
+    common::error::PawnError,
+    domain::{
+        dto::{CreateEvent, CreateEventSection},
+        model::EventSection,
+    },
+};
+
+#[allow(dead_code)]
+pub struct EventService;
+
+#[allow(dead_code)]
+impl EventService {
+    /// Check an event before it is created
+    pub fn validate_event(data: &CreateEvent) -> Result<(), PawnError> {
+        if data.name.trim().is_empty() {
+            return Err(PawnError::InvalidInput("An event needs a name".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Check a section before it is added to an event
+    pub fn validate_section(
+        data: &CreateEventSection,
+        sections: &[EventSection],
+    ) -> Result<(), PawnError> {
+        if data.name.trim().is_empty() {
+            return Err(PawnError::InvalidInput(
+                "A section needs a name".to_string(),
+            ));
+        }
+        if data.min_rating.is_some_and(|rating| rating < 0)
+            || data.max_rating.is_some_and(|rating| rating < 0)
+        {
+            return Err(PawnError::InvalidInput(
+                "Rating limits cannot be negative".to_string(),
+            ));
+        }
+        if let (Some(min_rating), Some(max_rating)) = (data.min_rating, data.max_rating)
+            && min_rating > max_rating
+        {
+            return Err(PawnError::InvalidInput(format!(
+                "{} cannot require a rating of {min_rating} and at most {max_rating}",
+                data.name
+            )));
+        }
+        if let Some(section) = sections
+            .iter()
+            .find(|s| s.name == data.name || s.tournament_id == data.tournament_id)
+        {
+            return Err(PawnError::InvalidInput(format!(
+                "{} is already a section of the event",
+                section.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check that a player with this rating may play in the section
+    pub fn check_eligibility(section: &EventSection, rating: Option<i32>) -> Result<(), PawnError> {
+        let Some(rating) = rating else {
+            return if section.allow_unrated {
+                Ok(())
+            } else {
+                Err(PawnError::ValidationError(format!(
+                    "Unrated players cannot play in {}",
+                    section.name
+                )))
+            };
+        };
+        if let Some(min_rating) = section.min_rating.filter(|&min| rating < min) {
+            return Err(PawnError::ValidationError(format!(
+                "{} requires a rating of at least {min_rating}",
+                section.name
+            )));
+        }
+        if let Some(max_rating) = section.max_rating.filter(|&max| rating > max) {
+            return Err(PawnError::ValidationError(format!(
+                "{} is limited to ratings up to {max_rating}",
+                section.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Sections a player with this rating may enter
+    pub fn eligible_sections(sections: &[EventSection], rating: Option<i32>) -> Vec<EventSection> {
+        sections
+            .iter()
+            .filter(|section| Self::check_eligibility(section, rating).is_ok())
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_section(
+        id: i32,
+        name: &str,
+        min_rating: Option<i32>,
+        max_rating: Option<i32>,
+    ) -> EventSection {
+        EventSection {
+            id,
+            event_id: 1,
+            tournament_id: id + 10,
+            name: name.to_string(),
+            min_rating,
+            max_rating,
+            allow_unrated: true,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    fn weekend_open() -> Vec<EventSection> {
+        vec![
+            create_section(1, "Open", None, None),
+            create_section(2, "U2000", None, Some(1999)),
+            create_section(3, "U1600", None, Some(1599)),
+        ]
+    }
+
+    fn section_names(sections: &[EventSection]) -> Vec<&str> {
+        sections.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_eligible_sections_by_rating() {
+        let sections = weekend_open();
+        assert_eq!(
+            section_names(&EventService::eligible_sections(&sections, Some(2100))),
+            vec!["Open"]
+        );
+        assert_eq!(
+            section_names(&EventService::eligible_sections(&sections, Some(1999))),
+            vec!["Open", "U2000"]
+        );
+        assert_eq!(
+            section_names(&EventService::eligible_sections(&sections, Some(1450))),
+            vec!["Open", "U2000", "U1600"]
+        );
+    }
+
+    #[test]
+    fn test_unrated_players() {
+        let mut sections = weekend_open();
+        sections[2].allow_unrated = false;
+        assert_eq!(
+            section_names(&EventService::eligible_sections(&sections, None)),
+            vec!["Open", "U2000"]
+        );
+    }
+
+    #[test]
+    fn test_minimum_rating() {
+        let section = create_section(4, "Masters", Some(2200), None);
+        assert!(EventService::check_eligibility(&section, Some(2200)).is_ok());
+        assert!(EventService::check_eligibility(&section, Some(2150)).is_err());
+    }
+
+    #[test]
+    fn test_validate_section() {
+        let sections = weekend_open();
+        let mut data = CreateEventSection {
+            event_id: 1,
+            tournament_id: 20,
+            name: "U1200".to_string(),
+            min_rating: None,
+            max_rating: Some(1199),
+            allow_unrated: true,
+        };
+        assert!(EventService::validate_section(&data, &sections).is_ok());
+
+        data.min_rating = Some(1300);
+        assert!(EventService::validate_section(&data, &sections).is_err());
+
+        data.min_rating = None;
+        data.name = "U2000".to_string();
+        assert!(EventService::validate_section(&data, &sections).is_err());
+
+        data.name = "U1200".to_string();
+        data.tournament_id = 11;
+        assert!(EventService::validate_section(&data, &sections).is_err());
+    }
+}
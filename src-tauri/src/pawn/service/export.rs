@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -12,13 +13,22 @@ use crate::pawn::{
     common::error::PawnError,
     db::Db,
     domain::{
-        model::{Game, Player, Tournament},
+        dto::{ArenaStanding, PlayerRatingReport, RatingReport},
+        model::{
+            ArenaGame, BoardPairingExplanation, Event, EventSection, Game, GameResult,
+            PairingExplanation, Player, Tournament,
+        },
         tiebreak::{
-            CrossTable, ExportFormat, ExportRequest, ExportResult, ExportType,
+            CrossTable, EventExportRequest, ExportFormat, ExportRequest, ExportResult, ExportType,
             StandingsCalculationResult, TournamentTiebreakConfig,
         },
     },
-    service::tiebreak::TiebreakCalculator,
+    service::{
+        arena::ArenaService,
+        rating_report::RatingReportService,
+        tiebreak::TiebreakCalculator,
+        trf::{TrfReport, write_trf},
+    },
 };
 
 #[allow(dead_code)]
@@ -80,8 +90,81 @@ impl<D: Db> ExportService<D> {
             ExportFormat::Txt => self.export_to_txt(&export_data, &file_path).await,
             ExportFormat::Pdf => self.export_to_pdf(&export_data, &file_path, &request).await,
             ExportFormat::Xlsx => self.export_to_xlsx(&export_data, &file_path).await,
+            ExportFormat::Trf => self.export_to_trf(&export_data, &file_path).await,
+        };
+
+        Ok(self.export_result(result, filename, &file_path, start_time))
+    }
+
+    /// Export the standings of every section of an event into one file
+    #[instrument(skip(self))]
+    pub async fn export_event_data(
+        &self,
+        request: EventExportRequest,
+    ) -> Result<ExportResult, PawnError> {
+        let start_time = Instant::now();
+
+        let write_event: fn(&EventExportData) -> Result<String, PawnError> = match request.format {
+            ExportFormat::Csv => event_to_csv,
+            ExportFormat::Json => event_to_json,
+            ExportFormat::Html => event_to_html,
+            ExportFormat::Txt => event_to_txt,
+            ExportFormat::Pdf | ExportFormat::Xlsx | ExportFormat::Trf => {
+                return Err(PawnError::InvalidInput(format!(
+                    "Event exports are not available as {:?}",
+                    request.format
+                )));
+            }
+        };
+
+        let event = self.db.get_event(request.event_id).await?;
+        info!("Starting export for event {}", event.name);
+
+        let extension = self.get_file_extension(&request.format);
+        let filename = match &request.custom_filename {
+            Some(custom_name) => format!("{custom_name}.{extension}"),
+            None => format!(
+                "{}_event_{}_{}.{}",
+                event.name.replace(" ", "_").replace("/", "-"),
+                event.id,
+                chrono::Utc::now().format("%Y%m%d_%H%M%S"),
+                extension
+            ),
         };
+        let file_path = self.export_dir.join(&filename);
+
+        let mut sections = Vec::new();
+        for section in self.db.get_event_sections(event.id).await? {
+            let tournament = self.db.get_tournament(section.tournament_id).await?;
+            let config = self.get_tournament_config(section.tournament_id).await?;
+            let standings = self
+                .tiebreak_calculator
+                .calculate_standings(section.tournament_id, &config)
+                .await?;
+            sections.push(SectionExportData {
+                section,
+                tournament,
+                standings,
+            });
+        }
+
+        let result = write_event(&EventExportData { event, sections }).and_then(|output| {
+            let mut file = File::create(&file_path).map_err(PawnError::Io)?;
+            file.write_all(output.as_bytes()).map_err(PawnError::Io)?;
+            Ok(output.len() as u64)
+        });
+
+        Ok(self.export_result(result, filename, &file_path, start_time))
+    }
 
+    /// Describe the outcome of writing an export file
+    fn export_result(
+        &self,
+        result: Result<u64, PawnError>,
+        filename: String,
+        file_path: &Path,
+        start_time: Instant,
+    ) -> ExportResult {
         let export_time = start_time.elapsed();
 
         match result {
@@ -92,25 +175,25 @@ impl<D: Db> ExportService<D> {
                     filename,
                     export_time.as_millis()
                 );
-                Ok(ExportResult {
+                ExportResult {
                     success: true,
                     file_path: Some(file_path.to_string_lossy().to_string()),
                     file_name: filename,
                     file_size,
                     export_time_ms: export_time.as_millis() as u64,
                     error_message: None,
-                })
+                }
             }
             Err(e) => {
                 error!("Export failed: {}", e);
-                Ok(ExportResult {
+                ExportResult {
                     success: false,
                     file_path: None,
                     file_name: filename,
                     file_size: 0,
                     export_time_ms: export_time.as_millis() as u64,
                     error_message: Some(e.to_string()),
-                })
+                }
             }
         }
     }
@@ -127,11 +210,12 @@ impl<D: Db> ExportService<D> {
             .get_games_by_tournament(request.tournament_id)
             .await?;
 
-        // Get standings if needed
+        // Get standings if needed (the TRF report always carries the final ranks)
         let standings = if matches!(
             request.export_type,
             ExportType::Standings | ExportType::TournamentSummary | ExportType::Complete
-        ) {
+        ) || matches!(request.format, ExportFormat::Trf)
+        {
             let config = self.get_tournament_config(request.tournament_id).await?;
             Some(
                 self.tiebreak_calculator
@@ -157,15 +241,88 @@ impl<D: Db> ExportService<D> {
             None
         };
 
+        // Get pairings of every round if needed
+        let pairings = if matches!(
+            request.export_type,
+            ExportType::Pairings | ExportType::Complete
+        ) {
+            self.collect_round_pairings(request.tournament_id).await?
+        } else {
+            Vec::new()
+        };
+
+        // Arena standings replace the round-based ones of an arena tournament
+        let (arena_standings, arena_games) = if matches!(request.export_type, ExportType::Arena)
+            || (matches!(request.export_type, ExportType::Complete)
+                && ArenaService::is_arena(tournament.tournament_type.as_deref()))
+        {
+            let arena_games = self.db.get_arena_games(request.tournament_id).await?;
+            (
+                Some(ArenaService::calculate_standings(&players, &arena_games)),
+                arena_games,
+            )
+        } else {
+            (None, Vec::new())
+        };
+
+        // Get the rating calculation if needed
+        let rating_report = if matches!(
+            request.export_type,
+            ExportType::RatingReport | ExportType::Complete
+        ) {
+            Some(
+                RatingReportService::new(Arc::clone(&self.db))
+                    .calculate_rating_report(request.tournament_id)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
         Ok(ExportData {
             tournament,
             players,
             games,
             standings,
             cross_table,
+            pairings,
+            arena_standings,
+            arena_games,
+            rating_report,
         })
     }
 
+    /// Games of every round together with the stored pairing explanations
+    async fn collect_round_pairings(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Vec<RoundPairings>, PawnError> {
+        let mut rounds = self.db.get_rounds_by_tournament(tournament_id).await?;
+        rounds.sort_by_key(|round| round.round_number);
+
+        let mut pairings = Vec::new();
+        for round in rounds {
+            let games = self
+                .db
+                .get_games_by_round(tournament_id, round.round_number)
+                .await?;
+            let explanations = self
+                .db
+                .get_pairing_explanations(tournament_id, round.round_number)
+                .await?
+                .into_iter()
+                .map(|board| (board.game_id, board))
+                .collect();
+            pairings.push(RoundPairings {
+                round_number: round.round_number,
+                games,
+                explanations,
+            });
+        }
+
+        Ok(pairings)
+    }
+
     /// Generate filename based on request
     async fn generate_filename(&self, request: &ExportRequest) -> Result<String, PawnError> {
         if let Some(custom_name) = &request.custom_filename {
@@ -197,6 +354,7 @@ impl<D: Db> ExportService<D> {
             ExportFormat::Txt => "txt",
             ExportFormat::Pdf => "pdf",
             ExportFormat::Xlsx => "xlsx",
+            ExportFormat::Trf => "trf",
         }
     }
 
@@ -204,8 +362,30 @@ impl<D: Db> ExportService<D> {
     async fn export_to_csv(&self, data: &ExportData, file_path: &Path) -> Result<u64, PawnError> {
         let mut output = String::new();
 
-        match data.standings {
-            Some(ref standings) => {
+        match (&data.arena_standings, &data.standings, &data.rating_report) {
+            (Some(arena_standings), _, _) => {
+                output.push_str(
+                    "Rank,Player,Rating,Score,Games,Wins,Draws,Losses,Berserks,On Fire,Performance Rating,Sheet\n",
+                );
+                for standing in arena_standings {
+                    output.push_str(&format!(
+                        "{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                        standing.rank,
+                        standing.player.name,
+                        standing.player.rating.unwrap_or(0),
+                        standing.score,
+                        standing.games_played,
+                        standing.wins,
+                        standing.draws,
+                        standing.losses,
+                        standing.berserks,
+                        standing.on_fire,
+                        standing.performance_rating.unwrap_or(0),
+                        arena_sheet(standing)
+                    ));
+                }
+            }
+            (None, Some(standings), _) => {
                 // CSV header
                 output.push_str(
                     "Rank,Player,Rating,Points,Games,Wins,Draws,Losses,Performance Rating",
@@ -247,7 +427,42 @@ impl<D: Db> ExportService<D> {
                     output.push('\n');
                 }
             }
-            None => {
+            (None, None, Some(rating_report)) => {
+                // One row per game, then the total of the player
+                output.push_str(
+                    "Player,Rating,K,Round,Opponent,Opponent Rating,Result,Score,Difference,Expected,Change,Note\n",
+                );
+                for report in &rating_report.players {
+                    for game in &report.games {
+                        output.push_str(&format!(
+                            "{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                            report.player.name,
+                            optional(report.player.rating),
+                            optional(report.k_factor),
+                            game.round_number,
+                            game.opponent_name,
+                            optional(game.opponent_rating),
+                            game.result,
+                            optional(game.score),
+                            optional(game.rating_difference),
+                            optional(game.expected_score),
+                            optional(game.rating_change),
+                            game.note.as_deref().unwrap_or("")
+                        ));
+                    }
+                    output.push_str(&format!(
+                        "{},{},{},Total,,,,{},,{},{},{}\n",
+                        report.player.name,
+                        optional(report.player.rating),
+                        optional(report.k_factor),
+                        report.score,
+                        optional(report.expected_score),
+                        optional(report.rating_change),
+                        rating_report_note(report)
+                    ));
+                }
+            }
+            (None, None, None) => {
                 // Export player list
                 output.push_str("Name,Rating,Country,Title,Status\n");
                 for player in &data.players {
@@ -278,6 +493,9 @@ impl<D: Db> ExportService<D> {
             "games": data.games,
             "standings": data.standings,
             "cross_table": data.cross_table,
+            "arena_standings": data.arena_standings,
+            "arena_games": data.arena_games,
+            "rating_report": data.rating_report,
             "exported_at": chrono::Utc::now().to_rfc3339(),
             "export_version": "1.0"
         });
@@ -376,6 +594,88 @@ impl<D: Db> ExportService<D> {
             html.push_str("</table>\n");
         }
 
+        // Arena standings and games
+        if let Some(ref arena_standings) = data.arena_standings {
+            html.push_str("<h2>Arena Standings</h2>\n");
+            html.push_str("<table class='standings'>\n");
+            html.push_str("<tr><th>Rank</th><th>Player</th><th>Rating</th><th>Score</th><th>Games</th><th>W-D-L</th><th>Berserks</th><th>Performance</th><th>Sheet</th></tr>\n");
+            for standing in arena_standings {
+                html.push_str(&format!(
+                    "<tr><td>{}</td><td>{}{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}-{}-{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    standing.rank,
+                    standing.player.name,
+                    if standing.on_fire { " (on fire)" } else { "" },
+                    standing.player.rating.unwrap_or(0),
+                    standing.score,
+                    standing.games_played,
+                    standing.wins,
+                    standing.draws,
+                    standing.losses,
+                    standing.berserks,
+                    standing.performance_rating.unwrap_or(0),
+                    arena_sheet(standing)
+                ));
+            }
+            html.push_str("</table>\n");
+
+            let names: HashMap<i32, &str> = data
+                .players
+                .iter()
+                .map(|player| (player.id, player.name.as_str()))
+                .collect();
+            let name = |id: i32| names.get(&id).copied().unwrap_or("?");
+            let berserk = |berserk: bool| if berserk { " (berserk)" } else { "" };
+            html.push_str("<h2>Arena Games</h2>\n");
+            html.push_str("<table class='standings'>\n");
+            html.push_str("<tr><th>Started</th><th>White</th><th>Black</th><th>Result</th></tr>\n");
+            for game in &data.arena_games {
+                html.push_str(&format!(
+                    "<tr><td>{}</td><td>{}{}</td><td>{}{}</td><td>{}</td></tr>\n",
+                    game.started_at,
+                    name(game.white_player_id),
+                    berserk(game.white_berserk),
+                    name(game.black_player_id),
+                    berserk(game.black_berserk),
+                    game.result
+                ));
+            }
+            html.push_str("</table>\n");
+        }
+
+        // Rating calculation of every player
+        if let Some(ref rating_report) = data.rating_report {
+            html.push_str("<h2>Rating Report</h2>\n");
+            for report in &rating_report.players {
+                html.push_str(&format!(
+                    "<h3>{} ({}) K {}: {} / {}, expected {}, change {}</h3>\n",
+                    report.player.name,
+                    optional(report.player.rating),
+                    optional(report.k_factor),
+                    report.score,
+                    report.games_counted,
+                    optional(report.expected_score),
+                    optional(report.rating_change)
+                ));
+                html.push_str("<table class='standings'>\n");
+                html.push_str("<tr><th>Round</th><th>Opponent</th><th>Rating</th><th>Result</th><th>Difference</th><th>Expected</th><th>Change</th><th>Note</th></tr>\n");
+                for game in &report.games {
+                    html.push_str(&format!(
+                        "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                        game.round_number,
+                        game.opponent_name,
+                        optional(game.opponent_rating),
+                        game.result,
+                        optional(game.rating_difference),
+                        optional(game.expected_score),
+                        optional(game.rating_change),
+                        game.note.as_deref().unwrap_or("")
+                    ));
+                }
+                html.push_str("</table>\n");
+                html.push_str(&format!("<p>{}</p>\n", rating_report_note(report)));
+            }
+        }
+
         // Cross table
         if let Some(ref cross_table) = data.cross_table {
             html.push_str("<h2>Cross Table</h2>\n");
@@ -409,6 +709,25 @@ impl<D: Db> ExportService<D> {
             html.push_str("</table>\n");
         }
 
+        // Pairings with the explanation of every board
+        for round in &data.pairings {
+            html.push_str(&format!("<h2>Round {} Pairings</h2>\n", round.round_number));
+            html.push_str("<table class='standings pairings'>\n");
+            html.push_str(
+                "<tr><th>Board</th><th>White</th><th>Black</th><th>Result</th><th>Explanation</th></tr>\n",
+            );
+            for (board_number, game, explanation) in round.boards() {
+                html.push_str(&format!(
+                    "<tr><td>{board_number}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    game.white_player.name,
+                    game.black_player.name,
+                    game.game.result,
+                    explanation.map_or("", |explanation| explanation.summary.as_str())
+                ));
+            }
+            html.push_str("</table>\n");
+        }
+
         // Footer
         html.push_str("<footer>\n");
         html.push_str(&format!(
@@ -467,6 +786,83 @@ impl<D: Db> ExportService<D> {
             }
         }
 
+        // Arena standings
+        if let Some(ref arena_standings) = data.arena_standings {
+            output.push_str("ARENA STANDINGS\n");
+            output.push_str("===============\n\n");
+
+            for standing in arena_standings {
+                output.push_str(&format!(
+                    "{:2}. {:25} ({:4}) {:4} pts  {}-{}-{}  {}{}\n",
+                    standing.rank,
+                    standing.player.name,
+                    standing.player.rating.unwrap_or(0),
+                    standing.score,
+                    standing.wins,
+                    standing.draws,
+                    standing.losses,
+                    arena_sheet(standing),
+                    if standing.on_fire { " (on fire)" } else { "" }
+                ));
+            }
+        }
+
+        // Rating report
+        if let Some(ref rating_report) = data.rating_report {
+            output.push_str("RATING REPORT\n");
+            output.push_str("=============\n\n");
+
+            for report in &rating_report.players {
+                output.push_str(&format!(
+                    "{} ({}) K {}\n",
+                    report.player.name,
+                    optional(report.player.rating),
+                    optional(report.k_factor)
+                ));
+                for game in &report.games {
+                    output.push_str(&format!(
+                        "  R{:<2} {:25} ({:4}) {:7} {:>6} {:>6}  {}\n",
+                        game.round_number,
+                        game.opponent_name,
+                        optional(game.opponent_rating),
+                        game.result,
+                        optional(game.expected_score),
+                        optional(game.rating_change),
+                        game.note.as_deref().unwrap_or("")
+                    ));
+                }
+                output.push_str(&format!(
+                    "  {} / {}, expected {}, change {}  {}\n\n",
+                    report.score,
+                    report.games_counted,
+                    optional(report.expected_score),
+                    optional(report.rating_change),
+                    rating_report_note(report)
+                ));
+            }
+        }
+
+        let mut file = File::create(file_path).map_err(PawnError::Io)?;
+
+        file.write_all(output.as_bytes()).map_err(PawnError::Io)?;
+
+        Ok(output.len() as u64)
+    }
+
+    /// Export to FIDE TRF-16 format
+    async fn export_to_trf(&self, data: &ExportData, file_path: &Path) -> Result<u64, PawnError> {
+        let config = self.get_tournament_config(data.tournament.id).await?;
+        let rounds = self.db.get_rounds_by_tournament(data.tournament.id).await?;
+
+        let output = write_trf(&TrfReport {
+            tournament: &data.tournament,
+            config: &config,
+            players: &data.players,
+            games: &data.games,
+            rounds: &rounds,
+            standings: data.standings.as_ref(),
+        });
+
         let mut file = File::create(file_path).map_err(PawnError::Io)?;
 
         file.write_all(output.as_bytes()).map_err(PawnError::Io)?;
@@ -491,7 +887,11 @@ impl<D: Db> ExportService<D> {
             "Layer 1",
         );
 
-        let current_layer = doc.get_page(page1).get_layer(layer1);
+        let mut current_layer = doc.get_page(page1).get_layer(layer1);
+        let new_page = || {
+            let (page, layer) = doc.add_page(Mm(210.0), Mm(297.0), "Layer 1");
+            doc.get_page(page).get_layer(layer)
+        };
 
         // Add fonts
         let font = doc.add_builtin_font(BuiltinFont::TimesRoman)?;
@@ -612,6 +1012,47 @@ impl<D: Db> ExportService<D> {
             }
         }
 
+        // Add pairings with their explanations, continuing on new pages
+        for round in &data.pairings {
+            if y_pos < Mm(40.0) {
+                current_layer = new_page();
+                y_pos = Mm(280.0);
+            }
+            y_pos -= Mm(6.0);
+            current_layer.use_text(
+                format!("Round {} Pairings", round.round_number),
+                14.0,
+                Mm(20.0),
+                y_pos,
+                &font_bold,
+            );
+            y_pos -= Mm(8.0);
+
+            for (board_number, game, explanation) in round.boards() {
+                if y_pos < Mm(25.0) {
+                    current_layer = new_page();
+                    y_pos = Mm(280.0);
+                }
+                current_layer.use_text(
+                    format!(
+                        "{board_number}. {} - {}   {}",
+                        game.white_player.name, game.black_player.name, game.game.result
+                    ),
+                    10.0,
+                    Mm(20.0),
+                    y_pos,
+                    &font,
+                );
+                y_pos -= Mm(5.0);
+                if let Some(explanation) = explanation {
+                    for line in wrap_text(&explanation.summary, 110) {
+                        current_layer.use_text(line, 8.0, Mm(26.0), y_pos, &font);
+                        y_pos -= Mm(4.0);
+                    }
+                }
+            }
+        }
+
         // Add footer
         current_layer.use_text(
             format!(
@@ -873,12 +1314,250 @@ struct ExportData {
     games: Vec<Game>,
     standings: Option<StandingsCalculationResult>,
     cross_table: Option<CrossTable>,
+    pairings: Vec<RoundPairings>,
+    arena_standings: Option<Vec<ArenaStanding>>,
+    arena_games: Vec<ArenaGame>,
+    rating_report: Option<RatingReport>,
+}
+
+/// Sections of an event with their standings for the event exports
+#[derive(Debug, Clone)]
+struct EventExportData {
+    event: Event,
+    sections: Vec<SectionExportData>,
+}
+
+#[derive(Debug, Clone)]
+struct SectionExportData {
+    section: EventSection,
+    tournament: Tournament,
+    standings: StandingsCalculationResult,
+}
+
+/// One CSV table of all sections, the section in the first column
+fn event_to_csv(data: &EventExportData) -> Result<String, PawnError> {
+    let mut output = String::new();
+    output
+        .push_str("Section,Rank,Player,Rating,Points,Games,Wins,Draws,Losses,Performance Rating\n");
+    for section in &data.sections {
+        for standing in &section.standings.standings {
+            output.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{}\n",
+                section.section.name,
+                standing.rank,
+                standing.player.name,
+                standing.player.rating.unwrap_or(0),
+                standing.points,
+                standing.games_played,
+                standing.wins,
+                standing.draws,
+                standing.losses,
+                standing.performance_rating.unwrap_or(0)
+            ));
+        }
+    }
+    Ok(output)
+}
+
+fn event_to_json(data: &EventExportData) -> Result<String, PawnError> {
+    let sections: Vec<_> = data
+        .sections
+        .iter()
+        .map(|section| {
+            serde_json::json!({
+                "section": section.section,
+                "tournament": section.tournament,
+                "standings": section.standings,
+            })
+        })
+        .collect();
+    let export_json = serde_json::json!({
+        "event": data.event,
+        "sections": sections,
+        "exported_at": chrono::Utc::now().to_rfc3339(),
+        "export_version": "1.0"
+    });
+
+    serde_json::to_string_pretty(&export_json).map_err(PawnError::SerdeError)
+}
+
+fn event_to_html(data: &EventExportData) -> Result<String, PawnError> {
+    let mut html = String::new();
+
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n");
+    html.push_str("<meta charset='utf-8'>\n");
+    html.push_str(&format!(
+        "<title>{} - Event Report</title>\n",
+        data.event.name
+    ));
+    html.push_str("<style>\n");
+    html.push_str(include_str!("../templates/default.css"));
+    html.push_str("</style>\n");
+    html.push_str("</head>\n<body>\n");
+
+    html.push_str(&format!("<h1>{name}</h1>\n", name = data.event.name));
+    html.push_str(&format!(
+        "<p><strong>Location:</strong> {}</p>\n",
+        data.event.location
+    ));
+    html.push_str(&format!(
+        "<p><strong>Date:</strong> {}</p>\n",
+        data.event.date
+    ));
+
+    for section in &data.sections {
+        html.push_str(&format!(
+            "<h2>{}</h2>\n<p><strong>Rounds:</strong> {}/{}</p>\n",
+            section.section.name, section.tournament.rounds_played, section.tournament.total_rounds
+        ));
+        html.push_str("<table class='standings'>\n");
+        html.push_str("<tr><th>Rank</th><th>Player</th><th>Rating</th><th>Points</th><th>Games</th><th>W-D-L</th></tr>\n");
+        for standing in &section.standings.standings {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}-{}-{}</td></tr>\n",
+                standing.rank,
+                standing.player.name,
+                standing.player.rating.unwrap_or(0),
+                standing.points,
+                standing.games_played,
+                standing.wins,
+                standing.draws,
+                standing.losses
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("<footer>\n");
+    html.push_str(&format!(
+        "<p>Generated by Pawn Tournament Manager on {}</p>\n",
+        chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+    ));
+    html.push_str("</footer>\n");
+    html.push_str("</body>\n</html>");
+
+    Ok(html)
+}
+
+fn event_to_txt(data: &EventExportData) -> Result<String, PawnError> {
+    let mut output = String::new();
+
+    output.push_str(&format!("Event: {name}\n", name = data.event.name));
+    output.push_str(&format!(
+        "Location: {location}\n",
+        location = data.event.location
+    ));
+    output.push_str(&format!("Date: {date}\n", date = data.event.date));
+
+    for section in &data.sections {
+        let heading = section.section.name.to_uppercase();
+        output.push_str(&format!(
+            "\n{heading}\n{}\n\n",
+            "=".repeat(heading.chars().count())
+        ));
+        for standing in &section.standings.standings {
+            output.push_str(&format!(
+                "{:2}. {:25} ({:4}) {:4.1} pts  {}-{}-{}\n",
+                standing.rank,
+                standing.player.name,
+                standing.player.rating.unwrap_or(0),
+                standing.points,
+                standing.wins,
+                standing.draws,
+                standing.losses
+            ));
+        }
+    }
+
+    Ok(output)
+}
+
+/// Points of every arena game in the order played, as in "2 4 4 0 1"
+fn arena_sheet(standing: &ArenaStanding) -> String {
+    standing
+        .sheet
+        .iter()
+        .map(|points| points.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Optional value of a report, empty when missing
+fn optional<T: std::fmt::Display>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+/// Outcome of the rating calculation of a player, as in "New rating 2007"
+fn rating_report_note(report: &PlayerRatingReport) -> String {
+    match (report.new_rating, &report.note) {
+        (_, Some(note)) => note.clone(),
+        (Some(rating), None) if report.initial_rating => format!("First rating {rating}"),
+        (Some(rating), None) => format!("New rating {rating}"),
+        (None, None) => String::new(),
+    }
+}
+
+/// Split text into lines of at most `width` characters at word boundaries
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Pairings of one round for the pairing exports
+#[derive(Debug, Clone)]
+struct RoundPairings {
+    round_number: i32,
+    games: Vec<GameResult>,
+    explanations: HashMap<i32, BoardPairingExplanation>, // By game id
+}
+
+impl RoundPairings {
+    /// Boards in board order with their explanations; games paired without an
+    /// explanation keep their stored order
+    fn boards(&self) -> Vec<(i32, &GameResult, Option<&PairingExplanation>)> {
+        let mut boards: Vec<_> = (1..)
+            .zip(&self.games)
+            .map(|(index, game)| match self.explanations.get(&game.game.id) {
+                Some(board) => (board.board_number, game, Some(&board.explanation)),
+                None => (index, game, None),
+            })
+            .collect();
+        boards.sort_by_key(|(board_number, _, _)| *board_number);
+        boards
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use tempfile::TempDir;
 
+    #[test]
+    fn test_wrap_text() {
+        let lines = super::wrap_text("Score group 1.0; Player 1 floats down to meet Player 3", 20);
+        assert_eq!(
+            lines,
+            vec![
+                "Score group 1.0;",
+                "Player 1 floats down",
+                "to meet Player 3"
+            ]
+        );
+        assert!(super::wrap_text("", 20).is_empty());
+    }
+
     #[tokio::test]
     async fn test_export_service_creation() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,579 @@
+use crate::pawn::{
+    common::error::PawnError,
+    domain::{
+        dto::{CreatePerson, ImportFideRating, UpdatePerson},
+        model::{FideRating, Player},
+    },
+};
+use std::io::BufRead;
+
+/// Unreadable entries reported by name; the rest are only counted
+const MAX_WARNINGS: usize = 50;
+
+/// Players read from a FIDE rating list file
+pub struct ParsedFideList {
+    pub ratings: Vec<ImportFideRating>,
+    pub skipped: i32,
+    pub warnings: Vec<String>,
+}
+
+impl ParsedFideList {
+    fn push(&mut self, entry: Result<ImportFideRating, String>) {
+        match entry {
+            Ok(rating) => self.ratings.push(rating),
+            Err(message) => {
+                self.skipped += 1;
+                if self.warnings.len() < MAX_WARNINGS {
+                    self.warnings.push(message);
+                }
+            }
+        }
+    }
+}
+
+/// Rating a single-list file holds, from the FIDE file name (e.g. rapid_rating_list.txt)
+pub fn file_rating_type(file_name: &str) -> &'static str {
+    let file_name = file_name.to_lowercase();
+    if file_name.contains("rapid") {
+        "rapid"
+    } else if file_name.contains("blitz") {
+        "blitz"
+    } else {
+        "fide"
+    }
+}
+
+/// Read a FIDE TXT or XML rating list; `rating_type` is the rating of single-list files
+pub fn parse_fide_rating_list<R: BufRead>(
+    mut reader: R,
+    rating_type: &str,
+) -> Result<ParsedFideList, PawnError> {
+    let mut parsed = ParsedFideList {
+        ratings: Vec::new(),
+        skipped: 0,
+        warnings: Vec::new(),
+    };
+    let mut buffer = Vec::new();
+
+    let first_line = loop {
+        match read_line(&mut reader, &mut buffer)? {
+            Some(line) if line.trim().is_empty() => continue,
+            Some(line) => break line,
+            None => {
+                return Err(PawnError::InvalidInput(
+                    "The rating list file is empty".to_string(),
+                ));
+            }
+        }
+    };
+    if first_line.trim_start().starts_with('<') {
+        parse_xml(first_line, reader, buffer, rating_type, &mut parsed)?;
+    } else {
+        parse_txt(&first_line, reader, buffer, rating_type, &mut parsed)?;
+    }
+
+    if parsed.ratings.is_empty() {
+        return Err(PawnError::InvalidInput(
+            "No players found in the rating list file".to_string(),
+        ));
+    }
+    Ok(parsed)
+}
+
+/// Rating type a tournament is rated in, from its time type
+pub fn tournament_rating_type(time_type: &str) -> &'static str {
+    let time_type = time_type.to_lowercase();
+    if time_type.contains("blitz") || time_type.contains("bullet") {
+        "blitz"
+    } else if time_type.contains("rapid") {
+        "rapid"
+    } else {
+        "fide"
+    }
+}
+
+pub fn list_rating(rating: &FideRating, rating_type: &str) -> Option<i32> {
+    match rating_type {
+        "rapid" => rating.rapid_rating,
+        "blitz" => rating.blitz_rating,
+        _ => rating.standard_rating,
+    }
+}
+
+/// Names a player may be listed under, as FIDE writes "Surname, Firstname"
+pub fn name_variants(name: &str) -> Vec<String> {
+    let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+    if name.contains(',') {
+        return vec![name];
+    }
+    match name.rsplit_once(' ') {
+        Some((first_names, surname)) => vec![format!("{surname}, {first_names}"), name],
+        None => vec![name],
+    }
+}
+
+/// Listed player a tournament player is, when the name and federation leave a single candidate
+pub fn match_by_name(player: &Player, candidates: Vec<FideRating>) -> Option<FideRating> {
+    let mut matches = candidates.into_iter().filter(|rating| {
+        player.country_code.as_ref().is_none_or(|code| {
+            rating
+                .federation
+                .as_ref()
+                .is_some_and(|federation| federation.eq_ignore_ascii_case(code))
+        })
+    });
+    match (matches.next(), matches.next()) {
+        (Some(rating), None) => Some(rating),
+        _ => None,
+    }
+}
+
+/// Registry entry of a listed player
+pub fn person_data(rating: &FideRating) -> CreatePerson {
+    CreatePerson {
+        name: rating.name.clone(),
+        fide_id: Some(rating.fide_id.to_string()),
+        national_id: None,
+        federation: rating.federation.clone(),
+        birth_date: rating.birth_year.map(|year| year.to_string()),
+        gender: person_gender(rating),
+        title: rating.title.clone(),
+        fide_rating: rating.standard_rating,
+        national_rating: None,
+        rapid_rating: rating.rapid_rating,
+        blitz_rating: rating.blitz_rating,
+    }
+}
+
+/// Registry details of a person brought up to date with the list
+pub fn person_update(person_id: i32, rating: &FideRating) -> UpdatePerson {
+    UpdatePerson {
+        person_id,
+        name: None,
+        fide_id: None,
+        national_id: None,
+        federation: rating.federation.clone(),
+        birth_date: rating.birth_year.map(|year| year.to_string()),
+        gender: person_gender(rating),
+        title: rating.title.clone(),
+    }
+}
+
+fn person_gender(rating: &FideRating) -> Option<String> {
+    rating
+        .sex
+        .as_deref()
+        .filter(|sex| ["M", "F"].contains(sex))
+        .map(str::to_string)
+}
+
+/// Next line of the file; bytes that are not UTF-8 are replaced rather than rejected
+fn read_line<R: BufRead>(
+    reader: &mut R,
+    buffer: &mut Vec<u8>,
+) -> Result<Option<String>, PawnError> {
+    buffer.clear();
+    if reader.read_until(b'\n', buffer).map_err(PawnError::Io)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(
+        String::from_utf8_lossy(buffer)
+            .trim_end_matches(['\r', '\n'])
+            .to_string(),
+    ))
+}
+
+/// Fixed-width list: each column runs from its header label to the next one
+fn parse_txt<R: BufRead>(
+    header: &str,
+    mut reader: R,
+    mut buffer: Vec<u8>,
+    rating_type: &str,
+    parsed: &mut ParsedFideList,
+) -> Result<(), PawnError> {
+    let columns = txt_columns(header, rating_type);
+    if !["fide_id", "name"]
+        .iter()
+        .all(|key| columns.iter().any(|(column, _, _)| column == key))
+    {
+        return Err(PawnError::InvalidInput(
+            "Not a FIDE rating list: the header has no ID Number and Name columns".to_string(),
+        ));
+    }
+
+    let mut line_number = 1;
+    while let Some(line) = read_line(&mut reader, &mut buffer)? {
+        line_number += 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let field = |key: &str| {
+            let &(_, start, end) = columns.iter().find(|(column, _, _)| *column == key)?;
+            Some(char_range(&line, start, end).trim().to_string())
+        };
+        parsed.push(fide_rating(field).map_err(|message| format!("Line {line_number}: {message}")));
+    }
+    Ok(())
+}
+
+/// Column keys with their start and end character positions
+fn txt_columns(header: &str, rating_type: &str) -> Vec<(&'static str, usize, usize)> {
+    let mut labels = Vec::new();
+    let mut start = None;
+    for (position, c) in header.chars().chain([' ']).enumerate() {
+        match (c.is_whitespace(), start) {
+            (false, None) => start = Some(position),
+            (true, Some(label_start)) => {
+                let label: String = header
+                    .chars()
+                    .skip(label_start)
+                    .take(position - label_start)
+                    .collect();
+                labels.push((label, label_start));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    let mut columns = Vec::new();
+    for (index, (label, start)) in labels.iter().enumerate() {
+        let end = labels.get(index + 1).map_or(usize::MAX, |(_, next)| *next);
+        let key = match label.as_str() {
+            "ID" => "fide_id",
+            "Name" => "name",
+            "Fed" => "federation",
+            "Sex" => "sex",
+            "Tit" => "title",
+            "WTit" => "w_title",
+            "SRtng" => "fide",
+            "RRtng" => "rapid",
+            "BRtng" => "blitz",
+            "B-day" => "birth_year",
+            "Flag" => "flag",
+            // Single-list files head their rating column with the list month, e.g. OCT25
+            label if is_month_label(label) => match rating_type {
+                "rapid" => "rapid",
+                "blitz" => "blitz",
+                _ => "fide",
+            },
+            // "Number" belongs to the ID column
+            "Number" => {
+                if let Some((_, _, column_end)) = columns.last_mut() {
+                    *column_end = end;
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        columns.push((key, *start, end));
+    }
+    columns
+}
+
+/// Text between two character positions, clamped to the line
+fn char_range(line: &str, start: usize, end: usize) -> &str {
+    if line.is_ascii() {
+        return line.get(start..end.min(line.len())).unwrap_or("");
+    }
+    let byte_index = |position: usize| {
+        line.char_indices()
+            .nth(position)
+            .map_or(line.len(), |(index, _)| index)
+    };
+    let start = byte_index(start);
+    &line[start..byte_index(end).max(start)]
+}
+
+fn is_month_label(label: &str) -> bool {
+    let bytes = label.as_bytes();
+    bytes.len() == 5
+        && bytes[..3].iter().all(u8::is_ascii_alphabetic)
+        && bytes[3..].iter().all(u8::is_ascii_digit)
+}
+
+/// XML list: one `<player>` element per player, read without holding the whole file
+fn parse_xml<R: BufRead>(
+    first_line: String,
+    mut reader: R,
+    mut buffer: Vec<u8>,
+    rating_type: &str,
+    parsed: &mut ParsedFideList,
+) -> Result<(), PawnError> {
+    let mut pending = first_line;
+    loop {
+        while let Some(end) = pending.find("</player>") {
+            let block_end = end + "</player>".len();
+            let block = &pending[..block_end];
+            let fields = xml_fields(block, rating_type);
+            parsed.push(fide_rating(|key| {
+                fields
+                    .iter()
+                    .find(|(field, _)| *field == key)
+                    .map(|(_, value)| value.clone())
+            }));
+            pending.drain(..block_end);
+        }
+        match read_line(&mut reader, &mut buffer)? {
+            Some(line) => {
+                pending.push_str(&line);
+                pending.push('\n');
+            }
+            None => return Ok(()),
+        }
+    }
+}
+
+fn xml_fields(block: &str, rating_type: &str) -> Vec<(&'static str, String)> {
+    let mut fields = Vec::new();
+    let mut rest = block;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..end];
+        rest = &rest[end + 1..];
+        let key = match tag {
+            "fideid" => "fide_id",
+            "name" => "name",
+            "country" => "federation",
+            "sex" => "sex",
+            "title" => "title",
+            "w_title" => "w_title",
+            "rating" => match rating_type {
+                "rapid" => "rapid",
+                "blitz" => "blitz",
+                _ => "fide",
+            },
+            "rapid_rating" => "rapid",
+            "blitz_rating" => "blitz",
+            "birthday" => "birth_year",
+            "flag" => "flag",
+            _ => continue,
+        };
+        let close = format!("</{tag}>");
+        if let Some(value_end) = rest.find(&close) {
+            fields.push((key, unescape_xml(rest[..value_end].trim())));
+            rest = &rest[value_end + close.len()..];
+        }
+    }
+    fields
+}
+
+fn unescape_xml(value: &str) -> String {
+    if !value.contains('&') {
+        return value.to_string();
+    }
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn fide_rating(field: impl Fn(&str) -> Option<String>) -> Result<ImportFideRating, String> {
+    let text = |key: &str| field(key).filter(|value| !value.is_empty());
+    let number = |key: &str| {
+        text(key)
+            .and_then(|value| value.parse::<i32>().ok())
+            .filter(|&value| value > 0)
+    };
+
+    let fide_id = number("fide_id").ok_or_else(|| match text("fide_id") {
+        Some(value) => format!("{value} is not a FIDE ID"),
+        None => "Entry without a FIDE ID".to_string(),
+    })?;
+    let name = text("name").ok_or_else(|| format!("FIDE ID {fide_id} has no name"))?;
+    Ok(ImportFideRating {
+        fide_id,
+        name,
+        federation: text("federation"),
+        sex: text("sex"),
+        title: text("title").or_else(|| text("w_title")),
+        birth_year: number("birth_year"),
+        standard_rating: number("fide"),
+        rapid_rating: number("rapid"),
+        blitz_rating: number("blitz"),
+        flag: text("flag"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMBINED_TXT: &str = "\
+ID Number      Name                                                         Fed Sex Tit  WTit OTit           FOA SRtng SGm SK RRtng RGm Rk BRtng BGm BK B-day Flag
+1503014        Carlsen, Magnus                                              NOR M   GM                           2839  0   10 2824  0   20 2886  0   20 1990
+8603405        Hou, Yifan                                                   CHN F   GM   WGM                     2633  0   10 2595  0   20 2574  0   20 1994  w
+2016192        Nakamura, Hikaru                                             USA M   GM                           2802  9   10                           1987
+               Missing, Identifier                                          ENG M                                1800  0   20                           2000
+";
+
+    const RAPID_TXT: &str = "\
+ID Number      Name                                                         Fed Sex Tit  WTit OTit           FOA OCT25 Gms  K B-day Flag
+1503014        Carlsen, Magnus                                              NOR M   GM                           2824  0   20 1990
+";
+
+    const COMBINED_XML: &str = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+<playerslist>
+<player>
+<fideid>1503014</fideid>
+<name>Carlsen, Magnus</name>
+<country>NOR</country>
+<sex>M</sex>
+<title>GM</title>
+<w_title></w_title>
+<o_title></o_title>
+<foa_title></foa_title>
+<rating>2839</rating>
+<games>0</games>
+<k>10</k>
+<rapid_rating>2824</rapid_rating>
+<rapid_games>0</rapid_games>
+<rapid_k>20</rapid_k>
+<blitz_rating>2886</blitz_rating>
+<blitz_games>0</blitz_games>
+<blitz_k>20</blitz_k>
+<birthday>1990</birthday>
+<flag></flag>
+</player>
+<player><fideid>24651516</fideid><name>O&apos;Brien, Tom &amp; Co</name><country>IRL</country><sex>M</sex><title/><rating>1702</rating><birthday/><flag>i</flag></player>
+</playerslist>
+";
+
+    fn create_rating(fide_id: i32, name: &str, federation: &str) -> FideRating {
+        FideRating {
+            list_id: 1,
+            fide_id,
+            name: name.to_string(),
+            federation: Some(federation.to_string()),
+            sex: Some("M".to_string()),
+            title: None,
+            birth_year: Some(1990),
+            standard_rating: Some(2000),
+            rapid_rating: None,
+            blitz_rating: None,
+            flag: None,
+        }
+    }
+
+    fn create_player(name: &str, country_code: Option<&str>) -> Player {
+        Player {
+            id: 1,
+            tournament_id: 1,
+            name: name.to_string(),
+            rating: None,
+            country_code: country_code.map(str::to_string),
+            title: None,
+            birth_date: None,
+            gender: None,
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: None,
+            initial_rating: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_combined_txt() {
+        let parsed = parse_fide_rating_list(COMBINED_TXT.as_bytes(), "fide").unwrap();
+        assert_eq!(parsed.ratings.len(), 3);
+        assert_eq!(parsed.skipped, 1);
+        assert_eq!(parsed.warnings, vec!["Line 5: Entry without a FIDE ID"]);
+
+        let carlsen = &parsed.ratings[0];
+        assert_eq!(carlsen.fide_id, 1503014);
+        assert_eq!(carlsen.name, "Carlsen, Magnus");
+        assert_eq!(carlsen.federation.as_deref(), Some("NOR"));
+        assert_eq!(carlsen.title.as_deref(), Some("GM"));
+        assert_eq!(carlsen.standard_rating, Some(2839));
+        assert_eq!(carlsen.rapid_rating, Some(2824));
+        assert_eq!(carlsen.blitz_rating, Some(2886));
+        assert_eq!(carlsen.birth_year, Some(1990));
+        assert_eq!(carlsen.flag, None);
+
+        assert_eq!(parsed.ratings[1].flag.as_deref(), Some("w"));
+        assert_eq!(parsed.ratings[2].rapid_rating, None);
+    }
+
+    #[test]
+    fn test_parse_single_list_txt() {
+        let parsed = parse_fide_rating_list(RAPID_TXT.as_bytes(), "rapid").unwrap();
+        assert_eq!(parsed.ratings[0].rapid_rating, Some(2824));
+        assert_eq!(parsed.ratings[0].standard_rating, None);
+        assert_eq!(parsed.ratings[0].birth_year, Some(1990));
+    }
+
+    #[test]
+    fn test_char_range() {
+        assert_eq!(char_range("NOR M   GM", 4, 8), "M   ");
+        assert_eq!(char_range("Øst, Åse   NOR", 11, usize::MAX), "NOR");
+        assert_eq!(char_range("short", 8, 12), "");
+    }
+
+    #[test]
+    fn test_parse_xml() {
+        let parsed = parse_fide_rating_list(COMBINED_XML.as_bytes(), "fide").unwrap();
+        assert_eq!(parsed.ratings.len(), 2);
+        assert_eq!(parsed.skipped, 0);
+
+        let carlsen = &parsed.ratings[0];
+        assert_eq!(carlsen.fide_id, 1503014);
+        assert_eq!(carlsen.standard_rating, Some(2839));
+        assert_eq!(carlsen.blitz_rating, Some(2886));
+        assert_eq!(carlsen.birth_year, Some(1990));
+
+        let obrien = &parsed.ratings[1];
+        assert_eq!(obrien.name, "O'Brien, Tom & Co");
+        assert_eq!(obrien.title, None);
+        assert_eq!(obrien.birth_year, None);
+        assert_eq!(obrien.flag.as_deref(), Some("i"));
+    }
+
+    #[test]
+    fn test_parse_rejects_other_files() {
+        assert!(parse_fide_rating_list("".as_bytes(), "fide").is_err());
+        assert!(parse_fide_rating_list("Name,Rating\nA,1500\n".as_bytes(), "fide").is_err());
+    }
+
+    #[test]
+    fn test_rating_types() {
+        assert_eq!(file_rating_type("blitz_rating_list.txt"), "blitz");
+        assert_eq!(file_rating_type("players_list_foa.txt"), "fide");
+        assert_eq!(tournament_rating_type("Rapid"), "rapid");
+        assert_eq!(tournament_rating_type("classical"), "fide");
+    }
+
+    #[test]
+    fn test_name_variants() {
+        assert_eq!(
+            name_variants("Magnus  Carlsen"),
+            vec!["Carlsen, Magnus", "Magnus Carlsen"]
+        );
+        assert_eq!(name_variants("Carlsen, Magnus"), vec!["Carlsen, Magnus"]);
+    }
+
+    #[test]
+    fn test_match_by_name() {
+        let candidates = vec![
+            create_rating(1, "Smith, John", "ENG"),
+            create_rating(2, "Smith, John", "USA"),
+        ];
+        let player = create_player("John Smith", Some("USA"));
+        assert_eq!(
+            match_by_name(&player, candidates.clone()).map(|r| r.fide_id),
+            Some(2)
+        );
+
+        let player = create_player("John Smith", None);
+        assert!(match_by_name(&player, candidates).is_none());
+    }
+}
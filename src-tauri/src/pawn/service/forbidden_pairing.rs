@@ -0,0 +1,300 @@
+use crate::pawn::{
+    common::error::PawnError,
+    domain::{
+        dto::CreateForbiddenPairing,
+        model::{ForbiddenPairing, Pairing, Player},
+    },
+    service::manual_pairing::{ConstraintPriority, ConstraintType, PairingConstraint},
+};
+use std::collections::HashSet;
+
+/// Whether a restriction is in force in a round
+pub fn applies_to_round(restriction: &ForbiddenPairing, round_number: i32) -> bool {
+    restriction
+        .first_round
+        .is_none_or(|first| round_number >= first)
+        && restriction
+            .last_round
+            .is_none_or(|last| round_number <= last)
+}
+
+/// Player pairs (smaller id first) that must not meet in a round. A group
+/// forbids every pair of its members.
+pub fn forbidden_pairs(
+    restrictions: &[ForbiddenPairing],
+    round_number: i32,
+) -> HashSet<(i32, i32)> {
+    forbidden_pair_constraints(restrictions, round_number)
+        .into_iter()
+        .filter_map(|constraint| Some((constraint.player1_id, constraint.player2_id?)))
+        .collect()
+}
+
+/// The restrictions in force in a round as absolute pairing constraints
+pub fn forbidden_pair_constraints(
+    restrictions: &[ForbiddenPairing],
+    round_number: i32,
+) -> Vec<PairingConstraint> {
+    let mut constraints = Vec::new();
+    for restriction in restrictions
+        .iter()
+        .filter(|restriction| applies_to_round(restriction, round_number))
+    {
+        let ids = &restriction.player_ids;
+        for (i, &a) in ids.iter().enumerate() {
+            for &b in ids[i + 1..].iter().filter(|&&b| a != b) {
+                constraints.push(PairingConstraint {
+                    constraint_type: ConstraintType::ForbidMatch,
+                    player1_id: a.min(b),
+                    player2_id: Some(a.max(b)),
+                    reason: restriction.reason.clone(),
+                    priority: ConstraintPriority::Critical,
+                    round_number: Some(round_number),
+                });
+            }
+        }
+    }
+    constraints
+}
+
+/// Player pairs (smaller id first) who share a club
+pub fn same_club_pairs(players: &[Player]) -> HashSet<(i32, i32)> {
+    let mut pairs = HashSet::new();
+    for (i, a) in players.iter().enumerate() {
+        let Some(club) = a.club.as_deref().filter(|club| !club.trim().is_empty()) else {
+            continue;
+        };
+        for b in &players[i + 1..] {
+            if b.club
+                .as_deref()
+                .is_some_and(|other| other.trim() == club.trim())
+            {
+                pairs.insert((a.id.min(b.id), a.id.max(b.id)));
+            }
+        }
+    }
+    pairs
+}
+
+/// Boards that pair two players who must not meet
+pub fn forbidden_boards<'a>(
+    pairings: &'a [Pairing],
+    forbidden: &HashSet<(i32, i32)>,
+) -> Vec<&'a Pairing> {
+    pairings
+        .iter()
+        .filter(|pairing| {
+            pairing.black_player.as_ref().is_some_and(|black| {
+                let white = pairing.white_player.id;
+                forbidden.contains(&(white.min(black.id), white.max(black.id)))
+            })
+        })
+        .collect()
+}
+
+/// Reject pairings that put two players who must not meet on one board. The
+/// Swiss engines pair around restrictions; round-robin and Scheveningen rounds
+/// follow a fixed schedule and manual pairings are entered by the arbiter, so
+/// their boards are checked here instead.
+pub fn check_forbidden_boards(
+    pairings: &[Pairing],
+    forbidden: &HashSet<(i32, i32)>,
+) -> Result<(), PawnError> {
+    let boards: Vec<String> = forbidden_boards(pairings, forbidden)
+        .into_iter()
+        .map(|pairing| {
+            format!(
+                "board {}: {} - {}",
+                pairing.board_number,
+                pairing.white_player.name,
+                pairing
+                    .black_player
+                    .as_ref()
+                    .map(|player| player.name.as_str())
+                    .unwrap_or_default()
+            )
+        })
+        .collect();
+    if boards.is_empty() {
+        return Ok(());
+    }
+    Err(PawnError::InvalidInput(format!(
+        "Forbidden pairings on {}",
+        boards.join("; ")
+    )))
+}
+
+/// Check a new restriction against the players and rounds of the tournament
+pub fn validate_forbidden_pairing(
+    data: &CreateForbiddenPairing,
+    players: &[Player],
+    total_rounds: i32,
+) -> Result<(), PawnError> {
+    let distinct: HashSet<i32> = data.player_ids.iter().copied().collect();
+    if distinct.len() < 2 {
+        return Err(PawnError::InvalidInput(
+            "A forbidden pairing needs at least two different players".into(),
+        ));
+    }
+
+    if let Some(unknown) = data
+        .player_ids
+        .iter()
+        .find(|&&id| !players.iter().any(|player| player.id == id))
+    {
+        return Err(PawnError::InvalidInput(format!(
+            "Player {unknown} is not registered in tournament {}",
+            data.tournament_id
+        )));
+    }
+
+    for round in [data.first_round, data.last_round].into_iter().flatten() {
+        if round < 1 || round > total_rounds {
+            return Err(PawnError::InvalidInput(format!(
+                "Round {round} is outside the tournament's {total_rounds} rounds"
+            )));
+        }
+    }
+
+    if let (Some(first), Some(last)) = (data.first_round, data.last_round)
+        && first > last
+    {
+        return Err(PawnError::InvalidInput(format!(
+            "First round {first} is after last round {last}"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_player(id: i32) -> Player {
+        Player {
+            id,
+            tournament_id: 1,
+            name: format!("Player {id}"),
+            rating: Some(1500),
+            country_code: None,
+            title: None,
+            birth_date: None,
+            gender: None,
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: None,
+            initial_rating: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    fn restriction(
+        player_ids: Vec<i32>,
+        first_round: Option<i32>,
+        last_round: Option<i32>,
+    ) -> ForbiddenPairing {
+        ForbiddenPairing {
+            id: 1,
+            tournament_id: 1,
+            player_ids,
+            reason: "Same club".to_string(),
+            first_round,
+            last_round,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    fn request(
+        player_ids: Vec<i32>,
+        first_round: Option<i32>,
+        last_round: Option<i32>,
+    ) -> CreateForbiddenPairing {
+        CreateForbiddenPairing {
+            tournament_id: 1,
+            player_ids,
+            reason: "Siblings".to_string(),
+            first_round,
+            last_round,
+        }
+    }
+
+    #[test]
+    fn test_group_forbids_every_pair_in_its_rounds() {
+        let restrictions = vec![
+            restriction(vec![3, 1, 2], Some(1), Some(3)),
+            restriction(vec![5, 4], None, None),
+        ];
+
+        assert_eq!(
+            forbidden_pairs(&restrictions, 2),
+            HashSet::from([(1, 3), (2, 3), (1, 2), (4, 5)])
+        );
+        assert_eq!(forbidden_pairs(&restrictions, 4), HashSet::from([(4, 5)]));
+        assert_eq!(forbidden_pair_constraints(&restrictions, 4).len(), 1);
+    }
+
+    #[test]
+    fn test_forbidden_boards() {
+        let players: Vec<Player> = (1..=4).map(create_test_player).collect();
+        let pairings = vec![
+            Pairing {
+                white_player: players[1].clone(),
+                black_player: Some(players[0].clone()),
+                board_number: 1,
+                explanation: None,
+            },
+            Pairing {
+                white_player: players[2].clone(),
+                black_player: Some(players[3].clone()),
+                board_number: 2,
+                explanation: None,
+            },
+        ];
+
+        let boards = forbidden_boards(&pairings, &HashSet::from([(1, 2)]));
+        assert_eq!(boards.len(), 1);
+        assert_eq!(boards[0].board_number, 1);
+
+        assert!(check_forbidden_boards(&pairings, &HashSet::from([(1, 3)])).is_ok());
+        let error = check_forbidden_boards(&pairings, &HashSet::from([(1, 2)])).unwrap_err();
+        assert!(matches!(
+            error,
+            PawnError::InvalidInput(message) if message == "Forbidden pairings on board 1: Player 2 - Player 1"
+        ));
+    }
+
+    #[test]
+    fn test_same_club_pairs() {
+        let mut players: Vec<Player> = (1..=4).map(create_test_player).collect();
+        players[0].club = Some("Hastings".to_string());
+        players[2].club = Some("Hastings".to_string());
+        players[3].club = Some(" ".to_string());
+
+        assert_eq!(same_club_pairs(&players), HashSet::from([(1, 3)]));
+    }
+
+    #[test]
+    fn test_validate_forbidden_pairing() {
+        let players: Vec<Player> = (1..=4).map(create_test_player).collect();
+
+        assert!(validate_forbidden_pairing(&request(vec![1, 2], None, None), &players, 5).is_ok());
+        assert!(
+            validate_forbidden_pairing(&request(vec![1, 2, 3], Some(1), Some(3)), &players, 5)
+                .is_ok()
+        );
+        assert!(validate_forbidden_pairing(&request(vec![1, 1], None, None), &players, 5).is_err());
+        assert!(validate_forbidden_pairing(&request(vec![1, 9], None, None), &players, 5).is_err());
+        assert!(
+            validate_forbidden_pairing(&request(vec![1, 2], Some(3), Some(2)), &players, 5)
+                .is_err()
+        );
+        assert!(
+            validate_forbidden_pairing(&request(vec![1, 2], None, Some(6)), &players, 5).is_err()
+        );
+    }
+}
@@ -1,8 +1,10 @@
 use crate::pawn::common::error::PawnError;
+use crate::pawn::domain::dto::KnockoutPlacing;
 use crate::pawn::domain::model::{
-    BracketPosition, BracketPositionStatus, BracketType, KnockoutBracket, Pairing, Player,
+    BracketPosition, BracketPositionStatus, BracketSide, BracketType, KnockoutBracket, Pairing,
+    Player,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[allow(dead_code)]
 pub struct KnockoutService;
@@ -50,6 +52,7 @@ impl KnockoutService {
             tournament_id,
             bracket_type: BracketType::SingleElimination.to_str().to_string(),
             total_rounds,
+            bracket_reset: false,
             created_at: chrono::Utc::now().to_rfc3339(),
         })
     }
@@ -70,6 +73,7 @@ impl KnockoutService {
             let position = BracketPosition {
                 id: 0, // Will be set by database
                 bracket_id,
+                bracket_side: BracketSide::Winners.to_str().to_string(),
                 round_number: 1,
                 position_number: i + 1,
                 player_id: seeded_players.get(i as usize).map(|p| p.id),
@@ -90,11 +94,13 @@ impl KnockoutService {
     /// Seed players using standard tournament seeding
     /// 1 vs lowest, 2 vs second-lowest, etc.
     fn seed_players(mut players: Vec<Player>) -> Vec<Player> {
-        // Sort by rating (highest first) for seeding
+        // Sort by seed number, e.g. from a qualifying stage, then by rating (highest first)
         players.sort_by(|a, b| {
+            let seed_a = a.seed_number.unwrap_or(i32::MAX);
+            let seed_b = b.seed_number.unwrap_or(i32::MAX);
             let rating_a = a.rating.unwrap_or(1000);
             let rating_b = b.rating.unwrap_or(1000);
-            rating_b.cmp(&rating_a)
+            seed_a.cmp(&seed_b).then(rating_b.cmp(&rating_a))
         });
 
         let player_count = players.len();
@@ -167,7 +173,9 @@ impl KnockoutService {
         let round_positions: Vec<&BracketPosition> = positions
             .iter()
             .filter(|p| {
-                p.round_number == round_number && p.status == BracketPositionStatus::Ready.to_str()
+                p.bracket_side == BracketSide::Winners.to_str()
+                    && p.round_number == round_number
+                    && p.status == BracketPositionStatus::Ready.to_str()
             })
             .collect();
 
@@ -185,45 +193,10 @@ impl KnockoutService {
                     // Note: This is a simplified pairing - in real implementation,
                     // we'd need to load the actual Player objects from the database
                     pairings.push(Pairing {
-                        white_player: Player {
-                            id: white_id,
-                            tournament_id: 0,
-                            name: format!("Player {white_id}"),
-                            rating: None,
-                            country_code: None,
-                            title: None,
-                            birth_date: None,
-                            gender: None,
-                            email: None,
-                            phone: None,
-                            club: None,
-                            status: "active".to_string(),
-                            seed_number: None,
-                            pairing_number: None,
-                            initial_rating: None,
-                            created_at: chrono::Utc::now().to_rfc3339(),
-                            updated_at: None,
-                        },
-                        black_player: Some(Player {
-                            id: black_id,
-                            tournament_id: 0,
-                            name: format!("Player {black_id}"),
-                            rating: None,
-                            country_code: None,
-                            title: None,
-                            birth_date: None,
-                            gender: None,
-                            email: None,
-                            phone: None,
-                            club: None,
-                            status: "active".to_string(),
-                            seed_number: None,
-                            pairing_number: None,
-                            initial_rating: None,
-                            created_at: chrono::Utc::now().to_rfc3339(),
-                            updated_at: None,
-                        }),
+                        white_player: Self::placeholder_player(white_id),
+                        black_player: Some(Self::placeholder_player(black_id)),
                         board_number,
+                        explanation: None,
                     });
                     board_number += 1;
                 }
@@ -232,27 +205,10 @@ impl KnockoutService {
                 let bye_pos = chunk[0];
                 if let Some(player_id) = bye_pos.player_id {
                     pairings.push(Pairing {
-                        white_player: Player {
-                            id: player_id,
-                            tournament_id: 0,
-                            name: format!("Player {player_id}"),
-                            rating: None,
-                            country_code: None,
-                            title: None,
-                            birth_date: None,
-                            gender: None,
-                            email: None,
-                            phone: None,
-                            club: None,
-                            status: "active".to_string(),
-                            seed_number: None,
-                            pairing_number: None,
-                            initial_rating: None,
-                            created_at: chrono::Utc::now().to_rfc3339(),
-                            updated_at: None,
-                        },
+                        white_player: Self::placeholder_player(player_id),
                         black_player: None, // Bye
                         board_number,
+                        explanation: None,
                     });
                     board_number += 1;
                 }
@@ -262,6 +218,73 @@ impl KnockoutService {
         pairings
     }
 
+    /// Generate pairings for a round of one side of a double-elimination
+    /// bracket. Positions 1 and 2 meet, then 3 and 4, and so on; a match is
+    /// only paired once both of its players have arrived.
+    pub fn generate_side_pairings(
+        side: &BracketSide,
+        round_number: i32,
+        positions: &[BracketPosition],
+    ) -> Vec<Pairing> {
+        let ready_player = |position_number: i32| {
+            positions
+                .iter()
+                .find(|p| {
+                    p.bracket_side == side.to_str()
+                        && p.round_number == round_number
+                        && p.position_number == position_number
+                        && p.status == BracketPositionStatus::Ready.to_str()
+                })
+                .and_then(|p| p.player_id)
+        };
+        let last_position = positions
+            .iter()
+            .filter(|p| p.bracket_side == side.to_str() && p.round_number == round_number)
+            .map(|p| p.position_number)
+            .max()
+            .unwrap_or(0);
+
+        let mut pairings = Vec::new();
+        for match_number in 1..=(last_position + 1) / 2 {
+            if let (Some(white_id), Some(black_id)) = (
+                ready_player(2 * match_number - 1),
+                ready_player(2 * match_number),
+            ) {
+                pairings.push(Pairing {
+                    white_player: Self::placeholder_player(white_id),
+                    black_player: Some(Self::placeholder_player(black_id)),
+                    board_number: pairings.len() as i32 + 1,
+                    explanation: None,
+                });
+            }
+        }
+
+        pairings
+    }
+
+    /// Stand-in for a bracket player until the real one is loaded from the database
+    fn placeholder_player(player_id: i32) -> Player {
+        Player {
+            id: player_id,
+            tournament_id: 0,
+            name: format!("Player {player_id}"),
+            rating: None,
+            country_code: None,
+            title: None,
+            birth_date: None,
+            gender: None,
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: None,
+            initial_rating: None,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            updated_at: None,
+        }
+    }
+
     /// Advance winners to the next round
     pub fn advance_winners(
         bracket_id: i32,
@@ -276,6 +299,7 @@ impl KnockoutService {
             let position = BracketPosition {
                 id: 0, // Will be set by database
                 bracket_id,
+                bracket_side: BracketSide::Winners.to_str().to_string(),
                 round_number: next_round,
                 position_number: (i + 1) as i32,
                 player_id: Some(*winner_id),
@@ -291,56 +315,506 @@ impl KnockoutService {
         next_round_positions
     }
 
+    /// Whether the semi-final losers of a bracket play a match for third place
+    pub fn has_third_place_match(bracket: &KnockoutBracket) -> bool {
+        bracket.bracket_type == BracketType::ThirdPlacePlayoff.to_str() && bracket.total_rounds >= 2
+    }
+
+    /// Once both semi-finals are decided, their losers meet for third place
+    /// on positions 3 and 4 of the final round, so that the match is paired
+    /// alongside the final
+    pub fn third_place_positions(
+        bracket: &KnockoutBracket,
+        round_number: i32,
+        game_results: &[(i32, i32)], // (winner_player_id, loser_player_id)
+    ) -> Vec<BracketPosition> {
+        if !Self::has_third_place_match(bracket)
+            || round_number != bracket.total_rounds - 1
+            || game_results.len() != 2
+        {
+            return Vec::new();
+        }
+
+        game_results
+            .iter()
+            .zip(3..)
+            .map(|(&(_, loser_id), position_number)| BracketPosition {
+                id: 0, // Will be set by database
+                bracket_id: bracket.id,
+                bracket_side: BracketSide::Winners.to_str().to_string(),
+                round_number: bracket.total_rounds,
+                position_number,
+                player_id: Some(loser_id),
+                advanced_from_position: None,
+                status: BracketPositionStatus::Ready.to_str().to_string(),
+                created_at: chrono::Utc::now().to_rfc3339(),
+            })
+            .collect()
+    }
+
+    /// Advance the results of a double-elimination round. Winners move on
+    /// within their side; losers of the winners bracket drop into the losers
+    /// bracket, whose champion meets the winners-bracket champion in the grand
+    /// final. Winners-bracket losers fill the even slots of a losers round,
+    /// in reverse order every other round so that players who already met
+    /// are kept apart. If the losers-bracket champion wins the grand final
+    /// and the bracket has a reset, the grand final is played again.
+    pub fn advance_double_elimination(
+        bracket: &KnockoutBracket,
+        side: &BracketSide,
+        round_number: i32,
+        positions: &[BracketPosition],
+        game_results: &[(i32, i32)], // (winner_player_id, loser_player_id)
+    ) -> Result<Vec<BracketPosition>, PawnError> {
+        let winners_rounds = bracket.total_rounds;
+        let losers_rounds = 2 * (winners_rounds - 1);
+        let last_round = match side {
+            BracketSide::Winners => winners_rounds,
+            BracketSide::Losers => losers_rounds,
+            BracketSide::GrandFinal => 2,
+        };
+        if round_number < 1 || round_number > last_round {
+            return Err(PawnError::InvalidInput(format!(
+                "Round {round_number} does not exist in the {} bracket",
+                side.to_str()
+            )));
+        }
+
+        let round_positions: Vec<&BracketPosition> = positions
+            .iter()
+            .filter(|p| p.bracket_side == side.to_str() && p.round_number == round_number)
+            .collect();
+        let find_position = |player_id: i32| {
+            round_positions
+                .iter()
+                .find(|p| p.player_id == Some(player_id))
+                .copied()
+                .ok_or_else(|| {
+                    PawnError::InvalidInput(format!(
+                        "Player {player_id} is not in round {round_number} of the {} bracket",
+                        side.to_str()
+                    ))
+                })
+        };
+
+        let new_position = |side: BracketSide, round: i32, number: i32, from: &BracketPosition| {
+            BracketPosition {
+                id: 0, // Will be set by database
+                bracket_id: bracket.id,
+                bracket_side: side.to_str().to_string(),
+                round_number: round,
+                position_number: number,
+                player_id: from.player_id,
+                advanced_from_position: Some(from.id),
+                status: BracketPositionStatus::Ready.to_str().to_string(),
+                created_at: chrono::Utc::now().to_rfc3339(),
+            }
+        };
+
+        let mut next_positions = Vec::new();
+        for &(winner_id, loser_id) in game_results {
+            let winner = find_position(winner_id)?;
+            let loser = find_position(loser_id)?;
+            let match_number = (winner.position_number + 1) / 2;
+            if match_number != (loser.position_number + 1) / 2 {
+                return Err(PawnError::InvalidInput(format!(
+                    "Players {winner_id} and {loser_id} did not meet in round {round_number}"
+                )));
+            }
+
+            if *side == BracketSide::GrandFinal {
+                if round_number == 2 && round_positions.len() < 2 {
+                    return Err(PawnError::InvalidInput(
+                        "The grand final has already been decided".to_string(),
+                    ));
+                }
+                // Position 1 holds the winners-bracket champion
+                if round_number == 1 && winner.position_number == 2 && bracket.bracket_reset {
+                    next_positions.push(new_position(BracketSide::GrandFinal, 2, 1, loser));
+                    next_positions.push(new_position(BracketSide::GrandFinal, 2, 2, winner));
+                } else {
+                    next_positions.push(new_position(
+                        BracketSide::GrandFinal,
+                        round_number + 1,
+                        1,
+                        winner,
+                    ));
+                }
+                continue;
+            }
+
+            let (winner_slot, loser_slot) = Self::double_elimination_destinations(
+                winners_rounds,
+                side,
+                round_number,
+                match_number,
+            );
+            let (next_side, next_round, next_number) = winner_slot;
+            next_positions.push(new_position(next_side, next_round, next_number, winner));
+            if let Some((next_side, next_round, next_number)) = loser_slot {
+                next_positions.push(new_position(next_side, next_round, next_number, loser));
+            }
+        }
+
+        Ok(next_positions)
+    }
+
+    /// Slots the winner and the loser of a winners- or losers-bracket match
+    /// move on to, as (side, round, position)
+    fn double_elimination_destinations(
+        winners_rounds: i32,
+        side: &BracketSide,
+        round_number: i32,
+        match_number: i32,
+    ) -> (BracketSlot, Option<BracketSlot>) {
+        let losers_rounds = 2 * (winners_rounds - 1);
+        if *side == BracketSide::Losers {
+            let winner = if round_number == losers_rounds {
+                (BracketSide::GrandFinal, 1, 2)
+            } else if round_number % 2 == 1 {
+                (BracketSide::Losers, round_number + 1, 2 * match_number - 1)
+            } else {
+                (BracketSide::Losers, round_number + 1, match_number)
+            };
+            return (winner, None);
+        }
+
+        let winner = if round_number < winners_rounds {
+            (BracketSide::Winners, round_number + 1, match_number)
+        } else {
+            (BracketSide::GrandFinal, 1, 1)
+        };
+        let loser = if losers_rounds == 0 {
+            (BracketSide::GrandFinal, 1, 2)
+        } else if round_number == 1 {
+            (BracketSide::Losers, 1, match_number)
+        } else {
+            let matches = 1 << (winners_rounds - round_number);
+            let slot = if round_number % 2 == 0 {
+                matches + 1 - match_number
+            } else {
+                match_number
+            };
+            (BracketSide::Losers, 2 * (round_number - 1), 2 * slot)
+        };
+        (winner, Some(loser))
+    }
+
+    /// Walkovers of a double-elimination bracket padded with byes. A player
+    /// whose opponent slot can never be filled advances without playing; such
+    /// a match has no loser, so the losers-bracket player who would have met
+    /// that loser moves up a round as well. Byes lead to further byes, so this
+    /// is applied again after the new positions are stored until nothing is
+    /// left to advance.
+    pub fn double_elimination_byes(
+        bracket: &KnockoutBracket,
+        positions: &[BracketPosition],
+    ) -> Vec<BracketPosition> {
+        let winners_rounds = bracket.total_rounds;
+        let dead_slots = DeadSlots::new(winners_rounds, positions);
+        let filled = |side: &BracketSide, round: i32, position_number: i32| {
+            positions.iter().find(|p| {
+                p.bracket_side == side.to_str()
+                    && p.round_number == round
+                    && p.position_number == position_number
+                    && p.player_id.is_some()
+            })
+        };
+
+        let bracket_size = 1 << winners_rounds;
+        let rounds = [
+            (BracketSide::Winners, 1..=winners_rounds),
+            (BracketSide::Losers, 1..=2 * (winners_rounds - 1)),
+        ];
+        let mut next_positions = Vec::new();
+        for (side, side_rounds) in rounds {
+            for round in side_rounds {
+                let slots = match side {
+                    BracketSide::Losers => bracket_size >> ((round + 1) / 2),
+                    _ => bracket_size >> (round - 1),
+                };
+                for match_number in 1..=slots / 2 {
+                    let pair = [2 * match_number - 1, 2 * match_number];
+                    let player = match pair.map(|number| dead_slots.is_dead(&side, round, number)) {
+                        [false, true] => filled(&side, round, pair[0]),
+                        [true, false] => filled(&side, round, pair[1]),
+                        _ => None,
+                    };
+                    let Some(player) = player else {
+                        continue;
+                    };
+
+                    let ((next_side, next_round, next_number), _) =
+                        Self::double_elimination_destinations(
+                            winners_rounds,
+                            &side,
+                            round,
+                            match_number,
+                        );
+                    if filled(&next_side, next_round, next_number).is_none() {
+                        next_positions.push(BracketPosition {
+                            id: 0, // Will be set by database
+                            bracket_id: bracket.id,
+                            bracket_side: next_side.to_str().to_string(),
+                            round_number: next_round,
+                            position_number: next_number,
+                            player_id: player.player_id,
+                            advanced_from_position: Some(player.id),
+                            status: BracketPositionStatus::Ready.to_str().to_string(),
+                            created_at: chrono::Utc::now().to_rfc3339(),
+                        });
+                    }
+                }
+            }
+        }
+
+        next_positions
+    }
+
     /// Check if tournament is complete
     pub fn is_tournament_complete(positions: &[BracketPosition], total_rounds: i32) -> bool {
-        // Tournament is complete when we have a winner in the final round
-        positions
-            .iter()
-            .any(|p| p.round_number == total_rounds + 1 && p.player_id.is_some())
+        Self::get_tournament_winner(positions, total_rounds).is_some()
     }
 
-    /// Get tournament winner
+    /// Get tournament winner. A double-elimination champion is the only
+    /// player of the last grand-final round after the first one.
     pub fn get_tournament_winner(positions: &[BracketPosition], total_rounds: i32) -> Option<i32> {
-        positions
+        let grand_final: Vec<&BracketPosition> = positions
             .iter()
-            .find(|p| p.round_number == total_rounds + 1 && p.player_id.is_some())
-            .and_then(|p| p.player_id)
+            .filter(|p| p.bracket_side == BracketSide::GrandFinal.to_str())
+            .collect();
+        if grand_final.is_empty() {
+            return positions
+                .iter()
+                .find(|p| {
+                    p.round_number == total_rounds + 1
+                        && p.position_number == 1
+                        && p.player_id.is_some()
+                })
+                .and_then(|p| p.player_id);
+        }
+
+        let last_round = grand_final.iter().map(|p| p.round_number).max()?;
+        let last_positions: Vec<&&BracketPosition> = grand_final
+            .iter()
+            .filter(|p| p.round_number == last_round)
+            .collect();
+        match last_positions.as_slice() {
+            [champion] if last_round > 1 => champion.player_id,
+            _ => None,
+        }
+    }
+
+    /// Placings 1 to 4 decided so far. Without a third-place match both
+    /// semi-final losers share third place.
+    pub fn get_placings(positions: &[BracketPosition], total_rounds: i32) -> Vec<KnockoutPlacing> {
+        let player_at = |side: &BracketSide, round: i32, position_number: i32| {
+            positions
+                .iter()
+                .find(|p| {
+                    p.bracket_side == side.to_str()
+                        && p.round_number == round
+                        && p.position_number == position_number
+                })
+                .and_then(|p| p.player_id)
+        };
+        // Players of a round who did not reach the given slots of the next one
+        let losers_of = |side: &BracketSide, round: i32, next: &[Option<i32>]| -> Vec<i32> {
+            if next.iter().any(Option::is_none) {
+                return Vec::new();
+            }
+            positions
+                .iter()
+                .filter(|p| p.bracket_side == side.to_str() && p.round_number == round)
+                .filter_map(|p| p.player_id)
+                .filter(|id| !next.contains(&Some(*id)))
+                .collect()
+        };
+
+        let mut placings = Vec::new();
+        let mut place = |place: i32, players: Vec<i32>| {
+            placings.extend(
+                players
+                    .into_iter()
+                    .map(|player_id| KnockoutPlacing { place, player_id }),
+            );
+        };
+
+        let winners = BracketSide::Winners;
+        let grand_final = BracketSide::GrandFinal;
+        let champion = Self::get_tournament_winner(positions, total_rounds);
+        let double_elimination = positions
+            .iter()
+            .any(|p| p.bracket_side == grand_final.to_str());
+
+        if double_elimination {
+            // The grand final is decided in its last round but one
+            let last_match = positions
+                .iter()
+                .filter(|p| p.bracket_side == grand_final.to_str())
+                .map(|p| p.round_number)
+                .max()
+                .unwrap_or(1)
+                - 1;
+            if let Some(champion) = champion {
+                place(1, vec![champion]);
+                place(2, losers_of(&grand_final, last_match, &[Some(champion)]));
+            }
+
+            // Losers of the last two losers-bracket rounds
+            let losers_final = 2 * (total_rounds - 1);
+            if losers_final > 0 {
+                let losers = BracketSide::Losers;
+                place(
+                    3,
+                    losers_of(&losers, losers_final, &[player_at(&grand_final, 1, 2)]),
+                );
+                place(
+                    4,
+                    losers_of(
+                        &losers,
+                        losers_final - 1,
+                        &[player_at(&losers, losers_final, 1)],
+                    ),
+                );
+            }
+        } else {
+            let finalists = [
+                player_at(&winners, total_rounds, 1),
+                player_at(&winners, total_rounds, 2),
+            ];
+            if let Some(champion) = champion {
+                place(1, vec![champion]);
+                place(2, losers_of_pair(finalists, champion));
+            }
+
+            let third_place_match = [
+                player_at(&winners, total_rounds, 3),
+                player_at(&winners, total_rounds, 4),
+            ];
+            if third_place_match.iter().all(Option::is_some) {
+                if let Some(third) = player_at(&winners, total_rounds + 1, 2) {
+                    place(3, vec![third]);
+                    place(4, losers_of_pair(third_place_match, third));
+                }
+            } else if total_rounds >= 2 {
+                place(3, losers_of(&winners, total_rounds - 1, &finalists));
+            }
+        }
+
+        placings
     }
 
     /// Validate bracket integrity
     pub fn validate_bracket(positions: &[BracketPosition]) -> Result<(), PawnError> {
         // Check that all rounds have proper number of positions
-        let mut rounds: HashMap<i32, Vec<&BracketPosition>> = HashMap::new();
+        let mut rounds: HashMap<(&str, i32), Vec<&BracketPosition>> = HashMap::new();
 
         for position in positions {
             rounds
-                .entry(position.round_number)
+                .entry((position.bracket_side.as_str(), position.round_number))
                 .or_default()
                 .push(position);
         }
 
-        for (round_num, round_positions) in rounds.iter() {
-            let expected_count = if *round_num == 1 {
-                // First round can have any power of 2
-                let count = round_positions.len();
-                if !count.is_power_of_two() {
+        let winners = BracketSide::Winners.to_str();
+        let bracket_size = rounds.get(&(winners, 1)).map(|v| v.len()).unwrap_or(0);
+        let padded = rounds
+            .get(&(winners, 1))
+            .is_some_and(|positions| positions.iter().any(|p| p.player_id.is_none()));
+
+        for (&(side, round_num), round_positions) in rounds.iter() {
+            if let Some(player_id) = round_positions.iter().enumerate().find_map(|(i, p)| {
+                p.player_id.filter(|id| {
+                    round_positions[i + 1..]
+                        .iter()
+                        .any(|other| other.player_id == Some(*id))
+                })
+            }) {
+                return Err(PawnError::InvalidInput(format!(
+                    "Player {player_id} appears twice in round {round_num} of the {side} bracket"
+                )));
+            }
+
+            if side == winners {
+                let expected_count = if round_num == 1 {
+                    // First round can have any power of 2
+                    let count = round_positions.len();
+                    if !count.is_power_of_two() {
+                        return Err(PawnError::InvalidInput(format!(
+                            "Round {round_num} must have power of 2 positions, found {count}"
+                        )));
+                    }
+                    count
+                } else if padded {
+                    // Byes leave some slots of a padded bracket empty for good
+                    let slots = bracket_size >> (round_num - 1).clamp(0, 31);
+                    if round_positions.len() > slots {
+                        return Err(PawnError::InvalidInput(format!(
+                            "Round {round_num} has {} positions, expected at most {slots}",
+                            round_positions.len()
+                        )));
+                    }
+                    continue;
+                } else {
+                    // Subsequent rounds should have half the positions of previous round
+                    let prev_count = rounds
+                        .get(&(winners, round_num - 1))
+                        .map(|v| v.len())
+                        .unwrap_or(0);
+                    prev_count / 2
+                };
+                // The final may be played next to the third-place match
+                let third_place_final = expected_count == 2 && round_positions.len() == 4;
+
+                if round_positions.len() != expected_count && !third_place_final {
+                    return Err(PawnError::InvalidInput(format!(
+                        "Round {} has {} positions, expected {}",
+                        round_num,
+                        round_positions.len(),
+                        expected_count
+                    )));
+                }
+                continue;
+            }
+
+            // Losers-bracket and grand-final slots fill up as results come in,
+            // so only their bounds can be checked
+            let slots = if side == BracketSide::Losers.to_str() {
+                // Losers rounds come in pairs: half the bracket, then a quarter...
+                let slots = bracket_size >> ((round_num + 1) / 2).clamp(0, 31);
+                if round_num < 1 || slots < 2 {
                     return Err(PawnError::InvalidInput(format!(
-                        "Round {round_num} must have power of 2 positions, found {count}"
+                        "Losers round {round_num} does not exist for {bracket_size} players"
                     )));
                 }
-                count
+                slots
+            } else if side == BracketSide::GrandFinal.to_str() {
+                let reset_played = rounds
+                    .get(&(side, 2))
+                    .is_some_and(|positions| positions.len() == 2);
+                match round_num {
+                    1 | 2 => 2,
+                    3 if reset_played => 1,
+                    _ => {
+                        return Err(PawnError::InvalidInput(format!(
+                            "Grand final round {round_num} does not exist"
+                        )));
+                    }
+                }
             } else {
-                // Subsequent rounds should have half the positions of previous round
-                let prev_count = rounds.get(&(round_num - 1)).map(|v| v.len()).unwrap_or(0);
-                prev_count / 2
+                return Err(PawnError::InvalidInput(format!(
+                    "Unknown bracket side: {side}"
+                )));
             };
 
-            if round_positions.len() != expected_count {
+            if let Some(position) = round_positions
+                .iter()
+                .find(|p| p.position_number < 1 || p.position_number as usize > slots)
+            {
                 return Err(PawnError::InvalidInput(format!(
-                    "Round {} has {} positions, expected {}",
-                    round_num,
-                    round_positions.len(),
-                    expected_count
+                    "Position {} is outside the {slots} slots of round {round_num} of the {side} bracket",
+                    position.position_number
                 )));
             }
         }
@@ -349,6 +823,82 @@ impl KnockoutService {
     }
 }
 
+/// The player of a two-player match who is not the winner
+fn losers_of_pair(players: [Option<i32>; 2], winner: i32) -> Vec<i32> {
+    players
+        .into_iter()
+        .flatten()
+        .filter(|&id| id != winner)
+        .collect()
+}
+
+/// A slot of a double-elimination bracket as (side, round, position)
+type BracketSlot = (BracketSide, i32, i32);
+
+/// Slots of a double-elimination bracket that stay empty for good because
+/// every match feeding them was padded with byes
+struct DeadSlots {
+    winners_rounds: i32,
+    first_round_players: HashSet<i32>, // Positions of the first round with a player
+}
+
+impl DeadSlots {
+    fn new(winners_rounds: i32, positions: &[BracketPosition]) -> Self {
+        Self {
+            winners_rounds,
+            first_round_players: positions
+                .iter()
+                .filter(|p| {
+                    p.bracket_side == BracketSide::Winners.to_str()
+                        && p.round_number == 1
+                        && p.player_id.is_some()
+                })
+                .map(|p| p.position_number)
+                .collect(),
+        }
+    }
+
+    fn is_dead(&self, side: &BracketSide, round: i32, position_number: i32) -> bool {
+        match side {
+            BracketSide::Winners if round == 1 => {
+                !self.first_round_players.contains(&position_number)
+            }
+            BracketSide::Winners => self.is_empty_match(side, round - 1, position_number),
+            BracketSide::Losers if round == 1 => !self.has_loser(1, position_number),
+            // Odd rounds take the winners of the round before, match by match
+            BracketSide::Losers if round % 2 == 1 => {
+                self.is_empty_match(side, round - 1, position_number)
+            }
+            // Even rounds take a winner on odd slots and a dropped player on even ones
+            BracketSide::Losers if position_number % 2 == 1 => {
+                self.is_empty_match(side, round - 1, (position_number + 1) / 2)
+            }
+            BracketSide::Losers => {
+                let winners_round = round / 2 + 1;
+                let matches = 1 << (self.winners_rounds - winners_round);
+                let slot = position_number / 2;
+                let match_number = if winners_round % 2 == 0 {
+                    matches + 1 - slot
+                } else {
+                    slot
+                };
+                !self.has_loser(winners_round, match_number)
+            }
+            BracketSide::GrandFinal => false,
+        }
+    }
+
+    fn is_empty_match(&self, side: &BracketSide, round: i32, match_number: i32) -> bool {
+        self.is_dead(side, round, 2 * match_number - 1)
+            && self.is_dead(side, round, 2 * match_number)
+    }
+
+    fn has_loser(&self, round: i32, match_number: i32) -> bool {
+        !self.is_dead(&BracketSide::Winners, round, 2 * match_number - 1)
+            && !self.is_dead(&BracketSide::Winners, round, 2 * match_number)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -379,4 +929,415 @@ mod tests {
         assert_eq!(KnockoutService::get_seed_position(3, 8), 5);
         assert_eq!(KnockoutService::get_seed_position(4, 8), 4);
     }
+
+    fn double_bracket(total_rounds: i32, bracket_reset: bool) -> KnockoutBracket {
+        KnockoutBracket {
+            id: 1,
+            tournament_id: 1,
+            bracket_type: BracketType::DoubleElimination.to_str().to_string(),
+            total_rounds,
+            bracket_reset,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    fn position(side: BracketSide, round: i32, number: i32, player_id: i32) -> BracketPosition {
+        BracketPosition {
+            id: player_id * 100 + round,
+            bracket_id: 1,
+            bracket_side: side.to_str().to_string(),
+            round_number: round,
+            position_number: number,
+            player_id: Some(player_id),
+            advanced_from_position: None,
+            status: BracketPositionStatus::Ready.to_str().to_string(),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    fn slots(positions: &[BracketPosition]) -> Vec<(String, i32, i32, i32)> {
+        positions
+            .iter()
+            .map(|p| {
+                (
+                    p.bracket_side.clone(),
+                    p.round_number,
+                    p.position_number,
+                    p.player_id.unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    /// Play a round and store the new positions like the database would
+    fn play(
+        bracket: &KnockoutBracket,
+        positions: &mut Vec<BracketPosition>,
+        side: BracketSide,
+        round: i32,
+        results: &[(i32, i32)],
+    ) -> Vec<BracketPosition> {
+        let next =
+            KnockoutService::advance_double_elimination(bracket, &side, round, positions, results)
+                .unwrap();
+        positions.extend(next.iter().map(|p| BracketPosition {
+            id: p.player_id.unwrap() * 100 + p.round_number,
+            ..p.clone()
+        }));
+        KnockoutService::validate_bracket(positions).unwrap();
+        next
+    }
+    fn walk_over(
+        bracket: &KnockoutBracket,
+        positions: &mut Vec<BracketPosition>,
+    ) -> Vec<BracketPosition> {
+        let mut walked_over = Vec::new();
+        loop {
+            let next = KnockoutService::double_elimination_byes(bracket, positions);
+            if next.is_empty() {
+                KnockoutService::validate_bracket(positions).unwrap();
+                return walked_over;
+            }
+            positions.extend(next.iter().map(|p| BracketPosition {
+                id: p.player_id.unwrap() * 100 + p.round_number,
+                ..p.clone()
+            }));
+            walked_over.extend(next);
+        }
+    }
+
+    #[test]
+    fn test_double_elimination_with_bracket_reset() {
+        let bracket = double_bracket(2, true);
+        let mut positions: Vec<BracketPosition> = (1..=4)
+            .map(|id| position(BracketSide::Winners, 1, id, id))
+            .collect();
+
+        let next = play(
+            &bracket,
+            &mut positions,
+            BracketSide::Winners,
+            1,
+            &[(1, 2), (3, 4)],
+        );
+        assert_eq!(
+            slots(&next),
+            vec![
+                ("winners".to_string(), 2, 1, 1),
+                ("losers".to_string(), 1, 1, 2),
+                ("winners".to_string(), 2, 2, 3),
+                ("losers".to_string(), 1, 2, 4),
+            ]
+        );
+
+        play(&bracket, &mut positions, BracketSide::Losers, 1, &[(2, 4)]);
+        let next = play(&bracket, &mut positions, BracketSide::Winners, 2, &[(1, 3)]);
+        assert_eq!(
+            slots(&next),
+            vec![
+                ("grand_final".to_string(), 1, 1, 1),
+                ("losers".to_string(), 2, 2, 3),
+            ]
+        );
+        assert_eq!(
+            KnockoutService::generate_side_pairings(&BracketSide::Losers, 2, &positions).len(),
+            1
+        );
+
+        let next = play(&bracket, &mut positions, BracketSide::Losers, 2, &[(3, 2)]);
+        assert_eq!(slots(&next), vec![("grand_final".to_string(), 1, 2, 3)]);
+        assert_eq!(KnockoutService::get_tournament_winner(&positions, 2), None);
+
+        // The losers-bracket champion wins, so the grand final is replayed
+        let next = play(
+            &bracket,
+            &mut positions,
+            BracketSide::GrandFinal,
+            1,
+            &[(3, 1)],
+        );
+        assert_eq!(next.len(), 2);
+        assert!(!KnockoutService::is_tournament_complete(&positions, 2));
+
+        play(
+            &bracket,
+            &mut positions,
+            BracketSide::GrandFinal,
+            2,
+            &[(3, 1)],
+        );
+        assert_eq!(
+            KnockoutService::get_tournament_winner(&positions, 2),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_double_elimination_grand_final_without_reset() {
+        let bracket = double_bracket(1, false);
+        let mut positions = vec![
+            position(BracketSide::Winners, 1, 1, 1),
+            position(BracketSide::Winners, 1, 2, 2),
+        ];
+
+        // With two players the loser goes straight to the grand final
+        let next = play(&bracket, &mut positions, BracketSide::Winners, 1, &[(1, 2)]);
+        assert_eq!(
+            slots(&next),
+            vec![
+                ("grand_final".to_string(), 1, 1, 1),
+                ("grand_final".to_string(), 1, 2, 2),
+            ]
+        );
+
+        play(
+            &bracket,
+            &mut positions,
+            BracketSide::GrandFinal,
+            1,
+            &[(2, 1)],
+        );
+        assert_eq!(
+            KnockoutService::get_tournament_winner(&positions, 1),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_winners_bracket_losers_drop_in_reverse_order() {
+        let bracket = double_bracket(3, false);
+        let positions = vec![
+            position(BracketSide::Winners, 2, 1, 1),
+            position(BracketSide::Winners, 2, 2, 4),
+            position(BracketSide::Winners, 2, 3, 3),
+            position(BracketSide::Winners, 2, 4, 2),
+        ];
+
+        let next = KnockoutService::advance_double_elimination(
+            &bracket,
+            &BracketSide::Winners,
+            2,
+            &positions,
+            &[(1, 4), (2, 3)],
+        )
+        .unwrap();
+
+        assert_eq!(
+            slots(&next),
+            vec![
+                ("winners".to_string(), 3, 1, 1),
+                ("losers".to_string(), 2, 4, 4),
+                ("winners".to_string(), 3, 2, 2),
+                ("losers".to_string(), 2, 2, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_double_elimination_rejections() {
+        let bracket = double_bracket(2, false);
+        let positions: Vec<BracketPosition> = (1..=4)
+            .map(|id| position(BracketSide::Winners, 1, id, id))
+            .collect();
+        let advance = |side: BracketSide, round: i32, results: &[(i32, i32)]| {
+            KnockoutService::advance_double_elimination(&bracket, &side, round, &positions, results)
+        };
+
+        // Players 1 and 3 are in different matches
+        assert!(advance(BracketSide::Winners, 1, &[(1, 3)]).is_err());
+        // Player 5 is not in the bracket
+        assert!(advance(BracketSide::Winners, 1, &[(1, 5)]).is_err());
+        // Four players have two losers rounds
+        assert!(advance(BracketSide::Losers, 3, &[(1, 2)]).is_err());
+    }
+
+    #[test]
+    fn test_validate_double_elimination_bracket() {
+        let mut positions: Vec<BracketPosition> = (1..=4)
+            .map(|id| position(BracketSide::Winners, 1, id, id))
+            .collect();
+        positions.push(position(BracketSide::Losers, 1, 2, 2));
+        assert!(KnockoutService::validate_bracket(&positions).is_ok());
+
+        // Losers round 1 of a four-player bracket has two slots
+        positions.push(position(BracketSide::Losers, 1, 3, 4));
+        assert!(KnockoutService::validate_bracket(&positions).is_err());
+        positions.pop();
+
+        positions.push(position(BracketSide::Losers, 3, 1, 4));
+        assert!(KnockoutService::validate_bracket(&positions).is_err());
+        positions.pop();
+
+        positions.push(position(BracketSide::Losers, 1, 1, 2));
+        assert!(KnockoutService::validate_bracket(&positions).is_err());
+    }
+
+    fn placings(positions: &[BracketPosition], total_rounds: i32) -> Vec<(i32, i32)> {
+        KnockoutService::get_placings(positions, total_rounds)
+            .into_iter()
+            .map(|placing| (placing.place, placing.player_id))
+            .collect()
+    }
+
+    #[test]
+    fn test_third_place_match() {
+        let mut bracket = double_bracket(3, false);
+        bracket.bracket_type = BracketType::ThirdPlacePlayoff.to_str().to_string();
+        let winners = BracketSide::Winners;
+        let mut positions = vec![
+            position(winners.clone(), 2, 1, 1),
+            position(winners.clone(), 2, 2, 4),
+            position(winners.clone(), 2, 3, 3),
+            position(winners.clone(), 2, 4, 2),
+        ];
+
+        // Nothing happens before the semi-finals or for a single result
+        assert!(KnockoutService::third_place_positions(&bracket, 1, &[(1, 8), (4, 5)]).is_empty());
+        assert!(KnockoutService::third_place_positions(&bracket, 2, &[(1, 4)]).is_empty());
+
+        let semi_finals = [(1, 4), (2, 3)];
+        positions.extend(KnockoutService::advance_winners(1, 2, &semi_finals));
+        positions.extend(KnockoutService::third_place_positions(
+            &bracket,
+            2,
+            &semi_finals,
+        ));
+        assert_eq!(
+            slots(&positions[4..]),
+            vec![
+                ("winners".to_string(), 3, 1, 1),
+                ("winners".to_string(), 3, 2, 2),
+                ("winners".to_string(), 3, 3, 4),
+                ("winners".to_string(), 3, 4, 3),
+            ]
+        );
+
+        // The third-place match is paired on the board after the final
+        let pairings = KnockoutService::generate_round_pairings(1, 3, &positions);
+        assert_eq!(pairings.len(), 2);
+        assert_eq!(pairings[1].white_player.id, 4);
+        assert_eq!(placings(&positions, 3), vec![]);
+
+        positions.extend(KnockoutService::advance_winners(1, 3, &[(2, 1), (3, 4)]));
+        assert_eq!(
+            KnockoutService::get_tournament_winner(&positions, 3),
+            Some(2)
+        );
+        assert_eq!(
+            placings(&positions, 3),
+            vec![(1, 2), (2, 1), (3, 3), (4, 4)]
+        );
+
+        let mut single = bracket.clone();
+        single.bracket_type = BracketType::SingleElimination.to_str().to_string();
+        assert!(KnockoutService::third_place_positions(&single, 2, &semi_finals).is_empty());
+    }
+
+    #[test]
+    fn test_semi_final_losers_share_third_place() {
+        let mut positions: Vec<BracketPosition> = (1..=4)
+            .map(|id| position(BracketSide::Winners, 1, id, id))
+            .collect();
+        positions.extend(KnockoutService::advance_winners(1, 1, &[(1, 2), (4, 3)]));
+        assert_eq!(placings(&positions, 2), vec![(3, 2), (3, 3)]);
+
+        positions.extend(KnockoutService::advance_winners(1, 2, &[(4, 1)]));
+        assert_eq!(
+            placings(&positions, 2),
+            vec![(1, 4), (2, 1), (3, 2), (3, 3)]
+        );
+    }
+
+    #[test]
+    fn test_double_elimination_with_byes() {
+        let bracket = double_bracket(3, false);
+        let mut positions: Vec<BracketPosition> = (1..=5)
+            .map(|id| position(BracketSide::Winners, 1, id, id))
+            .collect();
+        positions.extend((6..=8).map(|number| BracketPosition {
+            player_id: None,
+            status: BracketPositionStatus::Bye.to_str().to_string(),
+            ..position(BracketSide::Winners, 1, number, 0)
+        }));
+
+        // Player 5 has no opponent in the first two winners rounds
+        assert_eq!(
+            slots(&walk_over(&bracket, &mut positions)),
+            vec![
+                ("winners".to_string(), 2, 3, 5),
+                ("winners".to_string(), 3, 2, 5),
+            ]
+        );
+
+        play(
+            &bracket,
+            &mut positions,
+            BracketSide::Winners,
+            1,
+            &[(1, 2), (3, 4)],
+        );
+        assert!(walk_over(&bracket, &mut positions).is_empty());
+        play(&bracket, &mut positions, BracketSide::Winners, 2, &[(1, 3)]);
+        play(&bracket, &mut positions, BracketSide::Losers, 1, &[(2, 4)]);
+
+        // The second round of the losers bracket has no dropped players to meet
+        assert!(
+            KnockoutService::generate_side_pairings(&BracketSide::Losers, 2, &positions).is_empty()
+        );
+        assert_eq!(
+            slots(&walk_over(&bracket, &mut positions)),
+            vec![
+                ("losers".to_string(), 3, 1, 2),
+                ("losers".to_string(), 3, 2, 3),
+            ]
+        );
+
+        play(&bracket, &mut positions, BracketSide::Winners, 3, &[(1, 5)]);
+        play(&bracket, &mut positions, BracketSide::Losers, 3, &[(3, 2)]);
+        let next = play(&bracket, &mut positions, BracketSide::Losers, 4, &[(5, 3)]);
+        assert_eq!(slots(&next), vec![("grand_final".to_string(), 1, 2, 5)]);
+
+        play(
+            &bracket,
+            &mut positions,
+            BracketSide::GrandFinal,
+            1,
+            &[(1, 5)],
+        );
+        assert_eq!(
+            KnockoutService::get_tournament_winner(&positions, 3),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_double_elimination_placings() {
+        let bracket = double_bracket(2, false);
+        let mut positions: Vec<BracketPosition> = (1..=4)
+            .map(|id| position(BracketSide::Winners, 1, id, id))
+            .collect();
+        play(
+            &bracket,
+            &mut positions,
+            BracketSide::Winners,
+            1,
+            &[(1, 2), (3, 4)],
+        );
+        play(&bracket, &mut positions, BracketSide::Losers, 1, &[(2, 4)]);
+        play(&bracket, &mut positions, BracketSide::Winners, 2, &[(1, 3)]);
+        play(&bracket, &mut positions, BracketSide::Losers, 2, &[(3, 2)]);
+        assert_eq!(placings(&positions, 2), vec![(3, 2), (4, 4)]);
+
+        play(
+            &bracket,
+            &mut positions,
+            BracketSide::GrandFinal,
+            1,
+            &[(1, 3)],
+        );
+        assert_eq!(
+            placings(&positions, 2),
+            vec![(1, 1), (2, 3), (3, 2), (4, 4)]
+        );
+    }
 }
@@ -0,0 +1,571 @@
+use crate::pawn::{
+    common::error::PawnError,
+    domain::{
+        dto::{KnockoutMatchStatus, SetKnockoutMatchFormat, StartArmageddon},
+        model::{ArmageddonColor, GameResultType, KnockoutMatchFormat, KnockoutMatchGame},
+    },
+};
+
+#[allow(dead_code)]
+pub struct KnockoutMatchService;
+
+#[allow(dead_code)]
+impl KnockoutMatchService {
+    /// Check a mini-match format before it is stored
+    pub fn validate_format(data: &SetKnockoutMatchFormat) -> Result<(), PawnError> {
+        if data.games < 1 {
+            return Err(PawnError::InvalidInput(
+                "A mini-match needs at least one game".to_string(),
+            ));
+        }
+        if let Some(stage) = data
+            .tiebreak_stages
+            .iter()
+            .position(|stage| stage.games < 1)
+        {
+            return Err(PawnError::InvalidInput(format!(
+                "Tiebreak stage {} needs at least one game",
+                stage + 1
+            )));
+        }
+        data.armageddon_color
+            .parse::<ArmageddonColor>()
+            .map_err(PawnError::InvalidInput)?;
+        Ok(())
+    }
+
+    /// Stage of the Armageddon game, after the main games and the tiebreaks
+    pub fn armageddon_stage(format: &KnockoutMatchFormat) -> i32 {
+        format.tiebreak_stages.len() as i32 + 1
+    }
+
+    /// Points of White and Black in a finished game
+    pub fn game_points(result: &str) -> Result<Option<(f32, f32)>, PawnError> {
+        match result.parse().unwrap_or(GameResultType::Ongoing) {
+            GameResultType::Ongoing => Ok(None),
+            GameResultType::WhiteWins
+            | GameResultType::BlackForfeit
+            | GameResultType::BlackDefault => Ok(Some((1.0, 0.0))),
+            GameResultType::BlackWins
+            | GameResultType::WhiteForfeit
+            | GameResultType::WhiteDefault => Ok(Some((0.0, 1.0))),
+            GameResultType::Draw => Ok(Some((0.5, 0.5))),
+            _ => Err(PawnError::InvalidInput(format!(
+                "{result} is not a valid mini-match result"
+            ))),
+        }
+    }
+
+    /// The two players of a mini-match, the first having White in its first game
+    pub fn match_players(games: &[KnockoutMatchGame]) -> Option<(i32, i32)> {
+        games
+            .iter()
+            .min_by_key(|game| (game.stage, game.game_number))
+            .map(|game| (game.white_player_id, game.black_player_id))
+    }
+
+    /// Score and state of a mini-match. A stage ends early once its leader
+    /// cannot be caught in the games left; a tied stage is followed by the
+    /// next one. In the Armageddon game a draw counts as a win for Black.
+    pub fn match_status(
+        format: &KnockoutMatchFormat,
+        match_number: i32,
+        (player1_id, player2_id): (i32, i32),
+        games: &[KnockoutMatchGame],
+    ) -> Result<KnockoutMatchStatus, PawnError> {
+        let mut status = KnockoutMatchStatus {
+            match_number,
+            player1_id,
+            player2_id,
+            player1_score: 0.0,
+            player2_score: 0.0,
+            stage: 0,
+            awaiting_armageddon: false,
+            winner_id: None,
+        };
+        let stage_games = |stage: i32| {
+            games
+                .iter()
+                .filter(move |game| game.match_number == match_number && game.stage == stage)
+        };
+        // Points of both players in a finished game
+        let points = |game: &KnockoutMatchGame| -> Result<Option<(f32, f32)>, PawnError> {
+            Ok(Self::game_points(&game.result)?.map(|(white, black)| {
+                if game.white_player_id == player1_id {
+                    (white, black)
+                } else {
+                    (black, white)
+                }
+            }))
+        };
+
+        let armageddon_stage = Self::armageddon_stage(format);
+        for stage in 0..armageddon_stage {
+            status.stage = stage;
+            let planned = if stage == 0 {
+                format.games
+            } else {
+                format.tiebreak_stages[stage as usize - 1].games
+            };
+
+            let (mut score1, mut score2, mut finished) = (0.0, 0.0, 0);
+            for game in stage_games(stage) {
+                if let Some((points1, points2)) = points(game)? {
+                    score1 += points1;
+                    score2 += points2;
+                    finished += 1;
+                }
+            }
+            status.player1_score += score1;
+            status.player2_score += score2;
+
+            let remaining = (planned - finished) as f32;
+            if score1 - score2 > remaining {
+                status.winner_id = Some(player1_id);
+                return Ok(status);
+            }
+            if score2 - score1 > remaining {
+                status.winner_id = Some(player2_id);
+                return Ok(status);
+            }
+            if remaining > 0.0 {
+                return Ok(status);
+            }
+        }
+
+        status.stage = armageddon_stage;
+        match stage_games(armageddon_stage).next() {
+            None => status.awaiting_armageddon = true,
+            Some(game) => {
+                if let Some((points1, points2)) = points(game)? {
+                    status.player1_score += points1;
+                    status.player2_score += points2;
+                    status.winner_id = Some(if points1 > points2 {
+                        player1_id
+                    } else if points2 > points1 {
+                        player2_id
+                    } else {
+                        game.black_player_id
+                    });
+                }
+            }
+        }
+
+        Ok(status)
+    }
+
+    /// Games to schedule for a mini-match: all games of the stage it has
+    /// reached, if that stage has not started yet. Colours alternate, the
+    /// first player having White in the first game of every stage.
+    pub fn next_stage_games(
+        format: &KnockoutMatchFormat,
+        status: &KnockoutMatchStatus,
+        games: &[KnockoutMatchGame],
+    ) -> Vec<KnockoutMatchGame> {
+        let started = games
+            .iter()
+            .any(|game| game.match_number == status.match_number && game.stage == status.stage);
+        if status.winner_id.is_some() || status.awaiting_armageddon || started {
+            return Vec::new();
+        }
+
+        let (planned, time_control_id) = if status.stage == 0 {
+            (format.games, format.time_control_id)
+        } else {
+            let stage = &format.tiebreak_stages[status.stage as usize - 1];
+            (stage.games, stage.time_control_id)
+        };
+
+        (1..=planned)
+            .map(|game_number| {
+                let (white, black) = if game_number % 2 == 1 {
+                    (status.player1_id, status.player2_id)
+                } else {
+                    (status.player2_id, status.player1_id)
+                };
+                Self::new_game(
+                    format,
+                    status.match_number,
+                    status.stage,
+                    game_number,
+                    (white, black),
+                    time_control_id,
+                )
+            })
+            .collect()
+    }
+
+    /// Armageddon game of a tied mini-match. With colour choice the given
+    /// player has White; with bidding the lowest bid plays Black with the
+    /// time bid, equal bids being settled by the lot in `white_player_id`.
+    pub fn armageddon_game(
+        format: &KnockoutMatchFormat,
+        status: &KnockoutMatchStatus,
+        data: &StartArmageddon,
+    ) -> Result<KnockoutMatchGame, PawnError> {
+        if !status.awaiting_armageddon {
+            return Err(PawnError::InvalidInput(format!(
+                "Match {} does not need an Armageddon game",
+                status.match_number
+            )));
+        }
+        let players = [status.player1_id, status.player2_id];
+        let opponent = |player_id: i32| {
+            if player_id == status.player1_id {
+                status.player2_id
+            } else {
+                status.player1_id
+            }
+        };
+        let check_player = |player_id: i32| {
+            if players.contains(&player_id) {
+                Ok(player_id)
+            } else {
+                Err(PawnError::InvalidInput(format!(
+                    "Player {player_id} does not play match {}",
+                    status.match_number
+                )))
+            }
+        };
+
+        let (white, black_time_minutes) = match format
+            .armageddon_color
+            .parse::<ArmageddonColor>()
+            .map_err(PawnError::InvalidInput)?
+        {
+            ArmageddonColor::Choice => {
+                let white = data.white_player_id.ok_or_else(|| {
+                    PawnError::InvalidInput("Choose the player who has White".to_string())
+                })?;
+                (check_player(white)?, None)
+            }
+            ArmageddonColor::Bidding => {
+                let bid = |player_id: i32| {
+                    data.bids
+                        .iter()
+                        .find(|bid| bid.player_id == player_id)
+                        .map(|bid| bid.minutes)
+                        .ok_or_else(|| {
+                            PawnError::InvalidInput(format!("Player {player_id} has not bid"))
+                        })
+                };
+                let (bid1, bid2) = (bid(status.player1_id)?, bid(status.player2_id)?);
+                if bid1 < 1 || bid2 < 1 {
+                    return Err(PawnError::InvalidInput(
+                        "Bids must be at least one minute".to_string(),
+                    ));
+                }
+                let black = match bid1.cmp(&bid2) {
+                    std::cmp::Ordering::Less => status.player1_id,
+                    std::cmp::Ordering::Greater => status.player2_id,
+                    std::cmp::Ordering::Equal => {
+                        let white = data.white_player_id.ok_or_else(|| {
+                            PawnError::InvalidInput(
+                                "The bids are equal; draw lots for White".to_string(),
+                            )
+                        })?;
+                        opponent(check_player(white)?)
+                    }
+                };
+                (opponent(black), Some(bid1.min(bid2)))
+            }
+        };
+
+        let mut game = Self::new_game(
+            format,
+            status.match_number,
+            status.stage,
+            1,
+            (white, opponent(white)),
+            format.armageddon_time_control_id,
+        );
+        game.black_time_minutes = black_time_minutes;
+        Ok(game)
+    }
+
+    /// Check that every (winner, loser) pair won a decided mini-match
+    pub fn check_decided(
+        format: &KnockoutMatchFormat,
+        games: &[KnockoutMatchGame],
+        game_results: &[(i32, i32)],
+    ) -> Result<(), PawnError> {
+        for &(winner_id, loser_id) in game_results {
+            let match_number = games
+                .iter()
+                .find(|game| {
+                    [game.white_player_id, game.black_player_id] == [winner_id, loser_id]
+                        || [game.white_player_id, game.black_player_id] == [loser_id, winner_id]
+                })
+                .map(|game| game.match_number)
+                .ok_or_else(|| {
+                    PawnError::InvalidInput(format!(
+                        "Players {winner_id} and {loser_id} have not played a mini-match"
+                    ))
+                })?;
+            let match_games: Vec<KnockoutMatchGame> = games
+                .iter()
+                .filter(|game| game.match_number == match_number)
+                .cloned()
+                .collect();
+            let players = Self::match_players(&match_games).unwrap_or((winner_id, loser_id));
+
+            let status = Self::match_status(format, match_number, players, &match_games)?;
+            if status.winner_id != Some(winner_id) {
+                return Err(PawnError::InvalidInput(format!(
+                    "Player {winner_id} has not won match {match_number} yet"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn new_game(
+        format: &KnockoutMatchFormat,
+        match_number: i32,
+        stage: i32,
+        game_number: i32,
+        (white_player_id, black_player_id): (i32, i32),
+        time_control_id: Option<i32>,
+    ) -> KnockoutMatchGame {
+        KnockoutMatchGame {
+            id: 0, // Will be set by database
+            bracket_id: format.bracket_id,
+            bracket_side: format.bracket_side.clone(),
+            round_number: format.round_number,
+            match_number,
+            stage,
+            game_number,
+            white_player_id,
+            black_player_id,
+            result: "*".to_string(),
+            time_control_id,
+            black_time_minutes: None,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::domain::{dto::ArmageddonBid, model::TiebreakStage};
+
+    fn create_format(armageddon_color: ArmageddonColor) -> KnockoutMatchFormat {
+        KnockoutMatchFormat {
+            id: 1,
+            bracket_id: 1,
+            bracket_side: "winners".to_string(),
+            round_number: 1,
+            games: 2,
+            time_control_id: Some(1),
+            tiebreak_stages: vec![
+                TiebreakStage {
+                    games: 2,
+                    time_control_id: Some(2),
+                },
+                TiebreakStage {
+                    games: 2,
+                    time_control_id: Some(3),
+                },
+            ],
+            armageddon_time_control_id: Some(4),
+            armageddon_color: armageddon_color.to_str().to_string(),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    /// Play the next stage of match 1 between players 1 and 2 with the given results
+    fn play_stage(
+        format: &KnockoutMatchFormat,
+        games: &mut Vec<KnockoutMatchGame>,
+        results: &[&str],
+    ) -> KnockoutMatchStatus {
+        let status = KnockoutMatchService::match_status(format, 1, (1, 2), games).unwrap();
+        let mut stage_games = KnockoutMatchService::next_stage_games(format, &status, games);
+        assert_eq!(stage_games.len(), results.len());
+        for (game, result) in stage_games.iter_mut().zip(results) {
+            game.result = result.to_string();
+        }
+        games.extend(stage_games);
+        KnockoutMatchService::match_status(format, 1, (1, 2), games).unwrap()
+    }
+
+    fn start_armageddon(white_player_id: Option<i32>, bids: Vec<(i32, i32)>) -> StartArmageddon {
+        StartArmageddon {
+            bracket_id: 1,
+            bracket_side: None,
+            round_number: 1,
+            match_number: 1,
+            white_player_id,
+            bids: bids
+                .into_iter()
+                .map(|(player_id, minutes)| ArmageddonBid { player_id, minutes })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_main_games_decide_the_match() {
+        let format = create_format(ArmageddonColor::Choice);
+        let mut games = Vec::new();
+
+        let status = play_stage(&format, &mut games, &["1-0", "1/2-1/2"]);
+        assert_eq!(status.winner_id, Some(1));
+        assert_eq!((status.player1_score, status.player2_score), (1.5, 0.5));
+        assert_eq!(games[1].white_player_id, 2);
+        assert_eq!(games[1].time_control_id, Some(1));
+        assert!(KnockoutMatchService::next_stage_games(&format, &status, &games).is_empty());
+    }
+
+    #[test]
+    fn test_ties_go_to_the_tiebreak_stages() {
+        let format = create_format(ArmageddonColor::Choice);
+        let mut games = Vec::new();
+
+        let status = play_stage(&format, &mut games, &["1-0", "1-0"]);
+        assert_eq!(status.winner_id, None);
+        assert_eq!(status.stage, 1);
+
+        // Player 2 wins the first rapid game; the second one is still to play
+        let mut status = play_stage(&format, &mut games, &["0-1", "*"]);
+        assert_eq!(status.winner_id, None);
+        assert_eq!(games[2].time_control_id, Some(2));
+
+        games[3].result = "1-0".to_string();
+        status = KnockoutMatchService::match_status(&format, 1, (1, 2), &games).unwrap();
+        assert_eq!(status.winner_id, Some(2));
+        assert_eq!((status.player1_score, status.player2_score), (1.0, 3.0));
+    }
+
+    #[test]
+    fn test_armageddon_bidding() {
+        let format = create_format(ArmageddonColor::Bidding);
+        let mut games = Vec::new();
+        play_stage(&format, &mut games, &["1/2-1/2", "1/2-1/2"]);
+        play_stage(&format, &mut games, &["1-0", "1-0"]);
+        let status = play_stage(&format, &mut games, &["0-1", "0-1"]);
+        assert!(status.awaiting_armageddon);
+        assert_eq!(status.stage, 3);
+
+        assert!(
+            KnockoutMatchService::armageddon_game(
+                &format,
+                &status,
+                &start_armageddon(None, vec![(1, 10)])
+            )
+            .is_err()
+        );
+        assert!(
+            KnockoutMatchService::armageddon_game(
+                &format,
+                &status,
+                &start_armageddon(None, vec![(1, 10), (2, 10)])
+            )
+            .is_err()
+        );
+
+        // The lower bid plays Black with the time bid
+        let mut game = KnockoutMatchService::armageddon_game(
+            &format,
+            &status,
+            &start_armageddon(None, vec![(1, 10), (2, 12)]),
+        )
+        .unwrap();
+        assert_eq!((game.white_player_id, game.black_player_id), (2, 1));
+        assert_eq!(game.black_time_minutes, Some(10));
+        assert_eq!(game.time_control_id, Some(4));
+
+        // A draw is a win for Black
+        game.result = "1/2-1/2".to_string();
+        games.push(game);
+        let status = KnockoutMatchService::match_status(&format, 1, (1, 2), &games).unwrap();
+        assert_eq!(status.winner_id, Some(1));
+        assert!(!status.awaiting_armageddon);
+    }
+
+    #[test]
+    fn test_armageddon_colour_choice() {
+        let format = create_format(ArmageddonColor::Choice);
+        let mut games = Vec::new();
+        let status = play_stage(&format, &mut games, &["1-0", "*"]);
+
+        // The match is not tied yet
+        assert!(
+            KnockoutMatchService::armageddon_game(
+                &format,
+                &status,
+                &start_armageddon(Some(1), vec![])
+            )
+            .is_err()
+        );
+
+        games[1].result = "1-0".to_string();
+        play_stage(&format, &mut games, &["1/2-1/2", "1/2-1/2"]);
+        let status = play_stage(&format, &mut games, &["1/2-1/2", "1/2-1/2"]);
+        assert!(
+            KnockoutMatchService::armageddon_game(
+                &format,
+                &status,
+                &start_armageddon(None, vec![])
+            )
+            .is_err()
+        );
+        assert!(
+            KnockoutMatchService::armageddon_game(
+                &format,
+                &status,
+                &start_armageddon(Some(3), vec![])
+            )
+            .is_err()
+        );
+        let game = KnockoutMatchService::armageddon_game(
+            &format,
+            &status,
+            &start_armageddon(Some(2), vec![]),
+        )
+        .unwrap();
+        assert_eq!((game.white_player_id, game.black_player_id), (2, 1));
+        assert_eq!(game.black_time_minutes, None);
+    }
+
+    #[test]
+    fn test_check_decided() {
+        let format = create_format(ArmageddonColor::Choice);
+        let mut games = Vec::new();
+        play_stage(&format, &mut games, &["1-0", "*"]);
+
+        assert!(KnockoutMatchService::check_decided(&format, &games, &[(1, 2)]).is_err());
+        assert!(KnockoutMatchService::check_decided(&format, &games, &[(1, 3)]).is_err());
+
+        games[1].result = "0-1".to_string();
+        assert!(KnockoutMatchService::check_decided(&format, &games, &[(1, 2)]).is_ok());
+        assert!(KnockoutMatchService::check_decided(&format, &games, &[(2, 1)]).is_err());
+    }
+
+    #[test]
+    fn test_validate_format() {
+        let mut data = SetKnockoutMatchFormat {
+            bracket_id: 1,
+            bracket_side: None,
+            round_number: 1,
+            games: 2,
+            time_control_id: None,
+            tiebreak_stages: vec![TiebreakStage {
+                games: 2,
+                time_control_id: None,
+            }],
+            armageddon_time_control_id: None,
+            armageddon_color: "bidding".to_string(),
+        };
+        assert!(KnockoutMatchService::validate_format(&data).is_ok());
+
+        data.tiebreak_stages[0].games = 0;
+        assert!(KnockoutMatchService::validate_format(&data).is_err());
+        data.tiebreak_stages[0].games = 2;
+        data.armageddon_color = "coin".to_string();
+        assert!(KnockoutMatchService::validate_format(&data).is_err());
+        data.armageddon_color = "choice".to_string();
+        data.games = 0;
+        assert!(KnockoutMatchService::validate_format(&data).is_err());
+    }
+}
@@ -0,0 +1,689 @@
+#![allow(dead_code)]
+
+use crate::pawn::{
+    common::error::PawnError,
+    domain::model::{GameResult, Pairing, Player, PlayerResult},
+    service::{
+        dutch_optimal::{half_points, higher_ranked_color, preferred_color},
+        swiss_pairing::{
+            Color, ColorPreference, FloatDirection, PairingResult, SwissPairingEngine, SwissPlayer,
+        },
+    },
+};
+use std::{cmp::Ordering, collections::HashSet};
+
+/// Search steps allowed per attempt before a bracket gives up on a pairing
+const SEARCH_LIMIT: usize = 20_000;
+
+/// Largest rating difference between the natural opponent and the transposed
+/// one when a transposition is made only to grant the due colours
+const COLOUR_TRANSPOSITION_LIMIT: i32 = 100;
+
+/// FIDE Lim System (C.04.4.3).
+///
+/// Score groups above the median group are paired from the top down, score
+/// groups below it from the bottom up, and the median group last. In every
+/// group the upper half meets the lower half; the lower half is transposed to
+/// grant the due colours and to avoid rematches before players are exchanged
+/// between the halves. Odd groups above the median float their lowest player
+/// down, odd groups below it float their highest player up.
+pub struct LimEngine {
+    swiss_engine: SwissPairingEngine,
+}
+
+/// Pairs (higher, lower) and floaters of one bracket, as positions in the
+/// ranked field
+#[derive(Debug, Default)]
+struct BracketPairing {
+    pairs: Vec<(usize, usize)>,
+    floaters: Vec<usize>,
+}
+
+impl Default for LimEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LimEngine {
+    pub fn new() -> Self {
+        Self {
+            swiss_engine: SwissPairingEngine::new(),
+        }
+    }
+
+    /// Engine that keeps the given player pairs apart
+    pub fn with_forbidden_pairs(mut self, forbidden_pairs: HashSet<(i32, i32)>) -> Self {
+        self.swiss_engine = self.swiss_engine.with_forbidden_pairs(forbidden_pairs);
+        self
+    }
+
+    pub fn generate_pairings(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+    ) -> Result<PairingResult, PawnError> {
+        tracing::info!(
+            "Starting Lim System pairing for {} players, round {}",
+            players.len(),
+            round_number
+        );
+
+        let mut ranked =
+            self.swiss_engine
+                .build_swiss_players(players, player_results, game_history)?;
+        ranked.sort_by(|a, b| {
+            b.points
+                .partial_cmp(&a.points)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| b.rating.cmp(&a.rating))
+                .then_with(|| a.player.id.cmp(&b.player.id))
+        });
+
+        let mut validation_errors = Vec::new();
+        let mut byes = Vec::new();
+
+        // The bye goes to the lowest rated player of the lowest score group who
+        // has not had one yet
+        if ranked.len() % 2 == 1 {
+            let position = ranked
+                .iter()
+                .rposition(|player| player.is_bye_eligible)
+                .unwrap_or_else(|| {
+                    let last = ranked.len() - 1;
+                    validation_errors.push(format!(
+                        "{} receives a second pairing-allocated bye",
+                        ranked[last].player.name
+                    ));
+                    last
+                });
+            byes.push(ranked.remove(position));
+        }
+
+        // The median group holds the players on half the points of the rounds played
+        let median = i64::from(round_number - 1);
+        let mut upper: Vec<Vec<usize>> = Vec::new();
+        let mut median_group: Vec<usize> = Vec::new();
+        let mut lower: Vec<Vec<usize>> = Vec::new();
+        for (position, player) in ranked.iter().enumerate() {
+            let score = half_points(player.points);
+            let groups = match score.cmp(&median) {
+                Ordering::Greater => &mut upper,
+                Ordering::Equal => {
+                    median_group.push(position);
+                    continue;
+                }
+                Ordering::Less => &mut lower,
+            };
+            match groups.last_mut() {
+                Some(group) if half_points(ranked[group[0]].points) == score => {
+                    group.push(position)
+                }
+                _ => groups.push(vec![position]),
+            }
+        }
+        // Groups below the median are paired from the bottom up
+        lower.reverse();
+
+        let mut pairs = Vec::new();
+        let mut downfloaters: Vec<usize> = Vec::new();
+        for (index, group) in upper.iter().enumerate() {
+            let bracket: Vec<usize> = downfloaters
+                .drain(..)
+                .chain(group.iter().copied())
+                .collect();
+            let next = upper.get(index + 1).unwrap_or(&median_group);
+            let bracket_pairing =
+                self.pair_bracket(&ranked, &bracket, FloatDirection::Down, next, round_number);
+            pairs.extend(bracket_pairing.pairs);
+            downfloaters = bracket_pairing.floaters;
+        }
+
+        let mut upfloaters: Vec<usize> = Vec::new();
+        for (index, group) in lower.iter().enumerate() {
+            let mut bracket: Vec<usize> =
+                upfloaters.drain(..).chain(group.iter().copied()).collect();
+            bracket.sort_unstable();
+            let next = lower.get(index + 1).unwrap_or(&median_group);
+            let bracket_pairing =
+                self.pair_bracket(&ranked, &bracket, FloatDirection::Up, next, round_number);
+            pairs.extend(bracket_pairing.pairs);
+            upfloaters = bracket_pairing.floaters;
+        }
+
+        // The median group is paired last, together with the floaters from both sides
+        let mut bracket: Vec<usize> = downfloaters
+            .into_iter()
+            .chain(median_group)
+            .chain(upfloaters)
+            .collect();
+        bracket.sort_unstable();
+        let bracket_pairing =
+            self.pair_bracket(&ranked, &bracket, FloatDirection::Down, &[], round_number);
+        pairs.extend(bracket_pairing.pairs);
+
+        // Players left over in the median group are paired relaxing the absolute criteria
+        for leftover in bracket_pairing.floaters.chunks(2) {
+            if let [higher, lower] = *leftover {
+                validation_errors.push(format!(
+                    "Absolute criteria relaxed to pair {} vs {}",
+                    ranked[higher].player.name, ranked[lower].player.name
+                ));
+                pairs.push((higher, lower));
+            }
+        }
+
+        // Boards are ordered by the higher score, then the lower score, then ranking
+        for pair in &mut pairs {
+            *pair = (pair.0.min(pair.1), pair.0.max(pair.1));
+        }
+        pairs.sort_by(|&(a_high, a_low), &(b_high, b_low)| {
+            ranked[b_high]
+                .points
+                .partial_cmp(&ranked[a_high].points)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| {
+                    ranked[b_low]
+                        .points
+                        .partial_cmp(&ranked[a_low].points)
+                        .unwrap_or(Ordering::Equal)
+                })
+                .then_with(|| a_high.cmp(&b_high))
+        });
+
+        let mut float_count = 0;
+        let pairings: Vec<Pairing> = pairs
+            .iter()
+            .enumerate()
+            .map(|(position, &(higher, lower))| {
+                if half_points(ranked[higher].points) != half_points(ranked[lower].points) {
+                    float_count += 1;
+                }
+                let board_number = position as i32 + 1;
+                let (white, black) =
+                    match higher_ranked_color(&ranked[higher], &ranked[lower], board_number) {
+                        Color::White => (&ranked[higher], &ranked[lower]),
+                        Color::Black => (&ranked[lower], &ranked[higher]),
+                    };
+                Pairing {
+                    white_player: white.player.clone(),
+                    black_player: Some(black.player.clone()),
+                    board_number,
+                    explanation: None,
+                }
+            })
+            .collect();
+
+        tracing::info!(
+            "Lim System produced {} pairings, {} floats, {} byes",
+            pairings.len(),
+            float_count,
+            byes.len()
+        );
+
+        Ok(PairingResult {
+            pairings,
+            byes,
+            float_count,
+            validation_errors,
+        })
+    }
+
+    /// Pair a bracket, floating one player in `direction` when it is odd.
+    ///
+    /// The floater is the lowest (downwards) or highest (upwards) ranked player
+    /// who lets the rest of the bracket be paired and has an opponent in the
+    /// `next` group; players who floated the same way last round are only
+    /// chosen when nobody else qualifies.
+    fn pair_bracket(
+        &self,
+        ranked: &[SwissPlayer],
+        bracket: &[usize],
+        direction: FloatDirection,
+        next: &[usize],
+        round_number: i32,
+    ) -> BracketPairing {
+        if bracket.len() % 2 == 1 {
+            let mut candidates = bracket.to_vec();
+            if direction == FloatDirection::Down {
+                candidates.reverse();
+            }
+
+            for allow_repeat_float in [false, true] {
+                for &floater in &candidates {
+                    if !allow_repeat_float
+                        && floated_last_round(&ranked[floater], direction, round_number)
+                    {
+                        continue;
+                    }
+                    if !next.is_empty()
+                        && !next
+                            .iter()
+                            .any(|&other| compatible(&ranked[floater], &ranked[other]))
+                    {
+                        continue;
+                    }
+                    let rest: Vec<usize> = bracket
+                        .iter()
+                        .copied()
+                        .filter(|&position| position != floater)
+                        .collect();
+                    if let Some(pairs) = self.pair_halves(ranked, &rest) {
+                        return BracketPairing {
+                            pairs,
+                            floaters: vec![floater],
+                        };
+                    }
+                }
+            }
+        } else if let Some(pairs) = self.pair_halves(ranked, bracket) {
+            return BracketPairing {
+                pairs,
+                floaters: vec![],
+            };
+        }
+
+        self.pair_partially(ranked, bracket, direction)
+    }
+
+    /// Pair the upper half of an even bracket with its lower half: first by
+    /// transpositions of the lower half, then by exchanging one player of each
+    /// half, the players closest in ranking first
+    fn pair_halves(
+        &self,
+        ranked: &[SwissPlayer],
+        bracket: &[usize],
+    ) -> Option<Vec<(usize, usize)>> {
+        let half = bracket.len() / 2;
+        let (upper, lower) = bracket.split_at(half);
+        if let Some(pairs) = self.transpose(ranked, upper, lower) {
+            return Some(pairs);
+        }
+
+        let mut exchanges: Vec<(usize, usize)> = (0..half)
+            .flat_map(|i| (0..lower.len()).map(move |j| (i, j)))
+            .collect();
+        exchanges.sort_by_key(|&(i, j)| (half - i + j, std::cmp::Reverse(i)));
+
+        for (i, j) in exchanges {
+            let mut upper = upper.to_vec();
+            let mut lower = lower.to_vec();
+            std::mem::swap(&mut upper[i], &mut lower[j]);
+            upper.sort_unstable();
+            lower.sort_unstable();
+            if let Some(pairs) = self.transpose(ranked, &upper, &lower) {
+                return Some(pairs);
+            }
+        }
+
+        None
+    }
+
+    /// Transpositions of the lower half in lexicographic order. The first pass
+    /// only accepts pairings granting both due colours, transposing to
+    /// opponents rated within `COLOUR_TRANSPOSITION_LIMIT` of the natural one;
+    /// the second pass only avoids rematches and absolute colour conflicts.
+    fn transpose(
+        &self,
+        ranked: &[SwissPlayer],
+        upper: &[usize],
+        lower: &[usize],
+    ) -> Option<Vec<(usize, usize)>> {
+        for grant_colours in [true, false] {
+            let mut search = TranspositionSearch {
+                ranked,
+                upper,
+                lower,
+                grant_colours,
+                used: vec![false; lower.len()],
+                pairs: Vec::new(),
+                budget: SEARCH_LIMIT,
+            };
+            if search.search() {
+                return Some(search.pairs);
+            }
+        }
+
+        None
+    }
+
+    /// Pair as many players of the bracket as possible; the players left over
+    /// are the ones nearest to the end the bracket floats from
+    fn pair_partially(
+        &self,
+        ranked: &[SwissPlayer],
+        bracket: &[usize],
+        direction: FloatDirection,
+    ) -> BracketPairing {
+        let mut order = bracket.to_vec();
+        if direction == FloatDirection::Down {
+            order.reverse();
+        }
+
+        for pair_count in (0..=order.len() / 2).rev() {
+            let mut result = BracketPairing::default();
+            let mut budget = SEARCH_LIMIT;
+            let floats = order.len() - 2 * pair_count;
+            if self.search_partial(ranked, &order, floats, &mut result, &mut budget) {
+                result.floaters.sort_unstable();
+                return result;
+            }
+        }
+
+        BracketPairing {
+            pairs: vec![],
+            floaters: bracket.to_vec(),
+        }
+    }
+
+    /// Backtracking search pairing the first remaining player or letting it float
+    fn search_partial(
+        &self,
+        ranked: &[SwissPlayer],
+        remaining: &[usize],
+        floats: usize,
+        result: &mut BracketPairing,
+        budget: &mut usize,
+    ) -> bool {
+        let Some((&first, rest)) = remaining.split_first() else {
+            return true;
+        };
+        if *budget == 0 {
+            return false;
+        }
+        *budget -= 1;
+
+        if floats > 0 {
+            result.floaters.push(first);
+            if self.search_partial(ranked, rest, floats - 1, result, budget) {
+                return true;
+            }
+            result.floaters.pop();
+        }
+
+        for &opponent in rest.iter().rev() {
+            if !compatible(&ranked[first], &ranked[opponent]) {
+                continue;
+            }
+            let others: Vec<usize> = rest
+                .iter()
+                .copied()
+                .filter(|&position| position != opponent)
+                .collect();
+            result.pairs.push((first, opponent));
+            if self.search_partial(ranked, &others, floats, result, budget) {
+                return true;
+            }
+            result.pairs.pop();
+        }
+
+        false
+    }
+}
+
+/// State of the search pairing the upper half of a bracket with its lower half
+struct TranspositionSearch<'a> {
+    ranked: &'a [SwissPlayer],
+    upper: &'a [usize],
+    lower: &'a [usize],
+    grant_colours: bool,
+    used: Vec<bool>,
+    pairs: Vec<(usize, usize)>,
+    budget: usize,
+}
+
+impl TranspositionSearch<'_> {
+    /// Backtracking over the upper half in order; each player meets the first
+    /// unused player of the lower half it can be paired with
+    fn search(&mut self) -> bool {
+        let Some(&higher) = self.upper.get(self.pairs.len()) else {
+            return true;
+        };
+        if self.budget == 0 {
+            return false;
+        }
+        self.budget -= 1;
+
+        let Some(natural) = (0..self.lower.len()).find(|&index| !self.used[index]) else {
+            return false;
+        };
+        let natural_rating = self.ranked[self.lower[natural]].rating;
+
+        for candidate in natural..self.lower.len() {
+            if self.used[candidate] {
+                continue;
+            }
+            let (a, b) = (&self.ranked[higher], &self.ranked[self.lower[candidate]]);
+            if !compatible(a, b) {
+                continue;
+            }
+            if self.grant_colours
+                && (!colours_granted(a, b)
+                    || (b.rating - natural_rating).abs() > COLOUR_TRANSPOSITION_LIMIT)
+            {
+                continue;
+            }
+            self.used[candidate] = true;
+            self.pairs.push((higher, self.lower[candidate]));
+            if self.search() {
+                return true;
+            }
+            self.pairs.pop();
+            self.used[candidate] = false;
+        }
+
+        false
+    }
+}
+
+/// No rematches and no conflicting absolute colour preferences
+fn compatible(a: &SwissPlayer, b: &SwissPlayer) -> bool {
+    if a.opponents.contains(&b.player.id) || b.opponents.contains(&a.player.id) {
+        return false;
+    }
+
+    !matches!(
+        (a.color_preference, b.color_preference),
+        (ColorPreference::Absolute(ca), ColorPreference::Absolute(cb)) if ca == cb
+    )
+}
+
+/// Both players can receive their due colour
+fn colours_granted(a: &SwissPlayer, b: &SwissPlayer) -> bool {
+    match (
+        preferred_color(a.color_preference),
+        preferred_color(b.color_preference),
+    ) {
+        (Some(ca), Some(cb)) => ca != cb,
+        _ => true,
+    }
+}
+
+/// Whether the player floated in `direction` in the previous round
+fn floated_last_round(player: &SwissPlayer, direction: FloatDirection, round_number: i32) -> bool {
+    player.float_history.last().is_some_and(|record| {
+        record.round_number == round_number - 1 && record.direction == Some(direction)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::service::pairing_test_support::*;
+
+    #[test]
+    fn test_empty_field() {
+        let engine = LimEngine::new();
+        let result = engine.generate_pairings(vec![], vec![], vec![], 1).unwrap();
+
+        assert!(result.pairings.is_empty());
+        assert!(result.byes.is_empty());
+    }
+
+    #[test]
+    fn test_first_round_upper_half_meets_lower_half() {
+        let engine = LimEngine::new();
+        let players = create_field(8);
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.0))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 1)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 5), (2, 6), (3, 7), (4, 8)]);
+        assert_eq!(result.float_count, 0);
+
+        // Colours alternate by board in the first round
+        assert_eq!(result.pairings[0].white_player.id, 1);
+        assert_eq!(result.pairings[1].white_player.id, 6);
+    }
+
+    #[test]
+    fn test_odd_groups_float_towards_the_median() {
+        let engine = LimEngine::new();
+        let players = create_field(6);
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), if p.id <= 3 { 1.0 } else { 0.0 }))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 2)
+            .unwrap();
+
+        // The lowest player of the top group floats down, the highest player of
+        // the bottom group floats up, and they meet in the (empty) median group
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 2), (3, 4), (5, 6)]);
+        assert_eq!(result.float_count, 1);
+        assert!(result.validation_errors.is_empty());
+    }
+
+    #[test]
+    fn test_transposition_avoids_rematch() {
+        let engine = LimEngine::new();
+        let players = create_field(4);
+        let history = vec![
+            create_game_result(1, &players[0], &players[2], 1, "1/2-1/2"),
+            create_game_result(2, &players[1], &players[3], 1, "1/2-1/2"),
+        ];
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.5))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, history, 2)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 4), (2, 3)]);
+        assert!(result.validation_errors.is_empty());
+    }
+
+    /// Players 1 and 3 are due black, players 2 and 4 are due white
+    fn colour_history(players: &[Player]) -> Vec<GameResult> {
+        vec![
+            create_game_result(1, &players[0], &players[1], 1, "1/2-1/2"),
+            create_game_result(2, &players[2], &players[3], 1, "1/2-1/2"),
+            create_game_result(3, &players[0], &players[1], 2, "1/2-1/2"),
+            create_game_result(4, &players[2], &players[3], 2, "1/2-1/2"),
+        ]
+    }
+
+    #[test]
+    fn test_transposition_grants_due_colours() {
+        let engine = LimEngine::new();
+        let players = create_field(4);
+        let history = colour_history(&players);
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 1.0))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, history, 3)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 4), (2, 3)]);
+        assert_eq!(result.pairings[0].white_player.id, 4);
+        assert_eq!(result.pairings[1].white_player.id, 2);
+    }
+
+    #[test]
+    fn test_colour_transposition_limited_by_rating_difference() {
+        let engine = LimEngine::new();
+        let mut players = create_field(4);
+        players[3].rating = Some(2000);
+        let history = colour_history(&players);
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 1.0))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, history, 3)
+            .unwrap();
+
+        // Player 4 is rated more than 100 points below player 3, so the
+        // natural pairing is kept despite the colours
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 3), (2, 4)]);
+    }
+
+    #[test]
+    fn test_exchange_between_halves_when_transpositions_fail() {
+        let engine = LimEngine::new();
+        let players = create_field(4);
+        let history = vec![
+            create_game_result(1, &players[0], &players[2], 1, "1/2-1/2"),
+            create_game_result(2, &players[1], &players[3], 1, "1/2-1/2"),
+            create_game_result(3, &players[0], &players[3], 2, "1/2-1/2"),
+            create_game_result(4, &players[1], &players[2], 2, "1/2-1/2"),
+        ];
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 1.0))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, history, 3)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 2), (3, 4)]);
+        assert!(result.validation_errors.is_empty());
+    }
+
+    #[test]
+    fn test_previous_bye_receiver_is_skipped() {
+        let engine = LimEngine::new();
+        let players = create_field(3);
+        let bye = create_test_player(-1, "BYE", None);
+        let history = vec![
+            create_game_result(1, &players[0], &players[1], 1, "1-0"),
+            create_game_result(2, &players[2], &bye, 1, "1-0"),
+        ];
+        let results = vec![
+            create_test_result(players[0].clone(), 1.0),
+            create_test_result(players[1].clone(), 0.0),
+            create_test_result(players[2].clone(), 0.0),
+        ];
+
+        // Player 3 is ranked last but already had the bye
+        let result = engine
+            .generate_pairings(players, results, history, 2)
+            .unwrap();
+
+        assert_eq!(result.byes.len(), 1);
+        assert_eq!(result.byes[0].player.id, 2);
+        assert_eq!(paired_ids(&result.pairings[0]), (1, 3));
+    }
+}
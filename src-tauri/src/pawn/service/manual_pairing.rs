@@ -311,6 +311,7 @@ impl ManualPairingController {
                                 white_player: player1.clone(),
                                 black_player: Some(player2.clone()),
                                 board_number: board_counter,
+                                explanation: None,
                             };
                             board_counter += 1;
                             final_pairings.push(orphaned_pairing);
@@ -397,6 +398,7 @@ impl ManualPairingController {
             white_player: white_player.clone(),
             black_player: black_player.cloned(),
             board_number,
+            explanation: None,
         })
     }
 
@@ -640,12 +642,46 @@ impl ManualPairingController {
     /// Validate that all constraints are satisfied
     fn validate_constraints_satisfied(
         &self,
-        _pairings: &[Pairing],
-        _constraints: &[PairingConstraint],
-        _errors: &mut [ValidationError],
+        pairings: &[Pairing],
+        constraints: &[PairingConstraint],
+        errors: &mut Vec<ValidationError>,
     ) {
-        // TODO: Implement constraint validation
-        // Check that all high/critical priority constraints are met
+        // TODO: Check the remaining constraint types
+        for constraint in constraints
+            .iter()
+            .filter(|c| c.constraint_type == ConstraintType::ForbidMatch)
+        {
+            let Some(player2_id) = constraint.player2_id else {
+                continue;
+            };
+
+            for pairing in pairings {
+                let Some(ref black_player) = pairing.black_player else {
+                    continue;
+                };
+                let players = [pairing.white_player.id, black_player.id];
+                if !players.contains(&constraint.player1_id) || !players.contains(&player2_id) {
+                    continue;
+                }
+
+                errors.push(ValidationError {
+                    error_type: ValidationErrorType::ConstraintViolation,
+                    message: format!(
+                        "Forbidden pairing on board {}: {} vs {} ({})",
+                        pairing.board_number,
+                        pairing.white_player.name,
+                        black_player.name,
+                        constraint.reason
+                    ),
+                    affected_players: players.to_vec(),
+                    severity: if constraint.priority == ConstraintPriority::Critical {
+                        ErrorSeverity::Critical
+                    } else {
+                        ErrorSeverity::Major
+                    },
+                });
+            }
+        }
     }
 
     /// Validate board number assignments
@@ -709,6 +745,7 @@ mod tests {
             white_player: create_test_player(white_id, &format!("Player {white_id}")),
             black_player: Some(create_test_player(black_id, &format!("Player {black_id}"))),
             board_number: board,
+            explanation: None,
         }
     }
 
@@ -944,6 +981,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validation_forbidden_match() {
+        let controller = ManualPairingController::new();
+        let pairings = vec![create_test_pairing(2, 1, 1), create_test_pairing(3, 4, 2)];
+
+        let request = create_manual_request(
+            vec![PairingConstraint {
+                constraint_type: ConstraintType::ForbidMatch,
+                player1_id: 1,
+                player2_id: Some(2),
+                reason: "Siblings".to_string(),
+                priority: ConstraintPriority::Critical,
+                round_number: Some(1),
+            }],
+            vec![],
+        );
+        let validation = controller
+            .validate_pairings(&pairings, &[], &[], &request)
+            .unwrap();
+
+        assert!(!validation.is_valid);
+        assert_eq!(validation.errors.len(), 1);
+        assert!(matches!(
+            validation.errors[0].error_type,
+            ValidationErrorType::ConstraintViolation
+        ));
+        assert_eq!(validation.errors[0].affected_players, vec![2, 1]);
+    }
+
     #[test]
     fn test_validation_board_conflicts() {
         let controller = ManualPairingController::new();
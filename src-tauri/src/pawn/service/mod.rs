@@ -1,16 +1,38 @@
+pub mod acceleration;
+pub mod arena;
+pub mod burstein;
+pub mod dubov;
+pub mod dutch_optimal;
+pub mod event;
 pub mod export;
+pub mod fide_rating_list;
+pub mod forbidden_pairing;
 pub mod knockout;
+pub mod knockout_match;
+pub mod lim;
 pub mod manual_pairing;
+pub mod monrad;
 pub mod norm_calculation;
 pub mod pairing;
+pub mod pairing_checker;
+pub mod pairing_draft;
+pub mod pairing_explanation;
 pub mod pairing_optimizer;
+#[cfg(test)]
+pub mod pairing_test_support;
+pub mod partial_repairing;
+pub mod performance_rating;
 pub mod player;
+pub mod rating;
+pub mod rating_report;
 pub mod realtime_standings;
+pub mod registry;
 pub mod round;
 pub mod round_robin_analysis;
 pub mod round_robin_pairing;
 pub mod seeding;
 pub mod settings;
+pub mod stage;
 pub mod swiss_analysis;
 pub mod swiss_pairing;
 pub mod team;
@@ -19,4 +41,6 @@ pub mod team_scoring;
 pub mod tiebreak;
 pub mod time_control;
 pub mod tournament;
+pub mod trf;
 pub mod validation;
+pub mod weighted_matching;
@@ -0,0 +1,325 @@
+#![allow(dead_code)]
+
+use crate::pawn::{
+    common::error::PawnError,
+    domain::model::{GameResult, Pairing, Player, PlayerResult},
+    service::{
+        dutch_optimal::{half_points, higher_ranked_color},
+        swiss_pairing::{
+            Color, PairingResult, SwissPairingEngine, SwissPlayer, starting_rank_order,
+        },
+    },
+};
+use std::{cmp::Ordering, collections::HashSet};
+
+/// Search steps allowed before rematches are accepted
+const SEARCH_LIMIT: usize = 20_000;
+
+/// Monrad (Danish) System.
+///
+/// Players are ordered by score and then by starting rank, and the first
+/// unpaired player meets the next one who is not a rematch: 1–2, 3–4 and so
+/// on. Byes are selected as in the Dutch System engine.
+pub struct MonradEngine {
+    swiss_engine: SwissPairingEngine,
+}
+
+impl Default for MonradEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MonradEngine {
+    pub fn new() -> Self {
+        Self {
+            swiss_engine: SwissPairingEngine::new(),
+        }
+    }
+
+    /// Engine that keeps the given player pairs apart
+    pub fn with_forbidden_pairs(mut self, forbidden_pairs: HashSet<(i32, i32)>) -> Self {
+        self.swiss_engine = self.swiss_engine.with_forbidden_pairs(forbidden_pairs);
+        self
+    }
+
+    pub fn generate_pairings(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+    ) -> Result<PairingResult, PawnError> {
+        tracing::info!(
+            "Starting Monrad pairing for {} players, round {}",
+            players.len(),
+            round_number
+        );
+
+        let mut ranked =
+            self.swiss_engine
+                .build_swiss_players(players, player_results, game_history)?;
+        ranked.sort_by(|a, b| {
+            b.points
+                .partial_cmp(&a.points)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| starting_rank_order(&a.player, &b.player))
+        });
+
+        let mut validation_errors = Vec::new();
+        let mut byes = Vec::new();
+
+        // The bye goes to the lowest score group with a bye-eligible player
+        if ranked.len() % 2 == 1 {
+            let lowest_eligible = ranked
+                .iter()
+                .rev()
+                .find(|player| player.is_bye_eligible)
+                .map(|player| half_points(player.points));
+            let candidates: Vec<SwissPlayer> = match lowest_eligible {
+                Some(score) => ranked
+                    .iter()
+                    .filter(|player| half_points(player.points) == score)
+                    .cloned()
+                    .collect(),
+                None => ranked.clone(),
+            };
+            if let Some(bye_id) = self
+                .swiss_engine
+                .select_bye_player(&candidates)
+                .map(|player| player.player.id)
+            {
+                let position = ranked
+                    .iter()
+                    .position(|player| player.player.id == bye_id)
+                    .expect("bye player is part of the field");
+                if !ranked[position].is_bye_eligible {
+                    validation_errors.push(format!(
+                        "{} receives a second pairing-allocated bye",
+                        ranked[position].player.name
+                    ));
+                }
+                byes.push(ranked.remove(position));
+            }
+        }
+
+        let positions: Vec<usize> = (0..ranked.len()).collect();
+        let mut pairs = Vec::new();
+        let mut budget = SEARCH_LIMIT;
+        if !self.search(&ranked, &positions, &mut pairs, &mut budget) {
+            // Pair in order, accepting rematches
+            pairs = positions
+                .chunks(2)
+                .filter_map(|chunk| match *chunk {
+                    [higher, lower] => Some((higher, lower)),
+                    _ => None,
+                })
+                .collect();
+            for &(higher, lower) in &pairs {
+                if ranked[higher].opponents.contains(&ranked[lower].player.id) {
+                    validation_errors.push(format!(
+                        "Rematch accepted to pair {} vs {}",
+                        ranked[higher].player.name, ranked[lower].player.name
+                    ));
+                }
+            }
+        }
+
+        let mut float_count = 0;
+        let pairings: Vec<Pairing> = pairs
+            .iter()
+            .enumerate()
+            .map(|(position, &(higher, lower))| {
+                if half_points(ranked[higher].points) != half_points(ranked[lower].points) {
+                    float_count += 1;
+                }
+                let board_number = position as i32 + 1;
+                let (white, black) =
+                    match higher_ranked_color(&ranked[higher], &ranked[lower], board_number) {
+                        Color::White => (&ranked[higher], &ranked[lower]),
+                        Color::Black => (&ranked[lower], &ranked[higher]),
+                    };
+                Pairing {
+                    white_player: white.player.clone(),
+                    black_player: Some(black.player.clone()),
+                    board_number,
+                    explanation: None,
+                }
+            })
+            .collect();
+
+        tracing::info!(
+            "Monrad pairing produced {} pairings, {} floats, {} byes",
+            pairings.len(),
+            float_count,
+            byes.len()
+        );
+
+        Ok(PairingResult {
+            pairings,
+            byes,
+            float_count,
+            validation_errors,
+        })
+    }
+
+    /// Backtracking search pairing the first remaining player with the next
+    /// one it has not played yet
+    fn search(
+        &self,
+        ranked: &[SwissPlayer],
+        remaining: &[usize],
+        pairs: &mut Vec<(usize, usize)>,
+        budget: &mut usize,
+    ) -> bool {
+        let Some((&first, rest)) = remaining.split_first() else {
+            return true;
+        };
+        if *budget == 0 {
+            return false;
+        }
+        *budget -= 1;
+
+        for &opponent in rest {
+            if ranked[first]
+                .opponents
+                .contains(&ranked[opponent].player.id)
+            {
+                continue;
+            }
+            let others: Vec<usize> = rest
+                .iter()
+                .copied()
+                .filter(|&position| position != opponent)
+                .collect();
+            pairs.push((first, opponent));
+            if self.search(ranked, &others, pairs, budget) {
+                return true;
+            }
+            pairs.pop();
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::service::pairing_test_support::*;
+
+    #[test]
+    fn test_empty_field() {
+        let engine = MonradEngine::new();
+        let result = engine.generate_pairings(vec![], vec![], vec![], 1).unwrap();
+
+        assert!(result.pairings.is_empty());
+        assert!(result.byes.is_empty());
+    }
+
+    #[test]
+    fn test_pairs_neighbours_by_score() {
+        let engine = MonradEngine::new();
+        let players = create_field(6);
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), if p.id % 2 == 0 { 1.0 } else { 0.0 }))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 2)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(2, 4), (1, 6), (3, 5)]);
+        assert_eq!(result.float_count, 1);
+    }
+
+    #[test]
+    fn test_pairing_numbers_give_the_starting_rank() {
+        let engine = MonradEngine::new();
+        let mut players = create_field(4);
+        for (player, number) in players.iter_mut().zip([4, 3, 2, 1]) {
+            player.pairing_number = Some(number);
+        }
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.0))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, vec![], 1)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(3, 4), (1, 2)]);
+    }
+
+    #[test]
+    fn test_rematches_are_skipped() {
+        let engine = MonradEngine::new();
+        let players = create_field(4);
+        let history = vec![
+            create_game_result(1, &players[0], &players[1], 1, "1/2-1/2"),
+            create_game_result(2, &players[2], &players[3], 1, "1/2-1/2"),
+        ];
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.5))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, history, 2)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 3), (2, 4)]);
+        assert!(result.validation_errors.is_empty());
+    }
+
+    #[test]
+    fn test_backtracks_when_last_players_already_met() {
+        let engine = MonradEngine::new();
+        let players = create_field(4);
+        let history = vec![create_game_result(
+            1,
+            &players[2],
+            &players[3],
+            1,
+            "1/2-1/2",
+        )];
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.5))
+            .collect();
+
+        let result = engine
+            .generate_pairings(players, results, history, 2)
+            .unwrap();
+
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 3), (2, 4)]);
+    }
+
+    #[test]
+    fn test_bye_goes_to_lowest_score_group() {
+        let engine = MonradEngine::new();
+        let players = create_field(5);
+        let bye = create_test_player(-1, "BYE", None);
+        let history = vec![create_game_result(1, &players[4], &bye, 1, "1-0")];
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), if p.id <= 2 { 1.0 } else { 0.0 }))
+            .collect();
+
+        // Player 5 is the lowest rated but already had the bye
+        let result = engine
+            .generate_pairings(players, results, history, 2)
+            .unwrap();
+
+        assert_eq!(result.byes.len(), 1);
+        assert_eq!(result.byes[0].player.id, 4);
+        let pairs: Vec<(i32, i32)> = result.pairings.iter().map(paired_ids).collect();
+        assert_eq!(pairs, vec![(1, 2), (3, 5)]);
+    }
+}
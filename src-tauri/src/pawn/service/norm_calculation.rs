@@ -14,7 +14,10 @@ use crate::pawn::{
             StandingsCalculationResult,
         },
     },
-    service::tiebreak::TiebreakCalculator,
+    service::{
+        performance_rating::{norm_opponent_ratings, tournament_performance_rating},
+        tiebreak::TiebreakCalculator,
+    },
 };
 
 /// Parameters for checking norm requirements
@@ -80,8 +83,13 @@ impl<D: Db> NormCalculationService<D> {
         let performance_rating = if let Some(pr) = request.performance_rating {
             pr
         } else {
-            self.calculate_performance_rating(&player, &player_games, &all_players)
-                .await?
+            self.calculate_performance_rating(
+                &player,
+                &player_games,
+                &all_players,
+                &request.norm_type,
+            )
+            .await?
         };
 
         // Calculate tournament category (average rating of participants)
@@ -263,68 +271,49 @@ impl<D: Db> NormCalculationService<D> {
         })
     }
 
-    /// Calculate performance rating for a player
+    /// Calculate the norm performance rating of a player, with unrated opponents counted
+    /// as 1400 and the lowest opponent raised to the rating floor of the norm
     async fn calculate_performance_rating(
         &self,
         player: &Player,
         games: &[&Game],
         all_players: &[Player],
+        norm_type: &NormType,
     ) -> Result<i32, PawnError> {
-        if games.is_empty() {
-            return Ok(player.rating.unwrap_or(1200));
-        }
-
-        let mut total_opponent_rating = 0.0;
+        let mut opponent_ratings = Vec::new();
         let mut total_score = 0.0;
-        let mut game_count = 0;
 
         // Create player lookup map
         let player_map: HashMap<i32, &Player> = all_players.iter().map(|p| (p.id, p)).collect();
 
         for game in games {
-            if !game.result.is_empty() {
-                let (opponent_id, score) = if game.white_player_id == player.id {
-                    (
-                        game.black_player_id,
-                        self.parse_game_result_for_white(&game.result),
-                    )
-                } else {
-                    (
-                        game.white_player_id,
-                        self.parse_game_result_for_black(&game.result),
-                    )
-                };
-
-                if let Some(opponent) = player_map.get(&opponent_id) {
-                    if let Some(opponent_rating) = opponent.rating {
-                        total_opponent_rating += opponent_rating as f64;
-                        total_score += score;
-                        game_count += 1;
-                    }
-                }
+            if game.result.is_empty() || game.result == "*" {
+                continue;
             }
-        }
 
-        if game_count == 0 {
-            return Ok(player.rating.unwrap_or(1200));
-        }
+            let (opponent_id, score) = if game.white_player_id == player.id {
+                (
+                    game.black_player_id,
+                    self.parse_game_result_for_white(&game.result),
+                )
+            } else {
+                (
+                    game.white_player_id,
+                    self.parse_game_result_for_black(&game.result),
+                )
+            };
 
-        let average_opponent_rating = total_opponent_rating / game_count as f64;
-        let score_percentage = total_score / game_count as f64;
+            if let Some(opponent) = player_map.get(&opponent_id) {
+                opponent_ratings.push(opponent.rating);
+                total_score += score;
+            }
+        }
 
-        // Use standard performance rating formula
-        let performance_rating = if score_percentage >= 1.0 {
-            average_opponent_rating + 800.0
-        } else if score_percentage <= 0.0 {
-            average_opponent_rating - 800.0
-        } else {
-            // Use logistic function to convert percentage to rating difference
-            let rating_diff = 400.0 * (score_percentage / (1.0 - score_percentage)).ln()
-                / std::f64::consts::LN_10;
-            average_opponent_rating + rating_diff
-        };
+        let opponent_ratings =
+            norm_opponent_ratings(&opponent_ratings, norm_type.opponent_rating_floor());
+        let performance_rating = tournament_performance_rating(&opponent_ratings, total_score);
 
-        Ok(performance_rating.round() as i32)
+        Ok(performance_rating.unwrap_or(player.rating.unwrap_or(1200)))
     }
 
     /// Calculate tournament category (average rating)
@@ -496,6 +485,9 @@ impl<D: Db> NormCalculationService<D> {
             "Norm type: {norm_type}",
             norm_type = norm_type.display_name()
         ));
+        if let Some(floor) = norm_type.opponent_rating_floor() {
+            info.push(format!("Opponent rating floor: {floor}"));
+        }
 
         if requirements.performance_rating_met {
             info.push("✓ Performance rating requirement met".to_string());
@@ -735,6 +727,8 @@ impl<D: Db> NormCalculationService<D> {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[tokio::test]
     async fn test_norm_calculation_service_creation() {
         // Test basic service creation without dependencies
@@ -755,6 +749,102 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_norm_performance_rating_with_rating_floor() {
+        let db = Arc::new(crate::pawn::db::sqlite::SqliteDb::new(
+            sqlx::SqlitePool::connect(":memory:").await.unwrap(),
+        ));
+        let service =
+            NormCalculationService::new(db.clone(), Arc::new(TiebreakCalculator::new(db)));
+
+        let player_with = |id: i32, rating: Option<i32>| Player {
+            id,
+            tournament_id: 1,
+            name: format!("Player {id}"),
+            rating,
+            country_code: None,
+            title: None,
+            birth_date: None,
+            gender: None,
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: None,
+            initial_rating: None,
+            created_at: "2024-01-01T00:00:00".to_string(),
+            updated_at: None,
+        };
+        let opponent_ratings = [
+            Some(2500),
+            Some(2450),
+            Some(2400),
+            Some(2350),
+            Some(2300),
+            Some(2250),
+            Some(2100),
+            None,
+            Some(2000),
+        ];
+        let mut players = vec![player_with(1, Some(2400))];
+        players.extend(
+            opponent_ratings
+                .iter()
+                .enumerate()
+                .map(|(index, rating)| player_with(index as i32 + 2, *rating)),
+        );
+        let results = [
+            "1-0", "1-0", "1-0", "1-0", "1-0", "1-0", "1/2-1/2", "0-1", "0-1",
+        ];
+        let games: Vec<Game> = results
+            .iter()
+            .enumerate()
+            .map(|(index, result)| Game {
+                id: index as i32 + 1,
+                tournament_id: 1,
+                round_number: index as i32 + 1,
+                white_player_id: 1,
+                black_player_id: index as i32 + 2,
+                result: result.to_string(),
+                result_type: None,
+                result_reason: None,
+                arbiter_notes: None,
+                last_updated: None,
+                approved_by: None,
+                created_at: "2024-01-01T00:00:00".to_string(),
+            })
+            .collect();
+        let games: Vec<&Game> = games.iter().collect();
+
+        // 6.5 / 9 rounds to 0.72 (dp 166); the unrated opponent counts as 1400 and is the
+        // one raised to the floor: 20550 / 9 = 2283.3 for the GM norm
+        let gm = service
+            .calculate_performance_rating(&players[0], &games, &players, &NormType::Grandmaster)
+            .await
+            .unwrap();
+        assert_eq!(gm, 2283 + 166);
+
+        // 20400 / 9 = 2266.7 with the IM floor of 2050
+        let im = service
+            .calculate_performance_rating(
+                &players[0],
+                &games,
+                &players,
+                &NormType::InternationalMaster,
+            )
+            .await
+            .unwrap();
+        assert_eq!(im, 2267 + 166);
+
+        // No floor for FM norms: 19750 / 9 = 2194.4
+        let fm = service
+            .calculate_performance_rating(&players[0], &games, &players, &NormType::FideMaster)
+            .await
+            .unwrap();
+        assert_eq!(fm, 2194 + 166);
+    }
+
     #[tokio::test]
     async fn test_tournament_category_calculation() {
         // Test tournament category calculation constants
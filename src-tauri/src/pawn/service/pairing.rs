@@ -1,17 +1,28 @@
 use crate::pawn::{
     common::error::PawnError,
     domain::model::{GameResult, Pairing, PairingMethod, Player, PlayerResult},
+    service::acceleration::BakuAcceleration,
+    service::burstein::{BursteinEngine, BursteinIndex},
+    service::dubov::DubovEngine,
+    service::dutch_optimal::DutchOptimalEngine,
+    service::lim::LimEngine,
     service::manual_pairing::{
         ManualPairingController, ManualPairingRequest, PairingValidationResult,
     },
+    service::monrad::MonradEngine,
     service::round_robin_pairing::{RoundRobinEngine, RoundRobinType},
-    service::swiss_pairing::SwissPairingEngine,
+    service::swiss_pairing::{SwissPairingEngine, SwissPlayer},
 };
 use std::collections::{HashMap, HashSet};
 
 #[allow(dead_code)]
 pub struct PairingService {
     swiss_engine: SwissPairingEngine,
+    dutch_optimal_engine: DutchOptimalEngine,
+    burstein_engine: BursteinEngine,
+    dubov_engine: DubovEngine,
+    lim_engine: LimEngine,
+    monrad_engine: MonradEngine,
     round_robin_engine: RoundRobinEngine,
     manual_controller: ManualPairingController,
 }
@@ -28,11 +39,37 @@ impl PairingService {
     pub fn new() -> Self {
         Self {
             swiss_engine: SwissPairingEngine::new(),
+            dutch_optimal_engine: DutchOptimalEngine::new(),
+            burstein_engine: BursteinEngine::new(),
+            dubov_engine: DubovEngine::new(),
+            lim_engine: LimEngine::new(),
+            monrad_engine: MonradEngine::new(),
             round_robin_engine: RoundRobinEngine::new(),
             manual_controller: ManualPairingController::new(),
         }
     }
 
+    /// Service whose Swiss engines keep the given player pairs apart
+    pub fn with_forbidden_pairs(mut self, forbidden_pairs: HashSet<(i32, i32)>) -> Self {
+        self.swiss_engine = self
+            .swiss_engine
+            .with_forbidden_pairs(forbidden_pairs.clone());
+        self.dutch_optimal_engine = self
+            .dutch_optimal_engine
+            .with_forbidden_pairs(forbidden_pairs.clone());
+        self.burstein_engine = self
+            .burstein_engine
+            .with_forbidden_pairs(forbidden_pairs.clone());
+        self.dubov_engine = self
+            .dubov_engine
+            .with_forbidden_pairs(forbidden_pairs.clone());
+        self.lim_engine = self
+            .lim_engine
+            .with_forbidden_pairs(forbidden_pairs.clone());
+        self.monrad_engine = self.monrad_engine.with_forbidden_pairs(forbidden_pairs);
+        self
+    }
+
     pub fn generate_pairings(
         &self,
         players: Vec<Player>,
@@ -44,6 +81,34 @@ impl PairingService {
             PairingMethod::Swiss => {
                 self.generate_swiss_pairings(players, player_results, round_number)
             }
+            PairingMethod::DutchOptimal => self.generate_dutch_optimal_pairings_with_history(
+                players,
+                player_results,
+                vec![],
+                round_number,
+            ),
+            PairingMethod::Burstein => {
+                self.generate_burstein_pairings(players, player_results, vec![], round_number, None)
+            }
+            PairingMethod::Dubov => self.generate_dubov_pairings(
+                players,
+                player_results,
+                vec![],
+                round_number,
+                &HashMap::new(),
+            ),
+            PairingMethod::Lim => self.generate_lim_pairings_with_history(
+                players,
+                player_results,
+                vec![],
+                round_number,
+            ),
+            PairingMethod::Monrad => self.generate_monrad_pairings_with_history(
+                players,
+                player_results,
+                vec![],
+                round_number,
+            ),
             PairingMethod::RoundRobin => self.generate_round_robin_pairings(players, round_number),
             PairingMethod::Manual => Ok(vec![]), // Manual pairings are created by user
             PairingMethod::Knockout => Ok(vec![]), // Knockout pairings handled by KnockoutService
@@ -187,6 +252,40 @@ impl PairingService {
                 game_history,
                 round_number,
             ),
+            PairingMethod::DutchOptimal => self.generate_dutch_optimal_pairings_with_history(
+                players,
+                player_results,
+                game_history,
+                round_number,
+            ),
+            // Without an index the round is paired like a seeding round
+            PairingMethod::Burstein => self.generate_burstein_pairings(
+                players,
+                player_results,
+                game_history,
+                round_number,
+                None,
+            ),
+            // Without ARO values white seekers are ordered by rating only
+            PairingMethod::Dubov => self.generate_dubov_pairings(
+                players,
+                player_results,
+                game_history,
+                round_number,
+                &HashMap::new(),
+            ),
+            PairingMethod::Lim => self.generate_lim_pairings_with_history(
+                players,
+                player_results,
+                game_history,
+                round_number,
+            ),
+            PairingMethod::Monrad => self.generate_monrad_pairings_with_history(
+                players,
+                player_results,
+                game_history,
+                round_number,
+            ),
             PairingMethod::RoundRobin => self.generate_round_robin_pairings(players, round_number),
             PairingMethod::Manual => Ok(vec![]), // Manual pairings are created by user
             PairingMethod::Knockout => Ok(vec![]), // Knockout pairings handled by KnockoutService
@@ -283,6 +382,7 @@ impl PairingService {
                     white_player: player.clone(),
                     black_player: Some(sorted_players[j].clone()),
                     board_number,
+                    explanation: None,
                 });
                 board_number += 1;
             } else {
@@ -292,6 +392,7 @@ impl PairingService {
                     white_player: player.clone(),
                     black_player: None,
                     board_number,
+                    explanation: None,
                 });
                 paired_indices.insert(i);
                 board_number += 1;
@@ -312,13 +413,35 @@ impl PairingService {
         game_history: Vec<GameResult>,
         round_number: i32,
     ) -> Result<Vec<Pairing>, PawnError> {
-        // Use the new FIDE-compliant Dutch System
-        let pairing_result = self.swiss_engine.generate_dutch_system_pairings(
+        self.generate_accelerated_swiss_pairings(
             players,
             player_results,
             game_history,
             round_number,
-        )?;
+            None,
+        )
+    }
+
+    /// Dutch System pairings, with the virtual points of the Baku
+    /// acceleration added to the pairing scores when one is given
+    pub fn generate_accelerated_swiss_pairings(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+        acceleration: Option<&BakuAcceleration>,
+    ) -> Result<Vec<Pairing>, PawnError> {
+        // Use the new FIDE-compliant Dutch System
+        let pairing_result = self
+            .swiss_engine
+            .generate_accelerated_dutch_system_pairings(
+                players,
+                player_results,
+                game_history,
+                round_number,
+                acceleration,
+            )?;
 
         // Log any validation errors but still return pairings
         if !pairing_result.validation_errors.is_empty() {
@@ -337,6 +460,152 @@ impl PairingService {
         Ok(pairing_result.pairings)
     }
 
+    fn generate_dutch_optimal_pairings_with_history(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+    ) -> Result<Vec<Pairing>, PawnError> {
+        let pairing_result = self.dutch_optimal_engine.generate_pairings(
+            players,
+            player_results,
+            game_history,
+            round_number,
+        )?;
+
+        if !pairing_result.validation_errors.is_empty() {
+            tracing::warn!(
+                "Optimal Dutch pairing validation warnings: {:?}",
+                pairing_result.validation_errors
+            );
+        }
+
+        tracing::info!(
+            "Optimal Dutch System generated {} pairings with {} floats",
+            pairing_result.pairings.len(),
+            pairing_result.float_count
+        );
+
+        Ok(append_byes(pairing_result.pairings, pairing_result.byes))
+    }
+
+    fn generate_lim_pairings_with_history(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+    ) -> Result<Vec<Pairing>, PawnError> {
+        let pairing_result = self.lim_engine.generate_pairings(
+            players,
+            player_results,
+            game_history,
+            round_number,
+        )?;
+
+        if !pairing_result.validation_errors.is_empty() {
+            tracing::warn!(
+                "Lim pairing validation warnings: {:?}",
+                pairing_result.validation_errors
+            );
+        }
+
+        tracing::info!(
+            "Lim System generated {} pairings with {} floats",
+            pairing_result.pairings.len(),
+            pairing_result.float_count
+        );
+
+        Ok(append_byes(pairing_result.pairings, pairing_result.byes))
+    }
+
+    fn generate_monrad_pairings_with_history(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+    ) -> Result<Vec<Pairing>, PawnError> {
+        let pairing_result = self.monrad_engine.generate_pairings(
+            players,
+            player_results,
+            game_history,
+            round_number,
+        )?;
+
+        if !pairing_result.validation_errors.is_empty() {
+            tracing::warn!(
+                "Monrad pairing validation warnings: {:?}",
+                pairing_result.validation_errors
+            );
+        }
+
+        tracing::info!(
+            "Monrad System generated {} pairings with {} floats",
+            pairing_result.pairings.len(),
+            pairing_result.float_count
+        );
+
+        Ok(append_byes(pairing_result.pairings, pairing_result.byes))
+    }
+
+    /// Burstein System pairings; `index` is the Burstein index of the players
+    /// after the seeding rounds
+    pub fn generate_burstein_pairings(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+        index: Option<&BursteinIndex>,
+    ) -> Result<Vec<Pairing>, PawnError> {
+        let pairing_result = self.burstein_engine.generate_pairings(
+            players,
+            player_results,
+            game_history,
+            round_number,
+            index,
+        )?;
+
+        if !pairing_result.validation_errors.is_empty() {
+            tracing::warn!(
+                "Burstein pairing validation warnings: {:?}",
+                pairing_result.validation_errors
+            );
+        }
+
+        Ok(append_byes(pairing_result.pairings, pairing_result.byes))
+    }
+
+    /// Dubov System pairings; `aro` is the average rating of opponents of
+    /// every player so far
+    pub fn generate_dubov_pairings(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+        aro: &HashMap<i32, f64>,
+    ) -> Result<Vec<Pairing>, PawnError> {
+        let pairing_result = self.dubov_engine.generate_pairings(
+            players,
+            player_results,
+            game_history,
+            round_number,
+            aro,
+        )?;
+
+        if !pairing_result.validation_errors.is_empty() {
+            tracing::warn!(
+                "Dubov pairing validation warnings: {:?}",
+                pairing_result.validation_errors
+            );
+        }
+
+        Ok(append_byes(pairing_result.pairings, pairing_result.byes))
+    }
+
     fn generate_swiss_pairings_with_history_legacy(
         &self,
         players: Vec<Player>,
@@ -499,6 +768,7 @@ impl PairingService {
                     white_player,
                     black_player: Some(black_player),
                     board_number,
+                    explanation: None,
                 });
                 board_number += 1;
             } else {
@@ -512,6 +782,7 @@ impl PairingService {
                     white_player: player.clone(),
                     black_player: None,
                     board_number,
+                    explanation: None,
                 });
                 paired_indices.insert(i);
                 board_number += 1;
@@ -638,6 +909,7 @@ impl PairingService {
                         white_player: real_player.clone(),
                         black_player: None,
                         board_number,
+                        explanation: None,
                     });
                     board_number += 1;
                 }
@@ -655,6 +927,7 @@ impl PairingService {
                 white_player: white,
                 black_player: Some(black),
                 board_number,
+                explanation: None,
             });
             board_number += 1;
         }
@@ -743,6 +1016,7 @@ impl PairingService {
                 white_player,
                 black_player: Some(black_player),
                 board_number,
+                explanation: None,
             });
         }
 
@@ -756,6 +1030,21 @@ impl PairingService {
     }
 }
 
+/// Byes are appended as boards without a black player
+fn append_byes(mut pairings: Vec<Pairing>, byes: Vec<SwissPlayer>) -> Vec<Pairing> {
+    let first_bye_board = pairings.len() as i32 + 1;
+    for (board_number, bye) in (first_bye_board..).zip(byes) {
+        pairings.push(Pairing {
+            white_player: bye.player,
+            black_player: None,
+            board_number,
+            explanation: None,
+        });
+    }
+
+    pairings
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1032,6 +1321,7 @@ mod tests {
             white_player: players[0].clone(),
             black_player: Some(players[1].clone()),
             board_number: 1,
+            explanation: None,
         }];
         let game_history = vec![];
 
@@ -1065,6 +1355,105 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_generate_pairings_with_history_lim() {
+        let service = PairingService::new();
+        let players: Vec<Player> = (1..=5)
+            .map(|id| create_test_player(id, &format!("Player {id}"), 1600 - id * 50, 1))
+            .collect();
+        let player_results = players
+            .iter()
+            .map(|player| create_test_player_result(player.clone(), 0.0))
+            .collect();
+
+        let pairings = service
+            .generate_pairings_with_history(
+                players.clone(),
+                player_results,
+                vec![],
+                1,
+                &PairingMethod::Lim,
+            )
+            .unwrap();
+
+        // Two boards and the bye for the lowest rated player
+        assert_eq!(pairings.len(), 3);
+        assert_eq!(pairings[2].white_player.id, 5);
+        assert!(pairings[2].black_player.is_none());
+
+        let validation = service
+            .quick_validate_pairings(&pairings, &players, &[], 1, 1)
+            .unwrap();
+        assert!(validation.is_valid);
+    }
+
+    #[test]
+    fn test_generate_pairings_with_history_monrad() {
+        let service = PairingService::new();
+        let players: Vec<Player> = (1..=4)
+            .map(|id| create_test_player(id, &format!("Player {id}"), 1600 - id * 50, 1))
+            .collect();
+        let player_results = players
+            .iter()
+            .map(|player| create_test_player_result(player.clone(), 0.0))
+            .collect();
+
+        let pairings = service
+            .generate_pairings_with_history(
+                players,
+                player_results,
+                vec![],
+                1,
+                &PairingMethod::Monrad,
+            )
+            .unwrap();
+
+        // 1-2 and 3-4 in starting rank order
+        let boards: Vec<(i32, i32)> = pairings
+            .iter()
+            .map(|p| {
+                let (white, black) = (p.white_player.id, p.black_player.as_ref().unwrap().id);
+                (white.min(black), white.max(black))
+            })
+            .collect();
+        assert_eq!(boards, vec![(1, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn test_forbidden_pairs_apply_to_every_swiss_method() {
+        let players: Vec<Player> = (1..=4)
+            .map(|id| create_test_player(id, &format!("Player {id}"), 1600 - id * 50, 1))
+            .collect();
+
+        for method in [
+            PairingMethod::Swiss,
+            PairingMethod::DutchOptimal,
+            PairingMethod::Burstein,
+            PairingMethod::Dubov,
+            PairingMethod::Lim,
+            PairingMethod::Monrad,
+        ] {
+            let service = PairingService::new().with_forbidden_pairs(HashSet::from([(1, 3)]));
+            let player_results = players
+                .iter()
+                .map(|player| create_test_player_result(player.clone(), 0.0))
+                .collect();
+
+            let pairings = service
+                .generate_pairings(players.clone(), player_results, 1, &method)
+                .unwrap();
+
+            assert_eq!(pairings.len(), 2);
+            assert!(
+                pairings.iter().all(|p| {
+                    let (white, black) = (p.white_player.id, p.black_player.as_ref().unwrap().id);
+                    (white.min(black), white.max(black)) != (1, 3)
+                }),
+                "{method:?} paired a forbidden pair"
+            );
+        }
+    }
+
     #[test]
     fn test_generate_round_robin_pairings_insufficient_players() {
         // Red: Test round robin with less than 2 players
@@ -0,0 +1,912 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::pawn::{
+    common::error::PawnError,
+    domain::{
+        dto::{
+            ImportTournament, PairingCheckReport, PairingDifferenceDto, PairingErrorDto,
+            PairingSuggestionDto, PairingValidationResults, PairingWarningDto, RoundPairingCheck,
+        },
+        model::{
+            ByeRequest, ForbiddenPairing, Game, GameResult, GameResultType, Pairing, Player,
+            PlayerResult,
+        },
+    },
+    service::{
+        acceleration::BakuAcceleration,
+        forbidden_pairing::{forbidden_pair_constraints, forbidden_pairs},
+        manual_pairing::{ManualPairingController, ManualPairingRequest, PairingValidationResult},
+        pairing_explanation::floated_last_round,
+        round::{bye_player_id, virtual_bye_player},
+        swiss_pairing::{
+            Color, ColorPreference, FloatDirection, PlayerPairingHistory, SwissPairingEngine,
+        },
+    },
+};
+
+/// Pairing checker: replays stored rounds through the Dutch System engine and
+/// explains every board where the stored pairing differs from the engine's,
+/// citing the C.04.3 criterion involved
+pub struct PairingChecker {
+    engine: SwissPairingEngine,
+    validator: ManualPairingController,
+}
+
+impl Default for PairingChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// State of the tournament before the checked round is paired
+struct RoundContext {
+    histories: HashMap<i32, PlayerPairingHistory>,
+    scores: HashMap<i32, f32>,
+    /// Opponent (None for a bye) and colour each player receives from the engine
+    engine_boards: HashMap<i32, (Option<i32>, Color)>,
+    names: HashMap<i32, String>,
+    /// Player pairs that must not meet in the round
+    forbidden: HashSet<(i32, i32)>,
+    round_number: i32,
+}
+
+impl PairingChecker {
+    pub fn new() -> Self {
+        Self {
+            engine: SwissPairingEngine::new(),
+            validator: ManualPairingController::new(),
+        }
+    }
+
+    /// Check every round of a tournament that has stored games
+    pub fn check_tournament(
+        &self,
+        tournament_id: i32,
+        tournament_name: &str,
+        players: &[Player],
+        games: &[Game],
+        restrictions: &[ForbiddenPairing],
+        bye_requests: &[ByeRequest],
+        acceleration: Option<&BakuAcceleration>,
+    ) -> Result<PairingCheckReport, PawnError> {
+        let mut round_numbers: Vec<i32> = games.iter().map(|game| game.round_number).collect();
+        round_numbers.sort_unstable();
+        round_numbers.dedup();
+
+        let rounds = round_numbers
+            .into_iter()
+            .map(|round_number| {
+                self.check_round(
+                    tournament_id,
+                    round_number,
+                    players,
+                    games,
+                    restrictions,
+                    bye_requests,
+                    acceleration,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PairingCheckReport {
+            tournament_name: tournament_name.to_string(),
+            rounds_checked: rounds.len() as i32,
+            rounds_matching: rounds.iter().filter(|round| round.matches_engine).count() as i32,
+            rounds,
+        })
+    }
+
+    /// Replay one round: the engine pairs the players who took part in it, using
+    /// only the games of earlier rounds as history and keeping apart the players
+    /// of the forbidden pairings in force. Players with a requested bye sit the
+    /// round out, so their bye boards are not compared. Accelerated rounds are
+    /// replayed with the virtual points of the tournament's Baku acceleration.
+    pub fn check_round(
+        &self,
+        tournament_id: i32,
+        round_number: i32,
+        players: &[Player],
+        games: &[Game],
+        restrictions: &[ForbiddenPairing],
+        bye_requests: &[ByeRequest],
+        acceleration: Option<&BakuAcceleration>,
+    ) -> Result<RoundPairingCheck, PawnError> {
+        let player_map: HashMap<i32, &Player> =
+            players.iter().map(|player| (player.id, player)).collect();
+        let lookup = |player_id: i32| {
+            if player_id == bye_player_id(tournament_id) {
+                Some(virtual_bye_player(tournament_id))
+            } else {
+                player_map.get(&player_id).map(|player| (*player).clone())
+            }
+        };
+
+        let history: Vec<GameResult> = games
+            .iter()
+            .filter(|game| game.round_number < round_number)
+            .filter_map(|game| {
+                Some(GameResult {
+                    game: game.clone(),
+                    white_player: lookup(game.white_player_id)?,
+                    black_player: lookup(game.black_player_id)?,
+                })
+            })
+            .collect();
+
+        let mut round_games: Vec<&Game> = games
+            .iter()
+            .filter(|game| game.round_number == round_number)
+            .collect();
+        round_games.sort_by_key(|game| game.id);
+
+        let mut stored_pairings = Vec::new();
+        for (board_number, game) in (1..).zip(&round_games) {
+            let Some(white_player) = player_map.get(&game.white_player_id) else {
+                continue;
+            };
+            stored_pairings.push(Pairing {
+                white_player: (*white_player).clone(),
+                black_player: player_map
+                    .get(&game.black_player_id)
+                    .map(|player| (*player).clone()),
+                board_number,
+                explanation: None,
+            });
+        }
+
+        // Players absent from the round are not paired by the engine either
+        let requested_bye_ids: HashSet<i32> = bye_requests
+            .iter()
+            .filter(|bye| bye.round_number == round_number)
+            .map(|bye| bye.player_id)
+            .collect();
+        let paired: Vec<Pairing> = stored_pairings
+            .iter()
+            .filter(|pairing| {
+                pairing.black_player.is_some()
+                    || !requested_bye_ids.contains(&pairing.white_player.id)
+            })
+            .cloned()
+            .collect();
+        let participants: Vec<Player> = paired
+            .iter()
+            .flat_map(|pairing| {
+                std::iter::once(pairing.white_player.clone()).chain(pairing.black_player.clone())
+            })
+            .collect();
+        let player_results = player_results(&participants, &history);
+        let scores: HashMap<i32, f32> = player_results
+            .iter()
+            .map(|result| (result.player.id, result.points))
+            .collect();
+
+        let forbidden = forbidden_pairs(restrictions, round_number);
+        let result = SwissPairingEngine::new()
+            .with_forbidden_pairs(forbidden.clone())
+            .generate_accelerated_dutch_system_pairings(
+                participants.clone(),
+                player_results,
+                history.clone(),
+                round_number,
+                acceleration,
+            )?;
+        let mut engine_pairings = result.pairings;
+        let first_bye_board = engine_pairings.len() as i32 + 1;
+        for (board_number, bye) in (first_bye_board..).zip(result.byes) {
+            engine_pairings.push(Pairing {
+                white_player: bye.player,
+                black_player: None,
+                board_number,
+                explanation: None,
+            });
+        }
+
+        let participant_ids: Vec<i32> = participants.iter().map(|player| player.id).collect();
+        let context = RoundContext {
+            histories: self
+                .engine
+                .reconstruct_pairing_histories(&participant_ids, &history),
+            scores,
+            engine_boards: engine_boards(&engine_pairings),
+            names: participants
+                .iter()
+                .map(|player| (player.id, player.name.clone()))
+                .collect(),
+            forbidden,
+            round_number,
+        };
+
+        let differences: Vec<PairingDifferenceDto> = paired
+            .iter()
+            .filter(|pairing| {
+                let black_id = pairing.black_player.as_ref().map(|player| player.id);
+                context.engine_boards.get(&pairing.white_player.id)
+                    != Some(&(black_id, Color::White))
+            })
+            .map(|pairing| self.explain_difference(pairing, &engine_pairings, &context))
+            .collect();
+
+        let validation = self.validator.validate_pairings(
+            &paired,
+            &participants,
+            &history,
+            &ManualPairingRequest {
+                tournament_id,
+                round_number,
+                forced_pairings: vec![],
+                constraints: forbidden_pair_constraints(restrictions, round_number),
+                color_constraints: vec![],
+                apply_to_remaining: false,
+            },
+        )?;
+
+        Ok(RoundPairingCheck {
+            round_number,
+            matches_engine: differences.is_empty(),
+            stored_pairings,
+            engine_pairings,
+            differences,
+            validation: validation_results(validation),
+        })
+    }
+
+    /// Name the first C.04.3 criterion, in order of precedence, that the stored
+    /// pairing satisfies worse than the engine's pairing
+    fn explain_difference(
+        &self,
+        pairing: &Pairing,
+        engine_pairings: &[Pairing],
+        context: &RoundContext,
+    ) -> PairingDifferenceDto {
+        let white_id = pairing.white_player.id;
+        let black_id = pairing.black_player.as_ref().map(|player| player.id);
+        let affected_players: Vec<i32> = std::iter::once(white_id).chain(black_id).collect();
+
+        let engine_pairing = engine_pairings
+            .iter()
+            .filter(|engine| {
+                affected_players.contains(&engine.white_player.id)
+                    || engine
+                        .black_player
+                        .as_ref()
+                        .is_some_and(|player| affected_players.contains(&player.id))
+            })
+            .map(describe)
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        let (criterion, explanation) = self.criterion(white_id, black_id, context);
+
+        PairingDifferenceDto {
+            board_number: pairing.board_number,
+            stored_pairing: describe(pairing),
+            engine_pairing,
+            criterion: format!("C.04.3 {criterion}"),
+            explanation,
+            affected_players,
+        }
+    }
+
+    fn criterion(
+        &self,
+        white_id: i32,
+        black_id: Option<i32>,
+        context: &RoundContext,
+    ) -> (&'static str, String) {
+        let name = |id: i32| context.names.get(&id).cloned().unwrap_or_default();
+        let history = |id: i32| context.histories.get(&id);
+        let score = |id: i32| context.scores.get(&id).copied().unwrap_or(0.0);
+
+        let Some(black_id) = black_id else {
+            if history(white_id).is_some_and(|history| !history.is_bye_eligible) {
+                return (
+                    "C2",
+                    format!(
+                        "{} already received a bye or a point without playing",
+                        name(white_id)
+                    ),
+                );
+            }
+            let engine_bye = context
+                .engine_boards
+                .iter()
+                .find(|(_, (opponent, _))| opponent.is_none())
+                .map(|(id, _)| name(*id))
+                .unwrap_or_else(|| "nobody".to_string());
+            return (
+                "C4",
+                format!(
+                    "The engine gives the pairing-allocated bye to {engine_bye} so that {} can be paired",
+                    name(white_id)
+                ),
+            );
+        };
+
+        if history(white_id).is_some_and(|history| history.opponents.contains(&black_id)) {
+            return (
+                "C1",
+                format!(
+                    "{} and {} have already played each other",
+                    name(white_id),
+                    name(black_id)
+                ),
+            );
+        }
+
+        if context
+            .forbidden
+            .contains(&(white_id.min(black_id), white_id.max(black_id)))
+        {
+            return (
+                "C1",
+                format!(
+                    "{} and {} must not be paired under a forbidden pairing",
+                    name(white_id),
+                    name(black_id)
+                ),
+            );
+        }
+
+        let assigned = [(white_id, Color::White), (black_id, Color::Black)];
+        let preference = |id: i32| {
+            history(id)
+                .map(|history| {
+                    self.engine
+                        .calculate_color_preference(&history.color_history)
+                })
+                .unwrap_or(ColorPreference::None)
+        };
+        for (id, color) in assigned {
+            if let ColorPreference::Absolute(wanted) = preference(id)
+                && wanted != color
+            {
+                return (
+                    "C3",
+                    format!(
+                        "{} has an absolute colour preference for {}",
+                        name(id),
+                        color_name(wanted)
+                    ),
+                );
+            }
+        }
+
+        if context.engine_boards.get(&white_id) == Some(&(Some(black_id), Color::Black)) {
+            return (
+                "E",
+                format!(
+                    "The engine pairs the same players but gives White to {} under the colour allocation rules",
+                    name(black_id)
+                ),
+            );
+        }
+
+        let stored_difference = (score(white_id) - score(black_id)).abs();
+        let engine_difference = [white_id, black_id]
+            .iter()
+            .filter_map(|id| match context.engine_boards.get(id) {
+                Some((Some(opponent), _)) => Some((score(*id) - score(*opponent)).abs()),
+                _ => None,
+            })
+            .fold(0.0_f32, f32::max);
+        if stored_difference > engine_difference + f32::EPSILON {
+            return (
+                "C6",
+                format!(
+                    "Score difference of {stored_difference:.1} where the engine needs at most {engine_difference:.1}"
+                ),
+            );
+        }
+
+        for (id, color) in assigned {
+            let engine_color = context.engine_boards.get(&id).map(|(_, color)| *color);
+            match preference(id) {
+                ColorPreference::Strong(wanted)
+                    if wanted != color && engine_color == Some(wanted) =>
+                {
+                    return (
+                        "C13",
+                        format!(
+                            "{} does not get the strong colour preference for {}",
+                            name(id),
+                            color_name(wanted)
+                        ),
+                    );
+                }
+                ColorPreference::Mild(wanted)
+                    if wanted != color && engine_color == Some(wanted) =>
+                {
+                    return (
+                        "C12",
+                        format!(
+                            "{} does not get the colour preference for {}",
+                            name(id),
+                            color_name(wanted)
+                        ),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        // The higher-scored player floats down, the lower-scored one floats up
+        if score(white_id) != score(black_id) {
+            let (higher, lower) = if score(white_id) > score(black_id) {
+                (white_id, black_id)
+            } else {
+                (black_id, white_id)
+            };
+            if floated_last_round(history(higher), FloatDirection::Down, context.round_number) {
+                return (
+                    "C14",
+                    format!(
+                        "{} floats down again after a downfloat in the previous round",
+                        name(higher)
+                    ),
+                );
+            }
+            if floated_last_round(history(lower), FloatDirection::Up, context.round_number) {
+                return (
+                    "C15",
+                    format!(
+                        "{} floats up again after an upfloat in the previous round",
+                        name(lower)
+                    ),
+                );
+            }
+        }
+
+        (
+            "D",
+            "The engine reaches a different transposition or exchange within the bracket"
+                .to_string(),
+        )
+    }
+}
+
+/// Players and games of an imported TRF file, with pairing numbers as player ids
+pub fn import_to_models(import: &ImportTournament) -> (Vec<Player>, Vec<Game>) {
+    let tournament_id = 0;
+    let players = import
+        .players
+        .iter()
+        .map(|import| Player {
+            id: import.pairing_number,
+            tournament_id,
+            name: import.player.name.clone(),
+            rating: import.player.rating,
+            country_code: import.player.country_code.clone(),
+            title: import.player.title.clone(),
+            birth_date: import.player.birth_date.clone(),
+            gender: import.player.gender.clone(),
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: Some(import.pairing_number),
+            initial_rating: import.player.rating,
+            created_at: String::new(),
+            updated_at: None,
+        })
+        .collect();
+    let games = (1..)
+        .zip(&import.games)
+        .map(|(id, game)| Game {
+            id,
+            tournament_id,
+            round_number: game.round_number,
+            white_player_id: game.white_pairing_number,
+            black_player_id: game
+                .black_pairing_number
+                .unwrap_or(bye_player_id(tournament_id)),
+            result: game.result.clone(),
+            result_type: game.result_type.clone(),
+            result_reason: None,
+            arbiter_notes: None,
+            last_updated: None,
+            approved_by: None,
+            created_at: String::new(),
+        })
+        .collect();
+
+    (players, games)
+}
+
+/// Byes a TRF file shows as requested. Only half-point byes can be told apart:
+/// once imported, a full-point bye reads the same as a pairing-allocated one.
+pub fn import_bye_requests(import: &ImportTournament) -> Vec<ByeRequest> {
+    import
+        .games
+        .iter()
+        .filter(|game| game.black_pairing_number.is_none() && game.result == "1/2-1/2")
+        .map(|game| ByeRequest {
+            id: 0,
+            tournament_id: 0,
+            player_id: game.white_pairing_number,
+            round_number: game.round_number,
+            bye_points: 0.5,
+            created_at: String::new(),
+        })
+        .collect()
+}
+
+/// Scores before the round, counting byes and forfeits as stored
+pub(crate) fn player_results(players: &[Player], history: &[GameResult]) -> Vec<PlayerResult> {
+    players
+        .iter()
+        .map(|player| {
+            let mut result = PlayerResult {
+                player: player.clone(),
+                points: 0.0,
+                games_played: 0,
+                wins: 0,
+                draws: 0,
+                losses: 0,
+            };
+            for game in history {
+                let is_white = game.white_player.id == player.id;
+                if !is_white && game.black_player.id != player.id {
+                    continue;
+                }
+                let result_type: GameResultType =
+                    game.game.result.parse().unwrap_or(GameResultType::Ongoing);
+                let (white_points, black_points) = result_type.get_points();
+                let points = if is_white { white_points } else { black_points };
+                result.points += points;
+                result.games_played += 1;
+                if points >= 1.0 {
+                    result.wins += 1;
+                } else if points > 0.0 {
+                    result.draws += 1;
+                } else {
+                    result.losses += 1;
+                }
+            }
+            result
+        })
+        .collect()
+}
+
+fn engine_boards(pairings: &[Pairing]) -> HashMap<i32, (Option<i32>, Color)> {
+    let mut boards = HashMap::new();
+    for pairing in pairings {
+        let black_id = pairing.black_player.as_ref().map(|player| player.id);
+        boards.insert(pairing.white_player.id, (black_id, Color::White));
+        if let Some(black_id) = black_id {
+            boards.insert(black_id, (Some(pairing.white_player.id), Color::Black));
+        }
+    }
+    boards
+}
+
+fn describe(pairing: &Pairing) -> String {
+    match &pairing.black_player {
+        Some(black) => format!("{} - {}", pairing.white_player.name, black.name),
+        None => format!("{} - bye", pairing.white_player.name),
+    }
+}
+
+fn color_name(color: Color) -> &'static str {
+    match color {
+        Color::White => "White",
+        Color::Black => "Black",
+    }
+}
+
+/// Convert the manual pairing validation result into its transport DTO
+fn validation_results(result: PairingValidationResult) -> PairingValidationResults {
+    PairingValidationResults {
+        is_valid: result.is_valid,
+        critical_errors: result
+            .errors
+            .into_iter()
+            .map(|error| PairingErrorDto {
+                error_type: format!("{:?}", error.error_type),
+                message: error.message,
+                affected_players: error.affected_players,
+                severity: format!("{:?}", error.severity),
+            })
+            .collect(),
+        warnings: result
+            .warnings
+            .into_iter()
+            .map(|warning| PairingWarningDto {
+                warning_type: format!("{:?}", warning.warning_type),
+                message: warning.message,
+                affected_players: warning.affected_players,
+            })
+            .collect(),
+        suggestions: result
+            .suggestions
+            .into_iter()
+            .map(|suggestion| PairingSuggestionDto {
+                suggestion_type: format!("{:?}", suggestion.suggestion_type),
+                message: suggestion.message,
+                alternative_pairing: suggestion.alternative_pairing,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::service::acceleration::GroupBoundary;
+
+    fn create_test_player(id: i32, rating: i32) -> Player {
+        Player {
+            id,
+            tournament_id: 1,
+            name: format!("Player {id}"),
+            rating: Some(rating),
+            country_code: None,
+            title: None,
+            birth_date: None,
+            gender: None,
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: Some(id),
+            initial_rating: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    fn create_test_game(id: i32, round: i32, white: i32, black: i32, result: &str) -> Game {
+        Game {
+            id,
+            tournament_id: 1,
+            round_number: round,
+            white_player_id: white,
+            black_player_id: black,
+            result: result.to_string(),
+            result_type: None,
+            result_reason: None,
+            arbiter_notes: None,
+            last_updated: None,
+            approved_by: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    fn create_test_players() -> Vec<Player> {
+        vec![
+            create_test_player(1, 2400),
+            create_test_player(2, 2300),
+            create_test_player(3, 2200),
+            create_test_player(4, 2100),
+        ]
+    }
+
+    #[test]
+    fn test_engine_pairings_match_themselves() {
+        let checker = PairingChecker::new();
+        let players = create_test_players();
+        let stored = vec![
+            create_test_game(1, 1, 1, 2, "1-0"),
+            create_test_game(2, 1, 3, 4, "1-0"),
+        ];
+
+        let first = checker
+            .check_round(1, 1, &players, &stored, &[], &[], None)
+            .unwrap();
+        let replayed: Vec<Game> = (1..)
+            .zip(&first.engine_pairings)
+            .map(|(id, pairing)| {
+                let black = pairing.black_player.as_ref().map_or(-1, |player| player.id);
+                create_test_game(id, 1, pairing.white_player.id, black, "1-0")
+            })
+            .collect();
+
+        let check = checker
+            .check_round(1, 1, &players, &replayed, &[], &[], None)
+            .unwrap();
+        assert!(check.matches_engine);
+        assert!(check.differences.is_empty());
+        assert_eq!(check.stored_pairings.len(), check.engine_pairings.len());
+    }
+
+    #[test]
+    fn test_accelerated_round_is_replayed_with_virtual_points() {
+        let checker = PairingChecker::new();
+        let players: Vec<Player> = (1..=8)
+            .map(|id| create_test_player(id, 2500 - id * 50))
+            .collect();
+        let acceleration = BakuAcceleration {
+            accelerated_rounds: 2,
+            boundary: GroupBoundary::StartingRank,
+            virtual_points_first_half: 1.0,
+            virtual_points_second_half: 0.5,
+        };
+        let stored = vec![
+            create_test_game(1, 1, 1, 5, "*"),
+            create_test_game(2, 1, 6, 2, "*"),
+            create_test_game(3, 1, 3, 7, "*"),
+            create_test_game(4, 1, 8, 4, "*"),
+        ];
+
+        let first = checker
+            .check_round(1, 1, &players, &stored, &[], &[], Some(&acceleration))
+            .unwrap();
+        let accelerated: Vec<Game> = (1..)
+            .zip(&first.engine_pairings)
+            .map(|(id, pairing)| {
+                let black = pairing.black_player.as_ref().unwrap().id;
+                create_test_game(id, 1, pairing.white_player.id, black, "*")
+            })
+            .collect();
+
+        // Group A players meet each other in the first accelerated round
+        let check = checker
+            .check_round(1, 1, &players, &accelerated, &[], &[], Some(&acceleration))
+            .unwrap();
+        assert!(check.matches_engine);
+        assert!(check.engine_pairings.iter().all(|pairing| {
+            let black = pairing.black_player.as_ref().unwrap().id;
+            (pairing.white_player.id <= 4) == (black <= 4)
+        }));
+
+        // Without the acceleration the same round deviates from the Dutch System
+        let check = checker
+            .check_round(1, 1, &players, &accelerated, &[], &[], None)
+            .unwrap();
+        assert!(!check.matches_engine);
+    }
+
+    #[test]
+    fn test_rematch_is_reported_as_c1() {
+        let checker = PairingChecker::new();
+        let players = create_test_players();
+        let games = vec![
+            create_test_game(1, 1, 1, 3, "1-0"),
+            create_test_game(2, 1, 4, 2, "0-1"),
+            create_test_game(3, 2, 3, 1, "0-1"),
+            create_test_game(4, 2, 2, 4, "1-0"),
+        ];
+
+        let report = checker
+            .check_tournament(1, "Test", &players, &games, &[], &[], None)
+            .unwrap();
+        assert_eq!(report.rounds_checked, 2);
+
+        let round = &report.rounds[1];
+        assert!(!round.matches_engine);
+        let rematch = round
+            .differences
+            .iter()
+            .find(|difference| difference.affected_players.contains(&1))
+            .unwrap();
+        assert_eq!(rematch.criterion, "C.04.3 C1");
+        assert_eq!(rematch.stored_pairing, "Player 3 - Player 1");
+    }
+
+    #[test]
+    fn test_forbidden_pairing_is_reported() {
+        let checker = PairingChecker::new();
+        let players = create_test_players();
+        let games = vec![
+            create_test_game(1, 1, 1, 3, "1-0"),
+            create_test_game(2, 1, 4, 2, "0-1"),
+        ];
+        let restrictions = vec![ForbiddenPairing {
+            id: 1,
+            tournament_id: 1,
+            player_ids: vec![1, 3],
+            reason: "Siblings".to_string(),
+            first_round: None,
+            last_round: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }];
+
+        let check = checker
+            .check_round(1, 1, &players, &games, &restrictions, &[], None)
+            .unwrap();
+
+        assert!(!check.matches_engine);
+        assert!(check.engine_pairings.iter().all(|pairing| {
+            let black = pairing.black_player.as_ref().unwrap().id;
+            ![1, 3].contains(&pairing.white_player.id) || ![1, 3].contains(&black)
+        }));
+        assert_eq!(check.differences[0].criterion, "C.04.3 C1");
+        assert!(!check.validation.is_valid);
+        assert_eq!(check.validation.critical_errors.len(), 1);
+    }
+
+    #[test]
+    fn test_second_bye_is_reported_as_c2() {
+        let checker = PairingChecker::new();
+        let mut players = create_test_players();
+        players.push(create_test_player(5, 2000));
+        let games = vec![
+            create_test_game(1, 1, 1, 3, "1-0"),
+            create_test_game(2, 1, 4, 2, "0-1"),
+            create_test_game(3, 1, 5, -1, "1-0"),
+            create_test_game(4, 2, 2, 1, "1/2-1/2"),
+            create_test_game(5, 2, 3, 4, "1-0"),
+            create_test_game(6, 2, 5, -1, "1-0"),
+        ];
+
+        let check = checker
+            .check_round(1, 2, &players, &games, &[], &[], None)
+            .unwrap();
+        let bye = check
+            .differences
+            .iter()
+            .find(|difference| difference.stored_pairing == "Player 5 - bye")
+            .unwrap();
+        assert_eq!(bye.criterion, "C.04.3 C2");
+    }
+
+    #[test]
+    fn test_requested_bye_is_not_a_difference() {
+        let checker = PairingChecker::new();
+        let mut players = create_test_players();
+        players.push(create_test_player(5, 2000));
+        let bye_requests = vec![ByeRequest {
+            id: 1,
+            tournament_id: 1,
+            player_id: 1,
+            round_number: 1,
+            bye_points: 0.5,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }];
+        let bye = create_test_game(1, 1, 1, -1, "1/2-1/2");
+        let stored = vec![
+            bye.clone(),
+            create_test_game(2, 1, 2, 3, "*"),
+            create_test_game(3, 1, 4, 5, "*"),
+        ];
+
+        let first = checker
+            .check_round(1, 1, &players, &stored, &[], &bye_requests, None)
+            .unwrap();
+        let replayed: Vec<Game> = std::iter::once(bye)
+            .chain((2..).zip(&first.engine_pairings).map(|(id, pairing)| {
+                let black = pairing.black_player.as_ref().unwrap().id;
+                create_test_game(id, 1, pairing.white_player.id, black, "*")
+            }))
+            .collect();
+
+        let check = checker
+            .check_round(1, 1, &players, &replayed, &[], &bye_requests, None)
+            .unwrap();
+        assert!(check.matches_engine);
+        assert_eq!(check.engine_pairings.len(), 2);
+        assert_eq!(check.stored_pairings.len(), 3);
+
+        // Counted as a participant, the absent player would be paired
+        let check = checker
+            .check_round(1, 1, &players, &replayed, &[], &[], None)
+            .unwrap();
+        assert!(!check.matches_engine);
+    }
+
+    #[test]
+    fn test_import_to_models_maps_byes() {
+        let line = |rank: i32, rounds: &str| {
+            format!(
+                "001 {rank:>4}      {:<33} 2400{:>37}{rounds}",
+                format!("Player {rank}"),
+                ""
+            )
+        };
+        let content = [
+            "012 Test".to_string(),
+            line(1, "     2 w 1     0 - U"),
+            line(2, "     1 b 0     0 - Z"),
+        ]
+        .join("\n");
+        let parsed = crate::pawn::service::trf::parse_trf(&content).unwrap();
+
+        let (players, games) = import_to_models(&parsed.tournament);
+        assert_eq!(players.len(), 2);
+        assert_eq!(players[1].id, 2);
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[1].white_player_id, 1);
+        assert_eq!(games[1].black_player_id, bye_player_id(0));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::pawn::{
+    domain::{
+        dto::PairingDraftMetrics,
+        model::{GameResult, Pairing},
+    },
+    service::swiss_pairing::{Color, ColorPreference, SwissPairingEngine},
+};
+use std::collections::HashMap;
+
+/// Name given to a draft when the arbiter does not choose one
+pub fn default_draft_name(pairing_method: &str, avoid_same_club: bool) -> String {
+    if avoid_same_club {
+        format!("{pairing_method} (same club avoided)")
+    } else {
+        pairing_method.to_string()
+    }
+}
+
+/// Quality metrics of a draft, measured against the games played so far and
+/// the scores before the round
+pub fn draft_metrics(
+    pairings: &[Pairing],
+    game_history: &[GameResult],
+    scores: &HashMap<i32, f32>,
+) -> PairingDraftMetrics {
+    let engine = SwissPairingEngine::new();
+    let player_ids: Vec<i32> = pairings
+        .iter()
+        .flat_map(|pairing| {
+            std::iter::once(pairing.white_player.id)
+                .chain(pairing.black_player.as_ref().map(|player| player.id))
+        })
+        .collect();
+    let histories = engine.reconstruct_pairing_histories(&player_ids, game_history);
+
+    let mut metrics = PairingDraftMetrics {
+        color_violations: 0,
+        average_rating_difference: 0.0,
+        max_rating_difference: 0,
+        floats: 0,
+        rematches: 0,
+        byes: 0,
+    };
+    let mut rating_differences = Vec::new();
+
+    for pairing in pairings {
+        let Some(black) = &pairing.black_player else {
+            metrics.byes += 1;
+            continue;
+        };
+        let white = &pairing.white_player;
+
+        for (id, color) in [(white.id, Color::White), (black.id, Color::Black)] {
+            let preference = histories
+                .get(&id)
+                .map(|history| engine.calculate_color_preference(&history.color_history));
+            if let Some(ColorPreference::Absolute(wanted)) = preference
+                && wanted != color
+            {
+                metrics.color_violations += 1;
+            }
+        }
+
+        if histories
+            .get(&white.id)
+            .is_some_and(|history| history.opponents.contains(&black.id))
+        {
+            metrics.rematches += 1;
+        }
+
+        let score = |id: i32| scores.get(&id).copied().unwrap_or(0.0);
+        if score(white.id) != score(black.id) {
+            metrics.floats += 1;
+        }
+
+        if let (Some(white_rating), Some(black_rating)) = (white.rating, black.rating) {
+            rating_differences.push((white_rating - black_rating).abs());
+        }
+    }
+
+    if !rating_differences.is_empty() {
+        metrics.average_rating_difference =
+            rating_differences.iter().sum::<i32>() as f64 / rating_differences.len() as f64;
+        metrics.max_rating_difference = rating_differences.iter().copied().max().unwrap_or(0);
+    }
+
+    metrics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::domain::model::{Game, Player};
+
+    fn create_test_player(id: i32, rating: Option<i32>) -> Player {
+        Player {
+            id,
+            tournament_id: 1,
+            name: format!("Player {id}"),
+            rating,
+            country_code: None,
+            title: None,
+            birth_date: None,
+            gender: None,
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: None,
+            initial_rating: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    fn create_game(id: i32, round: i32, white: &Player, black: &Player) -> GameResult {
+        GameResult {
+            game: Game {
+                id,
+                tournament_id: 1,
+                round_number: round,
+                white_player_id: white.id,
+                black_player_id: black.id,
+                result: "1/2-1/2".to_string(),
+                result_type: None,
+                result_reason: None,
+                arbiter_notes: None,
+                last_updated: None,
+                approved_by: None,
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+            },
+            white_player: white.clone(),
+            black_player: black.clone(),
+        }
+    }
+
+    fn create_pairing(white: &Player, black: Option<&Player>, board_number: i32) -> Pairing {
+        Pairing {
+            white_player: white.clone(),
+            black_player: black.cloned(),
+            board_number,
+            explanation: None,
+        }
+    }
+
+    #[test]
+    fn test_default_draft_name() {
+        assert_eq!(default_draft_name("swiss", false), "swiss");
+        assert_eq!(
+            default_draft_name("dutch_optimal", true),
+            "dutch_optimal (same club avoided)"
+        );
+    }
+
+    #[test]
+    fn test_draft_metrics() {
+        let p1 = create_test_player(1, Some(2200));
+        let p2 = create_test_player(2, Some(2000));
+        let p3 = create_test_player(3, Some(1900));
+        let p4 = create_test_player(4, Some(1800));
+        let p5 = create_test_player(5, None);
+
+        // Player 1 had White three times, players 1 and 2 already met
+        let history = vec![
+            create_game(1, 1, &p1, &p3),
+            create_game(2, 1, &p2, &p4),
+            create_game(3, 2, &p1, &p4),
+            create_game(4, 2, &p3, &p2),
+            create_game(5, 3, &p1, &p2),
+            create_game(6, 3, &p4, &p5),
+        ];
+        let scores = HashMap::from([(1, 1.0), (2, 1.0), (3, 1.0), (4, 0.5), (5, 1.0)]);
+        let pairings = vec![
+            create_pairing(&p1, Some(&p2), 1),
+            create_pairing(&p3, Some(&p4), 2),
+            create_pairing(&p5, None, 3),
+        ];
+
+        let metrics = draft_metrics(&pairings, &history, &scores);
+
+        assert_eq!(metrics.color_violations, 1);
+        assert_eq!(metrics.rematches, 1);
+        assert_eq!(metrics.floats, 1);
+        assert_eq!(metrics.byes, 1);
+        assert_eq!(metrics.max_rating_difference, 200);
+        assert_eq!(metrics.average_rating_difference, 150.0);
+    }
+}
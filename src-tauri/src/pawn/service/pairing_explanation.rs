@@ -0,0 +1,417 @@
+use std::collections::HashMap;
+
+use crate::pawn::{
+    domain::model::{GameResult, Pairing, PairingExplanation, PlayerResult},
+    service::swiss_pairing::{
+        Color, ColorPreference, FloatDirection, PlayerPairingHistory, SwissPairingEngine,
+    },
+};
+
+/// Builds the per-board explanation of Swiss pairings: score group, floats,
+/// the colour allocation rule (C.04.3 E) and the quality criteria (C.04.3 C)
+/// that could not be met
+pub struct PairingExplainer {
+    engine: SwissPairingEngine,
+}
+
+impl Default for PairingExplainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PairingExplainer {
+    pub fn new() -> Self {
+        Self {
+            engine: SwissPairingEngine::new(),
+        }
+    }
+
+    /// Attach an explanation to every pairing, using the standings and games
+    /// before the round
+    pub fn explain_pairings(
+        &self,
+        pairings: &mut [Pairing],
+        player_results: &[PlayerResult],
+        game_history: &[GameResult],
+        round_number: i32,
+    ) {
+        let scores: HashMap<i32, f32> = player_results
+            .iter()
+            .map(|result| (result.player.id, result.points))
+            .collect();
+        let player_ids: Vec<i32> = pairings
+            .iter()
+            .flat_map(|pairing| {
+                std::iter::once(pairing.white_player.id)
+                    .chain(pairing.black_player.as_ref().map(|player| player.id))
+            })
+            .collect();
+        let histories = self
+            .engine
+            .reconstruct_pairing_histories(&player_ids, game_history);
+
+        for pairing in pairings.iter_mut() {
+            pairing.explanation = Some(self.explain(pairing, &scores, &histories, round_number));
+        }
+    }
+
+    fn explain(
+        &self,
+        pairing: &Pairing,
+        scores: &HashMap<i32, f32>,
+        histories: &HashMap<i32, PlayerPairingHistory>,
+        round_number: i32,
+    ) -> PairingExplanation {
+        let white = &pairing.white_player;
+        let white_score = scores.get(&white.id).copied().unwrap_or(0.0);
+
+        let Some(black) = &pairing.black_player else {
+            let mut relaxed_criteria = Vec::new();
+            if histories
+                .get(&white.id)
+                .is_some_and(|history| !history.is_bye_eligible)
+            {
+                relaxed_criteria.push(format!(
+                    "C2: {} already received a bye or a point without playing",
+                    white.name
+                ));
+            }
+            return PairingExplanation {
+                score_group: white_score,
+                floater_id: None,
+                float_direction: None,
+                color_rule: "Pairing-allocated bye".to_string(),
+                summary: format!(
+                    "Score group {white_score:.1}: {} receives the pairing-allocated bye",
+                    white.name
+                ),
+                relaxed_criteria,
+            };
+        };
+
+        let black_score = scores.get(&black.id).copied().unwrap_or(0.0);
+        let preference = |id: i32| {
+            histories
+                .get(&id)
+                .map(|history| {
+                    self.engine
+                        .calculate_color_preference(&history.color_history)
+                })
+                .unwrap_or(ColorPreference::None)
+        };
+        let white_preference = preference(white.id);
+        let black_preference = preference(black.id);
+        let mut relaxed_criteria = Vec::new();
+
+        if histories
+            .get(&white.id)
+            .is_some_and(|history| history.opponents.contains(&black.id))
+        {
+            relaxed_criteria.push(format!(
+                "C1: {} and {} have played each other before",
+                white.name, black.name
+            ));
+        }
+
+        // The higher-scored player floats down into the bracket of the lower one
+        let (score_group, floater_id, float_direction) = if white_score > black_score {
+            (white_score, Some(white.id), Some(FloatDirection::Down))
+        } else if black_score > white_score {
+            (black_score, Some(black.id), Some(FloatDirection::Down))
+        } else {
+            (white_score, None, None)
+        };
+        if floater_id.is_some() {
+            relaxed_criteria.push(format!(
+                "C6: score difference of {:.1}",
+                (white_score - black_score).abs()
+            ));
+            let (higher, lower) = if white_score > black_score {
+                (white, black)
+            } else {
+                (black, white)
+            };
+            if floated_last_round(
+                histories.get(&higher.id),
+                FloatDirection::Down,
+                round_number,
+            ) {
+                relaxed_criteria.push(format!(
+                    "C14: {} floats down in consecutive rounds",
+                    higher.name
+                ));
+            }
+            if floated_last_round(histories.get(&lower.id), FloatDirection::Up, round_number) {
+                relaxed_criteria.push(format!(
+                    "C15: {} floats up in consecutive rounds",
+                    lower.name
+                ));
+            }
+        }
+
+        for (player, color, preference) in [
+            (white, Color::White, white_preference),
+            (black, Color::Black, black_preference),
+        ] {
+            match preference {
+                ColorPreference::Absolute(wanted) if wanted != color => {
+                    relaxed_criteria.push(format!(
+                        "C3: absolute colour preference of {} not met",
+                        player.name
+                    ));
+                }
+                ColorPreference::Strong(wanted) if wanted != color => {
+                    relaxed_criteria.push(format!(
+                        "C13: strong colour preference of {} not met",
+                        player.name
+                    ));
+                }
+                ColorPreference::Mild(wanted) if wanted != color => {
+                    relaxed_criteria
+                        .push(format!("C12: colour preference of {} not met", player.name));
+                }
+                _ => {}
+            }
+        }
+
+        let color_rule = color_rule(
+            (&white.name, white_preference),
+            (&black.name, black_preference),
+        );
+
+        let mut summary = format!("Score group {score_group:.1}");
+        if let Some(floater_id) = floater_id {
+            let (floater, opponent) = if floater_id == white.id {
+                (white, black)
+            } else {
+                (black, white)
+            };
+            summary.push_str(&format!(
+                "; {} floats down to meet {}",
+                floater.name, opponent.name
+            ));
+        }
+        summary.push_str(&format!("; White to {}: {color_rule}", white.name));
+        if !relaxed_criteria.is_empty() {
+            summary.push_str(&format!("; relaxed: {}", relaxed_criteria.join(", ")));
+        }
+
+        PairingExplanation {
+            score_group,
+            floater_id,
+            float_direction: float_direction.map(|direction| match direction {
+                FloatDirection::Down => "down".to_string(),
+                FloatDirection::Up => "up".to_string(),
+            }),
+            color_rule,
+            relaxed_criteria,
+            summary,
+        }
+    }
+}
+
+/// Which C.04.3 E rule decided the colours, given White's and Black's preferences
+fn color_rule(white: (&str, ColorPreference), black: (&str, ColorPreference)) -> String {
+    let granted = |preference: ColorPreference, color: Color| match preference {
+        ColorPreference::Absolute(wanted)
+        | ColorPreference::Strong(wanted)
+        | ColorPreference::Mild(wanted) => wanted == color,
+        ColorPreference::None => false,
+    };
+    let strength = |preference: ColorPreference| match preference {
+        ColorPreference::Absolute(_) => 3,
+        ColorPreference::Strong(_) => 2,
+        ColorPreference::Mild(_) => 1,
+        ColorPreference::None => 0,
+    };
+
+    let white_granted = granted(white.1, Color::White);
+    let black_granted = granted(black.1, Color::Black);
+    if white_granted && black_granted {
+        return "E.1 both colour preferences granted".to_string();
+    }
+
+    let decided_by = match (white_granted, black_granted) {
+        (true, false) => Some((white.0, white.1)),
+        (false, true) => Some((black.0, black.1)),
+        _ => None,
+    };
+    match decided_by {
+        Some((name, preference)) => {
+            let kind = match strength(preference) {
+                3 => "absolute colour preference",
+                2 => "strong colour preference",
+                _ => "colour preference",
+            };
+            format!("E.2 {kind} of {name}")
+        }
+        None if strength(white.1) == 0 && strength(black.1) == 0 => {
+            "E.5 colours by ranking".to_string()
+        }
+        None => "E.4 colour preference of the higher ranked player".to_string(),
+    }
+}
+
+/// Whether the player received the given float in the round before `round_number`
+pub(crate) fn floated_last_round(
+    history: Option<&PlayerPairingHistory>,
+    direction: FloatDirection,
+    round_number: i32,
+) -> bool {
+    history
+        .and_then(|history| history.float_history.last())
+        .is_some_and(|record| {
+            record.round_number == round_number - 1 && record.direction == Some(direction)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::domain::model::{Game, Player};
+
+    fn create_test_player(id: i32) -> Player {
+        Player {
+            id,
+            tournament_id: 1,
+            name: format!("Player {id}"),
+            rating: Some(2500 - id * 100),
+            country_code: None,
+            title: None,
+            birth_date: None,
+            gender: None,
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: Some(id),
+            initial_rating: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    fn create_test_result(id: i32, points: f32) -> PlayerResult {
+        PlayerResult {
+            player: create_test_player(id),
+            points,
+            games_played: 1,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        }
+    }
+
+    fn create_game_result(round: i32, white: i32, black: i32, result: &str) -> GameResult {
+        GameResult {
+            game: Game {
+                id: round * 10 + white,
+                tournament_id: 1,
+                round_number: round,
+                white_player_id: white,
+                black_player_id: black,
+                result: result.to_string(),
+                result_type: None,
+                result_reason: None,
+                arbiter_notes: None,
+                last_updated: None,
+                approved_by: None,
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+            },
+            white_player: create_test_player(white),
+            black_player: create_test_player(black),
+        }
+    }
+
+    fn create_pairing(white: i32, black: Option<i32>, board: i32) -> Pairing {
+        Pairing {
+            white_player: create_test_player(white),
+            black_player: black.map(create_test_player),
+            board_number: board,
+            explanation: None,
+        }
+    }
+
+    #[test]
+    fn test_float_and_color_rule_are_explained() {
+        let explainer = PairingExplainer::new();
+        let history = vec![
+            create_game_result(1, 1, 2, "1-0"),
+            create_game_result(1, 3, 4, "1/2-1/2"),
+        ];
+        let results = vec![
+            create_test_result(1, 1.0),
+            create_test_result(2, 0.0),
+            create_test_result(3, 0.5),
+            create_test_result(4, 0.5),
+        ];
+        // One game each gives no colour preference yet; player 1 floats down to player 3
+        let mut pairings = vec![create_pairing(3, Some(1), 1), create_pairing(2, Some(4), 2)];
+
+        explainer.explain_pairings(&mut pairings, &results, &history, 2);
+
+        let first = pairings[0].explanation.as_ref().unwrap();
+        assert_eq!(first.score_group, 1.0);
+        assert_eq!(first.floater_id, Some(1));
+        assert_eq!(first.float_direction.as_deref(), Some("down"));
+        assert!(first.relaxed_criteria.iter().any(|c| c.starts_with("C6")));
+        assert!(first.color_rule.starts_with("E.5"));
+        assert!(
+            first
+                .summary
+                .contains("Player 1 floats down to meet Player 3")
+        );
+
+        let second = pairings[1].explanation.as_ref().unwrap();
+        assert_eq!(second.floater_id, Some(4));
+        assert!(second.color_rule.starts_with("E.5"));
+    }
+
+    #[test]
+    fn test_unmet_preference_and_rematch_are_relaxed_criteria() {
+        let explainer = PairingExplainer::new();
+        let history = vec![
+            create_game_result(1, 1, 2, "1/2-1/2"),
+            create_game_result(2, 1, 2, "1/2-1/2"),
+        ];
+        let results = vec![create_test_result(1, 1.0), create_test_result(2, 1.0)];
+        let mut pairings = vec![create_pairing(1, Some(2), 1)];
+
+        explainer.explain_pairings(&mut pairings, &results, &history, 3);
+
+        let explanation = pairings[0].explanation.as_ref().unwrap();
+        assert_eq!(explanation.floater_id, None);
+        assert!(explanation.relaxed_criteria[0].starts_with("C1"));
+        assert!(
+            explanation
+                .relaxed_criteria
+                .iter()
+                .any(|c| c == "C13: strong colour preference of Player 1 not met")
+        );
+        assert!(explanation.color_rule.starts_with("E.4"));
+        assert!(explanation.summary.contains("relaxed:"));
+    }
+
+    #[test]
+    fn test_repeated_bye_relaxes_c2() {
+        let explainer = PairingExplainer::new();
+        let mut bye_game = create_game_result(1, 3, -1, "1-0");
+        bye_game.game.result_type = Some("bye".to_string());
+        let history = vec![bye_game];
+        let results = vec![create_test_result(3, 1.0)];
+        let mut pairings = vec![create_pairing(3, None, 1)];
+
+        explainer.explain_pairings(&mut pairings, &results, &history, 2);
+
+        let explanation = pairings[0].explanation.as_ref().unwrap();
+        assert_eq!(explanation.color_rule, "Pairing-allocated bye");
+        assert!(explanation.relaxed_criteria[0].starts_with("C2"));
+        assert!(
+            explanation
+                .summary
+                .contains("receives the pairing-allocated bye")
+        );
+    }
+}
@@ -465,6 +465,7 @@ impl PairingOptimizer {
                     white_player,
                     black_player: Some(black_player),
                     board_number: *params.board_number,
+                    explanation: None,
                 });
 
                 params.global_paired_players.insert(sorted_batch[i].id);
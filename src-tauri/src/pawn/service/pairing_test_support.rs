@@ -0,0 +1,75 @@
+//! Player, result and game factories shared by the Swiss engine tests
+
+use crate::pawn::domain::model::{Game, GameResult, Pairing, Player, PlayerResult};
+
+pub(crate) fn create_test_player(id: i32, name: &str, rating: Option<i32>) -> Player {
+    Player {
+        id,
+        tournament_id: 1,
+        name: name.to_string(),
+        rating,
+        country_code: None,
+        title: None,
+        birth_date: None,
+        gender: None,
+        email: None,
+        phone: None,
+        club: None,
+        status: "active".to_string(),
+        seed_number: None,
+        pairing_number: None,
+        initial_rating: None,
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+        updated_at: None,
+    }
+}
+
+pub(crate) fn create_test_result(player: Player, points: f64) -> PlayerResult {
+    PlayerResult {
+        player,
+        points: points as f32,
+        games_played: 1,
+        wins: if points == 1.0 { 1 } else { 0 },
+        draws: if points == 0.5 { 1 } else { 0 },
+        losses: if points == 0.0 { 1 } else { 0 },
+    }
+}
+
+pub(crate) fn create_game_result(
+    id: i32,
+    white: &Player,
+    black: &Player,
+    round: i32,
+    result: &str,
+) -> GameResult {
+    GameResult {
+        game: Game {
+            id,
+            tournament_id: 1,
+            round_number: round,
+            white_player_id: white.id,
+            black_player_id: black.id,
+            result: result.to_string(),
+            result_type: None,
+            result_reason: None,
+            arbiter_notes: None,
+            last_updated: None,
+            approved_by: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        },
+        white_player: white.clone(),
+        black_player: black.clone(),
+    }
+}
+
+pub(crate) fn create_field(count: i32) -> Vec<Player> {
+    (1..=count)
+        .map(|id| create_test_player(id, &format!("Player {id}"), Some(2500 - id * 50)))
+        .collect()
+}
+
+pub(crate) fn paired_ids(pairing: &Pairing) -> (i32, i32) {
+    let white = pairing.white_player.id;
+    let black = pairing.black_player.as_ref().unwrap().id;
+    (white.min(black), white.max(black))
+}
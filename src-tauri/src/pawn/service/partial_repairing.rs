@@ -0,0 +1,449 @@
+use crate::pawn::{
+    common::error::PawnError,
+    domain::{
+        dto::BoardChangeDto,
+        model::{GameResult, Pairing, Player},
+    },
+    service::round::bye_player_id,
+};
+use std::collections::HashSet;
+
+/// Boards kept from a published round and players left to re-pair
+#[derive(Debug)]
+pub struct RepairingPlan {
+    pub locked: Vec<Pairing>,
+    /// Games of the unlocked boards, which are replaced
+    pub replaced_game_ids: Vec<i32>,
+    pub players: Vec<Player>,
+    pub removed: Vec<Player>,
+    pub added: Vec<Player>,
+}
+
+/// Pairings of a stored round, numbered in game order. Games against the
+/// virtual BYE player become bye boards.
+pub fn round_pairings(tournament_id: i32, games: &[GameResult]) -> Vec<Pairing> {
+    (1..)
+        .zip(games)
+        .map(|(board_number, game)| Pairing {
+            white_player: game.white_player.clone(),
+            black_player: (game.black_player.id != bye_player_id(tournament_id))
+                .then(|| game.black_player.clone()),
+            board_number,
+            explanation: None,
+        })
+        .collect()
+}
+
+/// Split a round into the locked boards and the players to re-pair: those of
+/// the unlocked boards without the removed players, plus the added players.
+/// Unlocked boards must not have a result yet, byes excepted.
+pub fn plan_repairing(
+    tournament_id: i32,
+    games: &[GameResult],
+    locked_boards: &HashSet<i32>,
+    removed_player_ids: &[i32],
+    added_players: Vec<Player>,
+) -> Result<RepairingPlan, PawnError> {
+    let pairings = round_pairings(tournament_id, games);
+
+    if let Some(board) = locked_boards
+        .iter()
+        .find(|&&board| board < 1 || board > pairings.len() as i32)
+    {
+        return Err(PawnError::InvalidInput(format!(
+            "Board {board} does not exist in this round"
+        )));
+    }
+
+    let mut plan = RepairingPlan {
+        locked: Vec::new(),
+        replaced_game_ids: Vec::new(),
+        players: Vec::new(),
+        removed: Vec::new(),
+        added: Vec::new(),
+    };
+    let mut paired_ids = HashSet::new();
+    for (pairing, game) in pairings.into_iter().zip(games) {
+        let players: Vec<&Player> = std::iter::once(&pairing.white_player)
+            .chain(pairing.black_player.as_ref())
+            .collect();
+        paired_ids.extend(players.iter().map(|player| player.id));
+
+        if locked_boards.contains(&pairing.board_number) {
+            if let Some(player) = players
+                .iter()
+                .find(|player| removed_player_ids.contains(&player.id))
+            {
+                return Err(PawnError::InvalidInput(format!(
+                    "{} plays on locked board {}",
+                    player.name, pairing.board_number
+                )));
+            }
+            plan.locked.push(pairing);
+            continue;
+        }
+
+        if pairing.black_player.is_some() && game.game.result != "*" {
+            return Err(PawnError::InvalidInput(format!(
+                "Board {} already has a result and must stay locked",
+                pairing.board_number
+            )));
+        }
+        plan.replaced_game_ids.push(game.game.id);
+        for player in players {
+            if removed_player_ids.contains(&player.id) {
+                plan.removed.push(player.clone());
+            } else {
+                plan.players.push(player.clone());
+            }
+        }
+    }
+
+    if let Some(missing) = removed_player_ids
+        .iter()
+        .find(|&&id| !paired_ids.contains(&id))
+    {
+        return Err(PawnError::InvalidInput(format!(
+            "Player {missing} is not paired in this round"
+        )));
+    }
+
+    for player in added_players {
+        if !paired_ids.insert(player.id) {
+            return Err(PawnError::InvalidInput(format!(
+                "{} is already paired in this round",
+                player.name
+            )));
+        }
+        plan.players.push(player.clone());
+        plan.added.push(player);
+    }
+
+    Ok(plan)
+}
+
+/// Number the re-paired boards of a round of `board_count` boards. They take
+/// the boards of the replaced games in order and reuse those games' ids, so
+/// the stored game order matches; extra boards follow the last one. Locked
+/// boards keep their numbers, unless boards left free by removed players close
+/// up before them, as a round is stored in game order. Returns the whole round
+/// and the re-paired boards with the id of the game each one replaces.
+pub fn number_repaired_boards(
+    plan: &RepairingPlan,
+    board_count: i32,
+    repaired: Vec<Pairing>,
+) -> (Vec<Pairing>, Vec<(Pairing, Option<i32>)>) {
+    let locked_boards: HashSet<i32> = plan
+        .locked
+        .iter()
+        .map(|pairing| pairing.board_number)
+        .collect();
+    let free_boards = (1..=board_count)
+        .filter(|board| !locked_boards.contains(board))
+        .zip(plan.replaced_game_ids.iter().copied().map(Some));
+    let new_boards = (board_count + 1..).map(|board| (board, None));
+    let mut games: Vec<(Pairing, Option<i32>)> = repaired
+        .into_iter()
+        .zip(free_boards.chain(new_boards))
+        .map(|(pairing, (board_number, game_id))| {
+            (
+                Pairing {
+                    board_number,
+                    ..pairing
+                },
+                game_id,
+            )
+        })
+        .collect();
+
+    let mut locked = plan.locked.clone();
+    let mut board_numbers: Vec<i32> = locked
+        .iter()
+        .chain(games.iter().map(|(pairing, _)| pairing))
+        .map(|pairing| pairing.board_number)
+        .collect();
+    board_numbers.sort_unstable();
+    let close_up = |board: i32| board_numbers.partition_point(|&number| number < board) as i32 + 1;
+    for pairing in locked
+        .iter_mut()
+        .chain(games.iter_mut().map(|(pairing, _)| pairing))
+    {
+        pairing.board_number = close_up(pairing.board_number);
+    }
+
+    let mut after: Vec<Pairing> = locked
+        .into_iter()
+        .chain(games.iter().map(|(pairing, _)| pairing.clone()))
+        .collect();
+    after.sort_by_key(|pairing| pairing.board_number);
+    (after, games)
+}
+
+/// Boards whose pairing differs between two versions of a round
+pub fn changed_boards(before: &[Pairing], after: &[Pairing]) -> Vec<BoardChangeDto> {
+    let board = |pairings: &[Pairing], number: i32| {
+        pairings
+            .iter()
+            .find(|pairing| pairing.board_number == number)
+            .map(describe)
+    };
+    let boards = before.len().max(after.len()) as i32;
+
+    (1..=boards)
+        .map(|number| BoardChangeDto {
+            board_number: number,
+            before: board(before, number),
+            after: board(after, number),
+        })
+        .filter(|change| change.before != change.after)
+        .collect()
+}
+
+/// Audit text summarising a re-pairing
+pub fn audit_details(
+    locked_boards: &HashSet<i32>,
+    plan: &RepairingPlan,
+    changes: &[BoardChangeDto],
+) -> String {
+    let names = |players: &[Player]| {
+        players
+            .iter()
+            .map(|player| player.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let mut locked: Vec<i32> = locked_boards.iter().copied().collect();
+    locked.sort_unstable();
+
+    let mut details = vec![format!(
+        "Re-paired {} players; locked boards: {}",
+        plan.players.len(),
+        if locked.is_empty() {
+            "none".to_string()
+        } else {
+            locked
+                .iter()
+                .map(|board| board.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        }
+    )];
+    if !plan.removed.is_empty() {
+        details.push(format!("removed: {}", names(&plan.removed)));
+    }
+    if !plan.added.is_empty() {
+        details.push(format!("added: {}", names(&plan.added)));
+    }
+    details.extend(changes.iter().map(|change| {
+        format!(
+            "board {}: {} -> {}",
+            change.board_number,
+            change.before.as_deref().unwrap_or("none"),
+            change.after.as_deref().unwrap_or("none")
+        )
+    }));
+
+    details.join("; ")
+}
+
+fn describe(pairing: &Pairing) -> String {
+    match &pairing.black_player {
+        Some(black) => format!("{} - {}", pairing.white_player.name, black.name),
+        None => format!("{} - bye", pairing.white_player.name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::{domain::model::Game, service::round::virtual_bye_player};
+
+    fn create_test_player(id: i32) -> Player {
+        Player {
+            id,
+            tournament_id: 1,
+            name: format!("Player {id}"),
+            rating: Some(2000 - id * 10),
+            country_code: None,
+            title: None,
+            birth_date: None,
+            gender: None,
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: None,
+            initial_rating: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    fn create_game(id: i32, white: Player, black: Player, result: &str) -> GameResult {
+        GameResult {
+            game: Game {
+                id,
+                tournament_id: 1,
+                round_number: 2,
+                white_player_id: white.id,
+                black_player_id: black.id,
+                result: result.to_string(),
+                result_type: None,
+                result_reason: None,
+                arbiter_notes: None,
+                last_updated: None,
+                approved_by: None,
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+            },
+            white_player: white,
+            black_player: black,
+        }
+    }
+
+    fn create_round() -> Vec<GameResult> {
+        let player = create_test_player;
+        vec![
+            create_game(10, player(1), player(2), "1-0"),
+            create_game(11, player(3), player(4), "*"),
+            create_game(12, player(5), virtual_bye_player(1), "1-0"),
+        ]
+    }
+
+    fn ids(players: &[Player]) -> Vec<i32> {
+        players.iter().map(|player| player.id).collect()
+    }
+
+    #[test]
+    fn test_round_pairings_turn_bye_games_into_byes() {
+        let pairings = round_pairings(1, &create_round());
+
+        assert_eq!(pairings.len(), 3);
+        assert_eq!(pairings[2].board_number, 3);
+        assert!(pairings[2].black_player.is_none());
+    }
+
+    #[test]
+    fn test_plan_keeps_locked_boards() {
+        let plan = plan_repairing(
+            1,
+            &create_round(),
+            &HashSet::from([1]),
+            &[4],
+            vec![create_test_player(6)],
+        )
+        .unwrap();
+
+        assert_eq!(plan.locked.len(), 1);
+        assert_eq!(plan.replaced_game_ids, vec![11, 12]);
+        assert_eq!(ids(&plan.players), vec![3, 5, 6]);
+        assert_eq!(ids(&plan.removed), vec![4]);
+        assert_eq!(ids(&plan.added), vec![6]);
+    }
+
+    #[test]
+    fn test_plan_rejections() {
+        let games = create_round();
+
+        // Board 1 already has a result
+        assert!(plan_repairing(1, &games, &HashSet::new(), &[], vec![]).is_err());
+        // Removed player on a locked board
+        assert!(plan_repairing(1, &games, &HashSet::from([1]), &[2], vec![]).is_err());
+        // Unknown board
+        assert!(plan_repairing(1, &games, &HashSet::from([1, 4]), &[], vec![]).is_err());
+        // Removed player not in the round
+        assert!(plan_repairing(1, &games, &HashSet::from([1]), &[9], vec![]).is_err());
+        // Added player already paired
+        assert!(
+            plan_repairing(
+                1,
+                &games,
+                &HashSet::from([1]),
+                &[],
+                vec![create_test_player(3)]
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_repaired_boards_keep_locked_numbers() {
+        let player = create_test_player;
+        let games = create_round();
+        let board = |white: i32, black: Option<i32>| Pairing {
+            white_player: player(white),
+            black_player: black.map(player),
+            board_number: 0,
+            explanation: None,
+        };
+        let boards = |pairings: &[Pairing]| {
+            pairings
+                .iter()
+                .map(|pairing| {
+                    (
+                        pairing.board_number,
+                        pairing.white_player.id,
+                        pairing.black_player.as_ref().map(|black| black.id),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // The re-paired game takes board 2 and an added one follows the bye
+        let plan = plan_repairing(
+            1,
+            &games,
+            &HashSet::from([1, 3]),
+            &[],
+            vec![player(6), player(7)],
+        )
+        .unwrap();
+        let (after, repaired) =
+            number_repaired_boards(&plan, 3, vec![board(4, Some(3)), board(6, Some(7))]);
+        assert_eq!(
+            boards(&after),
+            vec![
+                (1, 1, Some(2)),
+                (2, 4, Some(3)),
+                (3, 5, None),
+                (4, 6, Some(7))
+            ]
+        );
+        let game_ids: Vec<_> = repaired.iter().map(|(_, game_id)| *game_id).collect();
+        assert_eq!(game_ids, vec![Some(11), None]);
+        assert!(
+            changed_boards(&round_pairings(1, &games), &after)
+                .iter()
+                .all(|change| change.board_number != 1 && change.board_number != 3)
+        );
+
+        // Without players left on board 2 the bye closes up behind board 1
+        let plan = plan_repairing(1, &games, &HashSet::from([1, 3]), &[3, 4], vec![]).unwrap();
+        let (after, repaired) = number_repaired_boards(&plan, 3, vec![]);
+        assert_eq!(boards(&after), vec![(1, 1, Some(2)), (2, 5, None)]);
+        assert!(repaired.is_empty());
+    }
+
+    #[test]
+    fn test_changed_boards_and_audit_details() {
+        let games = create_round();
+        let before = round_pairings(1, &games);
+        let mut after = before.clone();
+        after[1].black_player = Some(create_test_player(5));
+        after.truncate(2);
+
+        let changes = changed_boards(&before, &after);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].before.as_deref(), Some("Player 3 - Player 4"));
+        assert_eq!(changes[0].after.as_deref(), Some("Player 3 - Player 5"));
+        assert_eq!(changes[1].after, None);
+
+        let plan = plan_repairing(1, &games, &HashSet::from([1]), &[4], vec![]).unwrap();
+        assert_eq!(
+            audit_details(&HashSet::from([1]), &plan, &changes),
+            "Re-paired 2 players; locked boards: 1; removed: Player 4; \
+             board 2: Player 3 - Player 4 -> Player 3 - Player 5; \
+             board 3: Player 5 - bye -> none"
+        );
+    }
+}
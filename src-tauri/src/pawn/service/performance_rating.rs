@@ -0,0 +1,185 @@
+/// Rating of unrated opponents when checking a title norm (FIDE B.01)
+pub const UNRATED_NORM_RATING: i32 = 1400;
+
+/// FIDE B.02 8.1.1: rating difference dp for scores p = 0.50, 0.51, ..., 1.00
+const DP_TABLE: [i32; 51] = [
+    0, 7, 14, 21, 29, 36, 43, 50, 57, 65, 72, 80, 87, 95, 102, 110, 117, 125, 133, 141, 149, 158,
+    166, 175, 184, 193, 202, 211, 220, 230, 240, 251, 262, 273, 284, 296, 309, 322, 336, 351, 366,
+    383, 401, 422, 444, 470, 501, 538, 589, 677, 800,
+];
+
+/// FIDE B.02 8.1.2: largest rating difference giving an expected score of 0.50, 0.51, ..., 0.99
+const EXPECTED_SCORE_TABLE: [i32; 50] = [
+    3, 10, 17, 25, 32, 39, 46, 53, 61, 68, 76, 83, 91, 98, 106, 113, 121, 129, 137, 145, 153, 162,
+    170, 179, 188, 197, 206, 215, 225, 235, 245, 256, 267, 278, 290, 302, 315, 328, 344, 357, 374,
+    391, 411, 432, 456, 484, 517, 559, 619, 735,
+];
+
+/// Rating difference dp for a score fraction, rounded to two decimals as in the FIDE table
+pub fn rating_difference(score_fraction: f64) -> i32 {
+    let percent = (score_fraction * 100.0).round().clamp(0.0, 100.0) as usize;
+    if percent >= 50 {
+        DP_TABLE[percent - 50]
+    } else {
+        -DP_TABLE[50 - percent]
+    }
+}
+
+/// Expected score of a player rated `rating_difference` points above the opponent
+pub fn expected_score(rating_difference: i32) -> f64 {
+    let steps = EXPECTED_SCORE_TABLE
+        .iter()
+        .position(|&limit| rating_difference.abs() <= limit)
+        .unwrap_or(EXPECTED_SCORE_TABLE.len());
+    let expected = 0.5 + steps as f64 / 100.0;
+    if rating_difference < 0 {
+        1.0 - expected
+    } else {
+        expected
+    }
+}
+
+/// Average of the ratings rounded to the nearest whole number, halves up
+pub fn average_rating(ratings: &[i32]) -> Option<i32> {
+    if ratings.is_empty() {
+        return None;
+    }
+    let total: i32 = ratings.iter().sum();
+    Some((total as f64 / ratings.len() as f64).round() as i32)
+}
+
+/// Tournament Performance Rating: rounded average opponent rating plus dp of the score
+pub fn tournament_performance_rating(opponent_ratings: &[i32], score: f64) -> Option<i32> {
+    let average = average_rating(opponent_ratings)?;
+    Some(average + rating_difference(score / opponent_ratings.len() as f64))
+}
+
+/// Perfect Tournament Performance (FIDE C.07): the lowest rating whose expected score
+/// against the opponents reaches the score; 0% and 100% are 800 below the lowest and
+/// above the highest opponent
+pub fn perfect_tournament_performance(opponent_ratings: &[i32], score: f64) -> Option<i32> {
+    let lowest = *opponent_ratings.iter().min()?;
+    let highest = *opponent_ratings.iter().max()?;
+    if score <= 0.0 {
+        return Some(lowest - 800);
+    }
+    if score >= opponent_ratings.len() as f64 {
+        return Some(highest + 800);
+    }
+
+    // The expected score only grows with the rating
+    let reaches = |rating: i32| {
+        let expected: f64 = opponent_ratings
+            .iter()
+            .map(|&opponent| expected_score(rating - opponent))
+            .sum();
+        expected >= score - 1e-9
+    };
+    let (mut low, mut high) = (lowest - 800, highest + 800);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if reaches(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    Some(low)
+}
+
+/// Opponent ratings as counted for a title norm: unrated opponents count as 1400 and,
+/// when some are below the rating floor, the lowest one of them is raised to it
+pub fn norm_opponent_ratings(ratings: &[Option<i32>], rating_floor: Option<i32>) -> Vec<i32> {
+    let mut ratings: Vec<i32> = ratings
+        .iter()
+        .map(|rating| rating.unwrap_or(UNRATED_NORM_RATING))
+        .collect();
+    if let Some(floor) = rating_floor
+        && let Some(lowest) = ratings
+            .iter_mut()
+            .filter(|rating| **rating < floor)
+            .min_by_key(|rating| **rating)
+    {
+        *lowest = floor;
+    }
+    ratings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rating_difference_table() {
+        assert_eq!(rating_difference(0.5), 0);
+        assert_eq!(rating_difference(1.0), 800);
+        assert_eq!(rating_difference(0.0), -800);
+        assert_eq!(rating_difference(0.75), 193);
+        assert_eq!(rating_difference(0.25), -193);
+        // 6.5 / 9 = 0.7222 rounds to 0.72
+        assert_eq!(rating_difference(6.5 / 9.0), 166);
+        // 5 / 8 = 0.625 rounds up to 0.63
+        assert_eq!(rating_difference(5.0 / 8.0), 95);
+    }
+
+    #[test]
+    fn test_expected_score_table() {
+        assert_eq!(expected_score(0), 0.5);
+        assert_eq!(expected_score(3), 0.5);
+        assert!((expected_score(4) - 0.51).abs() < 1e-9);
+        assert!((expected_score(-200) - 0.24).abs() < 1e-9);
+        assert!((expected_score(735) - 0.99).abs() < 1e-9);
+        assert_eq!(expected_score(736), 1.0);
+        assert_eq!(expected_score(-900), 0.0);
+    }
+
+    #[test]
+    fn test_tournament_performance_rating() {
+        assert_eq!(tournament_performance_rating(&[], 0.0), None);
+        // Average 2000.5 rounds up to 2001
+        assert_eq!(
+            tournament_performance_rating(&[2000, 2001], 1.5),
+            Some(2001 + 193)
+        );
+        assert_eq!(
+            tournament_performance_rating(&[2000, 2100, 2200], 3.0),
+            Some(2900)
+        );
+        assert_eq!(
+            tournament_performance_rating(&[2000, 2100, 2200], 0.0),
+            Some(1300)
+        );
+    }
+
+    #[test]
+    fn test_perfect_tournament_performance() {
+        let opponents = [1800, 2000, 2200];
+        assert_eq!(perfect_tournament_performance(&[], 1.0), None);
+        assert_eq!(perfect_tournament_performance(&opponents, 0.0), Some(1000));
+        assert_eq!(perfect_tournament_performance(&opponents, 3.0), Some(3000));
+
+        let ptp = perfect_tournament_performance(&opponents, 1.5).unwrap();
+        let expected = |rating: i32| -> f64 {
+            opponents
+                .iter()
+                .map(|&opponent| expected_score(rating - opponent))
+                .sum()
+        };
+        assert!(expected(ptp) >= 1.5 - 1e-9);
+        assert!(expected(ptp - 1) < 1.5);
+        assert_eq!(ptp, 1998);
+    }
+
+    #[test]
+    fn test_norm_opponent_ratings() {
+        let ratings = [Some(2400), Some(2150), None, Some(2100)];
+        assert_eq!(
+            norm_opponent_ratings(&ratings, Some(2200)),
+            vec![2400, 2150, 2200, 2100]
+        );
+        assert_eq!(
+            norm_opponent_ratings(&ratings, None),
+            vec![2400, 2150, 1400, 2100]
+        );
+    }
+}
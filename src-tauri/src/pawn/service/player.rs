@@ -1,11 +1,14 @@
 use crate::pawn::common::error::PawnError;
 use crate::pawn::db::Db;
 use crate::pawn::domain::dto::{
-    AssignPlayerToCategory, BulkImportRequest, BulkImportResult, CreatePlayer,
+    AssignPlayerToCategory, BulkImportRequest, BulkImportResult, CreateByeRequest, CreatePlayer,
     CreatePlayerCategory, CreateRatingHistory, PlayerImportValidation, PlayerSearchFilters,
-    UpdatePlayer,
+    UpdateByeSettings, UpdatePlayer,
 };
-use crate::pawn::domain::model::{Player, PlayerCategory, PlayerCategoryAssignment, RatingHistory};
+use crate::pawn::domain::model::{
+    ByeRequest, ByeSettings, Player, PlayerCategory, PlayerCategoryAssignment, RatingHistory,
+};
+use crate::pawn::service::registry::RegistryService;
 use std::sync::Arc;
 
 #[allow(dead_code)]
@@ -128,20 +131,25 @@ impl<D: Db> PlayerService<D> {
 
     pub async fn add_rating_history(
         &self,
-        _data: CreateRatingHistory,
+        data: CreateRatingHistory,
     ) -> Result<RatingHistory, PawnError> {
-        // TODO: Implement rating history once enhanced schema is ready
-        Err(PawnError::ValidationError(
-            "Rating history not yet implemented".to_string(),
-        ))
+        RegistryService::validate_rating(&data.rating_type, data.rating)?;
+
+        // Players linked to the registry also update their person's rating history
+        self.db
+            .add_rating_history(&data)
+            .await
+            .map_err(PawnError::from)
     }
 
     pub async fn get_player_rating_history(
         &self,
-        _player_id: i32,
+        player_id: i32,
     ) -> Result<Vec<RatingHistory>, PawnError> {
-        // TODO: Implement rating history once enhanced schema is ready
-        Ok(vec![])
+        self.db
+            .get_player_rating_history(player_id)
+            .await
+            .map_err(PawnError::from)
     }
 
     // Private helper methods
@@ -342,11 +350,181 @@ impl<D: Db> PlayerService<D> {
             .await
     }
 
+    // Bye Requests
+
+    pub async fn request_byes(&self, data: CreateByeRequest) -> Result<Vec<ByeRequest>, PawnError> {
+        let player = self
+            .db
+            .get_player(data.player_id)
+            .await
+            .map_err(PawnError::from)?;
+        if player.tournament_id != data.tournament_id {
+            return Err(PawnError::ValidationError(
+                "Player does not belong to this tournament".to_string(),
+            ));
+        }
+
+        let tournament = self
+            .db
+            .get_tournament(data.tournament_id)
+            .await
+            .map_err(PawnError::from)?;
+        let existing: Vec<ByeRequest> = self
+            .db
+            .get_bye_requests(data.tournament_id)
+            .await
+            .map_err(PawnError::from)?
+            .into_iter()
+            .filter(|request| request.player_id == data.player_id)
+            .collect();
+        let settings = self.get_bye_settings(data.tournament_id).await?;
+
+        validate_bye_request(
+            &data,
+            &existing,
+            settings.max_requested_byes,
+            tournament.total_rounds,
+        )?;
+
+        // Byes can only be requested for rounds that are not paired yet
+        for &round_number in &data.round_numbers {
+            self.ensure_round_not_paired(data.tournament_id, round_number)
+                .await?;
+        }
+
+        self.db
+            .create_bye_requests(&data)
+            .await
+            .map_err(PawnError::from)
+    }
+
+    pub async fn cancel_bye_request(&self, request_id: i32) -> Result<(), PawnError> {
+        let request = self
+            .db
+            .get_bye_request(request_id)
+            .await
+            .map_err(PawnError::from)?;
+
+        // Once the round is paired the bye is part of its games
+        self.ensure_round_not_paired(request.tournament_id, request.round_number)
+            .await?;
+
+        self.db
+            .delete_bye_request(request_id)
+            .await
+            .map_err(PawnError::from)
+    }
+
+    async fn ensure_round_not_paired(
+        &self,
+        tournament_id: i32,
+        round_number: i32,
+    ) -> Result<(), PawnError> {
+        let games = self
+            .db
+            .get_games_by_round(tournament_id, round_number)
+            .await
+            .map_err(PawnError::from)?;
+        if !games.is_empty() {
+            return Err(PawnError::ValidationError(format!(
+                "Round {round_number} has already been paired"
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_bye_requests(&self, tournament_id: i32) -> Result<Vec<ByeRequest>, PawnError> {
+        self.db
+            .get_bye_requests(tournament_id)
+            .await
+            .map_err(PawnError::from)
+    }
+
+    pub async fn get_bye_settings(&self, tournament_id: i32) -> Result<ByeSettings, PawnError> {
+        let settings = self
+            .db
+            .get_bye_settings(tournament_id)
+            .await
+            .map_err(PawnError::from)?;
+
+        Ok(settings.unwrap_or(ByeSettings {
+            tournament_id,
+            max_requested_byes: DEFAULT_MAX_REQUESTED_BYES,
+            updated_at: None,
+        }))
+    }
+
+    pub async fn update_bye_settings(
+        &self,
+        data: UpdateByeSettings,
+    ) -> Result<ByeSettings, PawnError> {
+        if data.max_requested_byes < 0 {
+            return Err(PawnError::ValidationError(
+                "Maximum number of requested byes cannot be negative".to_string(),
+            ));
+        }
+
+        self.db
+            .upsert_bye_settings(&data)
+            .await
+            .map_err(PawnError::from)
+    }
+
     // Note: This method is now implemented in the command layer
     // to avoid circular dependencies with the PlayerStatistics type.
     // The command layer handles the statistics calculation directly.
 }
 
+/// Requested byes allowed per player when the tournament has no bye settings
+const DEFAULT_MAX_REQUESTED_BYES: i32 = 2;
+
+/// Check a bye request against the player's earlier requests and the tournament
+fn validate_bye_request(
+    data: &CreateByeRequest,
+    existing: &[ByeRequest],
+    max_requested_byes: i32,
+    total_rounds: i32,
+) -> Result<(), PawnError> {
+    if ![0.0, 0.5, 1.0].contains(&data.bye_points) {
+        return Err(PawnError::ValidationError(
+            "A bye is worth 0, 0.5 or 1 point".to_string(),
+        ));
+    }
+
+    if data.round_numbers.is_empty() {
+        return Err(PawnError::ValidationError(
+            "At least one round is required".to_string(),
+        ));
+    }
+
+    let mut rounds = std::collections::HashSet::new();
+    for &round_number in &data.round_numbers {
+        if !(1..=total_rounds).contains(&round_number) {
+            return Err(PawnError::ValidationError(format!(
+                "Round {round_number} is not part of the tournament"
+            )));
+        }
+        if !rounds.insert(round_number)
+            || existing
+                .iter()
+                .any(|request| request.round_number == round_number)
+        {
+            return Err(PawnError::ValidationError(format!(
+                "A bye is already requested for round {round_number}"
+            )));
+        }
+    }
+
+    if existing.len() + rounds.len() > max_requested_byes.max(0) as usize {
+        return Err(PawnError::ValidationError(format!(
+            "At most {max_requested_byes} byes can be requested per player"
+        )));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -544,4 +722,41 @@ mod tests {
         assert_eq!(filters.limit, Some(10));
         assert_eq!(filters.offset, Some(0));
     }
+
+    fn create_bye_request(round_numbers: Vec<i32>, bye_points: f64) -> CreateByeRequest {
+        CreateByeRequest {
+            tournament_id: 1,
+            player_id: 1,
+            round_numbers,
+            bye_points,
+        }
+    }
+
+    #[test]
+    fn test_validate_bye_request() {
+        let existing = vec![ByeRequest {
+            id: 1,
+            tournament_id: 1,
+            player_id: 1,
+            round_number: 3,
+            bye_points: 0.5,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }];
+
+        assert!(validate_bye_request(&create_bye_request(vec![7], 0.5), &existing, 2, 9).is_ok());
+        assert!(validate_bye_request(&create_bye_request(vec![5], 0.0), &[], 2, 9).is_ok());
+
+        // Only 0, 0.5 and 1 point byes exist
+        assert!(validate_bye_request(&create_bye_request(vec![7], 0.25), &existing, 2, 9).is_err());
+        // Rounds outside the tournament
+        assert!(validate_bye_request(&create_bye_request(vec![10], 0.5), &existing, 2, 9).is_err());
+        assert!(validate_bye_request(&create_bye_request(vec![], 0.5), &existing, 2, 9).is_err());
+        // Round 3 is already requested
+        assert!(validate_bye_request(&create_bye_request(vec![3], 0.5), &existing, 2, 9).is_err());
+        assert!(validate_bye_request(&create_bye_request(vec![4, 4], 0.5), &[], 2, 9).is_err());
+        // A third bye exceeds the limit
+        assert!(
+            validate_bye_request(&create_bye_request(vec![5, 7], 0.5), &existing, 2, 9).is_err()
+        );
+    }
 }
@@ -0,0 +1,443 @@
+use crate::pawn::{
+    domain::{
+        dto::{PlayerRatingReport, RatedGame},
+        model::{Game, GameResultType, Player},
+    },
+    service::{
+        performance_rating::{average_rating, expected_score, rating_difference},
+        trf::game_result_type,
+    },
+};
+
+/// Rating differences of more than 400 points count as 400 (FIDE B.02 8.3.1)
+const MAX_RATING_DIFFERENCE: i32 = 400;
+
+/// K is lowered so that K times the games of a period stays within 700
+const MAX_K_TIMES_GAMES: i32 = 700;
+
+/// Players new to the rating list keep K = 40 until they complete 30 games
+const NEW_PLAYER_GAMES: i32 = 30;
+
+/// First ratings count two draws against hypothetical 1800 opponents
+const INITIAL_RATING_ANCHOR: i32 = 1800;
+const INITIAL_RATING_MIN_GAMES: i32 = 5;
+
+/// Lowest rating FIDE publishes
+const RATING_FLOOR: i32 = 1400;
+
+/// What the K-factor of a player depends on besides the rating
+#[derive(Debug, Clone, Default)]
+pub struct RatingProfile {
+    pub birth_year: Option<i32>,
+    pub reached_2400: bool,
+    pub rated_games: Option<i32>, // Before the tournament, when known since the first rating
+}
+
+impl RatingProfile {
+    /// Profile from the tournament entry alone, for players outside the registry
+    pub fn from_player(player: &Player) -> Self {
+        Self {
+            birth_year: date_year(player.birth_date.as_deref()),
+            reached_2400: player.rating.is_some_and(|rating| rating >= 2400),
+            rated_games: None,
+        }
+    }
+}
+
+/// Year of a YYYY-MM-DD date
+pub fn date_year(date: Option<&str>) -> Option<i32> {
+    date?.get(..4)?.parse().ok()
+}
+
+/// FIDE K-factor of a rated player for a tournament of `games` rated games, and why it applies
+pub fn k_factor(
+    rating: i32,
+    profile: &RatingProfile,
+    rating_type: &str,
+    year: i32,
+    games: i32,
+) -> (i32, &'static str) {
+    if rating_type != "fide" {
+        return (20, "Rapid and blitz");
+    }
+
+    let (k, reason) = if profile.reached_2400 || rating >= 2400 {
+        (10, "Rated 2400 or more")
+    } else if profile
+        .rated_games
+        .is_some_and(|rated_games| rated_games < NEW_PLAYER_GAMES)
+    {
+        (40, "Fewer than 30 rated games")
+    } else if rating < 2300 && profile.birth_year.is_some_and(|born| year - born <= 18) {
+        (40, "Under 18")
+    } else {
+        (20, "Rated under 2400")
+    };
+
+    if games > 0 && k * games > MAX_K_TIMES_GAMES {
+        (MAX_K_TIMES_GAMES / games, "K × games limited to 700")
+    } else {
+        (k, reason)
+    }
+}
+
+/// Score of a player in a game played over the board, or why the game does not count
+pub fn played_score(game: &Game, is_white: bool) -> Result<f64, &'static str> {
+    let white_score = match game_result_type(game) {
+        GameResultType::WhiteWins => 1.0,
+        GameResultType::BlackWins => 0.0,
+        GameResultType::Draw => 0.5,
+        GameResultType::Timeout if game.result.starts_with("1-0") => 1.0,
+        GameResultType::Timeout => 0.0,
+        GameResultType::Ongoing | GameResultType::Adjourned => return Err("Not finished"),
+        GameResultType::WhiteForfeit
+        | GameResultType::BlackForfeit
+        | GameResultType::WhiteDefault
+        | GameResultType::BlackDefault
+        | GameResultType::DoubleForfeit
+        | GameResultType::Cancelled => return Err("Not played"),
+    };
+    Ok(if is_white {
+        white_score
+    } else {
+        1.0 - white_score
+    })
+}
+
+/// First rating from games against rated opponents, with two draws against 1800 added
+pub fn initial_rating(opponent_ratings: &[i32], score: f64) -> Option<i32> {
+    if opponent_ratings.is_empty() {
+        return None;
+    }
+    let mut ratings = opponent_ratings.to_vec();
+    ratings.extend([INITIAL_RATING_ANCHOR, INITIAL_RATING_ANCHOR]);
+    let score_fraction = (score + 1.0) / ratings.len() as f64;
+    Some(average_rating(&ratings)? + rating_difference(score_fraction))
+}
+
+/// Games a player played over the board against rated opponents
+pub fn rated_game_count(player: &Player, games: &[Game], players: &[Player]) -> i32 {
+    rated_games(player, games, players)
+        .iter()
+        .filter(|game| game.counted)
+        .count() as i32
+}
+
+/// Rating calculation of one player: every game with its expected and actual score, the
+/// K-factor and the change, or the first rating of an unrated player
+pub fn player_rating_report(
+    player: &Player,
+    games: &[Game],
+    players: &[Player],
+    profile: &RatingProfile,
+    rating_type: &str,
+    year: i32,
+) -> PlayerRatingReport {
+    let mut games = rated_games(player, games, players);
+    let counted: Vec<(i32, f64)> = games
+        .iter()
+        .filter(|game| game.counted)
+        .filter_map(|game| Some((game.opponent_rating?, game.score?)))
+        .collect();
+    let games_counted = counted.len() as i32;
+    let score: f64 = counted.iter().map(|(_, score)| score).sum();
+
+    let Some(rating) = player.rating else {
+        let opponent_ratings: Vec<i32> = counted.iter().map(|(rating, _)| *rating).collect();
+        let (new_rating, note) = if games_counted < INITIAL_RATING_MIN_GAMES {
+            (
+                None,
+                Some("A first rating needs at least 5 games against rated opponents"),
+            )
+        } else {
+            match initial_rating(&opponent_ratings, score) {
+                Some(rating) if rating >= RATING_FLOOR => (Some(rating), None),
+                _ => (None, Some("Below the 1400 rating floor")),
+            }
+        };
+        return PlayerRatingReport {
+            player: player.clone(),
+            k_factor: None,
+            k_factor_reason: None,
+            games,
+            games_counted,
+            score,
+            expected_score: None,
+            rating_change: None,
+            new_rating,
+            initial_rating: new_rating.is_some(),
+            note: note.map(str::to_string),
+        };
+    };
+
+    let (k, reason) = k_factor(rating, profile, rating_type, year, games_counted);
+    let mut expected_total = 0.0;
+    for game in games.iter_mut().filter(|game| game.counted) {
+        let (Some(opponent_rating), Some(score)) = (game.opponent_rating, game.score) else {
+            continue;
+        };
+        let difference =
+            (rating - opponent_rating).clamp(-MAX_RATING_DIFFERENCE, MAX_RATING_DIFFERENCE);
+        let expected = expected_score(difference);
+        expected_total += expected;
+        game.rating_difference = Some(difference);
+        game.expected_score = Some(expected);
+        game.rating_change = Some(round_tenth(k as f64 * (score - expected)));
+    }
+    let rating_change = round_tenth(k as f64 * (score - expected_total));
+
+    PlayerRatingReport {
+        player: player.clone(),
+        k_factor: Some(k),
+        k_factor_reason: Some(reason.to_string()),
+        games,
+        games_counted,
+        score,
+        expected_score: Some(round_hundredth(expected_total)),
+        rating_change: Some(rating_change),
+        new_rating: Some(rating + rating_change.round() as i32),
+        initial_rating: false,
+        note: None,
+    }
+}
+
+/// Games of a player in round order, byes left out, marked with whether they are rated
+fn rated_games(player: &Player, games: &[Game], players: &[Player]) -> Vec<RatedGame> {
+    let mut player_games: Vec<&Game> = games
+        .iter()
+        .filter(|game| game.white_player_id == player.id || game.black_player_id == player.id)
+        .collect();
+    player_games.sort_by_key(|game| game.round_number);
+
+    player_games
+        .into_iter()
+        .filter_map(|game| {
+            let is_white = game.white_player_id == player.id;
+            let opponent_id = if is_white {
+                game.black_player_id
+            } else {
+                game.white_player_id
+            };
+            let opponent = players.iter().find(|p| p.id == opponent_id)?;
+
+            let (score, note) = match played_score(game, is_white) {
+                Ok(score) if opponent.rating.is_none() => (Some(score), Some("Unrated opponent")),
+                Ok(score) => (Some(score), None),
+                Err(note) => (None, Some(note)),
+            };
+            Some(RatedGame {
+                round_number: game.round_number,
+                opponent_id,
+                opponent_name: opponent.name.clone(),
+                opponent_rating: opponent.rating,
+                color: if is_white { "white" } else { "black" }.to_string(),
+                result: game.result.clone(),
+                score,
+                rating_difference: None,
+                expected_score: None,
+                rating_change: None,
+                counted: note.is_none(),
+                note: note.map(str::to_string),
+            })
+        })
+        .collect()
+}
+
+fn round_tenth(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+fn round_hundredth(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_player(id: i32, rating: Option<i32>) -> Player {
+        Player {
+            id,
+            tournament_id: 1,
+            name: format!("Player {id}"),
+            rating,
+            country_code: None,
+            title: None,
+            birth_date: Some("1990-05-01".to_string()),
+            gender: None,
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: None,
+            initial_rating: None,
+            created_at: "2024-01-01T00:00:00".to_string(),
+            updated_at: None,
+        }
+    }
+
+    fn create_test_game(round: i32, white_id: i32, black_id: i32, result: &str) -> Game {
+        Game {
+            id: round,
+            tournament_id: 1,
+            round_number: round,
+            white_player_id: white_id,
+            black_player_id: black_id,
+            result: result.to_string(),
+            result_type: None,
+            result_reason: None,
+            arbiter_notes: None,
+            last_updated: None,
+            approved_by: None,
+            created_at: "2024-01-01T00:00:00".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_k_factor_rules() {
+        let adult = RatingProfile {
+            birth_year: Some(1990),
+            reached_2400: false,
+            rated_games: None,
+        };
+        assert_eq!(k_factor(2100, &adult, "fide", 2025, 9).0, 20);
+        assert_eq!(k_factor(2400, &adult, "fide", 2025, 9).0, 10);
+        assert_eq!(k_factor(2100, &adult, "rapid", 2025, 9).0, 20);
+
+        // 2400 once reached keeps K = 10
+        let former = RatingProfile {
+            reached_2400: true,
+            ..adult.clone()
+        };
+        assert_eq!(k_factor(2350, &former, "fide", 2025, 9).0, 10);
+
+        // Until the end of the year of the 18th birthday, while under 2300
+        let junior = RatingProfile {
+            birth_year: Some(2007),
+            ..adult.clone()
+        };
+        assert_eq!(k_factor(2100, &junior, "fide", 2025, 9).0, 40);
+        assert_eq!(k_factor(2100, &junior, "fide", 2026, 9).0, 20);
+        assert_eq!(k_factor(2300, &junior, "fide", 2025, 9).0, 20);
+
+        let new_player = RatingProfile {
+            rated_games: Some(12),
+            ..adult.clone()
+        };
+        assert_eq!(k_factor(1700, &new_player, "fide", 2025, 9).0, 40);
+
+        // 40 × 20 games exceeds 700
+        assert_eq!(
+            k_factor(1700, &new_player, "fide", 2025, 20),
+            (35, "K × games limited to 700")
+        );
+    }
+
+    #[test]
+    fn test_played_score() {
+        let game = create_test_game(1, 1, 2, "1-0");
+        assert_eq!(played_score(&game, true), Ok(1.0));
+        assert_eq!(played_score(&game, false), Ok(0.0));
+        assert_eq!(
+            played_score(&create_test_game(1, 1, 2, "0-1T"), true),
+            Ok(0.0)
+        );
+        assert_eq!(
+            played_score(&create_test_game(1, 1, 2, "1-0F"), true),
+            Err("Not played")
+        );
+        assert_eq!(
+            played_score(&create_test_game(1, 1, 2, "*"), true),
+            Err("Not finished")
+        );
+
+        // A forfeit recorded through the result type
+        let mut forfeit = create_test_game(1, 1, 2, "1-0");
+        forfeit.result_type = Some("black_forfeit".to_string());
+        assert_eq!(played_score(&forfeit, true), Err("Not played"));
+    }
+
+    #[test]
+    fn test_rated_player_report() {
+        let players = vec![
+            create_test_player(1, Some(2000)),
+            create_test_player(2, Some(2100)),
+            create_test_player(3, Some(2200)),
+            create_test_player(4, Some(1900)),
+            create_test_player(5, Some(1500)),
+            create_test_player(6, None),
+            create_test_player(7, Some(2050)),
+        ];
+        let games = vec![
+            create_test_game(1, 1, 2, "1-0"),
+            create_test_game(2, 3, 1, "1-0"),
+            create_test_game(3, 1, 4, "1/2-1/2"),
+            create_test_game(4, 5, 1, "0-1"),
+            create_test_game(5, 1, 6, "1-0"),
+            create_test_game(6, 7, 1, "0-1F"),
+            create_test_game(7, 1, -1, "1-0"),
+        ];
+        let profile = RatingProfile::from_player(&players[0]);
+        let report = player_rating_report(&players[0], &games, &players, &profile, "fide", 2025);
+
+        // The bye is left out, the unrated opponent and the forfeit are listed but not counted
+        assert_eq!(report.games.len(), 6);
+        assert_eq!(report.games_counted, 4);
+        assert_eq!(report.k_factor, Some(20));
+        assert_eq!(report.games[4].note.as_deref(), Some("Unrated opponent"));
+        assert_eq!(report.games[5].note.as_deref(), Some("Not played"));
+
+        // 500 points above the 1500 opponent count as 400
+        assert_eq!(report.games[3].rating_difference, Some(400));
+        assert!((report.games[3].expected_score.unwrap() - 0.92).abs() < 1e-9);
+
+        // 2.5 against 0.36 + 0.24 + 0.64 + 0.92
+        assert_eq!(report.score, 2.5);
+        assert_eq!(report.expected_score, Some(2.16));
+        assert_eq!(report.rating_change, Some(6.8));
+        assert_eq!(report.new_rating, Some(2007));
+    }
+
+    #[test]
+    fn test_initial_rating() {
+        let mut players = vec![create_test_player(1, None)];
+        players.extend((2..=7).map(|id| create_test_player(id, Some(1700 + id * 10))));
+        let results = ["1-0", "1-0", "1/2-1/2", "0-1", "1-0", "1-0"];
+        let games: Vec<Game> = results
+            .iter()
+            .enumerate()
+            .map(|(index, result)| create_test_game(index as i32 + 1, 1, index as i32 + 2, result))
+            .collect();
+
+        // 4.5 + 1 out of 8, against 10470 + 3600 over 8 = 1758.75
+        let report = player_rating_report(
+            &players[0],
+            &games,
+            &players,
+            &RatingProfile::default(),
+            "fide",
+            2025,
+        );
+        assert_eq!(report.games_counted, 6);
+        assert!(report.initial_rating);
+        assert_eq!(report.new_rating, Some(1759 + rating_difference(5.5 / 8.0)));
+
+        // Five games are needed
+        let report = player_rating_report(
+            &players[0],
+            &games[..4],
+            &players,
+            &RatingProfile::default(),
+            "fide",
+            2025,
+        );
+        assert_eq!(report.new_rating, None);
+        assert!(!report.initial_rating);
+
+        assert_eq!(
+            initial_rating(&[1400, 1400, 1400, 1400, 1400], 0.0),
+            Some(1205)
+        );
+    }
+}
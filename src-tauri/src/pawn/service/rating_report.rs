@@ -0,0 +1,161 @@
+use chrono::Datelike;
+use std::{collections::HashSet, sync::Arc};
+
+use crate::pawn::{
+    common::error::PawnError,
+    db::Db,
+    domain::{
+        dto::RatingReport,
+        model::{PersonRating, Player, Tournament},
+    },
+    service::{
+        fide_rating_list::tournament_rating_type,
+        rating::{RatingProfile, date_year, player_rating_report, rated_game_count},
+    },
+};
+
+pub struct RatingReportService<D> {
+    db: Arc<D>,
+}
+
+impl<D: Db> RatingReportService<D> {
+    pub fn new(db: Arc<D>) -> Self {
+        Self { db }
+    }
+
+    /// FIDE rating calculation of every player, with K-factors from the player registry
+    pub async fn calculate_rating_report(
+        &self,
+        tournament_id: i32,
+    ) -> Result<RatingReport, PawnError> {
+        let tournament = self
+            .db
+            .get_tournament(tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let players = self
+            .db
+            .get_players_by_tournament(tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let games = self
+            .db
+            .get_games_by_tournament(tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+
+        let rating_type = tournament_rating_type(&tournament.time_type);
+        let year =
+            date_year(Some(tournament.date.as_str())).unwrap_or_else(|| chrono::Utc::now().year());
+        let mut reports = Vec::new();
+        for player in &players {
+            let profile = self
+                .rating_profile(player, &tournament, rating_type)
+                .await?;
+            reports.push(player_rating_report(
+                player,
+                &games,
+                &players,
+                &profile,
+                rating_type,
+                year,
+            ));
+        }
+
+        Ok(RatingReport {
+            tournament_id,
+            rating_type: rating_type.to_string(),
+            players: reports,
+        })
+    }
+
+    /// K-factor data of a player from the registry person, when the player is linked to one
+    async fn rating_profile(
+        &self,
+        player: &Player,
+        tournament: &Tournament,
+        rating_type: &str,
+    ) -> Result<RatingProfile, PawnError> {
+        let mut profile = RatingProfile::from_player(player);
+        let Some(person) = self
+            .db
+            .get_player_person(player.id)
+            .await
+            .map_err(PawnError::Database)?
+        else {
+            return Ok(profile);
+        };
+
+        if let Some(birth_year) = date_year(person.birth_date.as_deref()) {
+            profile.birth_year = Some(birth_year);
+        }
+        let history: Vec<PersonRating> = self
+            .db
+            .get_person_rating_history(person.id)
+            .await
+            .map_err(PawnError::Database)?
+            .into_iter()
+            .filter(|rating| rating.rating_type == rating_type)
+            .collect();
+        profile.reached_2400 |= history.iter().any(|rating| rating.rating >= 2400);
+
+        // Games since the first rating are only known when the registry holds it
+        let Some(first) = history.last().filter(|rating| rating.is_provisional) else {
+            return Ok(profile);
+        };
+        let earlier: HashSet<i32> = self
+            .db
+            .get_person_tournaments(person.id)
+            .await
+            .map_err(PawnError::Database)?
+            .into_iter()
+            .filter(|earlier| {
+                earlier.date.as_str() < tournament.date.as_str()
+                    && earlier.date.as_str() >= first.effective_date.as_str()
+                    && tournament_rating_type(&earlier.time_type) == rating_type
+            })
+            .map(|earlier| earlier.id)
+            .collect();
+        if earlier.is_empty() {
+            profile.rated_games = Some(0);
+            return Ok(profile);
+        }
+
+        // Players and games of all the person's tournaments come in one query each
+        let entries = self
+            .db
+            .get_person_players(person.id)
+            .await
+            .map_err(PawnError::Database)?;
+        let players = self
+            .db
+            .get_person_tournament_players(person.id)
+            .await
+            .map_err(PawnError::Database)?;
+        let games = self
+            .db
+            .get_person_tournament_games(person.id)
+            .await
+            .map_err(PawnError::Database)?;
+
+        let mut rated_games = 0;
+        for entry in entries
+            .iter()
+            .filter(|entry| earlier.contains(&entry.tournament_id))
+        {
+            let tournament_players: Vec<Player> = players
+                .iter()
+                .filter(|player| player.tournament_id == entry.tournament_id)
+                .cloned()
+                .collect();
+            let tournament_games: Vec<_> = games
+                .iter()
+                .filter(|game| game.tournament_id == entry.tournament_id)
+                .cloned()
+                .collect();
+            rated_games += rated_game_count(entry, &tournament_games, &tournament_players);
+        }
+        profile.rated_games = Some(rated_games);
+        Ok(profile)
+    }
+}
@@ -0,0 +1,169 @@
+use crate::pawn::{
+    common::error::PawnError,
+    domain::{dto::CreatePerson, model::Person},
+};
+
+const RATING_TYPES: [&str; 5] = ["fide", "national", "club", "rapid", "blitz"];
+
+#[allow(dead_code)]
+pub struct RegistryService;
+
+#[allow(dead_code)]
+impl RegistryService {
+    /// Check a person before they are added to the registry
+    pub fn validate_person(data: &CreatePerson) -> Result<(), PawnError> {
+        if data.name.trim().is_empty() {
+            return Err(PawnError::ValidationError(
+                "Person name cannot be empty".to_string(),
+            ));
+        }
+        if let Some(fide_id) = &data.fide_id
+            && (fide_id.is_empty() || !fide_id.chars().all(|c| c.is_ascii_digit()))
+        {
+            return Err(PawnError::ValidationError(format!(
+                "{fide_id} is not a FIDE ID"
+            )));
+        }
+        if let Some(gender) = &data.gender
+            && !["M", "F", "O"].contains(&gender.as_str())
+        {
+            return Err(PawnError::ValidationError(
+                "Gender must be M, F, or O".to_string(),
+            ));
+        }
+        if [
+            data.fide_rating,
+            data.national_rating,
+            data.rapid_rating,
+            data.blitz_rating,
+        ]
+        .into_iter()
+        .flatten()
+        .any(|rating| !(0..=4000).contains(&rating))
+        {
+            return Err(PawnError::ValidationError(
+                "Rating must be between 0 and 4000".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check a rating before it is recorded in a rating history
+    pub fn validate_rating(rating_type: &str, rating: i32) -> Result<(), PawnError> {
+        if !RATING_TYPES.contains(&rating_type) {
+            return Err(PawnError::ValidationError(format!(
+                "Unknown rating type: {rating_type}"
+            )));
+        }
+        if !(0..=4000).contains(&rating) {
+            return Err(PawnError::ValidationError(
+                "Rating must be between 0 and 4000".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Rating a person enters a tournament with, FIDE unless the caller asks for another one
+    pub fn registration_rating(
+        person: &Person,
+        rating_type: Option<&str>,
+    ) -> Result<Option<i32>, PawnError> {
+        Ok(match rating_type {
+            None | Some("fide") => person.fide_rating,
+            Some("national") => person.national_rating,
+            Some("rapid") => person.rapid_rating,
+            Some("blitz") => person.blitz_rating,
+            Some(other) => {
+                return Err(PawnError::InvalidInput(format!(
+                    "The registry has no {other} rating"
+                )));
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_person() -> Person {
+        Person {
+            id: 1,
+            name: "Ding Liren".to_string(),
+            fide_id: Some("8603677".to_string()),
+            national_id: None,
+            federation: Some("CHN".to_string()),
+            birth_date: None,
+            gender: Some("M".to_string()),
+            title: Some("GM".to_string()),
+            fide_rating: None,
+            national_rating: Some(2700),
+            rapid_rating: Some(2750),
+            blitz_rating: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    fn create_person_data() -> CreatePerson {
+        CreatePerson {
+            name: "Ding Liren".to_string(),
+            fide_id: Some("8603677".to_string()),
+            national_id: None,
+            federation: Some("CHN".to_string()),
+            birth_date: None,
+            gender: Some("M".to_string()),
+            title: Some("GM".to_string()),
+            fide_rating: Some(2780),
+            national_rating: None,
+            rapid_rating: None,
+            blitz_rating: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_person() {
+        let mut data = create_person_data();
+        assert!(RegistryService::validate_person(&data).is_ok());
+
+        data.fide_id = Some("86O3677".to_string());
+        assert!(RegistryService::validate_person(&data).is_err());
+
+        data.fide_id = None;
+        data.blitz_rating = Some(4100);
+        assert!(RegistryService::validate_person(&data).is_err());
+
+        data.blitz_rating = None;
+        data.name = "  ".to_string();
+        assert!(RegistryService::validate_person(&data).is_err());
+    }
+
+    #[test]
+    fn test_validate_rating() {
+        assert!(RegistryService::validate_rating("club", 1500).is_ok());
+        assert!(RegistryService::validate_rating("elo", 1500).is_err());
+        assert!(RegistryService::validate_rating("fide", -1).is_err());
+    }
+
+    #[test]
+    fn test_registration_rating() {
+        let person = create_person();
+        assert_eq!(
+            RegistryService::registration_rating(&person, None).unwrap(),
+            None
+        );
+        assert_eq!(
+            RegistryService::registration_rating(&person, Some("national")).unwrap(),
+            Some(2700)
+        );
+        assert_eq!(
+            RegistryService::registration_rating(&person, Some("rapid")).unwrap(),
+            Some(2750)
+        );
+        assert_eq!(
+            RegistryService::registration_rating(&person, Some("blitz")).unwrap(),
+            None
+        );
+        assert!(RegistryService::registration_rating(&person, Some("club")).is_err());
+    }
+}
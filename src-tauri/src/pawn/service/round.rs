@@ -2,17 +2,43 @@ use crate::pawn::{
     common::error::PawnError,
     db::Db,
     domain::{
-        dto::{CreateGame, CreateRound, GeneratePairingsRequest, UpdateRoundStatus},
-        model::{GameResult, Pairing, PairingMethod, Round, RoundDetails, RoundStatus},
+        dto::{
+            ApplyRoundRepair, CheckPairingsRequest, CreateForbiddenPairing, CreateGame,
+            CreatePairingDraft, CreateRound, GeneratePairingsRequest, PairingCheckReport,
+            PairingDraftComparison, PairingDraftSummary, RepairRoundRequest, RepairRoundResult,
+            RepairedGame, UpdateAccelerationSettings, UpdateRoundStatus,
+        },
+        model::{
+            AccelerationSettings, ForbiddenPairing, GameResult, Pairing, PairingDraft,
+            PairingMethod, Player, Round, RoundDetails, RoundStatus,
+        },
+        tiebreak::{StandingsCalculationResult, TiebreakType, TournamentTiebreakConfig},
+    },
+    service::{
+        acceleration::{BakuAcceleration, validate_acceleration_settings},
+        burstein::{BURSTEIN_INDEX_TIEBREAKS, index_from_standings, seeding_rounds},
+        dubov::aro_from_standings,
+        forbidden_pairing::{
+            check_forbidden_boards, forbidden_pairs, same_club_pairs, validate_forbidden_pairing,
+        },
+        pairing::PairingService,
+        pairing_checker::{PairingChecker, import_bye_requests, import_to_models, player_results},
+        pairing_draft::{default_draft_name, draft_metrics},
+        pairing_explanation::PairingExplainer,
+        partial_repairing::{
+            audit_details, changed_boards, number_repaired_boards, plan_repairing, round_pairings,
+        },
+        tiebreak::TiebreakCalculator,
+        trf::parse_trf,
     },
-    service::pairing::PairingService,
 };
 use std::sync::Arc;
 
 #[allow(dead_code)]
 pub struct RoundService<D> {
     db: Arc<D>,
-    pairing_service: PairingService,
+    pairing_checker: PairingChecker,
+    pairing_explainer: PairingExplainer,
 }
 
 #[allow(dead_code)]
@@ -20,7 +46,8 @@ impl<D: Db> RoundService<D> {
     pub fn new(db: Arc<D>) -> Self {
         Self {
             db,
-            pairing_service: PairingService::new(),
+            pairing_checker: PairingChecker::new(),
+            pairing_explainer: PairingExplainer::new(),
         }
     }
 
@@ -160,18 +187,43 @@ impl<D: Db> RoundService<D> {
             .await
             .map_err(PawnError::Database)?;
 
+        let pairing_explanations = self
+            .db
+            .get_pairing_explanations(round.tournament_id, round.round_number)
+            .await
+            .map_err(PawnError::Database)?;
+
         let status = round.status.parse().unwrap_or(RoundStatus::Planned);
 
+        let audit_trail = self
+            .db
+            .get_round_audit_trail(round.id)
+            .await
+            .map_err(PawnError::Database)?;
+
         Ok(RoundDetails {
             round,
             games,
             status,
+            pairing_explanations,
+            audit_trail,
         })
     }
 
     pub async fn generate_pairings(
         &self,
         request: GeneratePairingsRequest,
+    ) -> Result<Vec<Pairing>, PawnError> {
+        self.generate_pairings_keeping_apart(request, std::collections::HashSet::new())
+            .await
+    }
+
+    /// Generate pairings that also keep the given player pairs apart, on top of
+    /// the stored forbidden pairings
+    async fn generate_pairings_keeping_apart(
+        &self,
+        request: GeneratePairingsRequest,
+        keep_apart: std::collections::HashSet<(i32, i32)>,
     ) -> Result<Vec<Pairing>, PawnError> {
         tracing::info!(
             "Generating pairings for tournament {}, round {}, method: {}",
@@ -223,68 +275,529 @@ impl<D: Db> RoundService<D> {
 
         tracing::debug!("Found {} player results", player_results.len());
 
-        // For Swiss system, get game history to avoid rematches and balance colors
-        let pairings = if pairing_method == PairingMethod::Swiss && request.round_number > 1 {
-            // Get all previous games for this tournament
-            let all_games = self
-                .db
-                .get_games_by_tournament(request.tournament_id)
-                .await
-                .map_err(PawnError::Database)?;
-
-            // Create a lookup map for players
-            let player_map: std::collections::HashMap<i32, &crate::pawn::domain::model::Player> =
-                players.iter().map(|p| (p.id, p)).collect();
-
-            // Convert games to GameResult format for history analysis
-            let mut game_history = Vec::new();
-            for game in all_games {
-                // Skip games from the current round (shouldn't exist yet, but be safe)
-                if game.round_number >= request.round_number {
-                    continue;
-                }
-
-                // Get player details from our lookup map
-                if let (Some(white_player), Some(black_player)) = (
-                    player_map.get(&game.white_player_id),
-                    player_map.get(&game.black_player_id),
-                ) {
-                    game_history.push(crate::pawn::domain::model::GameResult {
-                        game,
-                        white_player: (*white_player).clone(),
-                        black_player: (*black_player).clone(),
-                    });
-                }
-            }
+        // For Swiss system, get game history to avoid rematches and balance colors.
+        // Players sitting this round out still count for their former opponents
+        let game_history = if pairing_method.is_swiss_system() && request.round_number > 1 {
+            let game_history = self
+                .game_history(request.tournament_id, request.round_number, &players)
+                .await?;
 
             tracing::debug!(
                 "Found {} games in history for pairing analysis",
                 game_history.len()
             );
 
+            game_history
+        } else {
+            Vec::new()
+        };
+
+        // Players with a requested bye sit this round out; round-robin schedules keep
+        // them so that no other player's scheduled opponent changes
+        let requested_bye_ids: std::collections::HashSet<i32> = self
+            .db
+            .get_bye_requests(request.tournament_id)
+            .await
+            .map_err(PawnError::Database)?
+            .into_iter()
+            .filter(|bye| bye.round_number == request.round_number)
+            .map(|bye| bye.player_id)
+            .collect();
+        let (requested_byes, players): (Vec<_>, Vec<_>) = players.into_iter().partition(|player| {
+            !pairing_method.is_round_robin() && requested_bye_ids.contains(&player.id)
+        });
+
+        // Baku acceleration only applies to the Dutch System
+        let acceleration = if pairing_method == PairingMethod::Swiss {
+            self.acceleration(request.tournament_id).await?
+        } else {
+            None
+        };
+
+        // Stored forbidden pairings are treated as absolute by every Swiss engine
+        let restrictions = self
+            .db
+            .get_forbidden_pairings(request.tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let restricted = forbidden_pairs(&restrictions, request.round_number);
+        let mut forbidden = restricted.clone();
+        forbidden.extend(keep_apart);
+        let pairing_service = PairingService::new().with_forbidden_pairs(forbidden);
+
+        let mut pairings = if pairing_method.is_swiss_system() && request.round_number > 1 {
             // Generate pairings with history awareness
-            self.pairing_service.generate_pairings_with_history(
-                players,
-                player_results,
-                game_history,
+            let mut pairings = if pairing_method == PairingMethod::Burstein
+                && request.round_number > seeding_rounds(tournament.total_rounds)
+            {
+                let standings = self
+                    .pairing_standings(request.tournament_id, &BURSTEIN_INDEX_TIEBREAKS)
+                    .await?;
+                pairing_service.generate_burstein_pairings(
+                    players,
+                    player_results.clone(),
+                    game_history.clone(),
+                    request.round_number,
+                    Some(&index_from_standings(&standings)),
+                )?
+            } else if pairing_method == PairingMethod::Dubov {
+                let standings = self
+                    .pairing_standings(
+                        request.tournament_id,
+                        &[TiebreakType::AverageRatingOfOpponents],
+                    )
+                    .await?;
+                pairing_service.generate_dubov_pairings(
+                    players,
+                    player_results.clone(),
+                    game_history.clone(),
+                    request.round_number,
+                    &aro_from_standings(&standings),
+                )?
+            } else if acceleration.is_some() {
+                pairing_service.generate_accelerated_swiss_pairings(
+                    players,
+                    player_results.clone(),
+                    game_history.clone(),
+                    request.round_number,
+                    acceleration.as_ref(),
+                )?
+            } else {
+                pairing_service.generate_pairings_with_history(
+                    players,
+                    player_results.clone(),
+                    game_history.clone(),
+                    request.round_number,
+                    &pairing_method,
+                )?
+            };
+            self.pairing_explainer.explain_pairings(
+                &mut pairings,
+                &player_results,
+                &game_history,
                 request.round_number,
-                &pairing_method,
-            )?
+            );
+            pairings
         } else {
             // First round or non-Swiss system - use basic pairing
-            self.pairing_service.generate_pairings(
-                players,
-                player_results,
-                request.round_number,
-                &pairing_method,
-            )?
+            let mut pairings = if acceleration.is_some() {
+                pairing_service.generate_accelerated_swiss_pairings(
+                    players,
+                    player_results.clone(),
+                    vec![],
+                    request.round_number,
+                    acceleration.as_ref(),
+                )?
+            } else {
+                pairing_service.generate_pairings(
+                    players,
+                    player_results.clone(),
+                    request.round_number,
+                    &pairing_method,
+                )?
+            };
+            if pairing_method.is_swiss_system() {
+                self.pairing_explainer.explain_pairings(
+                    &mut pairings,
+                    &player_results,
+                    &[],
+                    request.round_number,
+                );
+            }
+            pairings
         };
+        if pairing_method.is_round_robin() {
+            pairings = scheduled_byes(pairings, &requested_bye_ids);
+        }
+
+        // A round-robin schedule cannot move a restricted pair to another round
+        check_forbidden_boards(&pairings, &restricted)?;
+
+        // Requested byes are boards without a black player, like the pairing-allocated bye
+        let first_bye_board = pairings.len() as i32 + 1;
+        for (board_number, player) in (first_bye_board..).zip(requested_byes) {
+            pairings.push(Pairing {
+                white_player: player,
+                black_player: None,
+                board_number,
+                explanation: None,
+            });
+        }
 
         tracing::info!("Generated {} pairings successfully", pairings.len());
 
         Ok(pairings)
     }
 
+    /// Games of the rounds before `round_number` between the given players, with
+    /// byes against the virtual BYE player
+    async fn game_history(
+        &self,
+        tournament_id: i32,
+        round_number: i32,
+        players: &[Player],
+    ) -> Result<Vec<GameResult>, PawnError> {
+        let all_games = self
+            .db
+            .get_games_by_tournament(tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+
+        // Create a lookup map for players
+        let player_map: std::collections::HashMap<i32, &Player> =
+            players.iter().map(|p| (p.id, p)).collect();
+
+        // Convert games to GameResult format for history analysis
+        let mut game_history = Vec::new();
+        for game in all_games {
+            // Skip games from the current round and later ones
+            if game.round_number >= round_number {
+                continue;
+            }
+
+            // Bye games are stored against the virtual BYE player, which is
+            // not part of the player list but is needed for the bye history
+            let lookup = |player_id: i32| {
+                if player_id == bye_player_id(tournament_id) {
+                    Some(virtual_bye_player(tournament_id))
+                } else {
+                    player_map.get(&player_id).map(|player| (*player).clone())
+                }
+            };
+
+            // Get player details from our lookup map
+            if let (Some(white_player), Some(black_player)) =
+                (lookup(game.white_player_id), lookup(game.black_player_id))
+            {
+                game_history.push(GameResult {
+                    game,
+                    white_player,
+                    black_player,
+                });
+            }
+        }
+
+        Ok(game_history)
+    }
+
+    /// Standings over the games played so far with the tiebreaks a pairing system
+    /// uses as input
+    async fn pairing_standings(
+        &self,
+        tournament_id: i32,
+        tiebreaks: &[TiebreakType],
+    ) -> Result<StandingsCalculationResult, PawnError> {
+        let config = TournamentTiebreakConfig {
+            tournament_id,
+            tiebreaks: tiebreaks.to_vec(),
+            ..Default::default()
+        };
+        TiebreakCalculator::new(self.db.clone())
+            .calculate_standings(tournament_id, &config)
+            .await
+    }
+
+    /// Baku acceleration configured for the tournament, `None` when disabled
+    async fn acceleration(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Option<BakuAcceleration>, PawnError> {
+        match self
+            .db
+            .get_acceleration_settings(tournament_id)
+            .await
+            .map_err(PawnError::Database)?
+        {
+            Some(settings) => BakuAcceleration::from_settings(&settings),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn get_acceleration_settings(
+        &self,
+        tournament_id: i32,
+    ) -> Result<AccelerationSettings, PawnError> {
+        let settings = self
+            .db
+            .get_acceleration_settings(tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+
+        // Tournaments without stored settings are not accelerated
+        Ok(settings.unwrap_or(AccelerationSettings {
+            tournament_id,
+            enabled: false,
+            accelerated_rounds: 2,
+            group_boundary: "starting_rank".to_string(),
+            group_a_min_rating: None,
+            virtual_points_first_half: 1.0,
+            virtual_points_second_half: 0.5,
+            updated_at: None,
+        }))
+    }
+
+    pub async fn update_acceleration_settings(
+        &self,
+        data: UpdateAccelerationSettings,
+    ) -> Result<AccelerationSettings, PawnError> {
+        validate_acceleration_settings(&data)?;
+
+        let tournament = self
+            .db
+            .get_tournament(data.tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+        if data.accelerated_rounds > tournament.total_rounds {
+            return Err(PawnError::InvalidInput(format!(
+                "Cannot accelerate {} rounds of a {} round tournament",
+                data.accelerated_rounds, tournament.total_rounds
+            )));
+        }
+
+        self.db
+            .upsert_acceleration_settings(&data)
+            .await
+            .map_err(PawnError::Database)
+    }
+
+    pub async fn get_forbidden_pairings(
+        &self,
+        tournament_id: i32,
+    ) -> Result<Vec<ForbiddenPairing>, PawnError> {
+        self.db
+            .get_forbidden_pairings(tournament_id)
+            .await
+            .map_err(PawnError::Database)
+    }
+
+    pub async fn create_forbidden_pairing(
+        &self,
+        data: CreateForbiddenPairing,
+    ) -> Result<ForbiddenPairing, PawnError> {
+        let tournament = self
+            .db
+            .get_tournament(data.tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let players = self
+            .db
+            .get_players_by_tournament(data.tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+        validate_forbidden_pairing(&data, &players, tournament.total_rounds)?;
+
+        self.db
+            .create_forbidden_pairing(&data)
+            .await
+            .map_err(PawnError::Database)
+    }
+
+    pub async fn delete_forbidden_pairing(&self, pairing_id: i32) -> Result<(), PawnError> {
+        self.db
+            .delete_forbidden_pairing(pairing_id)
+            .await
+            .map_err(PawnError::Database)
+    }
+
+    /// Generate a candidate pairing for a round still being paired and keep it
+    /// as a draft next to the others
+    pub async fn create_pairing_draft(
+        &self,
+        data: CreatePairingDraft,
+    ) -> Result<PairingDraftSummary, PawnError> {
+        let round = self.pairing_round(data.round_id).await?;
+
+        let keep_apart = if data.avoid_same_club {
+            let players = self
+                .db
+                .get_players_by_tournament(round.tournament_id)
+                .await
+                .map_err(PawnError::Database)?;
+            same_club_pairs(&players)
+        } else {
+            std::collections::HashSet::new()
+        };
+        let pairings = self
+            .generate_pairings_keeping_apart(
+                GeneratePairingsRequest {
+                    tournament_id: round.tournament_id,
+                    round_number: round.round_number,
+                    pairing_method: data.pairing_method.clone(),
+                },
+                keep_apart,
+            )
+            .await?;
+
+        let name = data
+            .name
+            .clone()
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or_else(|| default_draft_name(&data.pairing_method, data.avoid_same_club));
+        let draft = self
+            .db
+            .create_pairing_draft(&data, &name, &pairings)
+            .await
+            .map_err(PawnError::Database)?;
+
+        let mut summaries = self.summarize_drafts(&round, vec![draft]).await?;
+        Ok(summaries.remove(0))
+    }
+
+    /// Drafts of a round with their quality metrics, oldest first
+    pub async fn get_pairing_drafts(
+        &self,
+        round_id: i32,
+    ) -> Result<Vec<PairingDraftSummary>, PawnError> {
+        let round = self
+            .db
+            .get_round(round_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let drafts = self
+            .db
+            .get_pairing_drafts(round_id)
+            .await
+            .map_err(PawnError::Database)?;
+        self.summarize_drafts(&round, drafts).await
+    }
+
+    /// Two drafts of the same round side by side, with the boards that differ
+    pub async fn compare_pairing_drafts(
+        &self,
+        first_id: i32,
+        second_id: i32,
+    ) -> Result<PairingDraftComparison, PawnError> {
+        let first = self
+            .db
+            .get_pairing_draft(first_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let second = self
+            .db
+            .get_pairing_draft(second_id)
+            .await
+            .map_err(PawnError::Database)?;
+        if first.round_id != second.round_id {
+            return Err(PawnError::InvalidInput(
+                "Only drafts of the same round can be compared".into(),
+            ));
+        }
+
+        let round = self
+            .db
+            .get_round(first.round_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let changed_boards = changed_boards(&first.pairings, &second.pairings);
+        let mut summaries = self
+            .summarize_drafts(&round, vec![first, second])
+            .await?
+            .into_iter();
+
+        Ok(PairingDraftComparison {
+            first: summaries.next().unwrap(),
+            second: summaries.next().unwrap(),
+            changed_boards,
+        })
+    }
+
+    pub async fn delete_pairing_draft(&self, draft_id: i32) -> Result<(), PawnError> {
+        self.db
+            .delete_pairing_draft(draft_id)
+            .await
+            .map_err(PawnError::Database)
+    }
+
+    /// Turn the chosen draft into the games of its round, publish the round and
+    /// discard the other drafts
+    pub async fn publish_pairing_draft(&self, draft_id: i32) -> Result<Vec<GameResult>, PawnError> {
+        let draft = self
+            .db
+            .get_pairing_draft(draft_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let round = self.pairing_round(draft.round_id).await?;
+
+        let existing_games = self
+            .db
+            .get_games_by_round(round.tournament_id, round.round_number)
+            .await
+            .map_err(PawnError::Database)?;
+        if !existing_games.is_empty() {
+            return Err(PawnError::InvalidInput(format!(
+                "Round {} already has games",
+                round.round_number
+            )));
+        }
+
+        let games = self
+            .create_pairings_as_games(round.tournament_id, round.round_number, draft.pairings)
+            .await?;
+        self.update_round_status(UpdateRoundStatus {
+            round_id: round.id,
+            status: RoundStatus::Published.to_str().to_string(),
+        })
+        .await?;
+        self.db
+            .delete_round_pairing_drafts(round.id)
+            .await
+            .map_err(PawnError::Database)?;
+
+        tracing::info!(
+            "Published draft \"{}\" for round {} of tournament {}",
+            draft.name,
+            round.round_number,
+            round.tournament_id
+        );
+
+        Ok(games)
+    }
+
+    /// A round that is being paired, the only state drafts belong to
+    async fn pairing_round(&self, round_id: i32) -> Result<Round, PawnError> {
+        let round = self
+            .db
+            .get_round(round_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let status: RoundStatus = round.status.parse().unwrap_or(RoundStatus::Planned);
+        if status != RoundStatus::Pairing {
+            return Err(PawnError::InvalidInput(format!(
+                "Round {} is not being paired",
+                round.round_number
+            )));
+        }
+        Ok(round)
+    }
+
+    /// Attach quality metrics, measured against the games before the round
+    async fn summarize_drafts(
+        &self,
+        round: &Round,
+        drafts: Vec<PairingDraft>,
+    ) -> Result<Vec<PairingDraftSummary>, PawnError> {
+        let players = self
+            .db
+            .get_players_by_tournament(round.tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let game_history = self
+            .game_history(round.tournament_id, round.round_number, &players)
+            .await?;
+        let scores: std::collections::HashMap<i32, f32> = player_results(&players, &game_history)
+            .into_iter()
+            .map(|result| (result.player.id, result.points))
+            .collect();
+
+        Ok(drafts
+            .into_iter()
+            .map(|draft| PairingDraftSummary {
+                metrics: draft_metrics(&draft.pairings, &game_history, &scores),
+                draft,
+            })
+            .collect())
+    }
+
     pub async fn create_pairings_as_games(
         &self,
         tournament_id: i32,
@@ -303,6 +816,46 @@ impl<D: Db> RoundService<D> {
             return Ok(existing_games);
         }
 
+        // Manual pairings are entered without the engines keeping restricted players apart
+        let restrictions = self
+            .db
+            .get_forbidden_pairings(tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+        check_forbidden_boards(&pairings, &forbidden_pairs(&restrictions, round_number))?;
+
+        self.insert_pairings(tournament_id, round_number, pairings)
+            .await
+    }
+
+    /// Points of the byes players requested for a round
+    async fn requested_bye_points(
+        &self,
+        tournament_id: i32,
+        round_number: i32,
+    ) -> Result<std::collections::HashMap<i32, f64>, PawnError> {
+        Ok(self
+            .db
+            .get_bye_requests(tournament_id)
+            .await
+            .map_err(PawnError::Database)?
+            .into_iter()
+            .filter(|bye| bye.round_number == round_number)
+            .map(|bye| (bye.player_id, bye.bye_points))
+            .collect())
+    }
+
+    /// Store pairings as games of a round, byes as games against the BYE player
+    async fn insert_pairings(
+        &self,
+        tournament_id: i32,
+        round_number: i32,
+        pairings: Vec<Pairing>,
+    ) -> Result<Vec<GameResult>, PawnError> {
+        let requested_bye_points = self
+            .requested_bye_points(tournament_id, round_number)
+            .await?;
+
         let mut created_games = Vec::new();
         let mut used_white_players = std::collections::HashSet::new();
         let mut used_black_players = std::collections::HashSet::new();
@@ -335,77 +888,213 @@ impl<D: Db> RoundService<D> {
         }
 
         for pairing in pairings {
-            if let Some(black_player) = pairing.black_player {
-                // Regular game
-                let game_data = CreateGame {
-                    tournament_id,
-                    round_number,
-                    white_player_id: pairing.white_player.id,
-                    black_player_id: black_player.id,
-                    result: "*".to_string(), // Ongoing game
-                };
-
-                let game = self
-                    .db
-                    .create_game(game_data)
-                    .await
-                    .map_err(PawnError::Database)?;
-                let game_result = GameResult {
-                    game,
-                    white_player: pairing.white_player,
-                    black_player,
-                };
-                created_games.push(game_result);
-            } else {
-                // Bye - create a special game using virtual bye player
-                // Use a unique negative ID that combines tournament and round to ensure uniqueness
-                let bye_player_id = -(tournament_id * 1000 + round_number); // Unique virtual bye player ID per round
-                let game_data = CreateGame {
+            let board_number = pairing.board_number;
+            let explanation = pairing.explanation.clone();
+            let game = self
+                .db
+                .create_game(pairing_game(
                     tournament_id,
                     round_number,
-                    white_player_id: pairing.white_player.id,
-                    black_player_id: bye_player_id,
-                    result: "1-0".to_string(), // Bye is treated as a win
-                };
-
-                // Create a virtual bye player for the result display
-                let bye_player = crate::pawn::domain::model::Player {
-                    id: bye_player_id,
-                    tournament_id,
-                    name: "BYE".to_string(),
-                    rating: None,
-                    country_code: None,
-                    title: None,
-                    birth_date: None,
-                    gender: None,
-                    email: None,
-                    phone: None,
-                    club: None,
-                    status: "bye".to_string(),
-                    seed_number: None,
-                    pairing_number: None,
-                    initial_rating: None,
-                    created_at: chrono::Utc::now().to_rfc3339(),
-                    updated_at: None,
-                };
-
-                let game = self
-                    .db
-                    .create_game(game_data)
+                    &pairing,
+                    &requested_bye_points,
+                ))
+                .await
+                .map_err(PawnError::Database)?;
+            created_games.push(GameResult {
+                game,
+                white_player: pairing.white_player,
+                // Byes are shown against the virtual BYE player
+                black_player: pairing
+                    .black_player
+                    .unwrap_or_else(|| virtual_bye_player(tournament_id)),
+            });
+
+            // Keep the explanation with the round so it can be shown later
+            if let (Some(explanation), Some(created)) = (&explanation, created_games.last()) {
+                self.db
+                    .save_pairing_explanation(created.game.id, board_number, explanation)
                     .await
                     .map_err(PawnError::Database)?;
-                let game_result = GameResult {
-                    game,
-                    white_player: pairing.white_player,
-                    black_player: bye_player,
-                };
-                created_games.push(game_result);
             }
         }
 
         Ok(created_games)
     }
 
+    /// Re-pair the unlocked boards of a published round after players were
+    /// removed or added. Locked boards and boards of requested byes are kept;
+    /// the other players are paired again by the Swiss engine against the same
+    /// history. Nothing is stored unless the request asks to apply the result.
+    pub async fn repair_round(
+        &self,
+        request: RepairRoundRequest,
+    ) -> Result<RepairRoundResult, PawnError> {
+        let round = self
+            .db
+            .get_round(request.round_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let (tournament_id, round_number) = (round.tournament_id, round.round_number);
+
+        let status: RoundStatus = round.status.parse().unwrap_or(RoundStatus::Planned);
+        if matches!(status, RoundStatus::Completed | RoundStatus::Verified) {
+            return Err(PawnError::InvalidInput(format!(
+                "Round {round_number} is already completed"
+            )));
+        }
+
+        let pairing_method: PairingMethod = request
+            .pairing_method
+            .parse()
+            .unwrap_or(PairingMethod::Manual);
+        if !pairing_method.is_swiss_system() {
+            return Err(PawnError::InvalidInput(format!(
+                "Partial re-pairing needs a Swiss pairing method, not {}",
+                request.pairing_method
+            )));
+        }
+
+        let games = self
+            .db
+            .get_games_by_round(tournament_id, round_number)
+            .await
+            .map_err(PawnError::Database)?;
+        if games.is_empty() {
+            return Err(PawnError::InvalidInput(format!(
+                "Round {round_number} has no pairings to re-pair"
+            )));
+        }
+        let before = round_pairings(tournament_id, &games);
+
+        // Requested byes stay where they are
+        let requested_bye_ids: std::collections::HashSet<i32> = self
+            .db
+            .get_bye_requests(tournament_id)
+            .await
+            .map_err(PawnError::Database)?
+            .into_iter()
+            .filter(|bye| bye.round_number == round_number)
+            .map(|bye| bye.player_id)
+            .collect();
+        let mut locked_boards: std::collections::HashSet<i32> =
+            request.locked_boards.iter().copied().collect();
+        locked_boards.extend(
+            before
+                .iter()
+                .filter(|pairing| {
+                    pairing.black_player.is_none()
+                        && requested_bye_ids.contains(&pairing.white_player.id)
+                })
+                .map(|pairing| pairing.board_number),
+        );
+
+        let players = self
+            .db
+            .get_players_by_tournament(tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let mut added_players = Vec::new();
+        for player_id in &request.added_player_ids {
+            let player = players
+                .iter()
+                .find(|player| player.id == *player_id)
+                .ok_or_else(|| {
+                    PawnError::InvalidInput(format!(
+                        "Player {player_id} is not registered in tournament {tournament_id}"
+                    ))
+                })?;
+            added_players.push(player.clone());
+        }
+
+        let plan = plan_repairing(
+            tournament_id,
+            &games,
+            &locked_boards,
+            &request.removed_player_ids,
+            added_players,
+        )?;
+
+        let game_history = self
+            .game_history(tournament_id, round_number, &players)
+            .await?;
+        let player_results = player_results(&plan.players, &game_history);
+        let restrictions = self
+            .db
+            .get_forbidden_pairings(tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let mut repaired = PairingService::new()
+            .with_forbidden_pairs(forbidden_pairs(&restrictions, round_number))
+            .generate_pairings_with_history(
+                plan.players.clone(),
+                player_results.clone(),
+                game_history.clone(),
+                round_number,
+                &pairing_method,
+            )?;
+        self.pairing_explainer.explain_pairings(
+            &mut repaired,
+            &player_results,
+            &game_history,
+            round_number,
+        );
+
+        let (after, repaired) = number_repaired_boards(&plan, before.len() as i32, repaired);
+        let changed_boards = changed_boards(&before, &after);
+
+        if !request.apply {
+            return Ok(RepairRoundResult {
+                before,
+                after,
+                changed_boards,
+                audit: None,
+            });
+        }
+
+        let requested_bye_points = self
+            .requested_bye_points(tournament_id, round_number)
+            .await?;
+        let repair = ApplyRoundRepair {
+            round_id: round.id,
+            replaced_game_ids: plan.replaced_game_ids.clone(),
+            games: repaired
+                .into_iter()
+                .map(|(pairing, game_id)| RepairedGame {
+                    game_id,
+                    board_number: pairing.board_number,
+                    game: pairing_game(
+                        tournament_id,
+                        round_number,
+                        &pairing,
+                        &requested_bye_points,
+                    ),
+                    explanation: pairing.explanation,
+                })
+                .collect(),
+            audit_details: audit_details(&locked_boards, &plan, &changed_boards),
+            reason: request.reason.clone(),
+        };
+        let audit = self
+            .db
+            .apply_round_repair(repair)
+            .await
+            .map_err(PawnError::Database)?;
+
+        tracing::info!(
+            "Re-paired {} players in round {} of tournament {}",
+            plan.players.len(),
+            round_number,
+            tournament_id
+        );
+
+        Ok(RepairRoundResult {
+            before,
+            after,
+            changed_boards,
+            audit: Some(audit),
+        })
+    }
+
     pub async fn complete_round(&self, round_id: i32) -> Result<Round, PawnError> {
         // Use the enhanced state machine validation in update_round_status
         self.update_round_status(UpdateRoundStatus {
@@ -450,6 +1139,193 @@ impl<D: Db> RoundService<D> {
         })
         .await
     }
+
+    /// Replay the rounds of a stored tournament or of a TRF file through the
+    /// Swiss engine and report where the stored pairings differ
+    pub async fn check_pairings(
+        &self,
+        request: CheckPairingsRequest,
+    ) -> Result<PairingCheckReport, PawnError> {
+        match (request.tournament_id, request.trf_content) {
+            (_, Some(content)) => {
+                let parsed = parse_trf(&content).map_err(|errors| {
+                    PawnError::ValidationError(
+                        errors
+                            .into_iter()
+                            .map(|issue| match issue.line_number {
+                                Some(line) => format!("line {line}: {}", issue.message),
+                                None => issue.message,
+                            })
+                            .collect::<Vec<_>>()
+                            .join("; "),
+                    )
+                })?;
+                let (players, games) = import_to_models(&parsed.tournament);
+                self.pairing_checker.check_tournament(
+                    0,
+                    &parsed.tournament.tournament.name,
+                    &players,
+                    &games,
+                    &[],
+                    &import_bye_requests(&parsed.tournament),
+                    None,
+                )
+            }
+            (Some(tournament_id), None) => {
+                let tournament = self
+                    .db
+                    .get_tournament(tournament_id)
+                    .await
+                    .map_err(PawnError::Database)?;
+                // The checker replays the Dutch System, so rounds of the other engines
+                // would only show their own rules as deviations
+                let pairing_method: PairingMethod = self
+                    .db
+                    .get_tournament_pairing_method(tournament_id)
+                    .await
+                    .map_err(PawnError::Database)?
+                    .and_then(|method| method.parse().ok())
+                    .unwrap_or(PairingMethod::Manual);
+                if !matches!(pairing_method, PairingMethod::Swiss | PairingMethod::Manual) {
+                    return Err(PawnError::InvalidInput(format!(
+                        "Only Dutch System and manual rounds can be checked, this tournament is paired with {}",
+                        pairing_method.to_str()
+                    )));
+                }
+                let acceleration = if pairing_method == PairingMethod::Swiss {
+                    self.acceleration(tournament_id).await?
+                } else {
+                    None
+                };
+                let players = self
+                    .db
+                    .get_players_by_tournament(tournament_id)
+                    .await
+                    .map_err(PawnError::Database)?;
+                let games = self
+                    .db
+                    .get_games_by_tournament(tournament_id)
+                    .await
+                    .map_err(PawnError::Database)?;
+                let restrictions = self
+                    .db
+                    .get_forbidden_pairings(tournament_id)
+                    .await
+                    .map_err(PawnError::Database)?;
+                let bye_requests = self
+                    .db
+                    .get_bye_requests(tournament_id)
+                    .await
+                    .map_err(PawnError::Database)?;
+                self.pairing_checker.check_tournament(
+                    tournament_id,
+                    &tournament.name,
+                    &players,
+                    &games,
+                    &restrictions,
+                    &bye_requests,
+                    acceleration.as_ref(),
+                )
+            }
+            (None, None) => Err(PawnError::InvalidInput(
+                "Either a tournament or a TRF file is required".into(),
+            )),
+        }
+    }
+}
+
+/// Id of the virtual BYE player created for every tournament (migration 0005)
+pub(crate) fn bye_player_id(tournament_id: i32) -> i32 {
+    -tournament_id
+}
+
+/// Game stored for a board: an ongoing game, or a bye against the tournament's
+/// virtual BYE player
+fn pairing_game(
+    tournament_id: i32,
+    round_number: i32,
+    pairing: &Pairing,
+    requested_bye_points: &std::collections::HashMap<i32, f64>,
+) -> CreateGame {
+    let white_player_id = pairing.white_player.id;
+    let (black_player_id, result) = match &pairing.black_player {
+        Some(black_player) => (black_player.id, "*"),
+        None => (
+            bye_player_id(tournament_id),
+            bye_result(requested_bye_points.get(&white_player_id)),
+        ),
+    };
+    CreateGame {
+        tournament_id,
+        round_number,
+        white_player_id,
+        black_player_id,
+        result: result.to_string(),
+    }
+}
+
+/// Result of a bye game: the requested points, or a win for the pairing-allocated bye
+fn bye_result(requested_points: Option<&f64>) -> &'static str {
+    match requested_points {
+        Some(&points) if points == 0.0 => "0-1",
+        Some(&points) if points == 0.5 => "1/2-1/2",
+        _ => "1-0",
+    }
+}
+
+/// Round-robin boards with the requested byes taken out of the schedule: the absent
+/// player gets the requested bye and the scheduled opponent an unplayed point, while
+/// every other board keeps its scheduled opponents
+fn scheduled_byes(
+    pairings: Vec<Pairing>,
+    requested_bye_ids: &std::collections::HashSet<i32>,
+) -> Vec<Pairing> {
+    let (absent, mut boards): (Vec<_>, Vec<_>) = pairings.into_iter().partition(|pairing| {
+        requested_bye_ids.contains(&pairing.white_player.id)
+            || pairing
+                .black_player
+                .as_ref()
+                .is_some_and(|black| requested_bye_ids.contains(&black.id))
+    });
+
+    // Byes are boards without a black player, after the games
+    boards.extend(absent.into_iter().flat_map(|pairing| {
+        std::iter::once(pairing.white_player)
+            .chain(pairing.black_player)
+            .map(|player| Pairing {
+                white_player: player,
+                black_player: None,
+                board_number: 0,
+                explanation: None,
+            })
+    }));
+    for (board_number, pairing) in (1..).zip(boards.iter_mut()) {
+        pairing.board_number = board_number;
+    }
+    boards
+}
+
+/// In-memory copy of the tournament's virtual BYE player
+pub(crate) fn virtual_bye_player(tournament_id: i32) -> crate::pawn::domain::model::Player {
+    crate::pawn::domain::model::Player {
+        id: bye_player_id(tournament_id),
+        tournament_id,
+        name: "BYE".to_string(),
+        rating: None,
+        country_code: None,
+        title: None,
+        birth_date: None,
+        gender: None,
+        email: None,
+        phone: None,
+        club: None,
+        status: "bye".to_string(),
+        seed_number: None,
+        pairing_number: None,
+        initial_rating: None,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        updated_at: None,
+    }
 }
 
 #[cfg(test)]
@@ -459,6 +1335,77 @@ mod tests {
 
     // Unit tests for business logic validation (no database dependencies)
 
+    #[test]
+    fn test_bye_result() {
+        assert_eq!(bye_result(None), "1-0");
+        assert_eq!(bye_result(Some(&1.0)), "1-0");
+        assert_eq!(bye_result(Some(&0.5)), "1/2-1/2");
+        assert_eq!(bye_result(Some(&0.0)), "0-1");
+    }
+
+    #[test]
+    fn test_requested_bye_keeps_round_robin_schedule() {
+        let players: Vec<Player> = (1..=6)
+            .map(|id| Player {
+                id,
+                tournament_id: 1,
+                name: format!("Player {id}"),
+                rating: Some(2000 - id * 10),
+                country_code: None,
+                title: None,
+                birth_date: None,
+                gender: None,
+                email: None,
+                phone: None,
+                club: None,
+                status: "active".to_string(),
+                seed_number: None,
+                pairing_number: Some(id),
+                initial_rating: None,
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+                updated_at: None,
+            })
+            .collect();
+        let scheduled = PairingService::new()
+            .generate_pairings(players, vec![], 2, &PairingMethod::RoundRobin)
+            .unwrap();
+        let pair = |pairing: &Pairing| {
+            (
+                pairing.white_player.id,
+                pairing.black_player.as_ref().map(|black| black.id),
+            )
+        };
+
+        let absent = scheduled[1].white_player.id;
+        let opponent = scheduled[1].black_player.as_ref().unwrap().id;
+        let pairings = scheduled_byes(scheduled.clone(), &[absent].into());
+
+        // The other boards keep their scheduled opponents and colours
+        let games: Vec<_> = pairings
+            .iter()
+            .filter(|pairing| pairing.black_player.is_some())
+            .map(pair)
+            .collect();
+        let expected: Vec<_> = scheduled
+            .iter()
+            .filter(|pairing| pairing.white_player.id != absent)
+            .map(pair)
+            .collect();
+        assert_eq!(games, expected);
+
+        // The absent player and the scheduled opponent get byes after the games
+        let byes: Vec<_> = pairings[games.len()..].iter().map(pair).collect();
+        assert_eq!(byes, vec![(absent, None), (opponent, None)]);
+        let board_numbers: Vec<i32> = pairings
+            .iter()
+            .map(|pairing| pairing.board_number)
+            .collect();
+        assert_eq!(
+            board_numbers,
+            (1..=pairings.len() as i32).collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn test_round_status_transitions() {
         use crate::pawn::domain::model::RoundStatus;
@@ -509,11 +1456,13 @@ mod tests {
                 white_player: player1.clone(),
                 black_player: Some(player2.clone()),
                 board_number: 1,
+                explanation: None,
             },
             Pairing {
                 white_player: player1, // Duplicate - should be caught
                 black_player: Some(player2),
                 board_number: 2,
+                explanation: None,
             },
         ];
 
@@ -569,19 +1518,15 @@ mod tests {
 
     #[test]
     fn test_bye_player_id_generation() {
-        // Test the logic for generating unique bye player IDs
-        let tournament_id = 1;
-        let round_number = 3;
-        let bye_player_id = -(tournament_id * 1000 + round_number);
-
-        // Should be negative and unique per tournament/round combination
-        assert!(bye_player_id < 0);
-        assert_eq!(bye_player_id, -1003);
-
-        // Different rounds should generate different IDs
-        let different_round = 4;
-        let different_bye_id = -(tournament_id * 1000 + different_round);
-        assert_ne!(bye_player_id, different_bye_id);
+        // Bye games reference the tournament's virtual BYE player
+        let bye_player = virtual_bye_player(3);
+
+        assert_eq!(bye_player_id(3), -3);
+        assert_eq!(bye_player.id, bye_player_id(3));
+        assert_eq!(bye_player.name, "BYE");
+
+        // Different tournaments use different BYE players
+        assert_ne!(bye_player_id(3), bye_player_id(4));
     }
 
     #[tokio::test]
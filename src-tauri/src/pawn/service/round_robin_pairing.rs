@@ -366,6 +366,7 @@ impl RoundRobinEngine {
                         white_player: players[*white_pos].player.clone(),
                         black_player: Some(players[*black_pos].player.clone()),
                         board_number: (board_number + 1) as i32,
+                        explanation: None,
                     });
                 }
             }
@@ -427,6 +428,7 @@ impl RoundRobinEngine {
                 white_player,
                 black_player: Some(black_player),
                 board_number: (board + 1) as i32,
+                explanation: None,
             });
         }
 
@@ -0,0 +1,442 @@
+use crate::pawn::{
+    common::error::PawnError,
+    domain::{
+        dto::{CreateTournamentStage, StageQualifier},
+        model::{
+            Game, StageQualification, StageSeeding, Tournament, TournamentStage, TournamentType,
+        },
+        tiebreak::PlayerStanding,
+    },
+    service::knockout::KnockoutService,
+};
+use std::cmp::Ordering;
+
+#[allow(dead_code)]
+pub struct StageService;
+
+#[allow(dead_code)]
+impl StageService {
+    /// Check a stage before it is added as `stage_number` of its event
+    pub fn validate_stage(
+        data: &CreateTournamentStage,
+        stage_number: i32,
+    ) -> Result<(), PawnError> {
+        if data.name.trim().is_empty() {
+            return Err(PawnError::InvalidInput("A stage needs a name".to_string()));
+        }
+        Self::tournament_type(&data.tournament_type)?;
+        data.qualification
+            .parse::<StageQualification>()
+            .map_err(PawnError::InvalidInput)?;
+        data.seeding
+            .parse::<StageSeeding>()
+            .map_err(PawnError::InvalidInput)?;
+        if data.qualifiers < 1 {
+            return Err(PawnError::InvalidInput(
+                "At least one player has to qualify from a stage".to_string(),
+            ));
+        }
+        if data.rounds.is_some_and(|rounds| rounds < 1) {
+            return Err(PawnError::InvalidInput(
+                "A stage needs at least one round".to_string(),
+            ));
+        }
+
+        if stage_number == 1 && data.group_tournament_ids.is_empty() {
+            return Err(PawnError::InvalidInput(
+                "The first stage needs at least one group tournament".to_string(),
+            ));
+        }
+        if stage_number > 1 && !data.group_tournament_ids.is_empty() {
+            return Err(PawnError::InvalidInput(format!(
+                "Stage {stage_number} is created from the qualifiers of stage {}",
+                stage_number - 1
+            )));
+        }
+        let mut group_tournament_ids = data.group_tournament_ids.clone();
+        group_tournament_ids.sort_unstable();
+        if let Some(pair) = group_tournament_ids
+            .windows(2)
+            .find(|pair| pair[0] == pair[1])
+        {
+            return Err(PawnError::InvalidInput(format!(
+                "Tournament {} is listed as two groups",
+                pair[0]
+            )));
+        }
+        if group_tournament_ids.contains(&data.tournament_id) {
+            return Err(PawnError::InvalidInput(
+                "An event cannot be one of its own groups".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Players qualifying from a stage, in the order they qualified. Every
+    /// group winner comes before every runner-up and so on; players sharing a
+    /// group rank are ordered by points, then by their tiebreaks.
+    pub fn select_qualifiers(
+        stage: &TournamentStage,
+        groups: &[(i32, Vec<PlayerStanding>)],
+    ) -> Result<Vec<StageQualifier>, PawnError> {
+        let qualification = stage
+            .qualification
+            .parse::<StageQualification>()
+            .map_err(PawnError::InvalidInput)?;
+
+        let mut candidates = Vec::new();
+        for (group_number, standings) in groups {
+            let mut standings: Vec<&PlayerStanding> = standings.iter().collect();
+            standings.sort_by_key(|standing| standing.rank);
+            if qualification == StageQualification::GroupWinners {
+                if standings.len() < stage.qualifiers as usize {
+                    return Err(PawnError::InvalidInput(format!(
+                        "Group {group_number} of {} has {} players, {} have to qualify",
+                        stage.name,
+                        standings.len(),
+                        stage.qualifiers
+                    )));
+                }
+                standings.truncate(stage.qualifiers as usize);
+            }
+            candidates.extend(
+                standings
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, standing)| (*group_number, index as i32 + 1, standing)),
+            );
+        }
+
+        candidates.sort_by(|a, b| {
+            a.1.cmp(&b.1)
+                .then_with(|| Self::compare_standings(b.2, a.2))
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        if qualification == StageQualification::TopN {
+            if candidates.len() < stage.qualifiers as usize {
+                return Err(PawnError::InvalidInput(format!(
+                    "{} has {} players, {} have to qualify",
+                    stage.name,
+                    candidates.len(),
+                    stage.qualifiers
+                )));
+            }
+            candidates.truncate(stage.qualifiers as usize);
+        }
+
+        Ok(candidates
+            .into_iter()
+            .enumerate()
+            .map(
+                |(index, (group_number, group_rank, standing))| StageQualifier {
+                    seed: index as i32 + 1,
+                    player: standing.player.clone(),
+                    group_number,
+                    group_rank,
+                    points: standing.points,
+                },
+            )
+            .collect())
+    }
+
+    /// Check that a group tournament is over before its qualifiers move on:
+    /// every round played and every game with a result
+    pub fn check_group_complete(group: &Tournament, games: &[Game]) -> Result<(), PawnError> {
+        if group.rounds_played != group.total_rounds {
+            return Err(PawnError::InvalidInput(format!(
+                "{} has played {} of its {} rounds",
+                group.name, group.rounds_played, group.total_rounds
+            )));
+        }
+        if let Some(game) = games.iter().find(|game| game.result == "*") {
+            return Err(PawnError::InvalidInput(format!(
+                "{} still has a game without a result in round {}",
+                group.name, game.round_number
+            )));
+        }
+        Ok(())
+    }
+
+    /// Qualifiers in the seed order of the stage they enter
+    pub fn seed_qualifiers(
+        stage: &TournamentStage,
+        mut qualifiers: Vec<StageQualifier>,
+    ) -> Result<Vec<StageQualifier>, PawnError> {
+        let seeding = stage
+            .seeding
+            .parse::<StageSeeding>()
+            .map_err(PawnError::InvalidInput)?;
+        if seeding == StageSeeding::Rating {
+            // Stable, so equal ratings keep their qualifier order
+            qualifiers
+                .sort_by_key(|qualifier| std::cmp::Reverse(qualifier.player.rating.unwrap_or(0)));
+        }
+        for (index, qualifier) in qualifiers.iter_mut().enumerate() {
+            qualifier.seed = index as i32 + 1;
+        }
+        Ok(qualifiers)
+    }
+
+    /// Rounds of a stage played by `player_count` players
+    pub fn stage_rounds(stage: &TournamentStage, player_count: i32) -> Result<i32, PawnError> {
+        if let Some(rounds) = stage.rounds {
+            return Ok(rounds);
+        }
+        match Self::tournament_type(&stage.tournament_type)? {
+            TournamentType::RoundRobin if player_count % 2 == 0 => Ok(player_count - 1),
+            TournamentType::RoundRobin => Ok(player_count),
+            TournamentType::Knockout => Ok(KnockoutService::calculate_rounds(player_count)),
+            _ => Err(PawnError::InvalidInput(format!(
+                "{} needs a number of rounds",
+                stage.name
+            ))),
+        }
+    }
+
+    fn tournament_type(tournament_type: &str) -> Result<TournamentType, PawnError> {
+        let parsed = tournament_type
+            .parse::<TournamentType>()
+            .map_err(PawnError::InvalidInput)?;
+        // Unknown types parse as Swiss
+        if parsed.to_str() != tournament_type {
+            return Err(PawnError::InvalidInput(format!(
+                "Unknown tournament type: {tournament_type}"
+            )));
+        }
+        Ok(parsed)
+    }
+
+    fn compare_standings(a: &PlayerStanding, b: &PlayerStanding) -> Ordering {
+        a.points.total_cmp(&b.points).then_with(|| {
+            a.tiebreak_scores
+                .iter()
+                .zip(&b.tiebreak_scores)
+                .map(|(a, b)| a.value.total_cmp(&b.value))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pawn::domain::{
+        model::Player,
+        tiebreak::{TiebreakScore, TiebreakType},
+    };
+
+    fn create_stage(qualification: StageQualification, qualifiers: i32) -> TournamentStage {
+        TournamentStage {
+            id: 1,
+            tournament_id: 1,
+            stage_number: 1,
+            name: "Qualifier".to_string(),
+            tournament_type: "round_robin".to_string(),
+            rounds: None,
+            qualification: qualification.to_str().to_string(),
+            qualifiers,
+            seeding: StageSeeding::QualifierRank.to_str().to_string(),
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    fn create_standing(id: i32, rank: i32, points: f64, buchholz: f64) -> PlayerStanding {
+        PlayerStanding {
+            player: Player {
+                id,
+                tournament_id: 1,
+                name: format!("Player {id}"),
+                rating: Some(2000 + id * 10),
+                country_code: None,
+                title: None,
+                birth_date: None,
+                gender: None,
+                email: None,
+                phone: None,
+                club: None,
+                status: "active".to_string(),
+                seed_number: None,
+                pairing_number: None,
+                initial_rating: None,
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+                updated_at: None,
+            },
+            rank,
+            points,
+            games_played: 3,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            tiebreak_scores: vec![TiebreakScore {
+                tiebreak_type: TiebreakType::BuchholzFull,
+                value: buchholz,
+                display_value: buchholz.to_string(),
+            }],
+            performance_rating: None,
+            rating_change: None,
+        }
+    }
+
+    fn qualifier_ids(qualifiers: &[StageQualifier]) -> Vec<i32> {
+        qualifiers.iter().map(|q| q.player.id).collect()
+    }
+
+    #[test]
+    fn test_top_n_follows_standings() {
+        let stage = create_stage(StageQualification::TopN, 2);
+        let groups = vec![(
+            1,
+            vec![
+                create_standing(3, 3, 1.0, 4.0),
+                create_standing(1, 1, 3.0, 4.0),
+                create_standing(2, 2, 2.0, 5.0),
+            ],
+        )];
+
+        let qualifiers = StageService::select_qualifiers(&stage, &groups).unwrap();
+        assert_eq!(qualifier_ids(&qualifiers), vec![1, 2]);
+        assert_eq!(qualifiers[1].seed, 2);
+        assert_eq!(qualifiers[1].group_rank, 2);
+    }
+
+    #[test]
+    fn test_group_winners_ordered_by_points_and_tiebreaks() {
+        let stage = create_stage(StageQualification::GroupWinners, 1);
+        let groups = vec![
+            (1, vec![create_standing(1, 1, 2.5, 4.0)]),
+            (2, vec![create_standing(2, 1, 3.0, 3.0)]),
+            (3, vec![create_standing(3, 1, 2.5, 5.0)]),
+        ];
+
+        let qualifiers = StageService::select_qualifiers(&stage, &groups).unwrap();
+        assert_eq!(qualifier_ids(&qualifiers), vec![2, 3, 1]);
+        assert_eq!(qualifiers[0].group_number, 2);
+    }
+
+    #[test]
+    fn test_top_n_across_groups_takes_winners_first() {
+        let stage = create_stage(StageQualification::TopN, 3);
+        let groups = vec![
+            (
+                1,
+                vec![
+                    create_standing(1, 1, 3.0, 0.0),
+                    create_standing(2, 2, 2.5, 0.0),
+                ],
+            ),
+            (
+                2,
+                vec![
+                    create_standing(3, 1, 2.0, 0.0),
+                    create_standing(4, 2, 1.0, 0.0),
+                ],
+            ),
+        ];
+
+        let qualifiers = StageService::select_qualifiers(&stage, &groups).unwrap();
+        assert_eq!(qualifier_ids(&qualifiers), vec![1, 3, 2]);
+    }
+
+    #[test]
+    fn test_not_enough_players_to_qualify() {
+        let stage = create_stage(StageQualification::GroupWinners, 2);
+        let groups = vec![(1, vec![create_standing(1, 1, 1.0, 0.0)])];
+        assert!(StageService::select_qualifiers(&stage, &groups).is_err());
+
+        let stage = create_stage(StageQualification::TopN, 2);
+        assert!(StageService::select_qualifiers(&stage, &groups).is_err());
+    }
+
+    #[test]
+    fn test_seed_by_rating() {
+        let mut stage = create_stage(StageQualification::TopN, 3);
+        let groups = vec![(
+            1,
+            vec![
+                create_standing(1, 1, 3.0, 0.0),
+                create_standing(3, 2, 2.0, 0.0),
+                create_standing(2, 3, 1.0, 0.0),
+            ],
+        )];
+        let qualifiers = StageService::select_qualifiers(&stage, &groups).unwrap();
+
+        let seeded = StageService::seed_qualifiers(&stage, qualifiers.clone()).unwrap();
+        assert_eq!(qualifier_ids(&seeded), vec![1, 3, 2]);
+
+        stage.seeding = StageSeeding::Rating.to_str().to_string();
+        let seeded = StageService::seed_qualifiers(&stage, qualifiers).unwrap();
+        assert_eq!(qualifier_ids(&seeded), vec![3, 2, 1]);
+        assert_eq!(seeded[0].seed, 1);
+    }
+
+    #[test]
+    fn test_unfinished_group_cannot_advance() {
+        let mut group = Tournament {
+            id: 2,
+            name: "Group A".to_string(),
+            location: "Test Location".to_string(),
+            date: "2024-01-01".to_string(),
+            time_type: "classical".to_string(),
+            tournament_type: Some("round_robin".to_string()),
+            player_count: 4,
+            rounds_played: 2,
+            total_rounds: 3,
+            country_code: "US".to_string(),
+            status: None,
+            start_time: None,
+            end_time: None,
+            description: None,
+            website_url: None,
+            contact_email: None,
+            entry_fee: None,
+            currency: None,
+            is_team_tournament: None,
+            team_size: None,
+            max_teams: None,
+        };
+        let mut games: Vec<Game> = (1..=6)
+            .map(|id| Game {
+                id,
+                tournament_id: 2,
+                round_number: (id + 1) / 2,
+                white_player_id: 1,
+                black_player_id: 2,
+                result: "1-0".to_string(),
+                result_type: None,
+                result_reason: None,
+                arbiter_notes: None,
+                last_updated: None,
+                approved_by: None,
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+            })
+            .collect();
+        games[5].result = "*".to_string();
+
+        // A round still to play
+        assert!(StageService::check_group_complete(&group, &games).is_err());
+
+        // A game of the last round without a result
+        group.rounds_played = 3;
+        let error = StageService::check_group_complete(&group, &games).unwrap_err();
+        assert!(matches!(error, PawnError::InvalidInput(message) if message.contains("round 3")));
+
+        games[5].result = "1/2-1/2".to_string();
+        assert!(StageService::check_group_complete(&group, &games).is_ok());
+    }
+
+    #[test]
+    fn test_stage_rounds() {
+        let mut stage = create_stage(StageQualification::TopN, 8);
+        assert_eq!(StageService::stage_rounds(&stage, 8).unwrap(), 7);
+        assert_eq!(StageService::stage_rounds(&stage, 7).unwrap(), 7);
+
+        stage.tournament_type = "knockout".to_string();
+        assert_eq!(StageService::stage_rounds(&stage, 8).unwrap(), 3);
+
+        stage.tournament_type = "swiss".to_string();
+        assert!(StageService::stage_rounds(&stage, 8).is_err());
+        stage.rounds = Some(5);
+        assert_eq!(StageService::stage_rounds(&stage, 8).unwrap(), 5);
+    }
+}
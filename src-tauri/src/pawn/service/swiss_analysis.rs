@@ -3,10 +3,16 @@ use crate::pawn::{
     db::Db,
     domain::{
         dto::{
-            ColorBalanceAnalysisDto, FloatStatisticsDto, RatingDistributionDto, ScoreGroupDto,
+            AcceleratedPlayerDto, AcceleratedRoundDto, ColorBalanceAnalysisDto, FloatStatisticsDto,
+            PlayerAroProgressionDto, PlayerFloatRecordDto, RatingDistributionDto, ScoreGroupDto,
             SwissPairingAnalysis, SwissPairingOptions,
         },
-        model::{GameResult, Player, PlayerResult},
+        model::{GameResult, GameResultType, Player, PlayerResult},
+    },
+    service::{
+        acceleration::BakuAcceleration,
+        swiss_pairing::{FloatDirection, SwissPairingEngine},
+        tiebreak::TiebreakCalculator,
     },
 };
 use std::{collections::HashMap, sync::Arc};
@@ -72,11 +78,45 @@ impl<D: Db> SwissAnalysisService<D> {
         // Analyze rating distribution
         let rating_distribution = self.analyze_rating_distribution(&player_results, &options);
 
+        // Float and bye record of every player, including byes against the BYE player
+        let player_float_records = analyze_player_float_records(&players, &games_up_to_round);
+
+        // Average rating of opponents after every completed round, the Dubov System input
+        let games: Vec<_> = games_up_to_round.iter().map(|g| g.game.clone()).collect();
+        let mut aro_by_player = TiebreakCalculator::new(self.db.clone())
+            .calculate_aro_progression(&players, &games, round_number - 1)?;
+        let aro_progression = players
+            .iter()
+            .map(|player| PlayerAroProgressionDto {
+                player_id: player.id,
+                player_name: player.name.clone(),
+                aro_by_round: aro_by_player.remove(&player.id).unwrap_or_default(),
+            })
+            .collect();
+
+        // Baku acceleration stored for the tournament, otherwise the one in the options
+        let stored_acceleration = self
+            .db
+            .get_acceleration_settings(tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+        let acceleration = match stored_acceleration {
+            Some(settings) if settings.enabled => BakuAcceleration::from_settings(&settings)?,
+            _ => BakuAcceleration::from_options(&options),
+        }
+        .map(|acceleration| {
+            analyze_acceleration(&acceleration, &players, &games_up_to_round, round_number)
+        })
+        .unwrap_or_default();
+
         Ok(SwissPairingAnalysis {
             score_groups,
             float_statistics,
             color_balance_analysis,
             rating_distribution,
+            player_float_records,
+            aro_progression,
+            acceleration,
         })
     }
 
@@ -293,6 +333,110 @@ impl<D: Db> SwissAnalysisService<D> {
     }
 }
 
+/// Per-player float and bye history reconstructed from the games played so far
+fn analyze_player_float_records(
+    players: &[Player],
+    games: &[GameResult],
+) -> Vec<PlayerFloatRecordDto> {
+    let player_ids: Vec<i32> = players.iter().map(|p| p.id).collect();
+    let mut histories = SwissPairingEngine::new().reconstruct_pairing_histories(&player_ids, games);
+
+    players
+        .iter()
+        .filter_map(|player| {
+            let history = histories.remove(&player.id)?;
+            let mut record = PlayerFloatRecordDto {
+                player_id: player.id,
+                player_name: player.name.clone(),
+                upfloat_rounds: Vec::new(),
+                downfloat_rounds: Vec::new(),
+                bye_rounds: Vec::new(),
+                unplayed_rounds: Vec::new(),
+                can_receive_bye: history.is_bye_eligible,
+            };
+
+            for round in &history.float_history {
+                match round.direction {
+                    Some(FloatDirection::Up) => record.upfloat_rounds.push(round.round_number),
+                    Some(FloatDirection::Down) => record.downfloat_rounds.push(round.round_number),
+                    None => {}
+                }
+                if round.received_bye {
+                    record.bye_rounds.push(round.round_number);
+                }
+                if round.unplayed {
+                    record.unplayed_rounds.push(round.round_number);
+                }
+            }
+
+            Some(record)
+        })
+        .collect()
+}
+
+/// Groups and virtual points of the Baku acceleration in every accelerated
+/// round up to `round_number`
+fn analyze_acceleration(
+    acceleration: &BakuAcceleration,
+    players: &[Player],
+    games: &[GameResult],
+    round_number: i32,
+) -> Vec<AcceleratedRoundDto> {
+    let group_a = acceleration.group_a(players);
+
+    (1..=round_number.min(acceleration.accelerated_rounds))
+        .map(|round| {
+            let group_a_virtual_points = acceleration.virtual_points(round);
+            let players = players
+                .iter()
+                .map(|player| {
+                    let in_group_a = group_a.contains(&player.id);
+                    let score = score_before_round(player.id, games, round);
+                    let virtual_points = if in_group_a {
+                        group_a_virtual_points
+                    } else {
+                        0.0
+                    };
+                    AcceleratedPlayerDto {
+                        player_id: player.id,
+                        player_name: player.name.clone(),
+                        group: if in_group_a { "A" } else { "B" }.to_string(),
+                        score,
+                        virtual_points,
+                        pairing_score: score + virtual_points,
+                    }
+                })
+                .collect();
+
+            AcceleratedRoundDto {
+                round_number: round,
+                group_a_virtual_points,
+                players,
+            }
+        })
+        .collect()
+}
+
+/// Points a player scored in the games before a round
+fn score_before_round(player_id: i32, games: &[GameResult], round_number: i32) -> f64 {
+    games
+        .iter()
+        .filter(|game| game.game.round_number < round_number)
+        .map(|game| {
+            let result: GameResultType =
+                game.game.result.parse().unwrap_or(GameResultType::Ongoing);
+            let (white_points, black_points) = result.get_points();
+            if game.game.white_player_id == player_id {
+                white_points as f64
+            } else if game.game.black_player_id == player_id {
+                black_points as f64
+            } else {
+                0.0
+            }
+        })
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1011,4 +1155,74 @@ mod tests {
         let rating_dist = service.analyze_rating_distribution(&player_results, &options);
         assert!(rating_dist.average_rating_difference > 0.0); // Should have some rating differences
     }
+
+    #[test]
+    fn test_player_float_records() {
+        let players = vec![
+            create_test_player(1, "Alice", Some(1800)),
+            create_test_player(2, "Bob", Some(1700)),
+            create_test_player(3, "Charlie", Some(1600)),
+        ];
+
+        // Round 1: Alice beats Bob, Charlie gets the bye (virtual BYE player -1)
+        // Round 2: Bob (0) plays up against Charlie (1), Alice is absent
+        let games = vec![
+            create_test_game(1, 1, 2, 1, "1-0"),
+            create_test_game(2, 3, -1, 1, "1-0"),
+            create_test_game(3, 2, 3, 2, "0-1"),
+        ];
+
+        let records = analyze_player_float_records(&players, &games);
+        assert_eq!(records.len(), 3);
+
+        let alice = records.iter().find(|r| r.player_id == 1).unwrap();
+        assert_eq!(alice.unplayed_rounds, vec![2]);
+        assert_eq!(alice.downfloat_rounds, vec![2]);
+        assert!(alice.can_receive_bye);
+
+        let bob = records.iter().find(|r| r.player_id == 2).unwrap();
+        assert_eq!(bob.upfloat_rounds, vec![2]);
+        assert!(bob.bye_rounds.is_empty());
+
+        let charlie = records.iter().find(|r| r.player_id == 3).unwrap();
+        assert_eq!(charlie.bye_rounds, vec![1]);
+        assert_eq!(charlie.downfloat_rounds, vec![1, 2]);
+        assert!(!charlie.can_receive_bye);
+    }
+
+    #[test]
+    fn test_analyze_acceleration() {
+        use crate::pawn::service::acceleration::GroupBoundary;
+
+        let players: Vec<Player> = (1..=4)
+            .map(|id| create_test_player(id, &format!("Player {id}"), Some(2000 - id * 100)))
+            .collect();
+        let acceleration = BakuAcceleration {
+            accelerated_rounds: 2,
+            boundary: GroupBoundary::StartingRank,
+            virtual_points_first_half: 1.0,
+            virtual_points_second_half: 0.5,
+        };
+        let games = vec![
+            create_test_game(1, 1, 2, 1, "1-0"),
+            create_test_game(2, 3, 4, 1, "1-0"),
+        ];
+
+        // Round 3 is no longer accelerated
+        let rounds = analyze_acceleration(&acceleration, &players, &games, 3);
+        assert_eq!(rounds.len(), 2);
+        assert_eq!(rounds[0].group_a_virtual_points, 1.0);
+        assert_eq!(rounds[1].group_a_virtual_points, 0.5);
+
+        let round2 = &rounds[1];
+        let player = |id: i32| round2.players.iter().find(|p| p.player_id == id).unwrap();
+        assert_eq!(player(1).group, "A");
+        assert_eq!(player(1).score, 1.0);
+        assert_eq!(player(1).pairing_score, 1.5);
+        assert_eq!(player(2).group, "A");
+        assert_eq!(player(2).pairing_score, 0.5);
+        assert_eq!(player(3).group, "B");
+        assert_eq!(player(3).virtual_points, 0.0);
+        assert_eq!(player(3).pairing_score, 1.0);
+    }
 }
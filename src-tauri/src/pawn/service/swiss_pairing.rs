@@ -2,13 +2,17 @@
 
 use crate::pawn::{
     common::error::PawnError,
-    domain::model::{GameResult, Pairing, Player, PlayerResult},
+    domain::model::{GameResult, GameResultType, Pairing, Player, PlayerResult},
+    service::acceleration::BakuAcceleration,
 };
 use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// FIDE-compliant Swiss pairing implementation
 /// Based on FIDE Handbook C.04 Swiss Pairing Rules
-pub struct SwissPairingEngine;
+pub struct SwissPairingEngine {
+    /// Player pairs (smaller id first) that must not meet, avoided like rematches
+    forbidden_pairs: HashSet<(i32, i32)>,
+}
 
 /// Parameters for handling odd groups with float management
 struct OddGroupParams<'a> {
@@ -37,7 +41,7 @@ pub struct SwissPlayer {
 
     pub is_bye_eligible: bool,
 
-    pub float_history: Vec<FloatDirection>,
+    pub float_history: Vec<FloatRecord>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -60,13 +64,54 @@ pub enum ColorPreference {
     None, // No preference
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 
 pub enum FloatDirection {
     Up,   // Floated up to higher score group
     Down, // Floated down to lower score group
 }
 
+/// Float and bye record of a player for one completed round
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatRecord {
+    pub round_number: i32,
+
+    /// Float received in the round; unplayed rounds count as a downfloat (C.04.3 A.4)
+    pub direction: Option<FloatDirection>,
+
+    pub received_bye: bool,
+
+    /// No game was played over the board (bye, forfeit, absence)
+    pub unplayed: bool,
+}
+
+/// Pairing history of a player reconstructed from the games of previous rounds
+#[derive(Debug, Clone)]
+pub struct PlayerPairingHistory {
+    pub color_history: Vec<Color>,
+
+    pub opponents: HashSet<i32>,
+
+    pub float_history: Vec<FloatRecord>,
+
+    /// False once the player received a bye or scored a point without playing (C.04.1.d)
+    pub is_bye_eligible: bool,
+}
+
+impl SwissPlayer {
+    /// Float received in the most recent completed round
+    pub fn last_float(&self) -> Option<FloatDirection> {
+        self.float_history
+            .last()
+            .and_then(|record| record.direction)
+    }
+
+    /// Whether the player already received a pairing-allocated bye
+    pub fn has_received_bye(&self) -> bool {
+        self.float_history.iter().any(|record| record.received_bye)
+    }
+}
+
 #[derive(Debug, Clone)]
 
 pub struct ScoreGroup {
@@ -95,7 +140,15 @@ impl Default for SwissPairingEngine {
 
 impl SwissPairingEngine {
     pub fn new() -> Self {
-        Self
+        Self {
+            forbidden_pairs: HashSet::new(),
+        }
+    }
+
+    /// Engine that also keeps the given player pairs apart
+    pub fn with_forbidden_pairs(mut self, forbidden_pairs: HashSet<(i32, i32)>) -> Self {
+        self.forbidden_pairs = forbidden_pairs;
+        self
     }
 
     /// Generate FIDE-compliant Swiss pairings using the Dutch System
@@ -105,6 +158,25 @@ impl SwissPairingEngine {
         player_results: Vec<PlayerResult>,
         game_history: Vec<GameResult>,
         round_number: i32,
+    ) -> Result<PairingResult, PawnError> {
+        self.generate_accelerated_dutch_system_pairings(
+            players,
+            player_results,
+            game_history,
+            round_number,
+            None,
+        )
+    }
+
+    /// Generate Dutch System pairings, adding the virtual points of the Baku
+    /// acceleration to the pairing scores when one is given
+    pub fn generate_accelerated_dutch_system_pairings(
+        &self,
+        players: Vec<Player>,
+        player_results: Vec<PlayerResult>,
+        game_history: Vec<GameResult>,
+        round_number: i32,
+        acceleration: Option<&BakuAcceleration>,
     ) -> Result<PairingResult, PawnError> {
         tracing::info!(
             "Starting Dutch System pairing for {} players, round {}",
@@ -124,9 +196,9 @@ impl SwissPairingEngine {
         // Convert to Swiss players with enhanced data
         let mut swiss_players = self.build_swiss_players(players, player_results, game_history)?;
 
-        // Apply accelerated pairing adjustments for early rounds
-        if round_number <= 2 && swiss_players.len() >= 16 {
-            self.apply_accelerated_pairings(&mut swiss_players, round_number);
+        // Virtual points only change the pairing scores, never the standings
+        if let Some(acceleration) = acceleration {
+            acceleration.apply(&mut swiss_players, round_number);
         }
 
         // Form score groups
@@ -180,7 +252,7 @@ impl SwissPairingEngine {
     }
 
     /// Build Swiss players with enhanced data from game history
-    fn build_swiss_players(
+    pub fn build_swiss_players(
         &self,
         players: Vec<Player>,
         player_results: Vec<PlayerResult>,
@@ -191,46 +263,178 @@ impl SwissPairingEngine {
             .map(|result| (result.player.id, result))
             .collect();
 
+        let player_ids: Vec<i32> = players.iter().map(|player| player.id).collect();
+        let mut histories = self.reconstruct_pairing_histories(&player_ids, &game_history);
+
         let mut swiss_players = Vec::new();
 
         for player in players {
             let points = results_map.get(&player.id).map(|r| r.points).unwrap_or(0.0);
             let rating = player.rating.unwrap_or(1200);
 
-            // Build color history and opponent list from game history
-            let mut color_history = Vec::new();
-            let mut opponents = HashSet::new();
-
-            for game in &game_history {
-                if game.white_player.id == player.id {
-                    color_history.push(Color::White);
-                    if game.black_player.id > 0 {
-                        opponents.insert(game.black_player.id);
-                    }
-                } else if game.black_player.id == player.id {
-                    color_history.push(Color::Black);
-                    opponents.insert(game.white_player.id);
-                }
-            }
+            let history = histories
+                .remove(&player.id)
+                .unwrap_or_else(|| PlayerPairingHistory {
+                    color_history: Vec::new(),
+                    opponents: HashSet::new(),
+                    float_history: Vec::new(),
+                    is_bye_eligible: true,
+                });
 
             // Calculate color preference based on recent games
-            let color_preference = self.calculate_color_preference(&color_history);
+            let color_preference = self.calculate_color_preference(&history.color_history);
+
+            // Forbidden pairs are treated as if the players had already met
+            let mut opponents = history.opponents;
+            opponents.extend(self.forbidden_pairs.iter().filter_map(|&(a, b)| {
+                if a == player.id {
+                    Some(b)
+                } else if b == player.id {
+                    Some(a)
+                } else {
+                    None
+                }
+            }));
 
             swiss_players.push(SwissPlayer {
                 player,
                 points: points as f64,
                 rating,
-                color_history,
+                color_history: history.color_history,
                 opponents,
                 color_preference,
-                is_bye_eligible: true, // Will be refined based on tournament rules
-                float_history: Vec::new(), // Will be tracked across rounds
+                is_bye_eligible: history.is_bye_eligible,
+                float_history: history.float_history,
             });
         }
 
         Ok(swiss_players)
     }
 
+    /// Reconstruct colours, opponents, floats and byes of every player round by round.
+    ///
+    /// Bye games are stored against the virtual BYE player (negative id), and a
+    /// round without any game counts as unplayed. Only a full-point bye counts as
+    /// the pairing-allocated bye. Floats are derived from the scores both players
+    /// had before the round.
+    pub fn reconstruct_pairing_histories(
+        &self,
+        player_ids: &[i32],
+        game_history: &[GameResult],
+    ) -> HashMap<i32, PlayerPairingHistory> {
+        let mut histories: HashMap<i32, PlayerPairingHistory> = player_ids
+            .iter()
+            .map(|&id| {
+                (
+                    id,
+                    PlayerPairingHistory {
+                        color_history: Vec::new(),
+                        opponents: HashSet::new(),
+                        float_history: Vec::new(),
+                        is_bye_eligible: true,
+                    },
+                )
+            })
+            .collect();
+
+        let last_round = game_history
+            .iter()
+            .map(|game| game.game.round_number)
+            .max()
+            .unwrap_or(0);
+        let mut scores: HashMap<i32, f32> = HashMap::new();
+
+        for round_number in 1..=last_round {
+            let round_games: Vec<&GameResult> = game_history
+                .iter()
+                .filter(|game| game.game.round_number == round_number)
+                .collect();
+
+            for &player_id in player_ids {
+                let game = round_games.iter().find(|game| {
+                    game.white_player.id == player_id || game.black_player.id == player_id
+                });
+
+                let mut record = FloatRecord {
+                    round_number,
+                    direction: Some(FloatDirection::Down),
+                    received_bye: false,
+                    unplayed: true,
+                };
+
+                let Some(game) = game else {
+                    histories
+                        .entry(player_id)
+                        .and_modify(|history| history.float_history.push(record));
+                    continue;
+                };
+
+                let is_white = game.white_player.id == player_id;
+                let opponent = if is_white {
+                    &game.black_player
+                } else {
+                    &game.white_player
+                };
+                let result: GameResultType =
+                    game.game.result.parse().unwrap_or(GameResultType::Ongoing);
+                let (white_points, black_points) = result.get_points();
+                let points = if is_white { white_points } else { black_points };
+
+                let Some(history) = histories.get_mut(&player_id) else {
+                    continue;
+                };
+
+                if opponent.id <= 0 {
+                    // Requested zero- and half-point byes keep the player
+                    // eligible for the pairing-allocated bye
+                    if points >= 1.0 {
+                        record.received_bye = true;
+                        history.is_bye_eligible = false;
+                    }
+                } else if result.is_unplayed()
+                    || game
+                        .game
+                        .result_type
+                        .as_deref()
+                        .and_then(|result_type| result_type.parse::<GameResultType>().ok())
+                        .is_some_and(|result_type| result_type.is_unplayed())
+                {
+                    if points > 0.0 {
+                        history.is_bye_eligible = false;
+                    }
+                } else {
+                    let own_score = scores.get(&player_id).copied().unwrap_or(0.0);
+                    let opponent_score = scores.get(&opponent.id).copied().unwrap_or(0.0);
+
+                    record.unplayed = false;
+                    record.direction = if own_score < opponent_score {
+                        Some(FloatDirection::Up)
+                    } else if own_score > opponent_score {
+                        Some(FloatDirection::Down)
+                    } else {
+                        None
+                    };
+                    history
+                        .color_history
+                        .push(if is_white { Color::White } else { Color::Black });
+                    history.opponents.insert(opponent.id);
+                }
+
+                history.float_history.push(record);
+            }
+
+            for game in round_games {
+                let result: GameResultType =
+                    game.game.result.parse().unwrap_or(GameResultType::Ongoing);
+                let (white_points, black_points) = result.get_points();
+                *scores.entry(game.white_player.id).or_default() += white_points;
+                *scores.entry(game.black_player.id).or_default() += black_points;
+            }
+        }
+
+        histories
+    }
+
     /// Form score groups from players
     fn form_score_groups(&self, players: Vec<SwissPlayer>) -> Vec<ScoreGroup> {
         let mut groups_map: BTreeMap<OrderedFloat, Vec<SwissPlayer>> = BTreeMap::new();
@@ -257,7 +461,7 @@ impl SwissPairingEngine {
     }
 
     /// Calculate color preference based on recent games
-    fn calculate_color_preference(&self, color_history: &[Color]) -> ColorPreference {
+    pub fn calculate_color_preference(&self, color_history: &[Color]) -> ColorPreference {
         if color_history.len() < 2 {
             return ColorPreference::None;
         }
@@ -332,88 +536,12 @@ impl SwissPairingEngine {
                     player_to_float.player.name
                 );
 
-                // Find the best group to float to (prioritize groups with odd number of players)
-                let mut best_target = None;
-                let mut _float_found = false;
-
-                // First, try to find a group with odd number of players (to make it even)
-                // Try both directions: down and up
-                for (next_group_index, next_group) in
-                    score_groups.iter().enumerate().skip(group_index + 1)
-                {
-                    if next_group.players.len() % 2 == 1 {
-                        // Check if this player can pair with anyone in the next group
-                        let can_pair = next_group
-                            .players
-                            .iter()
-                            .any(|p| !player_to_float.opponents.contains(&p.player.id));
-
-                        if can_pair {
-                            best_target = Some(next_group_index);
-                            _float_found = true;
-                            break;
-                        }
-                    }
-                }
-
-                // Try floating up to previous groups with odd number of players
-                if !_float_found {
-                    for prev_group_index in (0..group_index).rev() {
-                        if score_groups[prev_group_index].players.len() % 2 == 1 {
-                            // Check if this player can pair with anyone in the previous group
-                            let can_pair = score_groups[prev_group_index]
-                                .players
-                                .iter()
-                                .any(|p| !player_to_float.opponents.contains(&p.player.id));
-
-                            if can_pair {
-                                best_target = Some(prev_group_index);
-                                _float_found = true;
-                                break;
-                            }
-                        }
-                    }
-                }
-
-                // If no odd groups found, try even groups (down first, then up)
-                if !_float_found {
-                    for (next_group_index, next_group) in
-                        score_groups.iter().enumerate().skip(group_index + 1)
-                    {
-                        if !next_group.players.is_empty() {
-                            // Check if this player can pair with anyone in the next group
-                            let can_pair = next_group
-                                .players
-                                .iter()
-                                .any(|p| !player_to_float.opponents.contains(&p.player.id));
-
-                            if can_pair {
-                                best_target = Some(next_group_index);
-                                _float_found = true;
-                                break;
-                            }
-                        }
-                    }
-                }
-
-                // Try floating up to previous groups with even number of players
-                if !_float_found {
-                    for prev_group_index in (0..group_index).rev() {
-                        if !score_groups[prev_group_index].players.is_empty() {
-                            // Check if this player can pair with anyone in the previous group
-                            let can_pair = score_groups[prev_group_index]
-                                .players
-                                .iter()
-                                .any(|p| !player_to_float.opponents.contains(&p.player.id));
-
-                            if can_pair {
-                                best_target = Some(prev_group_index);
-                                _float_found = true;
-                                break;
-                            }
-                        }
-                    }
-                }
+                // Respect the float history first; a repeated float beats a bye
+                let best_target = self
+                    .find_float_target(&score_groups, group_index, player_to_float, true)
+                    .or_else(|| {
+                        self.find_float_target(&score_groups, group_index, player_to_float, false)
+                    });
 
                 if let Some(target_group) = best_target {
                     players_to_float.push((group_index, target_group, player_to_float.clone()));
@@ -507,10 +635,12 @@ impl SwissPairingEngine {
 
         // FIDE C.04.1.3: Float limit validation
         if let Err(e) = self.validate_fide_float_limits(
+            &pairings,
+            &byes,
+            all_players,
             float_count,
             max_floats_allowed,
             round_number,
-            all_players.len(),
         ) {
             validation_errors.push(e.to_string());
             tracing::warn!("FIDE float validation failed: {}", e);
@@ -536,6 +666,56 @@ impl SwissPairingEngine {
         })
     }
 
+    /// Find the score group a single player should float to (prioritize groups with odd number of players)
+    fn find_float_target(
+        &self,
+        score_groups: &[ScoreGroup],
+        group_index: usize,
+        player_to_float: &SwissPlayer,
+        respect_float_history: bool,
+    ) -> Option<usize> {
+        let can_pair = |group: &ScoreGroup| {
+            group
+                .players
+                .iter()
+                .any(|p| !player_to_float.opponents.contains(&p.player.id))
+        };
+        let float_down_allowed = !respect_float_history || self.can_float_down(player_to_float);
+        let float_up_allowed = |target_group: usize| {
+            !respect_float_history || self.can_float_up(player_to_float, target_group)
+        };
+
+        // First, try to find a group with odd number of players (to make it even)
+        // Try both directions: down and up
+        if float_down_allowed
+            && let Some(next_group_index) = (group_index + 1..score_groups.len())
+                .find(|&i| score_groups[i].players.len() % 2 == 1 && can_pair(&score_groups[i]))
+        {
+            return Some(next_group_index);
+        }
+
+        // Try floating up to previous groups with odd number of players
+        if let Some(prev_group_index) = (0..group_index).rev().find(|&i| {
+            score_groups[i].players.len() % 2 == 1
+                && float_up_allowed(i)
+                && can_pair(&score_groups[i])
+        }) {
+            return Some(prev_group_index);
+        }
+
+        // If no odd groups found, try even groups (down first, then up)
+        if float_down_allowed
+            && let Some(next_group_index) = (group_index + 1..score_groups.len())
+                .find(|&i| !score_groups[i].players.is_empty() && can_pair(&score_groups[i]))
+        {
+            return Some(next_group_index);
+        }
+
+        (0..group_index).rev().find(|&i| {
+            !score_groups[i].players.is_empty() && float_up_allowed(i) && can_pair(&score_groups[i])
+        })
+    }
+
     /// Calculate maximum allowed floats based on FIDE C.04.1.3 rules
     fn calculate_max_floats(&self, total_players: usize, round_number: i32) -> usize {
         // FIDE C.04.1.3: Strict float limits based on tournament size and round
@@ -581,26 +761,87 @@ impl SwissPairingEngine {
         }
     }
 
-    /// Validate float limits according to FIDE C.04.1.3
+    /// Validate float limits according to FIDE C.04.1.3 and the float and
+    /// bye history rules of C.04.1.d and C.04.3 B.5
     fn validate_fide_float_limits(
         &self,
+        pairings: &[Pairing],
+        byes: &[SwissPlayer],
+        players: &[SwissPlayer],
         float_count: usize,
         max_floats_allowed: usize,
         round_number: i32,
-        total_players: usize,
     ) -> Result<(), PawnError> {
+        let total_players = players.len();
+        let mut validation_errors = Vec::new();
+
         if float_count > max_floats_allowed {
-            return Err(PawnError::InvalidInput(format!(
-                "FIDE C.04.1.3 violation: {float_count} floats exceed maximum allowed {max_floats_allowed} for round {round_number} with {total_players} players"
-            )));
+            validation_errors.push(format!(
+                "C.04.1.3: {float_count} floats exceed maximum allowed {max_floats_allowed} for round {round_number} with {total_players} players"
+            ));
+        } else if round_number > 2 && float_count > (total_players / 6) {
+            // Additional FIDE constraints
+            validation_errors.push(format!(
+                "C.04.1.3: after round 2, maximum {} floats allowed for {} players",
+                total_players / 6,
+                total_players
+            ));
+        }
+
+        let find_player = |id: i32| players.iter().find(|p| p.player.id == id);
+        let mut check_repeated_float = |player: &SwissPlayer, direction: FloatDirection| {
+            if player.last_float() == Some(direction) {
+                let direction = match direction {
+                    FloatDirection::Up => "upfloat",
+                    FloatDirection::Down => "downfloat",
+                };
+                validation_errors.push(format!(
+                    "C.04.3 B.5: player {name} receives a {direction} in consecutive rounds",
+                    name = player.player.name
+                ));
+            }
+        };
+
+        for pairing in pairings {
+            let Some(black_player) = &pairing.black_player else {
+                continue;
+            };
+            if let (Some(white), Some(black)) = (
+                find_player(pairing.white_player.id),
+                find_player(black_player.id),
+            ) {
+                if white.points > black.points {
+                    check_repeated_float(white, FloatDirection::Down);
+                    check_repeated_float(black, FloatDirection::Up);
+                } else if black.points > white.points {
+                    check_repeated_float(black, FloatDirection::Down);
+                    check_repeated_float(white, FloatDirection::Up);
+                }
+            }
         }
 
-        // Additional FIDE constraints
-        if round_number > 2 && float_count > (total_players / 6) {
+        // A bye counts as a downfloat
+        for bye in byes {
+            check_repeated_float(bye, FloatDirection::Down);
+        }
+        for bye in byes {
+            if bye.has_received_bye() {
+                validation_errors.push(format!(
+                    "C.04.1.d: player {name} receives a second bye",
+                    name = bye.player.name
+                ));
+            } else if !bye.is_bye_eligible {
+                validation_errors.push(format!(
+                    "C.04.1.d: player {name} receives a bye after scoring a point without playing",
+                    name = bye.player.name
+                ));
+            }
+        }
+
+        if !validation_errors.is_empty() {
             return Err(PawnError::InvalidInput(format!(
-                "FIDE C.04.1.3 violation: After round 2, maximum {} floats allowed for {} players",
-                total_players / 6,
-                total_players
+                "FIDE float violations: {}",
+                validation_errors.join("; ")
             )));
         }
 
@@ -1041,88 +1282,33 @@ impl SwissPairingEngine {
     }
 
     /// Check if a player can float up based on their float history
-    fn can_float_up(&self, _player: &SwissPlayer, _target_group: usize) -> bool {
-        // TODO: Implement float history tracking
-        // For now, allow any player to float up
-        true
+    /// (C.04.3 B.5: no identical float in two consecutive rounds)
+    fn can_float_up(&self, player: &SwissPlayer, _target_group: usize) -> bool {
+        player.last_float() != Some(FloatDirection::Up)
     }
 
-    /// Apply accelerated pairing system for first 2 rounds
-    fn apply_accelerated_pairings(&self, swiss_players: &mut [SwissPlayer], round_number: i32) {
-        tracing::info!("Applying accelerated pairings for round {}", round_number);
-
-        let total_players = swiss_players.len();
-
-        // Sort players by rating (descending) to determine top half
-        swiss_players.sort_by(|a, b| b.rating.cmp(&a.rating));
-
-        let top_half_size = total_players / 2;
-
-        // Add virtual points to top half of players
-        for (index, player) in swiss_players.iter_mut().enumerate() {
-            if index < top_half_size {
-                let virtual_points = match round_number {
-                    1 => {
-                        // Round 1: Add 1 virtual point to top half
-                        if index < top_half_size / 2 {
-                            1.0 // Top quarter gets 1 point
-                        } else {
-                            0.5 // Second quarter gets 0.5 points
-                        }
-                    }
-                    2 => {
-                        // Round 2: Adjust based on round 1 results and rating
-                        if player.points >= 1.0 {
-                            // Players who won round 1 get additional boost
-                            if index < top_half_size / 4 {
-                                0.5 // Top players get moderate boost
-                            } else {
-                                0.25 // Other winners get small boost
-                            }
-                        } else {
-                            // Players who didn't win round 1 get compensated
-                            if index < top_half_size / 2 {
-                                0.5 // Higher-rated players get more compensation
-                            } else {
-                                0.25
-                            }
-                        }
-                    }
-                    _ => 0.0, // No virtual points for round 3+
-                };
-
-                player.points += virtual_points;
-
-                if virtual_points > 0.0 {
-                    tracing::debug!(
-                        "Applied {} virtual points to {} (rating: {}, current points: {})",
-                        virtual_points,
-                        player.player.name,
-                        player.rating,
-                        player.points
-                    );
-                }
-            }
-        }
-
-        tracing::info!(
-            "Accelerated pairing applied: {} players in top half received virtual points",
-            top_half_size
-        );
+    /// Check if a player can float down based on their float history
+    fn can_float_down(&self, player: &SwissPlayer) -> bool {
+        player.last_float() != Some(FloatDirection::Down)
     }
 
     /// Select the most appropriate player for a bye using enhanced logic
-    fn select_bye_player<'a>(&self, players: &'a [SwissPlayer]) -> Option<&'a SwissPlayer> {
+    pub(crate) fn select_bye_player<'a>(
+        &self,
+        players: &'a [SwissPlayer],
+    ) -> Option<&'a SwissPlayer> {
         // Enhanced bye selection based on FIDE criteria:
         // 1. Prefer players who haven't had a bye
         // 2. Among those, prefer lowest-rated
         // 3. Avoid giving byes to top performers
 
-        let bye_candidates: Vec<&SwissPlayer> =
+        let mut bye_candidates: Vec<&SwissPlayer> =
             players.iter().filter(|p| p.is_bye_eligible).collect();
 
         if bye_candidates.is_empty() {
-            return None;
+            // Everybody already had a bye or a point without playing; someone must sit out
+            tracing::warn!("No bye-eligible player left, assigning a repeated bye");
+            bye_candidates = players.iter().collect();
         }
 
         // First preference: players who haven't had a bye and are in lower half by rating
@@ -1145,15 +1331,16 @@ impl SwissPairingEngine {
             return Some(player);
         }
 
-        // Last resort: lowest-rated player regardless of bye history
-        bye_candidates.into_iter().min_by_key(|p| p.rating)
+        // Last resort: fewest byes so far, then lowest-rated
+        bye_candidates.into_iter().min_by_key(|p| {
+            let bye_count = p.float_history.iter().filter(|r| r.received_bye).count();
+            (bye_count, p.rating)
+        })
     }
 
-    /// Check if a player has never had a bye (simplified for now)
-    fn has_never_had_bye(&self, _player: &SwissPlayer) -> bool {
-        // TODO: Implement bye history tracking
-        // For now, assume all players are eligible
-        true
+    /// Check if a player has never had a bye
+    fn has_never_had_bye(&self, player: &SwissPlayer) -> bool {
+        !player.has_received_bye()
     }
 
     /// Handle late entry players with proper integration
@@ -1322,6 +1509,7 @@ impl SwissPairingEngine {
                     white_player: white_player.player.clone(),
                     black_player: Some(black_player.player.clone()),
                     board_number: *board_number,
+                    explanation: None,
                 });
 
                 *board_number += 1;
@@ -1380,6 +1568,7 @@ impl SwissPairingEngine {
                 white_player: white_player.player.clone(),
                 black_player: Some(black_player.player.clone()),
                 board_number: *board_number,
+                explanation: None,
             });
 
             *board_number += 1;
@@ -1704,6 +1893,15 @@ impl SwissPairingEngine {
     }
 }
 
+/// Starting rank: pairing number when both players have one, otherwise rating
+pub(crate) fn starting_rank_order(a: &Player, b: &Player) -> std::cmp::Ordering {
+    match (a.pairing_number, b.pairing_number) {
+        (Some(a_number), Some(b_number)) => a_number.cmp(&b_number),
+        _ => b.rating.unwrap_or(0).cmp(&a.rating.unwrap_or(0)),
+    }
+    .then_with(|| a.id.cmp(&b.id))
+}
+
 /// Helper function to get opposite color
 fn opposite_color(color: Color) -> Color {
     match color {
@@ -1738,6 +1936,7 @@ impl Ord for OrderedFloat {
 mod tests {
     use super::*;
     use crate::pawn::domain::model::{Game, Player, PlayerResult};
+    use crate::pawn::service::acceleration::GroupBoundary;
 
     fn create_test_player(id: i32, name: &str, rating: Option<i32>) -> Player {
         Player {
@@ -2017,38 +2216,36 @@ mod tests {
 
     fn test_accelerated_pairings() {
         let engine = SwissPairingEngine::new();
-        let players = vec![
-            create_test_player(1, "High", Some(2000)),
-            create_test_player(2, "Medium High", Some(1800)),
-            create_test_player(3, "Medium", Some(1600)),
-            create_test_player(4, "Medium Low", Some(1400)),
-            create_test_player(5, "Low", Some(1200)),
-            create_test_player(6, "Very Low", Some(1000)),
-        ];
-
+        let players: Vec<Player> = (1..=8)
+            .map(|id| create_test_player(id, &format!("Player {id}"), Some(2400 - id * 100)))
+            .collect();
         let results = players
             .iter()
             .map(|p| create_test_result(p.clone(), 0.0))
             .collect();
+        let acceleration = BakuAcceleration {
+            accelerated_rounds: 2,
+            boundary: GroupBoundary::StartingRank,
+            virtual_points_first_half: 1.0,
+            virtual_points_second_half: 0.5,
+        };
 
-        let mut swiss_players = engine
-            .build_swiss_players(players, results, vec![])
+        let result = engine
+            .generate_accelerated_dutch_system_pairings(
+                players,
+                results,
+                vec![],
+                1,
+                Some(&acceleration),
+            )
             .unwrap();
 
-        let original_points: Vec<f64> = swiss_players.iter().map(|p| p.points).collect();
-
-        // Apply accelerated pairings for round 1
-        engine.apply_accelerated_pairings(&mut swiss_players, 1);
-
-        // Top half should have received virtual points
-        let top_half_size = swiss_players.len() / 2;
-        for i in 0..top_half_size {
-            assert!(swiss_players[i].points > original_points[i]);
-        }
-
-        // Bottom half should have same points
-        for i in top_half_size..swiss_players.len() {
-            assert_eq!(swiss_players[i].points, original_points[i]);
+        // Group A (players 1-4) is paired within itself, as is group B
+        assert_eq!(result.pairings.len(), 4);
+        for pairing in &result.pairings {
+            let white_in_a = pairing.white_player.id <= 4;
+            let black_in_a = pairing.black_player.as_ref().unwrap().id <= 4;
+            assert_eq!(white_in_a, black_in_a);
         }
     }
 
@@ -2269,4 +2466,255 @@ mod tests {
         }
         assert_eq!(player_ids.len(), 8); // All players should be paired
     }
+
+    #[test]
+
+    fn test_reconstruct_float_and_bye_history() {
+        let engine = SwissPairingEngine::new();
+
+        let p1 = create_test_player(1, "Player 1", Some(1800));
+        let p2 = create_test_player(2, "Player 2", Some(1700));
+        let p3 = create_test_player(3, "Player 3", Some(1600));
+        let bye = create_test_player(-1, "BYE", None);
+
+        // Round 1: 1 beats 2, 3 gets the bye. Round 2: 1 (1.0) vs 3 (1.0), 2 is absent.
+        let mut draw = create_test_game(2, 1, 3, 2);
+        draw.result = "1/2-1/2".to_string();
+        let history = vec![
+            create_game_result(create_test_game(1, 1, 2, 1), p1.clone(), p2.clone()),
+            create_game_result(create_test_game(2, 3, -1, 1), p3.clone(), bye),
+            create_game_result(draw, p1, p3),
+        ];
+
+        let histories = engine.reconstruct_pairing_histories(&[1, 2, 3], &history);
+
+        let player_3 = &histories[&3];
+        assert!(!player_3.is_bye_eligible);
+        assert_eq!(player_3.color_history, vec![Color::Black]);
+        assert!(!player_3.opponents.contains(&-1));
+        assert!(player_3.float_history[0].received_bye);
+        assert_eq!(
+            player_3.float_history[0].direction,
+            Some(FloatDirection::Down)
+        );
+        assert_eq!(player_3.float_history[1].direction, None);
+
+        let player_2 = &histories[&2];
+        assert!(player_2.is_bye_eligible);
+        assert!(player_2.float_history[1].unplayed);
+        assert_eq!(
+            player_2.float_history[1].direction,
+            Some(FloatDirection::Down)
+        );
+    }
+
+    #[test]
+
+    fn test_upfloat_and_downfloat_detection() {
+        let engine = SwissPairingEngine::new();
+
+        let players: Vec<Player> = (1..=4)
+            .map(|id| create_test_player(id, &format!("Player {id}"), Some(2000 - id * 100)))
+            .collect();
+
+        // Round 1: 1 and 3 win. Round 2: winner 3 meets loser 2.
+        let history = vec![
+            create_game_result(
+                create_test_game(1, 1, 2, 1),
+                players[0].clone(),
+                players[1].clone(),
+            ),
+            create_game_result(
+                create_test_game(2, 3, 4, 1),
+                players[2].clone(),
+                players[3].clone(),
+            ),
+            create_game_result(
+                create_test_game(3, 2, 3, 2),
+                players[1].clone(),
+                players[2].clone(),
+            ),
+        ];
+
+        let histories = engine.reconstruct_pairing_histories(&[1, 2, 3, 4], &history);
+
+        assert_eq!(
+            histories[&2].float_history[1].direction,
+            Some(FloatDirection::Up)
+        );
+        assert_eq!(
+            histories[&3].float_history[1].direction,
+            Some(FloatDirection::Down)
+        );
+        assert_eq!(
+            histories[&1].float_history[1].direction,
+            Some(FloatDirection::Down)
+        );
+        assert!(histories[&1].float_history[1].unplayed);
+    }
+
+    #[test]
+
+    fn test_forfeit_win_removes_bye_eligibility() {
+        let engine = SwissPairingEngine::new();
+
+        let p1 = create_test_player(1, "Player 1", Some(1800));
+        let p2 = create_test_player(2, "Player 2", Some(1700));
+        let mut forfeit = create_test_game(1, 1, 2, 1);
+        forfeit.result = "1-0F".to_string();
+
+        let histories =
+            engine.reconstruct_pairing_histories(&[1, 2], &[create_game_result(forfeit, p1, p2)]);
+
+        assert!(!histories[&1].is_bye_eligible);
+        assert!(histories[&2].is_bye_eligible);
+        assert!(histories[&1].color_history.is_empty());
+        assert!(!histories[&1].opponents.contains(&2));
+        assert!(histories[&1].float_history[0].unplayed);
+    }
+
+    #[test]
+
+    fn test_requested_half_point_bye_keeps_bye_eligibility() {
+        let engine = SwissPairingEngine::new();
+
+        let p1 = create_test_player(1, "Player 1", Some(1800));
+        let p2 = create_test_player(2, "Player 2", Some(1700));
+        let bye = create_test_player(-1, "BYE", None);
+        let mut half_point_bye = create_test_game(1, 1, -1, 1);
+        half_point_bye.result = "1/2-1/2".to_string();
+        let mut zero_point_bye = create_test_game(2, 2, -1, 1);
+        zero_point_bye.result = "0-1".to_string();
+
+        let histories = engine.reconstruct_pairing_histories(
+            &[1, 2],
+            &[
+                create_game_result(half_point_bye, p1, bye.clone()),
+                create_game_result(zero_point_bye, p2, bye),
+            ],
+        );
+
+        for id in [1, 2] {
+            assert!(histories[&id].is_bye_eligible);
+            assert!(!histories[&id].float_history[0].received_bye);
+            assert!(histories[&id].float_history[0].unplayed);
+        }
+    }
+
+    #[test]
+
+    fn test_forbidden_pairs_are_not_paired() {
+        let engine = SwissPairingEngine::new().with_forbidden_pairs(HashSet::from([(1, 3)]));
+        let players = vec![
+            create_test_player(1, "Player 1", Some(1800)),
+            create_test_player(2, "Player 2", Some(1700)),
+            create_test_player(3, "Player 3", Some(1600)),
+            create_test_player(4, "Player 4", Some(1500)),
+        ];
+        let results = players
+            .iter()
+            .map(|p| create_test_result(p.clone(), 0.0))
+            .collect();
+
+        let result = engine
+            .generate_dutch_system_pairings(players, results, vec![], 1)
+            .unwrap();
+
+        assert_eq!(result.pairings.len(), 2);
+        for pairing in &result.pairings {
+            let black_id = pairing.black_player.as_ref().unwrap().id;
+            let pair = (
+                pairing.white_player.id.min(black_id),
+                pairing.white_player.id.max(black_id),
+            );
+            assert_ne!(pair, (1, 3));
+        }
+    }
+
+    #[test]
+
+    fn test_bye_not_given_twice() {
+        let engine = SwissPairingEngine::new();
+
+        let players = vec![
+            create_test_player(1, "Player 1", Some(1800)),
+            create_test_player(2, "Player 2", Some(1700)),
+            create_test_player(3, "Player 3", Some(1200)),
+        ];
+        let bye = create_test_player(-1, "BYE", None);
+
+        // The lowest rated player already had the bye in round 1
+        let history = vec![
+            create_game_result(
+                create_test_game(1, 1, 2, 1),
+                players[0].clone(),
+                players[1].clone(),
+            ),
+            create_game_result(create_test_game(2, 3, -1, 1), players[2].clone(), bye),
+        ];
+        let results = vec![
+            create_test_result(players[0].clone(), 1.0),
+            create_test_result(players[1].clone(), 0.0),
+            create_test_result(players[2].clone(), 1.0),
+        ];
+
+        let result = engine
+            .generate_dutch_system_pairings(players, results, history, 2)
+            .unwrap();
+
+        assert_eq!(result.byes.len(), 1);
+        assert_ne!(result.byes[0].player.id, 3);
+    }
+
+    #[test]
+
+    fn test_float_history_limits() {
+        let engine = SwissPairingEngine::new();
+
+        let mut upfloater = SwissPlayer {
+            player: create_test_player(1, "Upfloater", Some(1500)),
+            points: 1.0,
+            rating: 1500,
+            color_history: vec![Color::White],
+            opponents: HashSet::new(),
+            color_preference: ColorPreference::None,
+            is_bye_eligible: true,
+            float_history: vec![FloatRecord {
+                round_number: 1,
+                direction: Some(FloatDirection::Up),
+                received_bye: false,
+                unplayed: false,
+            }],
+        };
+
+        assert!(!engine.can_float_up(&upfloater, 0));
+        assert!(engine.can_float_down(&upfloater));
+
+        let higher = SwissPlayer {
+            player: create_test_player(2, "Leader", Some(1800)),
+            points: 2.0,
+            float_history: vec![],
+            ..upfloater.clone()
+        };
+        let pairing = Pairing {
+            white_player: higher.player.clone(),
+            black_player: Some(upfloater.player.clone()),
+            board_number: 1,
+            explanation: None,
+        };
+        let players = vec![higher.clone(), upfloater.clone()];
+
+        let error = engine
+            .validate_fide_float_limits(&[pairing], &[], &players, 0, 2, 2)
+            .unwrap_err();
+        assert!(error.to_string().contains("upfloat in consecutive rounds"));
+
+        // A previous bye receiver cannot get a second bye
+        upfloater.float_history[0].received_bye = true;
+        upfloater.float_history[0].direction = Some(FloatDirection::Down);
+        let error = engine
+            .validate_fide_float_limits(&[], &[upfloater.clone()], &players, 0, 2, 2)
+            .unwrap_err();
+        assert!(error.to_string().contains("second bye"));
+    }
 }
@@ -653,6 +653,7 @@ impl<D: Db> TeamPairingEngine<D> {
                 white_player,
                 black_player: Some(black_player),
                 board_number: board as i32 + 1,
+                explanation: None,
             });
         }
 
@@ -1,3 +1,4 @@
+use chrono::Datelike;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::instrument;
@@ -13,6 +14,13 @@ use crate::pawn::{
             TiebreakType, TournamentTiebreakConfig,
         },
     },
+    service::{
+        performance_rating::{
+            average_rating, perfect_tournament_performance, rating_difference,
+            tournament_performance_rating,
+        },
+        rating::{RatingProfile, played_score, player_rating_report},
+    },
 };
 
 #[allow(dead_code)]
@@ -215,6 +223,9 @@ impl<D: Db> TiebreakCalculator<D> {
             TiebreakType::TournamentPerformanceRating => {
                 self.calculate_tournament_performance_rating(player, all_games, all_players)? as f64
             }
+            TiebreakType::PerfectTournamentPerformance => {
+                self.calculate_perfect_performance(player, all_games, all_players)? as f64
+            }
             TiebreakType::KoyaSystem => {
                 self.calculate_koya_system(player, all_games, all_results)?
             }
@@ -478,58 +489,46 @@ impl<D: Db> TiebreakCalculator<D> {
         games: &[Game],
         all_players: &[Player],
     ) -> Result<Option<i32>, PawnError> {
+        let (opponent_ratings, score) = self.rated_opponent_results(player, games, all_players);
+        Ok(tournament_performance_rating(&opponent_ratings, score))
+    }
+
+    /// Ratings of the rated opponents and the score made against them
+    fn rated_opponent_results(
+        &self,
+        player: &Player,
+        games: &[Game],
+        all_players: &[Player],
+    ) -> (Vec<i32>, f64) {
         let mut opponent_ratings = Vec::new();
         let mut score = 0.0;
-        let mut games_count = 0;
 
         for game in games {
-            if game.result == "*" {
+            let is_white = game.white_player_id == player.id;
+            if !is_white && game.black_player_id != player.id {
                 continue;
             }
-
-            let (opponent_id, game_score) = if game.white_player_id == player.id {
-                (
-                    game.black_player_id,
-                    match game.result.as_str() {
-                        "1-0" => 1.0,
-                        "1/2-1/2" => 0.5,
-                        _ => 0.0,
-                    },
-                )
-            } else if game.black_player_id == player.id {
-                (
-                    game.white_player_id,
-                    match game.result.as_str() {
-                        "0-1" => 1.0,
-                        "1/2-1/2" => 0.5,
-                        _ => 0.0,
-                    },
-                )
-            } else {
+            // Forfeits, defaults and unfinished games are not part of a performance
+            let Ok(game_score) = played_score(game, is_white) else {
                 continue;
             };
+            let opponent_id = if is_white {
+                game.black_player_id
+            } else {
+                game.white_player_id
+            };
 
-            if let Some(opponent) = all_players.iter().find(|p| p.id == opponent_id) {
-                if let Some(rating) = opponent.rating {
-                    opponent_ratings.push(rating);
-                    score += game_score;
-                    games_count += 1;
-                }
+            if let Some(rating) = all_players
+                .iter()
+                .find(|p| p.id == opponent_id)
+                .and_then(|opponent| opponent.rating)
+            {
+                opponent_ratings.push(rating);
+                score += game_score;
             }
         }
 
-        if opponent_ratings.is_empty() {
-            return Ok(None);
-        }
-
-        let avg_opponent_rating: f64 =
-            opponent_ratings.iter().sum::<i32>() as f64 / opponent_ratings.len() as f64;
-        let percentage = score / games_count as f64;
-
-        // Simplified TPR calculation
-        let tpr = avg_opponent_rating + 400.0 * (percentage - 0.5);
-
-        Ok(Some(tpr as i32))
+        (opponent_ratings, score)
     }
 
     fn count_games_with_black(&self, player: &Player, games: &[Game]) -> Result<i32, PawnError> {
@@ -697,64 +696,18 @@ impl<D: Db> TiebreakCalculator<D> {
         games: &[Game],
         all_players: &[Player],
     ) -> Result<i32, PawnError> {
-        let mut opponent_ratings = Vec::new();
-        let mut total_score = 0.0;
-        let mut games_count = 0;
-
-        for game in games {
-            if game.result == "*" {
-                continue;
-            }
-
-            let (opponent_id, game_score) = if game.white_player_id == player.id {
-                (
-                    game.black_player_id,
-                    match game.result.as_str() {
-                        "1-0" => 1.0,
-                        "1/2-1/2" => 0.5,
-                        _ => 0.0,
-                    },
-                )
-            } else if game.black_player_id == player.id {
-                (
-                    game.white_player_id,
-                    match game.result.as_str() {
-                        "0-1" => 1.0,
-                        "1/2-1/2" => 0.5,
-                        _ => 0.0,
-                    },
-                )
-            } else {
-                continue;
-            };
-
-            if let Some(opponent) = all_players.iter().find(|p| p.id == opponent_id) {
-                if let Some(rating) = opponent.rating {
-                    opponent_ratings.push(rating);
-                    total_score += game_score;
-                    games_count += 1;
-                }
-            }
-        }
-
-        if opponent_ratings.is_empty() || games_count == 0 {
-            return Ok(0);
-        }
-
-        let avg_opponent_rating: f64 =
-            opponent_ratings.iter().sum::<i32>() as f64 / opponent_ratings.len() as f64;
-        let percentage = total_score / games_count as f64;
-
-        // Enhanced TPR calculation with more accurate formula
-        let dp = if percentage >= 0.99 {
-            800.0
-        } else if percentage <= 0.01 {
-            -800.0
-        } else {
-            400.0_f64 * (percentage / (1.0_f64 - percentage)).ln() / (2.0_f64).ln()
-        };
+        let (opponent_ratings, score) = self.rated_opponent_results(player, games, all_players);
+        Ok(tournament_performance_rating(&opponent_ratings, score).unwrap_or(0))
+    }
 
-        Ok((avg_opponent_rating + dp) as i32)
+    fn calculate_perfect_performance(
+        &self,
+        player: &Player,
+        games: &[Game],
+        all_players: &[Player],
+    ) -> Result<i32, PawnError> {
+        let (opponent_ratings, score) = self.rated_opponent_results(player, games, all_players);
+        Ok(perfect_tournament_performance(&opponent_ratings, score).unwrap_or(0))
     }
 
     fn calculate_koya_system(
@@ -906,77 +859,44 @@ impl<D: Db> TiebreakCalculator<D> {
         games: &[Game],
         all_players: &[Player],
     ) -> Result<Option<i32>, PawnError> {
-        let player_rating = match player.rating {
-            Some(rating) => rating,
-            None => return Ok(None),
-        };
-
-        let mut total_expected = 0.0;
-        let mut total_actual = 0.0;
-        let mut rated_games = 0;
-
-        for game in games {
-            if game.result == "*" {
-                continue;
-            }
-
-            let (opponent_id, actual_score) = if game.white_player_id == player.id {
-                (
-                    game.black_player_id,
-                    match game.result.as_str() {
-                        "1-0" => 1.0,
-                        "1/2-1/2" => 0.5,
-                        _ => 0.0,
-                    },
-                )
-            } else if game.black_player_id == player.id {
-                (
-                    game.white_player_id,
-                    match game.result.as_str() {
-                        "0-1" => 1.0,
-                        "1/2-1/2" => 0.5,
-                        _ => 0.0,
-                    },
-                )
-            } else {
-                continue;
-            };
-
-            if let Some(opponent) = all_players.iter().find(|p| p.id == opponent_id) {
-                if let Some(opponent_rating) = opponent.rating {
-                    // Calculate expected score using ELO formula
-                    let rating_diff = opponent_rating - player_rating;
-                    let expected_score = 1.0 / (1.0 + 10.0_f64.powf(rating_diff as f64 / 400.0));
-
-                    total_expected += expected_score;
-                    total_actual += actual_score;
-                    rated_games += 1;
-                }
-            }
+        if player.rating.is_none() {
+            return Ok(None);
         }
 
-        if rated_games == 0 {
+        // Standings only know the tournament entry; the rating report reads the registry
+        let profile = RatingProfile::from_player(player);
+        let year = chrono::Utc::now().year();
+        let report = player_rating_report(player, games, all_players, &profile, "fide", year);
+        if report.games_counted == 0 {
             return Ok(None);
         }
+        Ok(report.rating_change.map(|change| change.round() as i32))
+    }
 
-        // Calculate K-factor based on player rating and games played
-        let k_factor = self.calculate_k_factor(player_rating, rated_games);
-
-        // Calculate rating change
-        let rating_change = k_factor * (total_actual - total_expected);
+    /// Average rating of opponents of every player after each of the first
+    /// `rounds` rounds, as used by the Dubov System
+    pub fn calculate_aro_progression(
+        &self,
+        players: &[Player],
+        games: &[Game],
+        rounds: i32,
+    ) -> Result<HashMap<i32, Vec<f64>>, PawnError> {
+        let mut progression: HashMap<i32, Vec<f64>> = HashMap::new();
 
-        Ok(Some(rating_change.round() as i32))
-    }
+        for round in 1..=rounds {
+            let games_so_far: Vec<Game> = games
+                .iter()
+                .filter(|game| game.round_number <= round)
+                .cloned()
+                .collect();
 
-    fn calculate_k_factor(&self, player_rating: i32, games_played: i32) -> f64 {
-        // FIDE K-factor rules
-        if player_rating >= 2400 {
-            10.0 // Top players
-        } else if player_rating < 2300 && games_played < 30 {
-            40.0 // New players under 2300
-        } else {
-            20.0 // Standard K-factor
+            for player in players {
+                let aro = self.calculate_aro(player, &games_so_far, players)?;
+                progression.entry(player.id).or_default().push(aro);
+            }
         }
+
+        Ok(progression)
     }
 
     /// Generate cross-table showing all player vs player results
@@ -1545,6 +1465,7 @@ impl<D: Db> TiebreakCalculator<D> {
     > {
         let mut opponents_involved = Vec::new();
         let mut calculation_details = Vec::new();
+        let mut opponent_ratings = Vec::new();
         let mut total_rating = 0;
         let mut total_score = 0.0;
         let mut games_count = 0;
@@ -1587,6 +1508,7 @@ impl<D: Db> TiebreakCalculator<D> {
 
             if let Some(opponent_player) = all_players.iter().find(|p| p.id == opponent_id) {
                 if let Some(rating) = opponent_player.rating {
+                    opponent_ratings.push(rating);
                     total_rating += rating;
                     total_score += game_score;
                     games_count += 1;
@@ -1603,15 +1525,14 @@ impl<D: Db> TiebreakCalculator<D> {
             }
         }
 
-        if games_count > 0 {
-            let avg_opponent_rating = total_rating as f64 / games_count as f64;
+        if let Some(avg_opponent_rating) = average_rating(&opponent_ratings) {
             let percentage = total_score / games_count as f64;
 
             calculation_details.push(TiebreakCalculationStep {
                 step_number,
                 description: "Calculate average opponent rating".to_string(),
-                calculation: format!("{total_rating} ÷ {games_count} = {avg_opponent_rating:.0}"),
-                intermediate_result: avg_opponent_rating,
+                calculation: format!("{total_rating} ÷ {games_count} = {avg_opponent_rating}"),
+                intermediate_result: avg_opponent_rating as f64,
             });
             step_number += 1;
 
@@ -1628,21 +1549,14 @@ impl<D: Db> TiebreakCalculator<D> {
             });
             step_number += 1;
 
-            let dp = if percentage >= 0.99 {
-                800.0
-            } else if percentage <= 0.01 {
-                -800.0
-            } else {
-                400.0_f64 * (percentage / (1.0_f64 - percentage)).ln() / (2.0_f64).ln()
-            };
-
+            let dp = rating_difference(percentage);
             let tpr = avg_opponent_rating + dp;
 
             calculation_details.push(TiebreakCalculationStep {
                 step_number,
-                description: "Apply ELO formula".to_string(),
-                calculation: format!("{avg_opponent_rating:.0} + {dp:.0} = {tpr:.0}"),
-                intermediate_result: tpr,
+                description: "Add dp from the FIDE conversion table".to_string(),
+                calculation: format!("{avg_opponent_rating} + {dp} = {tpr}"),
+                intermediate_result: tpr as f64,
             });
         }
 
@@ -2286,78 +2200,163 @@ mod tests {
         assert_eq!(koya_score, expected_koya);
     }
 
-    #[test]
-    fn test_rating_change_calculation() {
-        let _player = create_test_player(1, "Player 1", Some(2000));
-        let _games = vec![
-            create_test_game(1, 1, 1, 2, "1-0"),     // Win vs 2100
-            create_test_game(2, 2, 3, 1, "0-1"),     // Loss vs 2200
-            create_test_game(3, 3, 1, 4, "1/2-1/2"), // Draw vs 1900
-        ];
+    #[tokio::test]
+    async fn test_rating_change_calculation() {
+        let db = Arc::new(crate::pawn::db::sqlite::SqliteDb::new(
+            sqlx::SqlitePool::connect(":memory:").await.unwrap(),
+        ));
+        let calculator = TiebreakCalculator::new(db);
 
-        let _all_players = vec![
-            _player.clone(),
+        let players = vec![
+            create_test_player(1, "Player 1", Some(2000)),
             create_test_player(2, "Opponent 1", Some(2100)),
             create_test_player(3, "Opponent 2", Some(2200)),
             create_test_player(4, "Opponent 3", Some(1900)),
+            create_test_player(5, "Unrated", None),
+            create_test_player(6, "Opponent 4", Some(1500)),
+        ];
+        let mut games = vec![
+            create_test_game(1, 1, 1, 2, "1-0"), // Win vs 2100, expected 0.36
+            create_test_game(2, 2, 3, 1, "0-1"), // Loss vs 2200, expected 0.24
+            create_test_game(3, 3, 1, 4, "1/2-1/2"), // Draw vs 1900, expected 0.64
         ];
 
-        // Calculate expected scores manually
-        let mut total_expected = 0.0;
-        let mut total_actual = 0.0;
-
-        // Game 1: Win vs 2100 (expected: ~0.36, actual: 1.0)
-        let exp1 = 1.0 / (1.0 + 10.0_f64.powf((2100 - 2000) as f64 / 400.0));
-        total_expected += exp1;
-        total_actual += 1.0;
-
-        // Game 2: Loss vs 2200 (expected: ~0.24, actual: 0.0)
-        let exp2 = 1.0 / (1.0 + 10.0_f64.powf((2200 - 2000) as f64 / 400.0));
-        total_expected += exp2;
-        total_actual += 0.0;
-
-        // Game 3: Draw vs 1900 (expected: ~0.64, actual: 0.5)
-        let exp3 = 1.0 / (1.0 + 10.0_f64.powf((1900 - 2000) as f64 / 400.0));
-        total_expected += exp3;
-        total_actual += 0.5;
-
-        // K-factor for 2000 rating = 20.0
-        let k_factor = 20.0;
-        let expected_change = k_factor * (total_actual - total_expected);
-
-        // This test verifies the calculation logic
-        assert!(expected_change.abs() < 100.0); // Reasonable rating change
-        assert!((0.0..=3.0).contains(&total_actual)); // Valid score range
-        assert!((0.0..=3.0).contains(&total_expected)); // Valid expected range
+        // K = 20: 20 × (1.5 - 1.24) = 5.2
+        assert_eq!(
+            calculator
+                .calculate_rating_change(&players[0], &games, &players)
+                .unwrap(),
+            Some(5)
+        );
+
+        // Forfeits and games against unrated players are not rated
+        games.push(create_test_game(4, 4, 2, 1, "0-1F"));
+        games.push(create_test_game(5, 5, 1, 5, "1-0"));
+        assert_eq!(
+            calculator
+                .calculate_rating_change(&players[0], &games, &players)
+                .unwrap(),
+            Some(5)
+        );
+        assert_eq!(
+            calculator
+                .calculate_rating_change(&players[4], &games, &players)
+                .unwrap(),
+            None
+        );
+
+        // 500 points above the opponent count as 400, expected 0.92
+        games.push(create_test_game(6, 6, 6, 1, "0-1"));
+        assert_eq!(
+            calculator
+                .calculate_rating_change(&players[0], &games, &players)
+                .unwrap(),
+            Some(7)
+        );
     }
 
-    #[test]
-    fn test_tournament_performance_rating() {
-        // Test TPR calculation with various scenarios
-        let scenarios = vec![
-            (1.0, 2000, 2800), // 100% score vs 2000 avg = ~2800 TPR
-            (0.5, 2000, 2000), // 50% score vs 2000 avg = ~2000 TPR
-            (0.0, 2000, 1200), // 0% score vs 2000 avg = ~1200 TPR
+    #[tokio::test]
+    async fn test_tournament_performance_rating() {
+        let db = Arc::new(crate::pawn::db::sqlite::SqliteDb::new(
+            sqlx::SqlitePool::connect(":memory:").await.unwrap(),
+        ));
+        let calculator = TiebreakCalculator::new(db);
+
+        let players = vec![
+            create_test_player(1, "Alice", Some(2000)),
+            create_test_player(2, "Bob", Some(1800)),
+            create_test_player(3, "Charlie", Some(2200)),
+            create_test_player(4, "Diana", Some(2001)),
+            create_test_player(5, "Eve", None),
+            create_test_player(6, "Frank", Some(1900)),
+        ];
+        let games = vec![
+            create_test_game(1, 1, 1, 2, "1-0"),
+            create_test_game(2, 2, 3, 1, "1/2-1/2"),
+            create_test_game(3, 3, 1, 4, "0-1"),
+            create_test_game(4, 4, 5, 1, "0-1"),
+            create_test_game(5, 5, 1, 3, "*"),
         ];
 
-        for (percentage, avg_rating, expected_tpr_approx) in scenarios {
-            let dp = if percentage >= 0.99 {
-                800.0
-            } else if percentage <= 0.01 {
-                -800.0
-            } else {
-                400.0_f64 * (percentage / (1.0_f64 - percentage)).ln() / (2.0_f64).ln()
-            };
+        // Unrated and unfinished games are left out: 1.5 / 3 against an average of 2000.33
+        assert_eq!(
+            calculator
+                .calculate_tournament_performance_rating(&players[0], &games, &players)
+                .unwrap(),
+            2000
+        );
+        // Bob lost his only game: 2000 - 800
+        assert_eq!(
+            calculator
+                .calculate_tournament_performance_rating(&players[1], &games, &players)
+                .unwrap(),
+            1200
+        );
+        // Diana won her only game: 2000 + 800
+        assert_eq!(
+            calculator
+                .calculate_performance_rating(&players[3], &games, &players)
+                .unwrap(),
+            Some(2800)
+        );
+        assert_eq!(
+            calculator
+                .calculate_performance_rating(&players[5], &games, &players)
+                .unwrap(),
+            None
+        );
 
-            let tpr = (avg_rating as f64 + dp) as i32;
+        // 2 / 3 rounds to 0.67, dp 125
+        let games = vec![
+            create_test_game(1, 1, 1, 2, "1-0"),
+            create_test_game(2, 2, 3, 1, "1-0"),
+            create_test_game(3, 3, 1, 4, "1-0"),
+        ];
+        assert_eq!(
+            calculator
+                .calculate_tournament_performance_rating(&players[0], &games, &players)
+                .unwrap(),
+            2000 + 125
+        );
+        let ptp = calculator
+            .calculate_perfect_performance(&players[0], &games, &players)
+            .unwrap();
+        assert_eq!(ptp, 2145);
+        assert_eq!(
+            calculator
+                .calculate_perfect_performance(&players[1], &games, &players)
+                .unwrap(),
+            2000 - 800
+        );
 
-            // Allow for reasonable approximation
-            let diff = (tpr - expected_tpr_approx).abs();
-            assert!(
-                diff < 100,
-                "TPR calculation too far off: {tpr} vs {expected_tpr_approx}"
-            );
-        }
+        // A forfeit win and a game lost by default are not performance games
+        let mut forfeited = create_test_game(5, 5, 6, 1, "0-1");
+        forfeited.result_type = Some("white_forfeit".to_string());
+        let games = vec![
+            create_test_game(1, 1, 1, 2, "1-0"),
+            create_test_game(2, 2, 3, 1, "1-0"),
+            create_test_game(3, 3, 1, 4, "1-0"),
+            create_test_game(4, 4, 1, 6, "1-0F"),
+            forfeited,
+        ];
+        assert_eq!(
+            calculator
+                .calculate_tournament_performance_rating(&players[0], &games, &players)
+                .unwrap(),
+            2000 + 125
+        );
+        assert_eq!(
+            calculator
+                .calculate_perfect_performance(&players[0], &games, &players)
+                .unwrap(),
+            2145
+        );
+        assert_eq!(
+            calculator
+                .calculate_performance_rating(&players[5], &games, &players)
+                .unwrap(),
+            None
+        );
     }
 
     #[test]
@@ -2652,4 +2651,32 @@ mod tests {
         assert_eq!(charlie_contribution.contribution_value, 1900.0);
         assert_eq!(charlie_contribution.opponent_rating, Some(1900));
     }
+
+    #[tokio::test]
+    async fn test_calculate_aro_progression() {
+        let db = Arc::new(crate::pawn::db::sqlite::SqliteDb::new(
+            sqlx::SqlitePool::connect(":memory:").await.unwrap(),
+        ));
+        let calculator = TiebreakCalculator::new(db);
+
+        let players = vec![
+            create_test_player(1, "Alice", Some(1800)),
+            create_test_player(2, "Bob", Some(1700)),
+            create_test_player(3, "Charlie", Some(2000)),
+        ];
+
+        let games = vec![
+            create_test_game(1, 1, 1, 2, "1-0"),     // Alice beats Bob
+            create_test_game(2, 2, 3, 1, "1/2-1/2"), // Charlie draws with Alice
+            create_test_game(3, 3, 2, 3, "*"),       // Not played yet
+        ];
+
+        let progression = calculator
+            .calculate_aro_progression(&players, &games, 3)
+            .unwrap();
+
+        assert_eq!(progression[&1], vec![1700.0, 1850.0, 1850.0]);
+        assert_eq!(progression[&2], vec![1800.0, 1800.0, 1800.0]);
+        assert_eq!(progression[&3], vec![0.0, 1800.0, 1800.0]);
+    }
 }
@@ -4,9 +4,15 @@ use crate::pawn::{
     common::error::PawnError,
     db::Db,
     domain::{
-        dto::{CreateGame, CreatePlayer, CreateTournament, UpdateTournamentStatus},
-        model::{Game, GameResult, Player, PlayerResult, Tournament, TournamentDetails},
+        dto::{
+            CreateGame, CreatePlayer, CreateTournament, ImportTrfRequest, TrfImportResult,
+            UpdateTournamentPairingMethod, UpdateTournamentStatus,
+        },
+        model::{
+            Game, GameResult, PairingMethod, Player, PlayerResult, Tournament, TournamentDetails,
+        },
     },
+    service::trf::parse_trf,
 };
 
 #[allow(dead_code)]
@@ -166,6 +172,78 @@ impl<D: Db> TournamentService<D> {
             .map_err(PawnError::Database)
     }
 
+    /// Select the pairing method of a tournament, e.g. "burstein" for the Burstein System
+    pub async fn update_tournament_pairing_method(
+        &self,
+        data: UpdateTournamentPairingMethod,
+    ) -> Result<(), PawnError> {
+        let method: PairingMethod = data
+            .pairing_method
+            .parse()
+            .map_err(PawnError::InvalidInput)?;
+        if method.to_str() != data.pairing_method {
+            return Err(PawnError::InvalidInput(format!(
+                "Invalid pairing method: {}",
+                data.pairing_method
+            )));
+        }
+
+        // Make sure the tournament exists
+        self.db
+            .get_tournament(data.tournament_id)
+            .await
+            .map_err(PawnError::Database)?;
+
+        self.db
+            .update_tournament_pairing_method(data.tournament_id, method.to_str())
+            .await
+            .map_err(PawnError::Database)
+    }
+
+    /// Import a tournament from a TRF-16 file. With `validate_only` the file is only
+    /// checked and nothing is written.
+    pub async fn import_trf(&self, data: ImportTrfRequest) -> Result<TrfImportResult, PawnError> {
+        let parsed = match parse_trf(&data.content) {
+            Ok(parsed) => parsed,
+            Err(errors) => {
+                return Ok(TrfImportResult {
+                    success: false,
+                    tournament: None,
+                    players_imported: 0,
+                    rounds_imported: 0,
+                    games_imported: 0,
+                    errors,
+                    warnings: Vec::new(),
+                });
+            }
+        };
+
+        let players_imported = parsed.tournament.players.len() as i32;
+        let rounds_imported = parsed.tournament.rounds.len() as i32;
+        let games_imported = parsed.tournament.games.len() as i32;
+
+        let tournament = if data.validate_only {
+            None
+        } else {
+            Some(
+                self.db
+                    .import_tournament(parsed.tournament)
+                    .await
+                    .map_err(PawnError::Database)?,
+            )
+        };
+
+        Ok(TrfImportResult {
+            success: true,
+            tournament,
+            players_imported,
+            rounds_imported,
+            games_imported,
+            errors: Vec::new(),
+            warnings: parsed.warnings,
+        })
+    }
+
     // Player operations
     #[allow(dead_code)]
     pub async fn get_players_by_tournament(
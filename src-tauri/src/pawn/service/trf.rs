@@ -0,0 +1,1092 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use crate::pawn::domain::{
+    dto::{
+        CreatePlayer, CreateTournament, ImportGame, ImportPlayer, ImportRound, ImportTournament,
+        TrfImportIssue,
+    },
+    model::{Game, GameResultType, Player, Round, Tournament},
+    tiebreak::{StandingsCalculationResult, TournamentTiebreakConfig},
+};
+
+/// Column where the first round block of a 001 player line starts (1-based)
+const FIRST_ROUND_COLUMN: usize = 92;
+
+/// Width of one round block on a 001 line and on the 132 line
+const ROUND_BLOCK_WIDTH: usize = 10;
+
+/// Data written to a FIDE Tournament Report File (TRF-16)
+pub struct TrfReport<'a> {
+    pub tournament: &'a Tournament,
+    pub config: &'a TournamentTiebreakConfig,
+    pub players: &'a [Player],
+    pub games: &'a [Game],
+    pub rounds: &'a [Round],
+    pub standings: Option<&'a StandingsCalculationResult>,
+}
+
+/// One round of a player as written in the round blocks of a 001 line
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RoundEntry {
+    opponent_rank: Option<usize>,
+    color: char,
+    result: char,
+}
+
+impl RoundEntry {
+    fn unpaired(result: char) -> Self {
+        Self {
+            opponent_rank: None,
+            color: '-',
+            result,
+        }
+    }
+
+    /// Points scored according to the TRF result code
+    fn points(&self) -> f32 {
+        match self.result {
+            '1' | '+' | 'W' | 'F' | 'U' => 1.0,
+            '=' | 'D' | 'H' => 0.5,
+            _ => 0.0,
+        }
+    }
+
+    fn format(&self) -> String {
+        format!(
+            "  {:>4} {} {}",
+            self.opponent_rank.unwrap_or(0),
+            self.color,
+            self.result
+        )
+    }
+}
+
+/// Write the complete TRF-16 report: header lines 012-132 followed by one 001 line per player
+pub fn write_trf(report: &TrfReport) -> String {
+    let starting_ranks = starting_ranks(report.players);
+    let rank_by_id: HashMap<i32, usize> = starting_ranks
+        .iter()
+        .enumerate()
+        .map(|(index, player)| (player.id, index + 1))
+        .collect();
+
+    let last_round = report
+        .games
+        .iter()
+        .map(|game| game.round_number)
+        .chain(report.rounds.iter().map(|round| round.round_number))
+        .max()
+        .unwrap_or(0);
+
+    let entries: HashMap<i32, Vec<RoundEntry>> = starting_ranks
+        .iter()
+        .map(|player| {
+            let entries = (1..=last_round)
+                .map(|round| round_entry(player.id, round, report.games, &rank_by_id))
+                .collect();
+            (player.id, entries)
+        })
+        .collect();
+    let points: HashMap<i32, f32> = entries
+        .iter()
+        .map(|(id, rounds)| (*id, rounds.iter().map(RoundEntry::points).sum()))
+        .collect();
+    let final_ranks = final_ranks(&starting_ranks, &points, report.standings);
+
+    let mut output = String::new();
+    write_header(&mut output, report, last_round);
+
+    for (index, player) in starting_ranks.iter().enumerate() {
+        let mut line = format!(
+            "001 {:>4} {}{:>3} {:<33} {:>4} {:<3} {:>11} {:<10} {:>4.1} {:>4}",
+            index + 1,
+            sex_code(player),
+            title_code(player),
+            truncate(&player.name, 33),
+            player
+                .rating
+                .filter(|rating| *rating > 0)
+                .map_or(String::new(), |r| r.to_string()),
+            truncate(player.country_code.as_deref().unwrap_or(""), 3),
+            "",
+            trf_date(player.birth_date.as_deref().unwrap_or("")),
+            points[&player.id],
+            final_ranks[&player.id],
+        );
+        for entry in &entries[&player.id] {
+            line.push_str(&entry.format());
+        }
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Header lines 012-132 from the tournament and its settings
+fn write_header(output: &mut String, report: &TrfReport, last_round: i32) {
+    let tournament = report.tournament;
+    let mut push = |tag: &str, value: &str| {
+        if !value.trim().is_empty() {
+            output.push_str(&format!("{tag} {value}\n"));
+        }
+    };
+
+    push("012", &tournament.name);
+    push("022", &tournament.location);
+    push("032", &tournament.country_code);
+    push("042", &trf_date(&tournament.date));
+    push(
+        "052",
+        &trf_date(tournament.end_time.as_deref().unwrap_or_default()),
+    );
+    push("062", &report.players.len().to_string());
+    push(
+        "072",
+        &report
+            .players
+            .iter()
+            .filter(|player| player.rating.is_some_and(|rating| rating > 0))
+            .count()
+            .to_string(),
+    );
+    push(
+        "092",
+        tournament.tournament_type.as_deref().unwrap_or(
+            if tournament.is_team_tournament == Some(true) {
+                "Team"
+            } else {
+                "Individual"
+            },
+        ),
+    );
+    push(
+        "102",
+        report.config.chief_arbiter.as_deref().unwrap_or_default(),
+    );
+    for deputy in report
+        .config
+        .deputy_arbiters
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+    {
+        push("112", deputy.trim());
+    }
+    push("122", &tournament.time_type);
+
+    // Round dates are aligned with the round blocks of the 001 lines
+    let mut round_dates = String::new();
+    for round_number in 1..=last_round {
+        let date = report
+            .rounds
+            .iter()
+            .find(|round| round.round_number == round_number)
+            .map(|round| short_date(&round.created_at))
+            .unwrap_or_default();
+        round_dates.push_str(&format!("{date:<width$}", width = ROUND_BLOCK_WIDTH));
+    }
+    if !round_dates.trim().is_empty() {
+        let padding = FIRST_ROUND_COLUMN - 1 - "132 ".len();
+        push("132", &format!("{:padding$}{}", "", round_dates.trim_end()));
+    }
+
+    push("XXR", &tournament.total_rounds.to_string());
+}
+
+/// Players in starting rank order: pairing number, then rating, then name
+fn starting_ranks(players: &[Player]) -> Vec<&Player> {
+    let mut ordered: Vec<&Player> = players.iter().collect();
+    ordered.sort_by(|a, b| {
+        a.pairing_number
+            .unwrap_or(i32::MAX)
+            .cmp(&b.pairing_number.unwrap_or(i32::MAX))
+            .then_with(|| {
+                b.initial_rating
+                    .or(b.rating)
+                    .unwrap_or(0)
+                    .cmp(&a.initial_rating.or(a.rating).unwrap_or(0))
+            })
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    ordered
+}
+
+/// Final ranks from the standings, falling back to points and starting rank
+fn final_ranks(
+    starting_ranks: &[&Player],
+    points: &HashMap<i32, f32>,
+    standings: Option<&StandingsCalculationResult>,
+) -> HashMap<i32, i32> {
+    let mut ranks: HashMap<i32, i32> = standings
+        .map(|standings| {
+            standings
+                .standings
+                .iter()
+                .map(|standing| (standing.player.id, standing.rank))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if starting_ranks
+        .iter()
+        .any(|player| !ranks.contains_key(&player.id))
+    {
+        let mut by_points: Vec<&&Player> = starting_ranks.iter().collect();
+        by_points.sort_by(|a, b| {
+            points[&b.id]
+                .partial_cmp(&points[&a.id])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        ranks = by_points
+            .iter()
+            .enumerate()
+            .map(|(index, player)| (player.id, index as i32 + 1))
+            .collect();
+    }
+
+    ranks
+}
+
+/// Round block of a player: opponent, colour and TRF result code
+fn round_entry(
+    player_id: i32,
+    round_number: i32,
+    games: &[Game],
+    rank_by_id: &HashMap<i32, usize>,
+) -> RoundEntry {
+    let Some(game) = games.iter().find(|game| {
+        game.round_number == round_number
+            && (game.white_player_id == player_id || game.black_player_id == player_id)
+    }) else {
+        // Not paired in this round
+        return RoundEntry::unpaired('Z');
+    };
+
+    let is_white = game.white_player_id == player_id;
+    let opponent_id = if is_white {
+        game.black_player_id
+    } else {
+        game.white_player_id
+    };
+    let result = game_result_type(game);
+
+    // Byes are stored against the virtual BYE player (negative id)
+    if opponent_id <= 0 {
+        let (white_points, black_points) = result.get_points();
+        let points = if is_white { white_points } else { black_points };
+        return RoundEntry::unpaired(if points >= 1.0 {
+            'U'
+        } else if points > 0.0 {
+            'H'
+        } else {
+            'Z'
+        });
+    }
+
+    let Some(&opponent_rank) = rank_by_id.get(&opponent_id) else {
+        return RoundEntry::unpaired('Z');
+    };
+
+    let (white_code, black_code) = match result {
+        GameResultType::WhiteWins => ('1', '0'),
+        GameResultType::BlackWins => ('0', '1'),
+        GameResultType::Draw => ('=', '='),
+        GameResultType::BlackForfeit | GameResultType::BlackDefault => ('+', '-'),
+        GameResultType::WhiteForfeit | GameResultType::WhiteDefault => ('-', '+'),
+        GameResultType::DoubleForfeit => ('-', '-'),
+        GameResultType::Timeout if game.result.starts_with("1-0") => ('1', '0'),
+        GameResultType::Timeout => ('0', '1'),
+        GameResultType::Cancelled => return RoundEntry::unpaired('Z'),
+        GameResultType::Ongoing | GameResultType::Adjourned => (' ', ' '),
+    };
+
+    RoundEntry {
+        opponent_rank: Some(opponent_rank),
+        color: if is_white { 'w' } else { 'b' },
+        result: if is_white { white_code } else { black_code },
+    }
+}
+
+/// Result of a game, preferring the forfeit/default classification of `result_type`
+pub(crate) fn game_result_type(game: &Game) -> GameResultType {
+    let result: GameResultType = game.result.parse().unwrap_or(GameResultType::Ongoing);
+    game.result_type
+        .as_deref()
+        .and_then(|result_type| result_type.parse::<GameResultType>().ok())
+        .filter(GameResultType::is_unplayed)
+        .unwrap_or(result)
+}
+
+fn sex_code(player: &Player) -> char {
+    match player.gender.as_deref().map(str::to_uppercase).as_deref() {
+        Some("M") => 'm',
+        Some("F" | "W") => 'w',
+        _ => ' ',
+    }
+}
+
+fn title_code(player: &Player) -> &'static str {
+    match player.title.as_deref().map(str::to_uppercase).as_deref() {
+        Some("GM") => "g",
+        Some("IM") => "i",
+        Some("FM") => "f",
+        Some("CM") => "c",
+        Some("WGM") => "wg",
+        Some("WIM") => "wi",
+        Some("WFM") => "wf",
+        Some("WCM") => "wc",
+        _ => "",
+    }
+}
+
+/// Date as YYYY/MM/DD, accepting ISO dates and timestamps
+fn trf_date(value: &str) -> String {
+    value
+        .get(..10)
+        .filter(|date| date.as_bytes().get(4) == Some(&b'-'))
+        .map(|date| date.replace('-', "/"))
+        .unwrap_or_else(|| value.trim().to_string())
+}
+
+/// Date as YY/MM/DD for the 132 line
+fn short_date(value: &str) -> String {
+    let date = trf_date(value);
+    date.get(2..).map(str::to_string).unwrap_or(date)
+}
+
+fn truncate(value: &str, max_chars: usize) -> String {
+    value.chars().take(max_chars).collect()
+}
+
+/// Tournament read from a TRF file together with non-fatal findings
+#[derive(Debug, Clone)]
+pub struct ParsedTrf {
+    pub tournament: ImportTournament,
+    pub warnings: Vec<String>,
+}
+
+/// One round block of a 001 line as read from the file
+#[derive(Debug, Clone, Copy)]
+struct ParsedEntry {
+    round_number: i32,
+    opponent_rank: i32,
+    color: char,
+    result: char,
+}
+
+#[derive(Debug, Clone)]
+struct ParsedPlayer {
+    line_number: usize,
+    rank: i32,
+    player: CreatePlayer,
+    points: Option<f32>,
+    entries: Vec<ParsedEntry>,
+}
+
+/// Parse a TRF-16 file. All structural problems are collected so the user can
+/// fix the file in one pass; nothing is returned unless the file is consistent.
+pub fn parse_trf(content: &str) -> Result<ParsedTrf, Vec<TrfImportIssue>> {
+    let mut errors = Vec::new();
+    let mut warnings = Vec::new();
+    let mut tournament = CreateTournament {
+        name: String::new(),
+        location: String::new(),
+        date: String::new(),
+        time_type: String::new(),
+        tournament_type: None,
+        player_count: 0,
+        rounds_played: 0,
+        total_rounds: 0,
+        country_code: String::new(),
+    };
+    let mut end_date = None;
+    let mut chief_arbiter = None;
+    let mut deputy_arbiters: Vec<String> = Vec::new();
+    let mut players: Vec<ParsedPlayer> = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim_end();
+        let Some(tag) = line.get(..3) else {
+            continue;
+        };
+        let value = line.get(4..).unwrap_or_default().trim().to_string();
+
+        match tag {
+            "001" => match parse_player_line(line, line_number) {
+                Ok(player) => {
+                    if players.iter().any(|other| other.rank == player.rank) {
+                        errors.push(issue(
+                            line_number,
+                            format!("Duplicate starting rank {}", player.rank),
+                        ));
+                    } else {
+                        players.push(player);
+                    }
+                }
+                Err(issue) => errors.push(issue),
+            },
+            "012" => tournament.name = value,
+            "022" => tournament.location = value,
+            "032" => tournament.country_code = value,
+            "042" => tournament.date = iso_date(&value),
+            "052" => end_date = Some(iso_date(&value)).filter(|date| !date.is_empty()),
+            "092" => tournament.tournament_type = tournament_type(&value),
+            "102" => chief_arbiter = Some(value).filter(|arbiter| !arbiter.is_empty()),
+            "112" if !value.is_empty() => deputy_arbiters.push(value),
+            "122" => tournament.time_type = value,
+            "XXR" => match value.parse::<i32>() {
+                Ok(rounds) => tournament.total_rounds = rounds,
+                Err(_) => errors.push(issue(
+                    line_number,
+                    format!("Invalid number of rounds '{value}'"),
+                )),
+            },
+            _ => {}
+        }
+    }
+
+    if tournament.name.is_empty() {
+        errors.push(TrfImportIssue {
+            line_number: None,
+            message: "Missing tournament name (012 line)".to_string(),
+        });
+    }
+    if players.is_empty() {
+        errors.push(TrfImportIssue {
+            line_number: None,
+            message: "No player lines (001) found".to_string(),
+        });
+    }
+    if tournament.time_type.is_empty() {
+        tournament.time_type = "classical".to_string();
+    }
+
+    players.sort_by_key(|player| player.rank);
+    let games = collect_games(&players, &mut errors);
+
+    if !errors.is_empty() {
+        errors.sort_by_key(|issue| issue.line_number);
+        return Err(errors);
+    }
+
+    for player in &players {
+        let computed: f32 = player
+            .entries
+            .iter()
+            .map(|entry| RoundEntry::unpaired(entry.result).points())
+            .sum();
+        if let Some(points) = player.points
+            && (points - computed).abs() > f32::EPSILON
+        {
+            warnings.push(format!(
+                "Line {}: {} has {points:.1} points in the file but {computed:.1} from the round results",
+                player.line_number, player.player.name
+            ));
+        }
+    }
+
+    let last_round = players
+        .iter()
+        .flat_map(|player| player.entries.iter().map(|entry| entry.round_number))
+        .max()
+        .unwrap_or(0);
+    if tournament.total_rounds < last_round {
+        if tournament.total_rounds > 0 {
+            warnings.push(format!(
+                "XXR declares {} rounds but results exist for {last_round}",
+                tournament.total_rounds
+            ));
+        }
+        tournament.total_rounds = last_round;
+    }
+    tournament.rounds_played = last_round;
+    tournament.player_count = players.len() as i32;
+
+    let rounds = (1..=last_round)
+        .map(|round_number| {
+            let ongoing = games
+                .iter()
+                .any(|game| game.round_number == round_number && game.result == "*");
+            ImportRound {
+                round_number,
+                status: if ongoing { "in_progress" } else { "completed" }.to_string(),
+            }
+        })
+        .collect();
+
+    Ok(ParsedTrf {
+        tournament: ImportTournament {
+            tournament,
+            end_date,
+            chief_arbiter,
+            deputy_arbiters: Some(deputy_arbiters.join(", ")).filter(|d| !d.is_empty()),
+            players: players
+                .into_iter()
+                .map(|player| ImportPlayer {
+                    player: player.player,
+                    pairing_number: player.rank,
+                })
+                .collect(),
+            rounds,
+            games,
+        },
+        warnings,
+    })
+}
+
+fn parse_player_line(line: &str, line_number: usize) -> Result<ParsedPlayer, TrfImportIssue> {
+    let chars: Vec<char> = line.chars().collect();
+    let rank = field(&chars, 5, 8)
+        .parse::<i32>()
+        .ok()
+        .filter(|rank| *rank > 0)
+        .ok_or_else(|| issue(line_number, "Invalid starting rank".to_string()))?;
+    let name = field(&chars, 15, 47);
+    if name.is_empty() {
+        return Err(issue(line_number, format!("Player {rank} has no name")));
+    }
+    let rating = field(&chars, 49, 52);
+    let rating = if rating.is_empty() {
+        None
+    } else {
+        let rating = rating
+            .parse::<i32>()
+            .map_err(|_| issue(line_number, format!("Invalid rating '{rating}'")))?;
+        Some(rating).filter(|rating| *rating > 0)
+    };
+    let points = field(&chars, 81, 84);
+    let points = if points.is_empty() {
+        None
+    } else {
+        Some(
+            points
+                .parse::<f32>()
+                .map_err(|_| issue(line_number, format!("Invalid points '{points}'")))?,
+        )
+    };
+
+    let mut entries = Vec::new();
+    let mut start = FIRST_ROUND_COLUMN;
+    let mut round_number = 1;
+    while start <= chars.len() {
+        let opponent = field(&chars, start, start + 3);
+        let color = column(&chars, start + 5);
+        let result = column(&chars, start + 7);
+        let opponent_rank = if opponent.is_empty() {
+            0
+        } else {
+            opponent.parse::<i32>().map_err(|_| {
+                issue(
+                    line_number,
+                    format!("Invalid opponent '{opponent}' in round {round_number}"),
+                )
+            })?
+        };
+        if !"10=+-WDLHFUZ ".contains(result) {
+            return Err(issue(
+                line_number,
+                format!("Unknown result code '{result}' in round {round_number}"),
+            ));
+        }
+        if !"wb- ".contains(color) {
+            return Err(issue(
+                line_number,
+                format!("Unknown colour '{color}' in round {round_number}"),
+            ));
+        }
+        if opponent_rank != 0 || result != ' ' {
+            entries.push(ParsedEntry {
+                round_number,
+                opponent_rank,
+                color,
+                result,
+            });
+        }
+        start += ROUND_BLOCK_WIDTH;
+        round_number += 1;
+    }
+
+    Ok(ParsedPlayer {
+        line_number,
+        rank,
+        player: CreatePlayer {
+            tournament_id: 0,
+            name,
+            rating,
+            country_code: Some(field(&chars, 54, 56)).filter(|code| !code.is_empty()),
+            title: title_from_code(&field(&chars, 11, 13)),
+            birth_date: Some(iso_date(&field(&chars, 70, 79))).filter(|date| !date.is_empty()),
+            gender: match column(&chars, 10) {
+                'm' | 'M' => Some("M".to_string()),
+                'w' | 'W' | 'f' | 'F' => Some("F".to_string()),
+                _ => None,
+            },
+            email: None,
+            phone: None,
+            club: None,
+        },
+        points,
+        entries,
+    })
+}
+
+/// Build games from the round blocks, checking that both players report the same pairing
+fn collect_games(players: &[ParsedPlayer], errors: &mut Vec<TrfImportIssue>) -> Vec<ImportGame> {
+    let by_rank: HashMap<i32, &ParsedPlayer> =
+        players.iter().map(|player| (player.rank, player)).collect();
+    let mut games = Vec::new();
+
+    for player in players {
+        for entry in &player.entries {
+            let round_number = entry.round_number;
+            if entry.opponent_rank == 0 {
+                let result = match entry.result {
+                    'U' | 'F' | '+' => "1-0",
+                    'H' => "1/2-1/2",
+                    'Z' | '-' | ' ' => continue,
+                    code => {
+                        errors.push(issue(
+                            player.line_number,
+                            format!("Result '{code}' in round {round_number} has no opponent"),
+                        ));
+                        continue;
+                    }
+                };
+                games.push(ImportGame {
+                    round_number,
+                    white_pairing_number: player.rank,
+                    black_pairing_number: None,
+                    result: result.to_string(),
+                    result_type: None,
+                });
+                continue;
+            }
+
+            if entry.opponent_rank == player.rank {
+                errors.push(issue(
+                    player.line_number,
+                    format!(
+                        "Player {} is paired against themselves in round {round_number}",
+                        player.rank
+                    ),
+                ));
+                continue;
+            }
+            let Some(opponent) = by_rank.get(&entry.opponent_rank) else {
+                errors.push(issue(
+                    player.line_number,
+                    format!(
+                        "Unknown opponent {} in round {round_number}",
+                        entry.opponent_rank
+                    ),
+                ));
+                continue;
+            };
+            let reply = opponent
+                .entries
+                .iter()
+                .find(|other| other.round_number == round_number)
+                .filter(|other| other.opponent_rank == player.rank);
+            let Some(reply) = reply else {
+                errors.push(issue(
+                    player.line_number,
+                    format!(
+                        "Player {} lists {} in round {round_number} but {} does not list {}",
+                        player.rank, opponent.rank, opponent.rank, player.rank
+                    ),
+                ));
+                continue;
+            };
+
+            // Each game is created once, from White's line (or the lower rank if unplayed)
+            let is_white = match (entry.color, reply.color) {
+                ('w', 'b') => true,
+                ('b', 'w') => false,
+                ('-' | ' ', '-' | ' ') => player.rank < opponent.rank,
+                (color, other) => {
+                    if player.rank < opponent.rank {
+                        errors.push(issue(
+                            player.line_number,
+                            format!(
+                                "Inconsistent colours '{color}'/'{other}' between {} and {} in round {round_number}",
+                                player.rank, opponent.rank
+                            ),
+                        ));
+                    }
+                    continue;
+                }
+            };
+            if !is_white {
+                continue;
+            }
+
+            match game_result(entry.result, reply.result) {
+                Some(result) => games.push(ImportGame {
+                    round_number,
+                    white_pairing_number: player.rank,
+                    black_pairing_number: Some(opponent.rank),
+                    result: result.to_str().to_string(),
+                    result_type: result
+                        .is_unplayed()
+                        .then(|| unplayed_result_type(&result).to_string()),
+                }),
+                None => errors.push(issue(
+                    player.line_number,
+                    format!(
+                        "Results '{}'/'{}' between {} and {} in round {round_number} do not match",
+                        entry.result, reply.result, player.rank, opponent.rank
+                    ),
+                )),
+            }
+        }
+    }
+
+    games
+}
+
+/// Game result from the White and Black result codes of a paired game
+fn game_result(white: char, black: char) -> Option<GameResultType> {
+    Some(match (white, black) {
+        ('1' | 'W', '0' | 'L') => GameResultType::WhiteWins,
+        ('0' | 'L', '1' | 'W') => GameResultType::BlackWins,
+        ('=' | 'D', '=' | 'D') => GameResultType::Draw,
+        ('+', '-') => GameResultType::BlackForfeit,
+        ('-', '+') => GameResultType::WhiteForfeit,
+        ('-', '-') => GameResultType::DoubleForfeit,
+        (' ', ' ') => GameResultType::Ongoing,
+        _ => return None,
+    })
+}
+
+fn unplayed_result_type(result: &GameResultType) -> &'static str {
+    match result {
+        GameResultType::WhiteForfeit => "white_forfeit",
+        GameResultType::BlackForfeit => "black_forfeit",
+        _ => "double_forfeit",
+    }
+}
+
+fn title_from_code(code: &str) -> Option<String> {
+    let title = match code.to_lowercase().as_str() {
+        "g" => "GM",
+        "i" => "IM",
+        "f" => "FM",
+        "c" => "CM",
+        "wg" => "WGM",
+        "wi" => "WIM",
+        "wf" => "WFM",
+        "wc" => "WCM",
+        _ => return None,
+    };
+    Some(title.to_string())
+}
+
+fn tournament_type(value: &str) -> Option<String> {
+    let value = value.to_lowercase();
+    let tournament_type = if value.contains("robin") {
+        "round_robin"
+    } else if value.contains("knock") {
+        "knockout"
+    } else if value.contains("swiss") {
+        "swiss"
+    } else {
+        return None;
+    };
+    Some(tournament_type.to_string())
+}
+
+/// Date as YYYY-MM-DD from the TRF YYYY/MM/DD form
+fn iso_date(value: &str) -> String {
+    value.trim().replace('/', "-")
+}
+
+/// Trimmed text between two 1-based columns, inclusive
+fn field(chars: &[char], start: usize, end: usize) -> String {
+    chars
+        .get(start - 1..end.min(chars.len()))
+        .map(|slice| slice.iter().collect::<String>().trim().to_string())
+        .unwrap_or_default()
+}
+
+fn column(chars: &[char], position: usize) -> char {
+    chars.get(position - 1).copied().unwrap_or(' ')
+}
+
+fn issue(line_number: usize, message: String) -> TrfImportIssue {
+    TrfImportIssue {
+        line_number: Some(line_number),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_tournament() -> Tournament {
+        Tournament {
+            id: 1,
+            name: "Spring Open".to_string(),
+            location: "Kyiv".to_string(),
+            date: "2024-04-06".to_string(),
+            time_type: "90min+30s".to_string(),
+            tournament_type: Some("Swiss".to_string()),
+            player_count: 3,
+            rounds_played: 2,
+            total_rounds: 5,
+            country_code: "UKR".to_string(),
+            status: None,
+            start_time: None,
+            end_time: Some("2024-04-10".to_string()),
+            description: None,
+            website_url: None,
+            contact_email: None,
+            entry_fee: None,
+            currency: None,
+            is_team_tournament: None,
+            team_size: None,
+            max_teams: None,
+        }
+    }
+
+    fn create_test_player(id: i32, name: &str, rating: Option<i32>) -> Player {
+        Player {
+            id,
+            tournament_id: 1,
+            name: name.to_string(),
+            rating,
+            country_code: Some("UKR".to_string()),
+            title: None,
+            birth_date: None,
+            gender: Some("M".to_string()),
+            email: None,
+            phone: None,
+            club: None,
+            status: "active".to_string(),
+            seed_number: None,
+            pairing_number: None,
+            initial_rating: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    fn create_test_game(id: i32, round: i32, white: i32, black: i32, result: &str) -> Game {
+        Game {
+            id,
+            tournament_id: 1,
+            round_number: round,
+            white_player_id: white,
+            black_player_id: black,
+            result: result.to_string(),
+            result_type: None,
+            result_reason: None,
+            arbiter_notes: None,
+            last_updated: None,
+            approved_by: None,
+            created_at: "2024-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    fn create_test_round(round_number: i32, date: &str) -> Round {
+        Round {
+            id: round_number,
+            tournament_id: 1,
+            round_number,
+            status: "completed".to_string(),
+            created_at: format!("{date} 10:00:00"),
+            completed_at: None,
+        }
+    }
+
+    fn write_test_report() -> String {
+        let tournament = create_test_tournament();
+        let config = TournamentTiebreakConfig {
+            tournament_id: 1,
+            chief_arbiter: Some("IA Jane Doe".to_string()),
+            deputy_arbiters: Some("FA John Roe, NA Max Mustermann".to_string()),
+            ..Default::default()
+        };
+        let mut carlsen = create_test_player(1, "Carlsen, Magnus", Some(2830));
+        carlsen.title = Some("GM".to_string());
+        let players = vec![
+            carlsen,
+            create_test_player(2, "Caruana, Fabiano", Some(2800)),
+            create_test_player(3, "Nakamura, Hikaru", Some(2780)),
+        ];
+        let mut forfeit = create_test_game(3, 2, 2, 1, "1-0");
+        forfeit.result_type = Some("black_forfeit".to_string());
+        let games = vec![
+            create_test_game(1, 1, 1, 3, "1/2-1/2"),
+            create_test_game(2, 1, 2, -1, "1-0"),
+            forfeit,
+            create_test_game(4, 2, 3, -1, "1/2-1/2"),
+        ];
+        let rounds = vec![
+            create_test_round(1, "2024-04-06"),
+            create_test_round(2, "2024-04-07"),
+        ];
+
+        write_trf(&TrfReport {
+            tournament: &tournament,
+            config: &config,
+            players: &players,
+            games: &games,
+            rounds: &rounds,
+            standings: None,
+        })
+    }
+
+    #[test]
+    fn test_header_lines() {
+        let report = write_test_report();
+        let lines: Vec<&str> = report.lines().collect();
+
+        assert!(lines.contains(&"012 Spring Open"));
+        assert!(lines.contains(&"022 Kyiv"));
+        assert!(lines.contains(&"042 2024/04/06"));
+        assert!(lines.contains(&"052 2024/04/10"));
+        assert!(lines.contains(&"062 3"));
+        assert!(lines.contains(&"102 IA Jane Doe"));
+        assert!(lines.contains(&"112 FA John Roe"));
+        assert!(lines.contains(&"112 NA Max Mustermann"));
+        assert!(lines.contains(&"122 90min+30s"));
+        assert!(lines.contains(&"XXR 5"));
+
+        let round_dates = lines.iter().find(|line| line.starts_with("132")).unwrap();
+        assert_eq!(&round_dates[91..99], "24/04/06");
+        assert_eq!(&round_dates[101..109], "24/04/07");
+    }
+
+    #[test]
+    fn test_player_lines() {
+        let report = write_test_report();
+        let players: Vec<&str> = report
+            .lines()
+            .filter(|line| line.starts_with("001"))
+            .collect();
+        assert_eq!(players.len(), 3);
+
+        // Starting rank 1: draw with white against 3, forfeit loss with black against 2
+        let carlsen = players[0];
+        assert_eq!(&carlsen[4..8], "   1");
+        assert_eq!(&carlsen[9..13], "m  g");
+        assert_eq!(carlsen[14..47].trim_end(), "Carlsen, Magnus");
+        assert_eq!(&carlsen[48..52], "2830");
+        assert_eq!(&carlsen[53..56], "UKR");
+        assert_eq!(&carlsen[80..84], " 0.5");
+        assert_eq!(&carlsen[91..99], "   3 w =");
+        assert_eq!(&carlsen[101..109], "   2 b -");
+
+        // Starting rank 2: pairing-allocated bye, then forfeit win
+        let caruana = players[1];
+        assert_eq!(&caruana[80..84], " 2.0");
+        assert_eq!(&caruana[85..89], "   1");
+        assert_eq!(&caruana[91..99], "   0 - U");
+        assert_eq!(&caruana[101..109], "   1 w +");
+
+        // Starting rank 3: draw, then half-point bye
+        let nakamura = players[2];
+        assert_eq!(&nakamura[91..99], "   1 b =");
+        assert_eq!(&nakamura[101..109], "   0 - H");
+        assert_eq!(&nakamura[80..84], " 1.0");
+    }
+
+    #[test]
+    fn test_unpaired_round_is_zero_point_bye() {
+        let absent = create_test_player(1, "Absent", None);
+        let games = vec![create_test_game(1, 2, 2, 3, "1-0")];
+        let rank_by_id = HashMap::from([(1, 1)]);
+
+        let entry = round_entry(absent.id, 1, &games, &rank_by_id);
+        assert_eq!(entry, RoundEntry::unpaired('Z'));
+        assert_eq!(entry.format(), "     0 - Z");
+    }
+
+    #[test]
+    fn test_parse_roundtrip() {
+        let parsed = parse_trf(&write_test_report()).unwrap();
+        let import = parsed.tournament;
+
+        assert_eq!(import.tournament.name, "Spring Open");
+        assert_eq!(import.tournament.date, "2024-04-06");
+        assert_eq!(import.tournament.total_rounds, 5);
+        assert_eq!(import.tournament.rounds_played, 2);
+        assert_eq!(import.tournament.tournament_type.as_deref(), Some("swiss"));
+        assert_eq!(import.end_date.as_deref(), Some("2024-04-10"));
+        assert_eq!(import.chief_arbiter.as_deref(), Some("IA Jane Doe"));
+        assert_eq!(
+            import.deputy_arbiters.as_deref(),
+            Some("FA John Roe, NA Max Mustermann")
+        );
+        assert!(parsed.warnings.is_empty());
+
+        assert_eq!(import.players.len(), 3);
+        assert_eq!(import.players[0].player.name, "Carlsen, Magnus");
+        assert_eq!(import.players[0].player.title.as_deref(), Some("GM"));
+        assert_eq!(import.players[0].player.rating, Some(2830));
+        assert_eq!(import.players[0].player.gender.as_deref(), Some("M"));
+
+        assert_eq!(import.rounds.len(), 2);
+        assert!(
+            import
+                .rounds
+                .iter()
+                .all(|round| round.status == "completed")
+        );
+
+        // Draw, full-point bye, forfeit and half-point bye
+        assert_eq!(import.games.len(), 4);
+        let game = |round: i32, white: i32| {
+            import
+                .games
+                .iter()
+                .find(|game| game.round_number == round && game.white_pairing_number == white)
+                .unwrap()
+        };
+        assert_eq!(game(1, 1).black_pairing_number, Some(3));
+        assert_eq!(game(1, 1).result, "1/2-1/2");
+        assert_eq!(game(1, 2).black_pairing_number, None);
+        assert_eq!(game(1, 2).result, "1-0");
+        assert_eq!(game(2, 2).result, "1-0F");
+        assert_eq!(game(2, 2).result_type.as_deref(), Some("black_forfeit"));
+        assert_eq!(game(2, 3).black_pairing_number, None);
+        assert_eq!(game(2, 3).result, "1/2-1/2");
+    }
+
+    #[test]
+    fn test_parse_reports_inconsistent_pairings() {
+        let report = write_test_report()
+            .replace("   3 w =", "   3 w 1")
+            .replace("012 Spring Open\n", "");
+        let errors = parse_trf(&report).unwrap_err();
+
+        assert!(
+            errors
+                .iter()
+                .any(|issue| issue.message.contains("Missing tournament name"))
+        );
+        assert!(
+            errors
+                .iter()
+                .any(|issue| issue.message.contains("do not match") && issue.line_number.is_some())
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_one_sided_pairing() {
+        let report = write_test_report().replace("   1 b =", "   2 b =");
+        let errors = parse_trf(&report).unwrap_err();
+
+        assert!(
+            errors
+                .iter()
+                .any(|issue| issue.message.contains("1 lists 3 in round 1"))
+        );
+    }
+}
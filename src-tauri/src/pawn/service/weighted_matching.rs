@@ -0,0 +1,783 @@
+//! Maximum weighted matching in general graphs.
+//!
+//! Implementation of Edmonds' blossom algorithm with dual variables
+//! (Galil, "Efficient algorithms for finding maximum matching in graphs",
+//! 1986), running in O(n^3). It is used by the pairing engines that encode
+//! FIDE pairing criteria as edge weights and need a globally optimal
+//! solution instead of a greedy one.
+
+const NONE: usize = usize::MAX;
+
+/// Weighted undirected edge between two vertices
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightedEdge {
+    pub u: usize,
+    pub v: usize,
+    pub weight: i128,
+}
+
+impl WeightedEdge {
+    pub fn new(u: usize, v: usize, weight: i128) -> Self {
+        Self { u, v, weight }
+    }
+}
+
+/// Compute a maximum weight matching.
+///
+/// Returns for every vertex the vertex it is matched to, or `None` when it
+/// stays unmatched. When `max_cardinality` is set, only matchings of maximum
+/// cardinality are considered and the heaviest of those is returned.
+pub fn max_weight_matching(
+    vertex_count: usize,
+    edges: &[WeightedEdge],
+    max_cardinality: bool,
+) -> Vec<Option<usize>> {
+    if edges.is_empty() || vertex_count == 0 {
+        return vec![None; vertex_count];
+    }
+
+    let mut matcher = BlossomMatcher::new(vertex_count, edges, max_cardinality);
+    matcher.solve();
+
+    (0..vertex_count)
+        .map(|v| {
+            let p = matcher.mate[v];
+            if p == NONE {
+                None
+            } else {
+                Some(matcher.endpoint[p])
+            }
+        })
+        .collect()
+}
+
+/// Working state of the blossom algorithm.
+///
+/// Vertices are numbered `0..n`, blossoms `n..2n`. Edge endpoints are
+/// numbered `2k` and `2k + 1` for edge `k`, so `p ^ 1` is the other end.
+struct BlossomMatcher {
+    nvertex: usize,
+    max_cardinality: bool,
+    edges: Vec<(usize, usize, i128)>,
+    endpoint: Vec<usize>,
+    neighbend: Vec<Vec<usize>>,
+    mate: Vec<usize>,
+    label: Vec<u8>,
+    labelend: Vec<usize>,
+    inblossom: Vec<usize>,
+    blossomparent: Vec<usize>,
+    blossomchilds: Vec<Vec<usize>>,
+    blossombase: Vec<usize>,
+    blossomendps: Vec<Vec<usize>>,
+    bestedge: Vec<usize>,
+    blossombestedges: Vec<Option<Vec<usize>>>,
+    unusedblossoms: Vec<usize>,
+    dualvar: Vec<i128>,
+    allowedge: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+impl BlossomMatcher {
+    fn new(vertex_count: usize, edges: &[WeightedEdge], max_cardinality: bool) -> Self {
+        // Weights are doubled so that all dual variables stay integral
+        let edges: Vec<(usize, usize, i128)> =
+            edges.iter().map(|e| (e.u, e.v, e.weight * 2)).collect();
+
+        let nvertex = edges
+            .iter()
+            .fold(vertex_count, |n, &(u, v, _)| n.max(u + 1).max(v + 1));
+        let maxweight = edges.iter().map(|e| e.2).max().unwrap_or(0).max(0);
+
+        let endpoint: Vec<usize> = (0..2 * edges.len())
+            .map(|p| {
+                let (u, v, _) = edges[p / 2];
+                if p % 2 == 0 { u } else { v }
+            })
+            .collect();
+
+        let mut neighbend = vec![Vec::new(); nvertex];
+        for (k, &(u, v, _)) in edges.iter().enumerate() {
+            neighbend[u].push(2 * k + 1);
+            neighbend[v].push(2 * k);
+        }
+
+        let mut dualvar = vec![maxweight; nvertex];
+        dualvar.extend(std::iter::repeat_n(0, nvertex));
+
+        let mut blossombase: Vec<usize> = (0..nvertex).collect();
+        blossombase.extend(std::iter::repeat_n(NONE, nvertex));
+
+        let edge_count = edges.len();
+
+        Self {
+            nvertex,
+            max_cardinality,
+            edges,
+            endpoint,
+            neighbend,
+            mate: vec![NONE; nvertex],
+            label: vec![0; 2 * nvertex],
+            labelend: vec![NONE; 2 * nvertex],
+            inblossom: (0..nvertex).collect(),
+            blossomparent: vec![NONE; 2 * nvertex],
+            blossomchilds: vec![Vec::new(); 2 * nvertex],
+            blossombase,
+            blossomendps: vec![Vec::new(); 2 * nvertex],
+            bestedge: vec![NONE; 2 * nvertex],
+            blossombestedges: vec![None; 2 * nvertex],
+            unusedblossoms: (nvertex..2 * nvertex).collect(),
+            dualvar,
+            allowedge: vec![false; edge_count],
+            queue: Vec::new(),
+        }
+    }
+
+    fn slack(&self, k: usize) -> i128 {
+        let (i, j, wt) = self.edges[k];
+        self.dualvar[i] + self.dualvar[j] - 2 * wt
+    }
+
+    /// Collect all vertices contained in (sub-)blossom `b`
+    fn blossom_leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = Vec::new();
+        let mut stack = vec![b];
+        while let Some(t) = stack.pop() {
+            if t < self.nvertex {
+                leaves.push(t);
+            } else {
+                stack.extend(self.blossomchilds[t].iter().rev().copied());
+            }
+        }
+        leaves
+    }
+
+    /// Resolve a (possibly negative) child position inside a blossom
+    fn child_index(len: usize, j: isize) -> usize {
+        j.rem_euclid(len as isize) as usize
+    }
+
+    fn assign_label(&mut self, w: usize, t: u8, p: usize) {
+        let b = self.inblossom[w];
+        self.label[w] = t;
+        self.label[b] = t;
+        self.labelend[w] = p;
+        self.labelend[b] = p;
+        self.bestedge[w] = NONE;
+        self.bestedge[b] = NONE;
+        if t == 1 {
+            let leaves = self.blossom_leaves(b);
+            self.queue.extend(leaves);
+        } else if t == 2 {
+            let base = self.blossombase[b];
+            let mate_base = self.mate[base];
+            self.assign_label(self.endpoint[mate_base], 1, mate_base ^ 1);
+        }
+    }
+
+    /// Trace back from vertices `v` and `w` to discover either a new blossom
+    /// or an augmenting path. Returns the base of the blossom or `NONE`.
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = Vec::new();
+        let mut base = NONE;
+        while v != NONE || w != NONE {
+            let mut b = self.inblossom[v];
+            if self.label[b] & 4 != 0 {
+                base = self.blossombase[b];
+                break;
+            }
+            path.push(b);
+            self.label[b] = 5;
+            if self.labelend[b] == NONE {
+                v = NONE;
+            } else {
+                v = self.endpoint[self.labelend[b]];
+                b = self.inblossom[v];
+                v = self.endpoint[self.labelend[b]];
+            }
+            if w != NONE {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+        for b in path {
+            self.label[b] = 1;
+        }
+        base
+    }
+
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (mut v, mut w, _) = self.edges[k];
+        let bb = self.inblossom[base];
+        let mut bv = self.inblossom[v];
+        let mut bw = self.inblossom[w];
+
+        let b = self.unusedblossoms.pop().expect("blossom pool exhausted");
+        self.blossombase[b] = base;
+        self.blossomparent[b] = NONE;
+        self.blossomparent[bb] = b;
+
+        let mut path = Vec::new();
+        let mut endps = Vec::new();
+        while bv != bb {
+            self.blossomparent[bv] = b;
+            path.push(bv);
+            endps.push(self.labelend[bv]);
+            v = self.endpoint[self.labelend[bv]];
+            bv = self.inblossom[v];
+        }
+        path.push(bb);
+        path.reverse();
+        endps.reverse();
+        endps.push(2 * k);
+        while bw != bb {
+            self.blossomparent[bw] = b;
+            path.push(bw);
+            endps.push(self.labelend[bw] ^ 1);
+            w = self.endpoint[self.labelend[bw]];
+            bw = self.inblossom[w];
+        }
+
+        self.label[b] = 1;
+        self.labelend[b] = self.labelend[bb];
+        self.dualvar[b] = 0;
+        self.blossomchilds[b] = path.clone();
+        self.blossomendps[b] = endps;
+
+        for leaf in self.blossom_leaves(b) {
+            if self.label[self.inblossom[leaf]] == 2 {
+                self.queue.push(leaf);
+            }
+            self.inblossom[leaf] = b;
+        }
+
+        // Compute the least-slack edges to neighbouring S-blossoms
+        let mut bestedgeto = vec![NONE; 2 * self.nvertex];
+        for &sub in &path {
+            let nblists: Vec<Vec<usize>> = match self.blossombestedges[sub].take() {
+                Some(list) => vec![list],
+                None => self
+                    .blossom_leaves(sub)
+                    .into_iter()
+                    .map(|leaf| self.neighbend[leaf].iter().map(|p| p / 2).collect())
+                    .collect(),
+            };
+            for nblist in nblists {
+                for edge in nblist {
+                    let (i, j, _) = self.edges[edge];
+                    let j = if self.inblossom[j] == b { i } else { j };
+                    let bj = self.inblossom[j];
+                    if bj != b
+                        && self.label[bj] == 1
+                        && (bestedgeto[bj] == NONE || self.slack(edge) < self.slack(bestedgeto[bj]))
+                    {
+                        bestedgeto[bj] = edge;
+                    }
+                }
+            }
+            self.bestedge[sub] = NONE;
+        }
+
+        let best: Vec<usize> = bestedgeto.into_iter().filter(|&e| e != NONE).collect();
+        self.bestedge[b] = NONE;
+        for &edge in &best {
+            if self.bestedge[b] == NONE || self.slack(edge) < self.slack(self.bestedge[b]) {
+                self.bestedge[b] = edge;
+            }
+        }
+        self.blossombestedges[b] = Some(best);
+    }
+
+    fn expand_blossom(&mut self, b: usize, endstage: bool) {
+        let childs = self.blossomchilds[b].clone();
+        for &s in &childs {
+            self.blossomparent[s] = NONE;
+            if s < self.nvertex {
+                self.inblossom[s] = s;
+            } else if endstage && self.dualvar[s] == 0 {
+                self.expand_blossom(s, endstage);
+            } else {
+                for leaf in self.blossom_leaves(s) {
+                    self.inblossom[leaf] = s;
+                }
+            }
+        }
+
+        if !endstage && self.label[b] == 2 {
+            // Relabel the sub-blossoms along the even-length path from the
+            // entry child to the base
+            let len = childs.len();
+            let entrychild = self.inblossom[self.endpoint[self.labelend[b] ^ 1]];
+            let mut j = childs
+                .iter()
+                .position(|&c| c == entrychild)
+                .expect("entry child is part of the blossom") as isize;
+            let (jstep, endptrick): (isize, isize) = if j & 1 == 1 {
+                j -= len as isize;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+            let endps = self.blossomendps[b].clone();
+            let trick = endptrick as usize;
+
+            let mut p = self.labelend[b];
+            while j != 0 {
+                let q = endps[Self::child_index(len, j - endptrick)];
+                self.label[self.endpoint[p ^ 1]] = 0;
+                self.label[self.endpoint[q ^ trick ^ 1]] = 0;
+                self.assign_label(self.endpoint[p ^ 1], 2, p);
+                self.allowedge[q / 2] = true;
+                j += jstep;
+                p = endps[Self::child_index(len, j - endptrick)] ^ trick;
+                self.allowedge[p / 2] = true;
+                j += jstep;
+            }
+
+            let bv = childs[Self::child_index(len, j)];
+            let entry = self.endpoint[p ^ 1];
+            self.label[entry] = 2;
+            self.label[bv] = 2;
+            self.labelend[entry] = p;
+            self.labelend[bv] = p;
+            self.bestedge[bv] = NONE;
+
+            j += jstep;
+            while childs[Self::child_index(len, j)] != entrychild {
+                let bv = childs[Self::child_index(len, j)];
+                if self.label[bv] == 1 {
+                    j += jstep;
+                    continue;
+                }
+                let labelled = self
+                    .blossom_leaves(bv)
+                    .into_iter()
+                    .find(|&leaf| self.label[leaf] != 0);
+                if let Some(leaf) = labelled {
+                    self.label[leaf] = 0;
+                    let mate_base = self.mate[self.blossombase[bv]];
+                    self.label[self.endpoint[mate_base]] = 0;
+                    let end = self.labelend[leaf];
+                    self.assign_label(leaf, 2, end);
+                }
+                j += jstep;
+            }
+        }
+
+        self.label[b] = 0;
+        self.labelend[b] = NONE;
+        self.blossomchilds[b] = Vec::new();
+        self.blossomendps[b] = Vec::new();
+        self.blossombase[b] = NONE;
+        self.blossombestedges[b] = None;
+        self.bestedge[b] = NONE;
+        self.unusedblossoms.push(b);
+    }
+
+    /// Swap matched/unmatched edges over an alternating path through
+    /// blossom `b` between vertex `v` and the base vertex
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.blossomparent[t] != b {
+            t = self.blossomparent[t];
+        }
+        if t >= self.nvertex {
+            self.augment_blossom(t, v);
+        }
+
+        let len = self.blossomchilds[b].len();
+        let i = self.blossomchilds[b]
+            .iter()
+            .position(|&c| c == t)
+            .expect("child is part of the blossom");
+        let mut j = i as isize;
+        let (jstep, endptrick): (isize, isize) = if i & 1 == 1 {
+            j -= len as isize;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+        let trick = endptrick as usize;
+
+        while j != 0 {
+            j += jstep;
+            let t = self.blossomchilds[b][Self::child_index(len, j)];
+            let p = self.blossomendps[b][Self::child_index(len, j - endptrick)] ^ trick;
+            if t >= self.nvertex {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j += jstep;
+            let t = self.blossomchilds[b][Self::child_index(len, j)];
+            if t >= self.nvertex {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+
+        self.blossomchilds[b].rotate_left(i);
+        self.blossomendps[b].rotate_left(i);
+        self.blossombase[b] = self.blossombase[self.blossomchilds[b][0]];
+    }
+
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.inblossom[s];
+                if bs >= self.nvertex {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = p;
+                if self.labelend[bs] == NONE {
+                    break;
+                }
+                let t = self.endpoint[self.labelend[bs]];
+                let bt = self.inblossom[t];
+                s = self.endpoint[self.labelend[bt]];
+                let j = self.endpoint[self.labelend[bt] ^ 1];
+                if bt >= self.nvertex {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = self.labelend[bt];
+                p = self.labelend[bt] ^ 1;
+            }
+        }
+    }
+
+    fn solve(&mut self) {
+        let n = self.nvertex;
+
+        for _stage in 0..n {
+            self.label.iter_mut().for_each(|l| *l = 0);
+            self.bestedge.iter_mut().for_each(|e| *e = NONE);
+            for b in n..2 * n {
+                self.blossombestedges[b] = None;
+            }
+            self.allowedge.iter_mut().for_each(|a| *a = false);
+            self.queue.clear();
+
+            for v in 0..n {
+                if self.mate[v] == NONE && self.label[self.inblossom[v]] == 0 {
+                    self.assign_label(v, 1, NONE);
+                }
+            }
+
+            let mut augmented = false;
+            loop {
+                while let Some(v) = self.queue.pop() {
+                    let neighbours = self.neighbend[v].clone();
+                    for p in neighbours {
+                        let k = p / 2;
+                        let w = self.endpoint[p];
+                        if self.inblossom[v] == self.inblossom[w] {
+                            continue;
+                        }
+                        let mut kslack = 0;
+                        if !self.allowedge[k] {
+                            kslack = self.slack(k);
+                            if kslack <= 0 {
+                                self.allowedge[k] = true;
+                            }
+                        }
+                        if self.allowedge[k] {
+                            if self.label[self.inblossom[w]] == 0 {
+                                self.assign_label(w, 2, p ^ 1);
+                            } else if self.label[self.inblossom[w]] == 1 {
+                                let base = self.scan_blossom(v, w);
+                                if base != NONE {
+                                    self.add_blossom(base, k);
+                                } else {
+                                    self.augment_matching(k);
+                                    augmented = true;
+                                    break;
+                                }
+                            } else if self.label[w] == 0 {
+                                self.label[w] = 2;
+                                self.labelend[w] = p ^ 1;
+                            }
+                        } else if self.label[self.inblossom[w]] == 1 {
+                            let b = self.inblossom[v];
+                            if self.bestedge[b] == NONE || kslack < self.slack(self.bestedge[b]) {
+                                self.bestedge[b] = k;
+                            }
+                        } else if self.label[w] == 0
+                            && (self.bestedge[w] == NONE || kslack < self.slack(self.bestedge[w]))
+                        {
+                            self.bestedge[w] = k;
+                        }
+                    }
+                    if augmented {
+                        break;
+                    }
+                }
+                if augmented {
+                    break;
+                }
+
+                // No augmenting path found: adjust the dual variables
+                let mut deltatype = 0u8;
+                let mut delta: i128 = 0;
+                let mut deltaedge = NONE;
+                let mut deltablossom = NONE;
+
+                if !self.max_cardinality {
+                    deltatype = 1;
+                    delta = self.dualvar[..n].iter().copied().min().unwrap_or(0);
+                }
+
+                for v in 0..n {
+                    if self.label[self.inblossom[v]] == 0 && self.bestedge[v] != NONE {
+                        let d = self.slack(self.bestedge[v]);
+                        if deltatype == 0 || d < delta {
+                            delta = d;
+                            deltatype = 2;
+                            deltaedge = self.bestedge[v];
+                        }
+                    }
+                }
+
+                for b in 0..2 * n {
+                    if self.blossomparent[b] == NONE
+                        && self.label[b] == 1
+                        && self.bestedge[b] != NONE
+                    {
+                        let d = self.slack(self.bestedge[b]) / 2;
+                        if deltatype == 0 || d < delta {
+                            delta = d;
+                            deltatype = 3;
+                            deltaedge = self.bestedge[b];
+                        }
+                    }
+                }
+
+                for b in n..2 * n {
+                    if self.blossombase[b] != NONE
+                        && self.blossomparent[b] == NONE
+                        && self.label[b] == 2
+                        && (deltatype == 0 || self.dualvar[b] < delta)
+                    {
+                        delta = self.dualvar[b];
+                        deltatype = 4;
+                        deltablossom = b;
+                    }
+                }
+
+                if deltatype == 0 {
+                    // Maximum cardinality reached: do a final delta update to
+                    // make the optimum verifiable
+                    deltatype = 1;
+                    delta = self.dualvar[..n].iter().copied().min().unwrap_or(0).max(0);
+                }
+
+                for v in 0..n {
+                    match self.label[self.inblossom[v]] {
+                        1 => self.dualvar[v] -= delta,
+                        2 => self.dualvar[v] += delta,
+                        _ => {}
+                    }
+                }
+                for b in n..2 * n {
+                    if self.blossombase[b] != NONE && self.blossomparent[b] == NONE {
+                        match self.label[b] {
+                            1 => self.dualvar[b] += delta,
+                            2 => self.dualvar[b] -= delta,
+                            _ => {}
+                        }
+                    }
+                }
+
+                match deltatype {
+                    1 => break,
+                    2 => {
+                        self.allowedge[deltaedge] = true;
+                        let (mut i, j, _) = self.edges[deltaedge];
+                        if self.label[self.inblossom[i]] == 0 {
+                            i = j;
+                        }
+                        self.queue.push(i);
+                    }
+                    3 => {
+                        self.allowedge[deltaedge] = true;
+                        let (i, _, _) = self.edges[deltaedge];
+                        self.queue.push(i);
+                    }
+                    _ => self.expand_blossom(deltablossom, false),
+                }
+            }
+
+            if !augmented {
+                break;
+            }
+
+            // End of stage: expand all S-blossoms with zero dual
+            for b in n..2 * n {
+                if self.blossomparent[b] == NONE
+                    && self.blossombase[b] != NONE
+                    && self.label[b] == 1
+                    && self.dualvar[b] == 0
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng, rngs::StdRng};
+
+    fn edge(u: usize, v: usize, weight: i128) -> WeightedEdge {
+        WeightedEdge::new(u, v, weight)
+    }
+
+    fn matching_weight(edges: &[WeightedEdge], mates: &[Option<usize>]) -> i128 {
+        edges
+            .iter()
+            .filter(|e| mates[e.u] == Some(e.v))
+            .map(|e| e.weight)
+            .sum()
+    }
+
+    fn matching_size(mates: &[Option<usize>]) -> usize {
+        mates.iter().filter(|m| m.is_some()).count() / 2
+    }
+
+    /// Exhaustive search returning (best cardinality-first weight, best weight)
+    fn brute_force(edges: &[WeightedEdge], used: &mut [bool], start: usize) -> (usize, i128, i128) {
+        let mut best_weight = 0;
+        let mut best_card = (0usize, 0i128);
+        for (idx, e) in edges.iter().enumerate().skip(start) {
+            if used[e.u] || used[e.v] {
+                continue;
+            }
+            used[e.u] = true;
+            used[e.v] = true;
+            let (card, card_weight, weight) = brute_force(edges, used, idx + 1);
+            used[e.u] = false;
+            used[e.v] = false;
+            best_weight = best_weight.max(weight + e.weight);
+            let candidate = (card + 1, card_weight + e.weight);
+            if candidate > best_card {
+                best_card = candidate;
+            }
+        }
+        (best_card.0, best_card.1, best_weight)
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        assert_eq!(
+            max_weight_matching(0, &[], false),
+            Vec::<Option<usize>>::new()
+        );
+        assert_eq!(max_weight_matching(3, &[], false), vec![None, None, None]);
+    }
+
+    #[test]
+    fn test_single_edge() {
+        let mates = max_weight_matching(2, &[edge(0, 1, 1)], false);
+        assert_eq!(mates, vec![Some(1), Some(0)]);
+    }
+
+    #[test]
+    fn test_prefers_heavier_edges() {
+        let edges = [edge(1, 2, 10), edge(2, 3, 11)];
+        let mates = max_weight_matching(4, &edges, false);
+        assert_eq!(mates, vec![None, None, Some(3), Some(2)]);
+
+        let edges = [edge(1, 2, 5), edge(2, 3, 11), edge(3, 4, 5)];
+        let mates = max_weight_matching(5, &edges, false);
+        assert_eq!(mates[2], Some(3));
+        let mates = max_weight_matching(5, &edges, true);
+        assert_eq!(mates[1], Some(2));
+        assert_eq!(mates[3], Some(4));
+    }
+
+    #[test]
+    fn test_blossom_handling() {
+        // Odd cycle that forces a blossom to be created and expanded
+        let edges = [
+            edge(1, 2, 8),
+            edge(1, 3, 9),
+            edge(2, 3, 10),
+            edge(3, 4, 7),
+            edge(1, 6, 5),
+            edge(4, 5, 6),
+        ];
+        let mates = max_weight_matching(7, &edges, false);
+        assert_eq!(mates[1], Some(6));
+        assert_eq!(mates[2], Some(3));
+        assert_eq!(mates[4], Some(5));
+    }
+
+    #[test]
+    fn test_nested_blossom_expansion() {
+        let edges = [
+            edge(1, 2, 40),
+            edge(1, 3, 40),
+            edge(2, 3, 60),
+            edge(2, 4, 55),
+            edge(3, 5, 55),
+            edge(4, 5, 50),
+            edge(1, 8, 15),
+            edge(5, 7, 30),
+            edge(7, 6, 10),
+            edge(8, 10, 10),
+            edge(4, 9, 30),
+        ];
+        let mates = max_weight_matching(11, &edges, false);
+        assert_eq!(
+            mates,
+            vec![
+                None,
+                Some(2),
+                Some(1),
+                Some(5),
+                Some(9),
+                Some(3),
+                Some(7),
+                Some(6),
+                Some(10),
+                Some(4),
+                Some(8)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_matches_exhaustive_search() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..300 {
+            let n = rng.gen_range(2..9);
+            let mut edges = Vec::new();
+            for u in 0..n {
+                for v in (u + 1)..n {
+                    if rng.gen_bool(0.6) {
+                        edges.push(edge(u, v, rng.gen_range(1..30)));
+                    }
+                }
+            }
+
+            let (best_card, best_card_weight, best_weight) =
+                brute_force(&edges, &mut vec![false; n], 0);
+
+            let mates = max_weight_matching(n, &edges, false);
+            assert_eq!(matching_weight(&edges, &mates), best_weight, "{edges:?}");
+
+            let mates = max_weight_matching(n, &edges, true);
+            assert_eq!(matching_size(&mates), best_card, "{edges:?}");
+            assert_eq!(
+                matching_weight(&edges, &mates),
+                best_card_weight,
+                "{edges:?}"
+            );
+
+            for (v, mate) in mates.iter().enumerate() {
+                if let Some(m) = mate {
+                    assert_eq!(mates[*m], Some(v));
+                }
+            }
+        }
+    }
+}
@@ -105,6 +105,7 @@ export default function ExportDialog({
                 <MenuItem value="Csv">CSV</MenuItem>
                 <MenuItem value="Json">JSON</MenuItem>
                 <MenuItem value="Txt">Text</MenuItem>
+                <MenuItem value="Trf">FIDE Report (TRF)</MenuItem>
               </Select>
             </FormControl>
 
@@ -122,6 +123,9 @@ export default function ExportDialog({
                 <MenuItem value="TournamentSummary">
                   Tournament Summary
                 </MenuItem>
+                <MenuItem value="Pairings">Pairings</MenuItem>
+                <MenuItem value="Arena">Arena Standings</MenuItem>
+                <MenuItem value="RatingReport">Rating Report</MenuItem>
                 <MenuItem value="Complete">Complete Export</MenuItem>
               </Select>
             </FormControl>
@@ -205,12 +205,14 @@ const PairingsDisplay: React.FC<PairingsDisplayProps> = ({
                 {editedPairings.map((pairing, index) => (
                   <TableRow key={index} hover>
                     <TableCell align="center">
-                      <Chip
-                        label={pairing.board_number}
-                        color="primary"
-                        size="small"
-                        sx={{ fontWeight: 600 }}
-                      />
+                      <Tooltip title={pairing.explanation?.summary ?? ''}>
+                        <Chip
+                          label={pairing.board_number}
+                          color="primary"
+                          size="small"
+                          sx={{ fontWeight: 600 }}
+                        />
+                      </Tooltip>
                     </TableCell>
                     <TableCell>
                       {renderPlayerCell(pairing.white_player, true)}
@@ -127,6 +127,11 @@ const RoundManager: React.FC<RoundManagerProps> = ({
           ? Math.max(...rounds.map(r => r.round_number)) + 1
           : 1;
 
+      await commands.updateTournamentPairingMethod({
+        tournament_id: tournamentId,
+        pairing_method: pairingMethod,
+      });
+
       await commands.createRound({
         tournament_id: tournamentId,
         round_number: nextRoundNumber,
@@ -692,6 +697,10 @@ const RoundManager: React.FC<RoundManagerProps> = ({
               onChange={e => setPairingMethod(e.target.value)}
             >
               <MenuItem value="swiss">{t('rounds.swissSystem')}</MenuItem>
+              <MenuItem value="burstein">{t('rounds.bursteinSystem')}</MenuItem>
+              <MenuItem value="dubov">{t('rounds.dubovSystem')}</MenuItem>
+              <MenuItem value="lim">{t('rounds.limSystem')}</MenuItem>
+              <MenuItem value="monrad">{t('rounds.monradSystem')}</MenuItem>
               <MenuItem value="round_robin">{t('rounds.roundRobin')}</MenuItem>
               <MenuItem value="manual">{t('rounds.manual')}</MenuItem>
             </Select>
@@ -87,6 +87,7 @@ const TiebreakBreakdownDialog: React.FC<TiebreakBreakdownDialogProps> = ({
   const getTiebreakIcon = (tiebreakType: string) => {
     switch (tiebreakType) {
       case 'tournament_performance_rating':
+      case 'perfect_tournament_performance':
         return <StarsOutlined />;
       case 'average_rating_of_opponents':
         return <PersonOutline />;
@@ -69,6 +69,7 @@ const TiebreakConfig: React.FC<TiebreakConfigProps> = ({
     'direct_encounter',
     'average_rating_of_opponents',
     'tournament_performance_rating',
+    'perfect_tournament_performance',
     'number_of_wins',
     'number_of_games_with_black',
     'number_of_wins_with_black',
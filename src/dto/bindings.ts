@@ -3,12 +3,46 @@
 
 /** user-defined commands **/
 
+export type AcceleratedPlayerDto = {
+  player_id: number;
+  player_name: string;
+  group: string;
+  score: number;
+  virtual_points: number;
+  pairing_score: number;
+};
+/**
+ * Baku acceleration groups and virtual points used to pair one round
+ */
+export type AcceleratedRoundDto = {
+  round_number: number;
+  group_a_virtual_points: number;
+  players: AcceleratedPlayerDto[];
+};
+/**
+ * Baku acceleration (FIDE C.04.5) configuration of a Swiss tournament
+ */
+export type AccelerationSettings = {
+  tournament_id: number;
+  enabled: boolean;
+  accelerated_rounds: number;
+  group_boundary: string;
+  group_a_min_rating: number | null;
+  virtual_points_first_half: number;
+  virtual_points_second_half: number;
+  updated_at: string | null;
+};
 export type AddPlayerToTeam = {
   team_id: number;
   player_id: number;
   board_number: number;
   is_captain: boolean;
 };
+export type AdvanceTournamentStage = {
+  stage: TournamentStage;
+  tournament: Tournament;
+  qualifiers: StageQualifier[];
+};
 export type AgeGroupPrize = {
   age_group: string;
   percentage: number;
@@ -40,6 +74,52 @@ export type ApproveGameResult = {
   approved_by: string;
   notes: string | null;
 };
+/**
+ * Game of an arena tournament, paired from the waiting pool
+ */
+export type ArenaGame = {
+  id: number;
+  tournament_id: number;
+  white_player_id: number;
+  black_player_id: number;
+  result: string;
+  white_berserk: boolean;
+  black_berserk: boolean;
+  started_at: string;
+  finished_at: string | null;
+};
+export type ArenaResultOutcome = { game: ArenaGame; new_games: ArenaGame[] };
+/**
+ * Arena configuration of a tournament
+ */
+export type ArenaSettings = {
+  tournament_id: number;
+  ends_at: string;
+  berserk_allowed: boolean;
+  updated_at: string | null;
+};
+export type ArenaStanding = {
+  rank: number;
+  player: Player;
+  score: number;
+  games_played: number;
+  wins: number;
+  draws: number;
+  losses: number;
+  berserks: number;
+  on_fire: boolean;
+  performance_rating: number | null;
+  sheet: number[];
+};
+/**
+ * Player waiting in the arena pool for the next game
+ */
+export type ArenaWaitingPlayer = {
+  tournament_id: number;
+  player_id: number;
+  waiting_since: string;
+};
+export type ArmageddonBid = { player_id: number; minutes: number };
 export type AssignPlayerToCategory = { player_id: number; category_id: number };
 export type BatchUpdatePlayerSeeding = {
   tournament_id: number;
@@ -59,9 +139,20 @@ export type BergerTableInfoDto = {
   rotation_pattern: string;
   bye_player_position: number | null;
 };
+export type BoardChangeDto = {
+  board_number: number;
+  before: string | null;
+  after: string | null;
+};
+export type BoardPairingExplanation = {
+  game_id: number;
+  board_number: number;
+  explanation: PairingExplanation;
+};
 export type BracketPosition = {
   id: number;
   bracket_id: number;
+  bracket_side: string;
   round_number: number;
   position_number: number;
   player_id: number | null;
@@ -91,6 +182,26 @@ export type BulkImportResult = {
   validations: PlayerImportValidation[];
   imported_player_ids: number[];
 };
+/**
+ * Bye a player requested for a future round, scored against the BYE player
+ */
+export type ByeRequest = {
+  id: number;
+  tournament_id: number;
+  player_id: number;
+  round_number: number;
+  bye_points: number;
+  created_at: string;
+};
+export type ByeSettings = {
+  tournament_id: number;
+  max_requested_byes: number;
+  updated_at: string | null;
+};
+export type CheckPairingsRequest = {
+  tournament_id: number | null;
+  trf_content: string | null;
+};
 export type ColorBalanceAnalysisDto = {
   players_with_color_imbalance: number;
   average_color_balance: number;
@@ -103,6 +214,33 @@ export type ColorConstraintDto = {
   priority: string;
 };
 export type ColorScheme = 'Default' | 'Professional' | 'Minimal' | 'Classic';
+export type CreateByeRequest = {
+  tournament_id: number;
+  player_id: number;
+  round_numbers: number[];
+  bye_points: number;
+};
+export type CreateEvent = {
+  name: string;
+  location: string;
+  date: string;
+  country_code: string;
+};
+export type CreateEventSection = {
+  event_id: number;
+  tournament_id: number;
+  name: string;
+  min_rating: number | null;
+  max_rating: number | null;
+  allow_unrated: boolean;
+};
+export type CreateForbiddenPairing = {
+  tournament_id: number;
+  player_ids: number[];
+  reason: string;
+  first_round: number | null;
+  last_round: number | null;
+};
 export type CreateGame = {
   tournament_id: number;
   round_number: number;
@@ -114,6 +252,33 @@ export type CreateKnockoutBracket = {
   tournament_id: number;
   bracket_type: string;
 };
+export type CreatePairingDraft = {
+  round_id: number;
+  name: string | null;
+  pairing_method: string;
+  avoid_same_club: boolean;
+};
+export type CreatePerson = {
+  name: string;
+  fide_id: string | null;
+  national_id: string | null;
+  federation: string | null;
+  birth_date: string | null;
+  gender: string | null;
+  title: string | null;
+  fide_rating: number | null;
+  national_rating: number | null;
+  rapid_rating: number | null;
+  blitz_rating: number | null;
+};
+export type CreatePersonRating = {
+  person_id: number;
+  rating_type: string;
+  rating: number;
+  is_provisional: boolean;
+  effective_date: string;
+  tournament_id: number | null;
+};
 export type CreatePlayer = {
   tournament_id: number;
   name: string;
@@ -223,6 +388,16 @@ export type CreateTournamentSeedingSettings = {
   randomize_unrated: boolean;
   protect_top_seeds: number;
 };
+export type CreateTournamentStage = {
+  tournament_id: number;
+  name: string;
+  tournament_type: string;
+  rounds: number | null;
+  qualification: string;
+  qualifiers: number;
+  seeding: string;
+  group_tournament_ids: number[];
+};
 export type CreateUserPreference = {
   user_id: string | null;
   category: string;
@@ -277,7 +452,58 @@ export type EnhancedPairingResult = {
   performance_metrics: PairingPerformanceMetrics | null;
   warnings: string[];
 };
-export type ExportFormat = 'Csv' | 'Pdf' | 'Html' | 'Json' | 'Xlsx' | 'Txt';
+/**
+ * Event grouping several section tournaments with one registration desk
+ */
+export type Event = {
+  id: number;
+  name: string;
+  location: string;
+  date: string;
+  country_code: string;
+  created_at: string;
+};
+export type EventEntry = {
+  registration: EventRegistration;
+  section_name: string;
+  player: Player;
+};
+/**
+ * Standings of every section of an event in one file
+ */
+export type EventExportRequest = {
+  event_id: number;
+  format: ExportFormat;
+  custom_filename: string | null;
+};
+/**
+ * Entry of a player registered at the event desk
+ */
+export type EventRegistration = {
+  id: number;
+  event_id: number;
+  section_id: number;
+  player_id: number;
+  created_at: string;
+};
+export type EventSection = {
+  id: number;
+  event_id: number;
+  tournament_id: number;
+  name: string;
+  min_rating: number | null;
+  max_rating: number | null;
+  allow_unrated: boolean;
+  created_at: string;
+};
+export type ExportFormat =
+  | 'Csv'
+  | 'Pdf'
+  | 'Html'
+  | 'Json'
+  | 'Xlsx'
+  | 'Txt'
+  | 'Trf';
 export type ExportRequest = {
   tournament_id: number;
   export_type: ExportType;
@@ -312,6 +538,9 @@ export type ExportType =
   | 'GameResults'
   | 'PlayerList'
   | 'TournamentSummary'
+  | 'Pairings'
+  | 'Arena'
+  | 'RatingReport'
   | 'Complete';
 /**
  * Extended team standing for commands
@@ -328,6 +557,45 @@ export type ExtendedTeamStandingDto = {
   players: Player[];
   tiebreak_scores: Partial<{ [key in string]: number }>;
 };
+export type FideRating = {
+  list_id: number;
+  fide_id: number;
+  name: string;
+  federation: string | null;
+  sex: string | null;
+  title: string | null;
+  birth_year: number | null;
+  standard_rating: number | null;
+  rapid_rating: number | null;
+  blitz_rating: number | null;
+  flag: string | null;
+};
+export type FideRatingChange = { previous: FideRating; current: FideRating };
+/**
+ * FIDE rating list loaded for a list date
+ */
+export type FideRatingList = {
+  id: number;
+  list_date: string;
+  source_file: string;
+  player_count: number;
+  imported_at: string;
+};
+export type FideRatingListImport = {
+  list: FideRatingList;
+  players_imported: number;
+  players_added: number;
+  players_changed: number;
+  players_skipped: number;
+  changes: FideRatingChange[];
+  warnings: string[];
+};
+export type FideRatingRefresh = {
+  list: FideRatingList;
+  updated: PlayerRatingUpdate[];
+  unchanged: number;
+  unmatched: Player[];
+};
 export type FloatStatisticsDto = {
   total_floats: number;
   up_floats: number;
@@ -335,6 +603,20 @@ export type FloatStatisticsDto = {
   float_percentage: number;
 };
 export type FontSize = 'Small' | 'Medium' | 'Large';
+/**
+ * Players of a tournament who must not be paired against each other, optionally
+ * only within a range of rounds. The Swiss engines pair around them; round-robin,
+ * Scheveningen and manual pairings that break one are rejected.
+ */
+export type ForbiddenPairing = {
+  id: number;
+  tournament_id: number;
+  player_ids: number[];
+  reason: string;
+  first_round: number | null;
+  last_round: number | null;
+  created_at: string;
+};
 export type ForbiddenPairingDto = {
   player1_id: number;
   player2_id: number;
@@ -400,19 +682,71 @@ export type GenerateSeedingRequest = {
   preserve_manual_seeds: boolean;
   category_id: number | null;
 };
+export type ImportFideRatingListRequest = {
+  file_path: string;
+  list_date: string;
+};
+export type ImportTrfRequest = { content: string; validate_only: boolean };
 export type KnockoutBracket = {
   id: number;
   tournament_id: number;
   bracket_type: string;
   total_rounds: number;
+  bracket_reset: boolean;
+  created_at: string;
+};
+/**
+ * Mini-match format of a knockout round: the main games, then tiebreak
+ * stages while the match is tied, and finally an Armageddon game
+ */
+export type KnockoutMatchFormat = {
+  id: number;
+  bracket_id: number;
+  bracket_side: string;
+  round_number: number;
+  games: number;
+  time_control_id: number | null;
+  tiebreak_stages: TiebreakStage[];
+  armageddon_time_control_id: number | null;
+  armageddon_color: string;
   created_at: string;
 };
+/**
+ * Game of a knockout mini-match
+ */
+export type KnockoutMatchGame = {
+  id: number;
+  bracket_id: number;
+  bracket_side: string;
+  round_number: number;
+  match_number: number;
+  stage: number;
+  game_number: number;
+  white_player_id: number;
+  black_player_id: number;
+  result: string;
+  time_control_id: number | null;
+  black_time_minutes: number | null;
+  created_at: string;
+};
+export type KnockoutMatchStatus = {
+  match_number: number;
+  player1_id: number;
+  player2_id: number;
+  player1_score: number;
+  player2_score: number;
+  stage: number;
+  awaiting_armageddon: boolean;
+  winner_id: number | null;
+};
+export type KnockoutPlacing = { place: number; player_id: number };
 export type ManualPairingOverrides = {
   forced_pairings: ForcedPairingDto[];
   forbidden_pairings: ForbiddenPairingDto[];
   color_constraints: ColorConstraintDto[];
   bye_assignments: number[];
 };
+export type MoveEventPlayer = { registration_id: number; section_id: number };
 export type NormCalculationRequest = {
   tournament_id: number;
   player_id: number;
@@ -466,6 +800,50 @@ export type Pairing = {
   white_player: Player;
   black_player: Player | null;
   board_number: number;
+  explanation: PairingExplanation | null;
+};
+export type PairingCheckReport = {
+  tournament_name: string;
+  rounds_checked: number;
+  rounds_matching: number;
+  rounds: RoundPairingCheck[];
+};
+export type PairingDifferenceDto = {
+  board_number: number;
+  stored_pairing: string;
+  engine_pairing: string;
+  criterion: string;
+  explanation: string;
+  affected_players: number[];
+};
+/**
+ * Candidate pairings of a round that is being paired
+ */
+export type PairingDraft = {
+  id: number;
+  round_id: number;
+  name: string;
+  pairing_method: string;
+  avoid_same_club: boolean;
+  pairings: Pairing[];
+  created_at: string;
+};
+export type PairingDraftComparison = {
+  first: PairingDraftSummary;
+  second: PairingDraftSummary;
+  changed_boards: BoardChangeDto[];
+};
+export type PairingDraftMetrics = {
+  color_violations: number;
+  average_rating_difference: number;
+  max_rating_difference: number;
+  floats: number;
+  rematches: number;
+  byes: number;
+};
+export type PairingDraftSummary = {
+  draft: PairingDraft;
+  metrics: PairingDraftMetrics;
 };
 export type PairingErrorDto = {
   error_type: string;
@@ -473,6 +851,17 @@ export type PairingErrorDto = {
   affected_players: number[];
   severity: string;
 };
+/**
+ * Why a board was paired as it was, for answering players' questions
+ */
+export type PairingExplanation = {
+  score_group: number;
+  floater_id: number | null;
+  float_direction: string | null;
+  color_rule: string;
+  relaxed_criteria: string[];
+  summary: string;
+};
 export type PairingOptimizationConfig = {
   max_players_for_basic_algorithm: number | null;
   use_parallel_processing: boolean | null;
@@ -517,6 +906,42 @@ export type PairingWarningDto = {
   affected_players: number[];
 };
 export type PaperSize = 'A4' | 'A5' | 'Letter' | 'Legal';
+/**
+ * Person in the player registry, linked to the players they have been in each tournament
+ */
+export type Person = {
+  id: number;
+  name: string;
+  fide_id: string | null;
+  national_id: string | null;
+  federation: string | null;
+  birth_date: string | null;
+  gender: string | null;
+  title: string | null;
+  fide_rating: number | null;
+  national_rating: number | null;
+  rapid_rating: number | null;
+  blitz_rating: number | null;
+  created_at: string;
+  updated_at: string | null;
+};
+export type PersonRating = {
+  id: number;
+  person_id: number;
+  rating_type: string;
+  rating: number;
+  is_provisional: boolean;
+  effective_date: string;
+  tournament_id: number | null;
+  created_at: string;
+};
+export type PersonTournament = {
+  tournament: Tournament;
+  player: Player;
+  rank: number | null;
+  points: number;
+  games_played: number;
+};
 export type Player = {
   id: number;
   tournament_id: number;
@@ -536,6 +961,11 @@ export type Player = {
   created_at: string;
   updated_at: string | null;
 };
+export type PlayerAroProgressionDto = {
+  player_id: number;
+  player_name: string;
+  aro_by_round: number[];
+};
 export type PlayerCategory = {
   id: number;
   tournament_id: number;
@@ -561,12 +991,41 @@ export type PlayerColorStatsDto = {
   black_games: number;
   color_balance: number;
 };
+export type PlayerFloatRecordDto = {
+  player_id: number;
+  player_name: string;
+  upfloat_rounds: number[];
+  downfloat_rounds: number[];
+  bye_rounds: number[];
+  unplayed_rounds: number[];
+  can_receive_bye: boolean;
+};
 export type PlayerImportValidation = {
   is_valid: boolean;
   errors: string[];
   warnings: string[];
   player_data: BulkImportPlayer;
 };
+export type PlayerRatingReport = {
+  player: Player;
+  k_factor: number | null;
+  k_factor_reason: string | null;
+  games: RatedGame[];
+  games_counted: number;
+  score: number;
+  expected_score: number | null;
+  rating_change: number | null;
+  new_rating: number | null;
+  initial_rating: boolean;
+  note: string | null;
+};
+export type PlayerRatingUpdate = {
+  player_id: number;
+  name: string;
+  fide_id: number;
+  old_rating: number | null;
+  new_rating: number;
+};
 export type PlayerResult = {
   player: Player;
   points: number;
@@ -647,6 +1106,23 @@ export type PrizeStructure = {
   age_group_prizes: AgeGroupPrize[];
   rating_group_prizes: RatingGroupPrize[];
 };
+/**
+ * Game of a player as seen by the FIDE rating calculation
+ */
+export type RatedGame = {
+  round_number: number;
+  opponent_id: number;
+  opponent_name: string;
+  opponent_rating: number | null;
+  color: string;
+  result: string;
+  score: number | null;
+  rating_difference: number | null;
+  expected_score: number | null;
+  rating_change: number | null;
+  counted: boolean;
+  note: string | null;
+};
 export type RatingDistributionDto = {
   average_rating_difference: number;
   max_rating_difference: number;
@@ -667,7 +1143,56 @@ export type RatingHistory = {
   effective_date: string;
   created_at: string;
 };
+export type RatingReport = {
+  tournament_id: number;
+  rating_type: string;
+  players: PlayerRatingReport[];
+};
+export type RecordArenaResult = {
+  game_id: number;
+  result: string;
+  white_berserk: boolean;
+  black_berserk: boolean;
+};
+export type RegisterEventPlayer = {
+  event_id: number;
+  section_id: number;
+  name: string;
+  rating: number | null;
+  country_code: string | null;
+  title: string | null;
+  birth_date: string | null;
+  gender: string | null;
+  email: string | null;
+  phone: string | null;
+  club: string | null;
+};
+export type RegisterFidePlayer = {
+  tournament_id: number;
+  fide_id: number;
+  list_date: string | null;
+};
+export type RegisterPersonPlayer = {
+  person_id: number;
+  tournament_id: number;
+  rating_type: string | null;
+};
 export type RemovePlayerFromTeam = { team_id: number; player_id: number };
+export type RepairRoundRequest = {
+  round_id: number;
+  pairing_method: string;
+  locked_boards: number[];
+  removed_player_ids: number[];
+  added_player_ids: number[];
+  reason: string | null;
+  apply: boolean;
+};
+export type RepairRoundResult = {
+  before: Pairing[];
+  after: Pairing[];
+  changed_boards: BoardChangeDto[];
+  audit: RoundAudit | null;
+};
 export type RestoreSettingsBackup = {
   backup_id: number;
   user_id: string | null;
@@ -682,10 +1207,31 @@ export type Round = {
   created_at: string;
   completed_at: string | null;
 };
+/**
+ * Change made to a round after its pairings were published
+ */
+export type RoundAudit = {
+  id: number;
+  round_id: number;
+  action: string;
+  details: string;
+  reason: string | null;
+  changed_at: string;
+};
 export type RoundDetails = {
   round: Round;
   games: GameResult[];
   status: RoundStatus;
+  pairing_explanations: BoardPairingExplanation[];
+  audit_trail: RoundAudit[];
+};
+export type RoundPairingCheck = {
+  round_number: number;
+  matches_engine: boolean;
+  stored_pairings: Pairing[];
+  engine_pairings: Pairing[];
+  differences: PairingDifferenceDto[];
+  validation: PairingValidationResults;
 };
 export type RoundRobinAnalysis = {
   total_rounds_needed: number;
@@ -739,6 +1285,16 @@ export type SeedingPreview = {
   title: string | null;
   category: string | null;
 };
+export type SetKnockoutMatchFormat = {
+  bracket_id: number;
+  bracket_side: string | null;
+  round_number: number;
+  games: number;
+  time_control_id: number | null;
+  tiebreak_stages: TiebreakStage[];
+  armageddon_time_control_id: number | null;
+  armageddon_color: string;
+};
 export type SettingsAuditSummary = {
   category: string;
   setting_key: string;
@@ -876,16 +1432,34 @@ export type SpecialPrizeType =
   | 'FairPlay'
   | 'BestGame'
   | { Custom: string };
+export type StageQualifier = {
+  seed: number;
+  player: Player;
+  group_number: number;
+  group_rank: number;
+  points: number;
+};
 export type StandingsCalculationResult = {
   standings: PlayerStanding[];
   last_updated: string;
   tiebreak_config: TournamentTiebreakConfig;
 };
+export type StartArmageddon = {
+  bracket_id: number;
+  bracket_side: string | null;
+  round_number: number;
+  match_number: number;
+  white_player_id: number | null;
+  bids: ArmageddonBid[];
+};
 export type SwissPairingAnalysis = {
   score_groups: ScoreGroupDto[];
   float_statistics: FloatStatisticsDto;
   color_balance_analysis: ColorBalanceAnalysisDto;
   rating_distribution: RatingDistributionDto;
+  player_float_records: PlayerFloatRecordDto[];
+  aro_progression: PlayerAroProgressionDto[];
+  acceleration: AcceleratedRoundDto[];
 };
 export type SwissPairingOptions = {
   use_accelerated_pairings: boolean;
@@ -1073,6 +1647,7 @@ export type TiebreakScore = {
   value: number;
   display_value: string;
 };
+export type TiebreakStage = { games: number; time_control_id: number | null };
 export type TiebreakType =
   | 'buchholz_full'
   | 'buchholz_cut_1'
@@ -1084,6 +1659,7 @@ export type TiebreakType =
   | 'direct_encounter'
   | 'average_rating_of_opponents'
   | 'tournament_performance_rating'
+  | 'perfect_tournament_performance'
   | 'number_of_wins'
   | 'number_of_games_with_black'
   | 'number_of_wins_with_black'
@@ -1166,6 +1742,32 @@ export type TournamentSeedingSettings = {
   created_at: string;
   updated_at: string | null;
 };
+/**
+ * Stage of a multi-stage event, e.g. a Swiss qualifier followed by a knockout.
+ * The qualification picks who leaves the stage, the seeding orders who enters it.
+ */
+export type TournamentStage = {
+  id: number;
+  tournament_id: number;
+  stage_number: number;
+  name: string;
+  tournament_type: string;
+  rounds: number | null;
+  qualification: string;
+  qualifiers: number;
+  seeding: string;
+  created_at: string;
+};
+/**
+ * Tournament played as one group of a stage
+ */
+export type TournamentStageGroup = {
+  id: number;
+  stage_id: number;
+  group_number: number;
+  group_tournament_id: number;
+  created_at: string;
+};
 export type TournamentTiebreakConfig = {
   tournament_id: number;
   tiebreaks: TiebreakType[];
@@ -1181,10 +1783,22 @@ export type TournamentTiebreakConfig = {
   organizer_name: string | null;
   organizer_email: string | null;
   prize_structure: string | null;
+  chief_arbiter: string | null;
+  deputy_arbiters: string | null;
 };
 /**
  * Global error object returned by all commands
  */
+export type TrfImportIssue = { line_number: number | null; message: string };
+export type TrfImportResult = {
+  success: boolean;
+  tournament: Tournament | null;
+  players_imported: number;
+  rounds_imported: number;
+  games_imported: number;
+  errors: TrfImportIssue[];
+  warnings: string[];
+};
 export type TxError = {
   /**
    * short message to be displayed in the toast
@@ -1195,6 +1809,24 @@ export type TxError = {
    */
   details: string;
 };
+export type UpdateAccelerationSettings = {
+  tournament_id: number;
+  enabled: boolean;
+  accelerated_rounds: number;
+  group_boundary: string;
+  group_a_min_rating: number | null;
+  virtual_points_first_half: number;
+  virtual_points_second_half: number;
+};
+export type UpdateArenaSettings = {
+  tournament_id: number;
+  ends_at: string;
+  berserk_allowed: boolean;
+};
+export type UpdateByeSettings = {
+  tournament_id: number;
+  max_requested_byes: number;
+};
 export type UpdateGameResult = {
   game_id: number;
   result: string;
@@ -1203,6 +1835,16 @@ export type UpdateGameResult = {
   arbiter_notes: string | null;
   changed_by: string | null;
 };
+export type UpdatePerson = {
+  person_id: number;
+  name: string | null;
+  fide_id: string | null;
+  national_id: string | null;
+  federation: string | null;
+  birth_date: string | null;
+  gender: string | null;
+  title: string | null;
+};
 export type UpdatePlayer = {
   player_id: number;
   name: string | null;
@@ -1301,6 +1943,8 @@ export type UpdateTournamentSettings = {
   organizer_name: string | null;
   organizer_email: string | null;
   prize_structure: string | null;
+  chief_arbiter: string | null;
+  deputy_arbiters: string | null;
 };
 export type UpdateTournamentStatus = { tournament_id: number; status: string };
 export type UserPreference = {
@@ -1480,6 +2124,9 @@ export const commands = {
       settings,
     });
   },
+  async importTournamentTrf(data: ImportTrfRequest): Promise<TrfImportResult> {
+    return await TAURI_INVOKE('plugin:pawn|import_tournament_trf', { data });
+  },
   async updateTournamentStatus(
     data: UpdateTournamentStatus
   ): Promise<Tournament> {
@@ -1571,6 +2218,70 @@ export const commands = {
       pairings,
     });
   },
+  async checkTournamentPairings(
+    data: CheckPairingsRequest
+  ): Promise<PairingCheckReport> {
+    return await TAURI_INVOKE('plugin:pawn|check_tournament_pairings', { data });
+  },
+  async repairRoundPairings(
+    data: RepairRoundRequest
+  ): Promise<RepairRoundResult> {
+    return await TAURI_INVOKE('plugin:pawn|repair_round_pairings', { data });
+  },
+  async createPairingDraft(
+    data: CreatePairingDraft
+  ): Promise<PairingDraftSummary> {
+    return await TAURI_INVOKE('plugin:pawn|create_pairing_draft', { data });
+  },
+  async getPairingDrafts(roundId: number): Promise<PairingDraftSummary[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_pairing_drafts', { roundId });
+  },
+  async comparePairingDrafts(
+    firstDraftId: number,
+    secondDraftId: number
+  ): Promise<PairingDraftComparison> {
+    return await TAURI_INVOKE('plugin:pawn|compare_pairing_drafts', {
+      firstDraftId,
+      secondDraftId,
+    });
+  },
+  async publishPairingDraft(draftId: number): Promise<GameResult[]> {
+    return await TAURI_INVOKE('plugin:pawn|publish_pairing_draft', { draftId });
+  },
+  async deletePairingDraft(draftId: number): Promise<null> {
+    return await TAURI_INVOKE('plugin:pawn|delete_pairing_draft', { draftId });
+  },
+  async getAccelerationSettings(
+    tournamentId: number
+  ): Promise<AccelerationSettings> {
+    return await TAURI_INVOKE('plugin:pawn|get_acceleration_settings', {
+      tournamentId,
+    });
+  },
+  async updateAccelerationSettings(
+    data: UpdateAccelerationSettings
+  ): Promise<AccelerationSettings> {
+    return await TAURI_INVOKE('plugin:pawn|update_acceleration_settings', {
+      data,
+    });
+  },
+  async getForbiddenPairings(
+    tournamentId: number
+  ): Promise<ForbiddenPairing[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_forbidden_pairings', {
+      tournamentId,
+    });
+  },
+  async createForbiddenPairing(
+    data: CreateForbiddenPairing
+  ): Promise<ForbiddenPairing> {
+    return await TAURI_INVOKE('plugin:pawn|create_forbidden_pairing', { data });
+  },
+  async deleteForbiddenPairing(pairingId: number): Promise<null> {
+    return await TAURI_INVOKE('plugin:pawn|delete_forbidden_pairing', {
+      pairingId,
+    });
+  },
   async benchmarkPairingPerformance(
     playerCounts: number[]
   ): Promise<PairingPerformanceMetrics[]> {
@@ -1703,6 +2414,21 @@ export const commands = {
   async requestPlayerBye(playerId: number): Promise<Player> {
     return await TAURI_INVOKE('plugin:pawn|request_player_bye', { playerId });
   },
+  async requestByes(data: CreateByeRequest): Promise<ByeRequest[]> {
+    return await TAURI_INVOKE('plugin:pawn|request_byes', { data });
+  },
+  async cancelByeRequest(requestId: number): Promise<null> {
+    return await TAURI_INVOKE('plugin:pawn|cancel_bye_request', { requestId });
+  },
+  async getByeRequests(tournamentId: number): Promise<ByeRequest[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_bye_requests', { tournamentId });
+  },
+  async getByeSettings(tournamentId: number): Promise<ByeSettings> {
+    return await TAURI_INVOKE('plugin:pawn|get_bye_settings', { tournamentId });
+  },
+  async updateByeSettings(data: UpdateByeSettings): Promise<ByeSettings> {
+    return await TAURI_INVOKE('plugin:pawn|update_bye_settings', { data });
+  },
   async getPlayerStatistics(tournamentId: number): Promise<PlayerStatistics> {
     return await TAURI_INVOKE('plugin:pawn|get_player_statistics', {
       tournamentId,
@@ -1722,11 +2448,13 @@ export const commands = {
   },
   async initializeKnockoutTournament(
     tournamentId: number,
-    bracketType: string
+    bracketType: string,
+    bracketReset: boolean | null
   ): Promise<KnockoutBracket> {
     return await TAURI_INVOKE('plugin:pawn|initialize_knockout_tournament', {
       tournamentId,
       bracketType,
+      bracketReset,
     });
   },
   async getBracketPositions(bracketId: number): Promise<BracketPosition[]> {
@@ -1745,22 +2473,26 @@ export const commands = {
   },
   async generateKnockoutPairings(
     bracketId: number,
-    roundNumber: number
+    roundNumber: number,
+    bracketSide: string | null
   ): Promise<Pairing[]> {
     return await TAURI_INVOKE('plugin:pawn|generate_knockout_pairings', {
       bracketId,
       roundNumber,
+      bracketSide,
     });
   },
   async advanceKnockoutWinners(
     bracketId: number,
     roundNumber: number,
-    winnerResults: [number, number][]
+    winnerResults: [number, number][],
+    bracketSide: string | null
   ): Promise<BracketPosition[]> {
     return await TAURI_INVOKE('plugin:pawn|advance_knockout_winners', {
       bracketId,
       roundNumber,
       winnerResults,
+      bracketSide,
     });
   },
   async getKnockoutTournamentWinner(bracketId: number): Promise<number | null> {
@@ -1768,6 +2500,11 @@ export const commands = {
       bracketId,
     });
   },
+  async getKnockoutPlacings(bracketId: number): Promise<KnockoutPlacing[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_knockout_placings', {
+      bracketId,
+    });
+  },
   async isKnockoutTournamentComplete(bracketId: number): Promise<boolean> {
     return await TAURI_INVOKE('plugin:pawn|is_knockout_tournament_complete', {
       bracketId,
@@ -1778,6 +2515,255 @@ export const commands = {
       bracketId,
     });
   },
+  async setKnockoutMatchFormat(
+    data: SetKnockoutMatchFormat
+  ): Promise<KnockoutMatchFormat> {
+    return await TAURI_INVOKE('plugin:pawn|set_knockout_match_format', {
+      data,
+    });
+  },
+  async getKnockoutMatchFormats(
+    bracketId: number
+  ): Promise<KnockoutMatchFormat[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_knockout_match_formats', {
+      bracketId,
+    });
+  },
+  async startKnockoutMatches(
+    bracketId: number,
+    roundNumber: number,
+    bracketSide: string | null
+  ): Promise<KnockoutMatchGame[]> {
+    return await TAURI_INVOKE('plugin:pawn|start_knockout_matches', {
+      bracketId,
+      roundNumber,
+      bracketSide,
+    });
+  },
+  async getKnockoutMatchGames(
+    bracketId: number,
+    roundNumber: number,
+    bracketSide: string | null
+  ): Promise<KnockoutMatchGame[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_knockout_match_games', {
+      bracketId,
+      roundNumber,
+      bracketSide,
+    });
+  },
+  async getKnockoutMatchStatuses(
+    bracketId: number,
+    roundNumber: number,
+    bracketSide: string | null
+  ): Promise<KnockoutMatchStatus[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_knockout_match_statuses', {
+      bracketId,
+      roundNumber,
+      bracketSide,
+    });
+  },
+  async recordKnockoutMatchGame(
+    gameId: number,
+    result: string
+  ): Promise<KnockoutMatchStatus> {
+    return await TAURI_INVOKE('plugin:pawn|record_knockout_match_game', {
+      gameId,
+      result,
+    });
+  },
+  async startKnockoutArmageddon(
+    data: StartArmageddon
+  ): Promise<KnockoutMatchGame> {
+    return await TAURI_INVOKE('plugin:pawn|start_knockout_armageddon', {
+      data,
+    });
+  },
+  async getArenaSettings(tournamentId: number): Promise<ArenaSettings | null> {
+    return await TAURI_INVOKE('plugin:pawn|get_arena_settings', {
+      tournamentId,
+    });
+  },
+  async updateArenaSettings(data: UpdateArenaSettings): Promise<ArenaSettings> {
+    return await TAURI_INVOKE('plugin:pawn|update_arena_settings', { data });
+  },
+  async getArenaWaitingPlayers(
+    tournamentId: number
+  ): Promise<ArenaWaitingPlayer[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_arena_waiting_players', {
+      tournamentId,
+    });
+  },
+  async joinArena(
+    tournamentId: number,
+    playerId: number
+  ): Promise<ArenaGame[]> {
+    return await TAURI_INVOKE('plugin:pawn|join_arena', {
+      tournamentId,
+      playerId,
+    });
+  },
+  async leaveArena(tournamentId: number, playerId: number): Promise<null> {
+    return await TAURI_INVOKE('plugin:pawn|leave_arena', {
+      tournamentId,
+      playerId,
+    });
+  },
+  async getArenaGames(tournamentId: number): Promise<ArenaGame[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_arena_games', { tournamentId });
+  },
+  async recordArenaResult(
+    data: RecordArenaResult
+  ): Promise<ArenaResultOutcome> {
+    return await TAURI_INVOKE('plugin:pawn|record_arena_result', { data });
+  },
+  async getArenaStandings(tournamentId: number): Promise<ArenaStanding[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_arena_standings', {
+      tournamentId,
+    });
+  },
+  async getTournamentStages(tournamentId: number): Promise<TournamentStage[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_tournament_stages', {
+      tournamentId,
+    });
+  },
+  async getTournamentStageGroups(
+    stageId: number
+  ): Promise<TournamentStageGroup[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_tournament_stage_groups', {
+      stageId,
+    });
+  },
+  async createTournamentStage(
+    data: CreateTournamentStage
+  ): Promise<TournamentStage> {
+    return await TAURI_INVOKE('plugin:pawn|create_tournament_stage', { data });
+  },
+  async previewStageQualifiers(
+    tournamentId: number,
+    stageNumber: number
+  ): Promise<StageQualifier[]> {
+    return await TAURI_INVOKE('plugin:pawn|preview_stage_qualifiers', {
+      tournamentId,
+      stageNumber,
+    });
+  },
+  async advanceTournamentStage(
+    tournamentId: number,
+    stageNumber: number
+  ): Promise<AdvanceTournamentStage> {
+    return await TAURI_INVOKE('plugin:pawn|advance_tournament_stage', {
+      tournamentId,
+      stageNumber,
+    });
+  },
+  async getEvents(): Promise<Event[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_events');
+  },
+  async getEvent(eventId: number): Promise<Event> {
+    return await TAURI_INVOKE('plugin:pawn|get_event', { eventId });
+  },
+  async createEvent(data: CreateEvent): Promise<Event> {
+    return await TAURI_INVOKE('plugin:pawn|create_event', { data });
+  },
+  async getEventSections(eventId: number): Promise<EventSection[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_event_sections', { eventId });
+  },
+  async addEventSection(data: CreateEventSection): Promise<EventSection> {
+    return await TAURI_INVOKE('plugin:pawn|add_event_section', { data });
+  },
+  async getEligibleEventSections(
+    eventId: number,
+    rating: number | null
+  ): Promise<EventSection[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_eligible_event_sections', {
+      eventId,
+      rating,
+    });
+  },
+  async registerEventPlayer(data: RegisterEventPlayer): Promise<EventEntry> {
+    return await TAURI_INVOKE('plugin:pawn|register_event_player', { data });
+  },
+  async getEventEntries(eventId: number): Promise<EventEntry[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_event_entries', { eventId });
+  },
+  async moveEventPlayer(data: MoveEventPlayer): Promise<EventEntry> {
+    return await TAURI_INVOKE('plugin:pawn|move_event_player', { data });
+  },
+  async getPersons(): Promise<Person[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_persons');
+  },
+  async searchPersons(query: string): Promise<Person[]> {
+    return await TAURI_INVOKE('plugin:pawn|search_persons', { query });
+  },
+  async getPerson(personId: number): Promise<Person> {
+    return await TAURI_INVOKE('plugin:pawn|get_person', { personId });
+  },
+  async createPerson(data: CreatePerson): Promise<Person> {
+    return await TAURI_INVOKE('plugin:pawn|create_person', { data });
+  },
+  async updatePerson(data: UpdatePerson): Promise<Person> {
+    return await TAURI_INVOKE('plugin:pawn|update_person', { data });
+  },
+  async registerPersonPlayer(data: RegisterPersonPlayer): Promise<Player> {
+    return await TAURI_INVOKE('plugin:pawn|register_person_player', { data });
+  },
+  async linkPlayerToPerson(
+    playerId: number,
+    personId: number | null
+  ): Promise<Player> {
+    return await TAURI_INVOKE('plugin:pawn|link_player_to_person', {
+      playerId,
+      personId,
+    });
+  },
+  async getPlayerPerson(playerId: number): Promise<Person | null> {
+    return await TAURI_INVOKE('plugin:pawn|get_player_person', { playerId });
+  },
+  async getPersonHistory(personId: number): Promise<PersonTournament[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_person_history', { personId });
+  },
+  async addPersonRating(data: CreatePersonRating): Promise<PersonRating> {
+    return await TAURI_INVOKE('plugin:pawn|add_person_rating', { data });
+  },
+  async getPersonRatingHistory(personId: number): Promise<PersonRating[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_person_rating_history', {
+      personId,
+    });
+  },
+  async importFideRatingList(
+    data: ImportFideRatingListRequest
+  ): Promise<FideRatingListImport> {
+    return await TAURI_INVOKE('plugin:pawn|import_fide_rating_list', { data });
+  },
+  async getFideRatingLists(): Promise<FideRatingList[]> {
+    return await TAURI_INVOKE('plugin:pawn|get_fide_rating_lists');
+  },
+  async searchFideRatings(
+    query: string,
+    listDate: string | null
+  ): Promise<FideRating[]> {
+    return await TAURI_INVOKE('plugin:pawn|search_fide_ratings', {
+      query,
+      listDate,
+    });
+  },
+  async registerFidePlayer(data: RegisterFidePlayer): Promise<Player> {
+    return await TAURI_INVOKE('plugin:pawn|register_fide_player', { data });
+  },
+  async refreshTournamentRatings(
+    tournamentId: number,
+    listDate: string | null
+  ): Promise<FideRatingRefresh> {
+    return await TAURI_INVOKE('plugin:pawn|refresh_tournament_ratings', {
+      tournamentId,
+      listDate,
+    });
+  },
+  async getRatingReport(tournamentId: number): Promise<RatingReport> {
+    return await TAURI_INVOKE('plugin:pawn|get_rating_report', {
+      tournamentId,
+    });
+  },
   async createTimeControl(data: CreateTimeControl): Promise<TimeControl> {
     return await TAURI_INVOKE('plugin:pawn|create_time_control', { data });
   },
@@ -1864,6 +2850,9 @@ export const commands = {
       request,
     });
   },
+  async exportEventData(request: EventExportRequest): Promise<ExportResult> {
+    return await TAURI_INVOKE('plugin:pawn|export_event_data', { request });
+  },
   async getExportDirectory(): Promise<string> {
     return await TAURI_INVOKE('plugin:pawn|get_export_directory');
   },
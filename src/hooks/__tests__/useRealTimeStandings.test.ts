@@ -71,6 +71,8 @@ const createMockStandingsResult = (
     organizer_name: null,
     organizer_email: null,
     prize_structure: null,
+    chief_arbiter: null,
+    deputy_arbiters: null,
   },
   ...overrides,
 });
@@ -141,6 +141,8 @@ export const useRealTimeStandings = ({
                   organizer_name: null,
                   organizer_email: null,
                   prize_structure: null,
+                  chief_arbiter: null,
+                  deputy_arbiters: null,
                 },
               };
 
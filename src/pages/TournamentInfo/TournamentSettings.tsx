@@ -9,6 +9,7 @@ import {
   Typography,
   CircularProgress,
   Alert,
+  TextField,
 } from '@mui/material';
 import { Settings as SettingsIcon } from '@mui/icons-material';
 import { useTranslation } from 'react-i18next';
@@ -78,6 +79,18 @@ const TournamentSettings: React.FC<TournamentSettingsProps> = ({
     }
   };
 
+  const handleArbiterChange =
+    (field: 'chief_arbiter' | 'deputy_arbiters') =>
+    (event: React.ChangeEvent<HTMLInputElement>) => {
+      if (settings) {
+        setSettings({
+          ...settings,
+          [field]: event.target.value || null,
+        });
+        setIsDirty(true);
+      }
+    };
+
   const handleSave = async () => {
     if (!settings) return;
 
@@ -88,17 +101,19 @@ const TournamentSettings: React.FC<TournamentSettingsProps> = ({
         tournament_id: tournamentId,
         tiebreak_order: settings.tiebreaks,
         use_fide_defaults: settings.use_fide_defaults,
-        forfeit_time_minutes: null,
-        draw_offers_allowed: null,
-        mobile_phone_policy: null,
-        default_color_allocation: null,
-        late_entry_allowed: null,
-        bye_assignment_rule: null,
-        arbiter_notes: null,
-        tournament_category: null,
-        organizer_name: null,
-        organizer_email: null,
-        prize_structure: null,
+        forfeit_time_minutes: settings.forfeit_time_minutes,
+        draw_offers_allowed: settings.draw_offers_allowed,
+        mobile_phone_policy: settings.mobile_phone_policy,
+        default_color_allocation: settings.default_color_allocation,
+        late_entry_allowed: settings.late_entry_allowed,
+        bye_assignment_rule: settings.bye_assignment_rule,
+        arbiter_notes: settings.arbiter_notes,
+        tournament_category: settings.tournament_category,
+        organizer_name: settings.organizer_name,
+        organizer_email: settings.organizer_email,
+        prize_structure: settings.prize_structure,
+        chief_arbiter: settings.chief_arbiter,
+        deputy_arbiters: settings.deputy_arbiters,
       });
       setIsDirty(false);
       if (onSettingsUpdated) {
@@ -173,6 +188,25 @@ const TournamentSettings: React.FC<TournamentSettingsProps> = ({
               useFideDefaults={settings.use_fide_defaults}
               onFideDefaultsChange={handleFideDefaultsChange}
             />
+            <Typography variant="h6" gutterBottom sx={{ mt: 4 }}>
+              {t('arbiters')}
+            </Typography>
+            <Box sx={{ display: 'flex', flexDirection: 'column', gap: 2 }}>
+              <TextField
+                label={t('mainArbiter')}
+                placeholder={t('enterArbiterName')}
+                value={settings.chief_arbiter ?? ''}
+                onChange={handleArbiterChange('chief_arbiter')}
+                fullWidth
+              />
+              <TextField
+                label={t('deputyArbiters')}
+                placeholder={t('enterDeputyArbiters')}
+                value={settings.deputy_arbiters ?? ''}
+                onChange={handleArbiterChange('deputy_arbiters')}
+                fullWidth
+              />
+            </Box>
           </Box>
         ) : null}
       </DialogContent>